@@ -0,0 +1,66 @@
+//! # Embedded Server
+//!
+//! Runs [`mhub_server::Server`] in-process on a loopback port so the UI can call the local API
+//! without any configuration. A loopback port is used rather than a Unix socket because the
+//! webview can only issue HTTP requests to TCP addresses.
+
+use anyhow::Context;
+use dioxus::prelude::*;
+use mhub::domain::config::ApiConfig;
+use mhub_server::Server;
+use std::net::{IpAddr, Ipv4Addr, TcpListener};
+use tracing::error;
+
+/// Location of the embedded API, available to components via `use_context::<LocalApi>()`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LocalApi {
+    /// Base URL without a trailing slash, e.g. `http://127.0.0.1:49152`.
+    pub base_url: String,
+}
+
+/// Reason the embedded server failed to start, rendered instead of the application root.
+#[derive(Debug, Clone)]
+pub(crate) struct StartupError(pub String);
+
+/// Builds the server on the global runtime and spawns it on a free loopback port.
+///
+/// The port is bound here and handed to the server as is, so no other process can take it in
+/// between. Binding and building are done before returning, so their failures (e.g., an
+/// unreachable database) are reported before the window opens. Errors raised later by the
+/// running server are only logged.
+pub(crate) fn start(mut config: ApiConfig) -> anyhow::Result<LocalApi> {
+    let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, 0))
+        .context("Failed to bind a loopback port for the embedded server")?;
+    listener.set_nonblocking(true).context("Failed to configure the embedded server socket")?;
+    let port = listener.local_addr()?.port();
+    config.server.address = IpAddr::V4(Ipv4Addr::LOCALHOST);
+    config.server.port = port;
+    config.server.unix_socket = None;
+    let scheme = if config.server.ssl.is_some() { "https" } else { "http" };
+
+    let runtime = mhub_runtime::get_global_runtime();
+    let server = runtime.block_on(Server::builder().config(config).build())?;
+    runtime.spawn(async move {
+        if let Err(e) = server.run_on(listener).await {
+            error!("Embedded server stopped: {e:#}");
+        }
+    });
+
+    Ok(LocalApi { base_url: format!("{scheme}://{}:{port}", Ipv4Addr::LOCALHOST) })
+}
+
+/// Error dialog shown when the embedded server cannot be started.
+pub(crate) fn startup_error_view() -> Element {
+    let StartupError(message) = use_context::<StartupError>();
+
+    rsx! {
+        div {
+            role: "alertdialog",
+            style: "font-family: sans-serif; max-width: 640px; margin: 15vh auto; padding: 24px; \
+                    border: 1px solid #d33; border-radius: 8px;",
+            h2 { "The local server failed to start" }
+            pre { style: "white-space: pre-wrap;", "{message}" }
+            p { "Check the configuration and restart the application." }
+        }
+    }
+}
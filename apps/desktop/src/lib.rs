@@ -1,16 +1,31 @@
+#[cfg(feature = "embedded-server")]
+mod embedded;
+
 use dioxus::desktop::{Config, WindowBuilder};
 use dioxus::prelude::*;
+#[cfg(feature = "embedded-server")]
+pub use embedded::LocalApi;
+#[cfg(feature = "embedded-server")]
+use mhub::domain::config::ApiConfig;
 
 #[derive(Debug)]
 pub struct DesktopApp {
     title: String,
     width: f64,
     height: f64,
+    #[cfg(feature = "embedded-server")]
+    embedded_server: Option<ApiConfig>,
 }
 
 impl Default for DesktopApp {
     fn default() -> Self {
-        Self { title: "MusterHub Desktop".to_owned(), width: 1200.0, height: 800.0 }
+        Self {
+            title: "MusterHub Desktop".to_owned(),
+            width: 1200.0,
+            height: 800.0,
+            #[cfg(feature = "embedded-server")]
+            embedded_server: None,
+        }
     }
 }
 
@@ -33,6 +48,19 @@ impl DesktopApp {
         self
     }
 
+    /// Runs `mhub_server::Server` in-process with `config` and exposes its address to the UI
+    /// as a [`LocalApi`] context.
+    ///
+    /// The server listens on a free loopback port, overriding `server.address`, `server.port`
+    /// and `server.unix_socket`. If it fails to start, an error dialog replaces the root
+    /// component.
+    #[cfg(feature = "embedded-server")]
+    #[must_use]
+    pub fn with_embedded_server(mut self, config: ApiConfig) -> Self {
+        self.embedded_server = Some(config);
+        self
+    }
+
     /// The entry point for launching the app
     pub fn launch(self, root: fn() -> Element) {
         let window = WindowBuilder::new().with_title(&self.title).with_inner_size(
@@ -43,6 +71,24 @@ impl DesktopApp {
             r#"<meta name="viewport" content="width=device-width, initial-scale=1.0">"#.into(),
         );
 
-        LaunchBuilder::desktop().with_cfg(cfg).with_context_provider(|| Box::new(())).launch(root);
+        let builder = LaunchBuilder::desktop().with_cfg(cfg);
+
+        #[cfg(feature = "embedded-server")]
+        if let Some(config) = self.embedded_server {
+            match embedded::start(config) {
+                Ok(api) => {
+                    builder.with_context_provider(move || Box::new(api.clone())).launch(root);
+                },
+                Err(e) => {
+                    let error = embedded::StartupError(format!("{e:#}"));
+                    builder
+                        .with_context_provider(move || Box::new(error.clone()))
+                        .launch(embedded::startup_error_view);
+                },
+            }
+            return;
+        }
+
+        builder.with_context_provider(|| Box::new(())).launch(root);
     }
 }
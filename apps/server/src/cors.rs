@@ -0,0 +1,68 @@
+//! # CORS
+//!
+//! Builds the [`CorsLayer`] from `server.cors`, rejecting policies browsers would refuse.
+
+use axum::http::{HeaderName, HeaderValue, Method};
+use mhub::domain::config::CorsConfig;
+use mhub::kernel::config::ConfigError;
+use std::str::FromStr;
+use tower_http::cors::{AllowHeaders, AllowMethods, AllowOrigin, CorsLayer};
+
+const WILDCARD: &str = "*";
+
+/// Builds a CORS layer from the configured allow-lists.
+///
+/// # Errors
+/// Returns [`ConfigError::Validation`] if an entry cannot be parsed, or if a wildcard is
+/// combined with `allow_credentials`, which browsers reject.
+pub fn layer(config: &CorsConfig) -> Result<CorsLayer, ConfigError> {
+    if config.allow_credentials {
+        let lists = [
+            ("allowed_origins", &config.allowed_origins),
+            ("allowed_methods", &config.allowed_methods),
+            ("allowed_headers", &config.allowed_headers),
+        ];
+        if let Some((name, _)) = lists.iter().find(|(_, list)| is_wildcard(list)) {
+            return Err(invalid(format!(
+                "`{name}` cannot be \"*\" when `allow_credentials` is enabled; list them explicitly"
+            )));
+        }
+    }
+
+    let origins = if is_wildcard(&config.allowed_origins) {
+        AllowOrigin::any()
+    } else {
+        AllowOrigin::list(parse_all::<HeaderValue>(&config.allowed_origins, "origin")?)
+    };
+    let methods = if is_wildcard(&config.allowed_methods) {
+        AllowMethods::any()
+    } else {
+        AllowMethods::list(parse_all::<Method>(&config.allowed_methods, "method")?)
+    };
+    let headers = if is_wildcard(&config.allowed_headers) {
+        AllowHeaders::any()
+    } else {
+        AllowHeaders::list(parse_all::<HeaderName>(&config.allowed_headers, "header")?)
+    };
+
+    Ok(CorsLayer::new()
+        .allow_origin(origins)
+        .allow_methods(methods)
+        .allow_headers(headers)
+        .allow_credentials(config.allow_credentials))
+}
+
+fn is_wildcard(list: &[String]) -> bool {
+    list.iter().any(|entry| entry == WILDCARD)
+}
+
+fn parse_all<T: FromStr>(entries: &[String], kind: &str) -> Result<Vec<T>, ConfigError> {
+    entries
+        .iter()
+        .map(|entry| entry.parse().map_err(|_| invalid(format!("Invalid CORS {kind} `{entry}`"))))
+        .collect()
+}
+
+fn invalid(message: String) -> ConfigError {
+    ConfigError::Validation { message: message.into(), context: Some("server.cors".into()) }
+}
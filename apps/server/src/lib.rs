@@ -18,29 +18,40 @@
 //! }
 //! ```
 
+pub mod cors;
 mod router;
+pub mod tls;
 
 use anyhow::{Context, Result, anyhow};
 use axum_server::Handle;
 use mhub::domain::config::ApiConfig;
-use mhub::kernel::server::ApiState;
+use mhub::kernel::server::{ApiState, InFlight};
 use mhub_database::Database;
 use mhub_event_bus::EventBus;
 use std::net::SocketAddr;
+use std::path::PathBuf;
+use std::time::Duration;
 use tokio::signal;
-use tracing::{error, info};
+use tracing::{error, info, warn};
+
+/// How often drain progress is logged during graceful shutdown.
+const DRAIN_LOG_INTERVAL: Duration = Duration::from_secs(1);
 
 /// A fluent builder for configuring and initializing the [`Server`].
 #[must_use = "builders do nothing unless you call .build()"]
 #[derive(Debug, Default)]
 pub struct ServerBuilder {
     cfg: ApiConfig,
+    shutdown_grace: Option<Duration>,
 }
 
 impl ServerBuilder {
     /// Set up the server's configuration.
+    ///
+    /// Replaces any grace period set earlier with [`shutdown_grace`](Self::shutdown_grace).
     pub fn config(mut self, cfg: ApiConfig) -> Self {
         self.cfg = cfg;
+        self.shutdown_grace = None;
         self
     }
 
@@ -49,6 +60,30 @@ impl ServerBuilder {
         self
     }
 
+    /// Serves on a Unix domain socket at `path` instead of TCP.
+    ///
+    /// TLS and Unix sockets are mutually exclusive; [`build`](Self::build) fails if both
+    /// are configured.
+    pub fn unix_socket(mut self, path: impl Into<PathBuf>) -> Self {
+        self.cfg.server.unix_socket = Some(path.into());
+        self
+    }
+
+    /// Sets how long in-flight requests may drain after a shutdown signal (30 seconds by
+    /// default). Requests still running when the window closes are forcibly dropped.
+    ///
+    /// Unlike `server.shutdown_grace_seconds`, sub-second periods are kept as given.
+    pub fn shutdown_grace(mut self, grace: Duration) -> Self {
+        self.shutdown_grace = Some(grace);
+        self
+    }
+
+    /// Returns the grace period the built server will give in-flight requests on shutdown.
+    #[must_use]
+    pub fn shutdown_grace_period(&self) -> Duration {
+        self.shutdown_grace.unwrap_or_else(|| self.cfg.server.shutdown_grace())
+    }
+
     async fn init_database(&self) -> Result<Database> {
         let db_cfg = &self.cfg.database;
         let mut builder =
@@ -61,6 +96,18 @@ impl ServerBuilder {
         builder.init().await.context("Failed to establish database connection")
     }
 
+    fn validate_unix_socket_config(&self) -> Result<()> {
+        if self.cfg.server.unix_socket.is_some() {
+            if self.cfg.server.ssl.is_some() {
+                anyhow::bail!("`server.ssl` and `server.unix_socket` are mutually exclusive");
+            }
+            if cfg!(not(unix)) {
+                anyhow::bail!("`server.unix_socket` is only supported on Unix platforms");
+            }
+        }
+        Ok(())
+    }
+
     fn validate_ssl_config(&self) -> Result<()> {
         if let Some(ssl) = &self.cfg.server.ssl {
             if !ssl.cert.exists() {
@@ -99,6 +146,7 @@ impl ServerBuilder {
     /// * Database connection fails (unreachable host, invalid credentials)
     /// * Working directory or public directory is invalid
     /// * SSL certificate/key files cannot be read
+    /// * Both SSL and a Unix socket are configured
     ///
     /// # Examples
     /// ```no_run
@@ -111,7 +159,8 @@ impl ServerBuilder {
     /// # }
     /// ```
     pub async fn build(self) -> Result<Server> {
-        // 1. Validate SSL Configuration
+        // 1. Validate Listener and SSL Configuration
+        self.validate_unix_socket_config()?;
         self.validate_ssl_config()?;
 
         let address = SocketAddr::new(self.cfg.server.address, self.cfg.server.port);
@@ -127,9 +176,11 @@ impl ServerBuilder {
         // 3. Orchestrate Feature Slices
         let events = EventBus::new();
         let slices = mhub::init(&self.cfg, &db, &events)
+            .await
             .map_err(|e| anyhow!("Platform bootstrap failed: {e}"))?;
 
         // 4. Construct State using Functional Folding
+        let grace = self.shutdown_grace_period();
         let state = slices
             .into_iter()
             .fold(ApiState::builder().config(self.cfg).db(db).events(events), |builder, slice| {
@@ -137,7 +188,7 @@ impl ServerBuilder {
             })
             .build()
             .context("Failed to finalize API state registry")?;
-        Ok(Server { state })
+        Ok(Server { state, grace })
     }
 }
 
@@ -149,6 +200,7 @@ impl ServerBuilder {
 #[derive(Debug)]
 pub struct Server {
     state: ApiState,
+    grace: Duration,
 }
 
 impl Server {
@@ -174,8 +226,17 @@ impl Server {
 
     /// Starts the server and runs until the shutdown signal is received.
     ///
+    /// On SIGINT/SIGTERM the server stops accepting connections and gives in-flight requests
+    /// the configured grace period (`server.shutdown_grace_seconds`, or
+    /// [`ServerBuilder::shutdown_grace`]) to finish; requests still running afterwards are
+    /// forcibly dropped. The number of requests still draining is logged every second.
+    ///
+    /// When `server.unix_socket` is set, the server listens there instead of on TCP. A stale
+    /// socket left by a previous run is removed first, and the new one is restricted to
+    /// owner and group (`0660`).
+    ///
     /// # Errors
-    /// Returns an error if the server fails to bind to the configured address
+    /// Returns an error if the server fails to bind to the configured address or socket,
     /// or if SSL/TLS setup fails.
     ///
     /// # Examples
@@ -190,16 +251,43 @@ impl Server {
     /// # }
     /// ```
     pub async fn run(self) -> Result<()> {
+        self.serve(None).await
+    }
+
+    /// Like [`run`](Self::run), but serves on an already bound TCP `listener` instead of
+    /// binding `server.address`, `server.port` or `server.unix_socket`.
+    ///
+    /// Binding up front lets callers pick a free port without releasing it in between, and
+    /// report bind failures before the server is spawned.
+    ///
+    /// # Errors
+    /// Returns an error if the listener cannot be adopted, or if SSL/TLS setup fails.
+    pub async fn run_on(self, listener: std::net::TcpListener) -> Result<()> {
+        self.serve(Some(listener)).await
+    }
+
+    async fn serve(self, listener: Option<std::net::TcpListener>) -> Result<()> {
         let cfg = self.state.config.clone();
-        let address = SocketAddr::new(cfg.server.address, cfg.server.port);
+        let address = match &listener {
+            Some(listener) => listener.local_addr().context("Failed to read listener address")?,
+            None => SocketAddr::new(cfg.server.address, cfg.server.port),
+        };
 
         info!(
             address = %address,
             ssl = cfg.server.ssl.is_some(),
+            unix_socket = ?cfg.server.unix_socket,
             "Starting server"
         );
 
-        let app = router::init(self.state);
+        let in_flight = self.state.in_flight.clone();
+        let app = router::init(self.state).context("Invalid router configuration")?;
+        let grace = self.grace;
+
+        #[cfg(unix)]
+        if let (None, Some(path)) = (&listener, &cfg.server.unix_socket) {
+            return unix::serve(app, path, grace, in_flight).await;
+        }
 
         // 2. Set up Graceful Shutdown
         let handle = Handle::<SocketAddr>::new();
@@ -211,8 +299,9 @@ impl Server {
                 error!("Error while waiting for shutdown signal: {e}");
                 return;
             }
-            info!("Shutdown signal received, starting graceful shutdown...");
-            shutdown_handle.graceful_shutdown(Some(std::time::Duration::from_secs(30)));
+            info!(?grace, "Shutdown signal received, starting graceful shutdown...");
+            shutdown_handle.graceful_shutdown(Some(grace));
+            log_drain(in_flight, grace).await;
         });
 
         // 3. Start Server (HTTP or HTTPS)
@@ -227,18 +316,35 @@ impl Server {
             .await
             .context("Failed to load SSL/TLS certificates")?;
 
-            axum_server::bind_rustls(address, tls_config)
+            let reloader =
+                ssl_config.watch.then(|| tls::spawn_reloader(tls_config.clone(), ssl_config));
+
+            let server = match listener {
+                Some(listener) => axum_server::from_tcp_rustls(listener, tls_config)
+                    .context("Failed to adopt listener")?,
+                None => axum_server::bind_rustls(address, tls_config),
+            };
+            let served = server
                 .handle(handle)
-                .serve(app.into_make_service())
-                .await
-                .context("HTTPS server failed")?;
+                .serve(app.into_make_service_with_connect_info::<SocketAddr>())
+                .await;
+            if let Some(reloader) = reloader {
+                reloader.abort();
+            }
+            served.context("HTTPS server failed")?;
         } else {
             // HTTP mode
             info!("Starting HTTP server on http://{address}");
 
-            axum_server::bind(address)
+            let server = match listener {
+                Some(listener) => {
+                    axum_server::from_tcp(listener).context("Failed to adopt listener")?
+                },
+                None => axum_server::bind(address),
+            };
+            server
                 .handle(handle)
-                .serve(app.into_make_service())
+                .serve(app.into_make_service_with_connect_info::<SocketAddr>())
                 .await
                 .context("HTTP server failed")?;
         }
@@ -254,6 +360,135 @@ impl Server {
     }
 }
 
+#[cfg(unix)]
+mod unix {
+    use super::{log_drain, shutdown_signal};
+    use anyhow::{Context, Result};
+    use axum::Router;
+    use mhub::kernel::server::InFlight;
+    use std::fs::{self, DirBuilder, Permissions};
+    use std::future::IntoFuture;
+    use std::io;
+    use std::os::unix::fs::{DirBuilderExt, FileTypeExt, PermissionsExt};
+    use std::path::Path;
+    use std::sync::Arc;
+    use std::time::Duration;
+    use tokio::net::UnixListener;
+    use tokio::sync::Notify;
+    use tracing::{error, info, warn};
+
+    const SOCKET_MODE: u32 = 0o660;
+
+    /// Serves `app` on a Unix domain socket until a shutdown signal arrives, then drains
+    /// in-flight requests for at most `grace`.
+    pub(super) async fn serve(
+        app: Router,
+        path: &Path,
+        grace: Duration,
+        in_flight: InFlight,
+    ) -> Result<()> {
+        remove_stale_socket(path)?;
+        let listener = bind_private(path)?;
+
+        info!("Starting HTTP server on unix:{}", path.display());
+
+        let shutdown = Arc::new(Notify::new());
+        let trigger = Arc::clone(&shutdown);
+        let server = axum::serve(listener, app.into_make_service())
+            .with_graceful_shutdown(async move { trigger.notified().await })
+            .into_future();
+        tokio::pin!(server);
+
+        tokio::select! {
+            res = &mut server => res.context("HTTP server failed")?,
+            res = shutdown_signal() => {
+                if let Err(e) = res {
+                    error!("Error while waiting for shutdown signal: {e}");
+                    server.await.context("HTTP server failed")?;
+                } else {
+                    info!(?grace, "Shutdown signal received, starting graceful shutdown...");
+                    shutdown.notify_one();
+                    tokio::spawn(log_drain(in_flight, grace));
+                    match tokio::time::timeout(grace, server).await {
+                        Ok(res) => res.context("HTTP server failed")?,
+                        Err(_) => warn!("Shutdown grace period elapsed, dropping in-flight requests"),
+                    }
+                }
+            },
+        }
+
+        if let Err(e) = fs::remove_file(path) {
+            warn!("Failed to remove Unix socket {}: {e}", path.display());
+        }
+        info!("Server shutdown complete");
+        Ok(())
+    }
+
+    /// Binds a socket at `path` that no other user can connect to before its mode is set.
+    ///
+    /// The socket is created inside a fresh `0700` directory next to `path`, restricted to
+    /// [`SOCKET_MODE`] there, and only then renamed into place.
+    fn bind_private(path: &Path) -> Result<UnixListener> {
+        let parent = path.parent().filter(|p| !p.as_os_str().is_empty()).unwrap_or(Path::new("."));
+        let name = path.file_name().unwrap_or_default().to_string_lossy();
+        let staging = parent.join(format!(".{name}.{}.bind", std::process::id()));
+        DirBuilder::new()
+            .mode(0o700)
+            .create(&staging)
+            .with_context(|| format!("Failed to create {}", staging.display()))?;
+
+        let staged = staging.join("socket");
+        let bound = UnixListener::bind(&staged)
+            .with_context(|| format!("Failed to bind Unix socket at {}", path.display()))
+            .and_then(|listener| {
+                fs::set_permissions(&staged, Permissions::from_mode(SOCKET_MODE)).with_context(
+                    || format!("Failed to restrict permissions of {}", path.display()),
+                )?;
+                fs::rename(&staged, path)
+                    .with_context(|| format!("Failed to move socket to {}", path.display()))?;
+                Ok(listener)
+            });
+
+        if bound.is_err() {
+            let _ = fs::remove_file(&staged);
+        }
+        if let Err(e) = fs::remove_dir(&staging) {
+            warn!("Failed to remove {}: {e}", staging.display());
+        }
+        bound
+    }
+
+    /// Unlinks a socket left behind by a previous run; any other file type is left alone.
+    fn remove_stale_socket(path: &Path) -> Result<()> {
+        match fs::symlink_metadata(path) {
+            Ok(meta) if meta.file_type().is_socket() => fs::remove_file(path)
+                .with_context(|| format!("Failed to remove stale socket {}", path.display())),
+            Ok(_) => anyhow::bail!("Refusing to replace non-socket file at {}", path.display()),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(()),
+            Err(e) => Err(e).with_context(|| format!("Failed to inspect {}", path.display())),
+        }
+    }
+}
+
+/// Logs how many requests are still in flight until they drain or `grace` runs out.
+async fn log_drain(in_flight: InFlight, grace: Duration) {
+    let deadline = tokio::time::Instant::now() + grace;
+    let mut ticker = tokio::time::interval(DRAIN_LOG_INTERVAL);
+    loop {
+        ticker.tick().await;
+        let remaining = in_flight.count();
+        if remaining == 0 {
+            info!("All in-flight requests drained");
+            return;
+        }
+        if tokio::time::Instant::now() >= deadline {
+            warn!(in_flight = remaining, "Grace period elapsed with requests still in flight");
+            return;
+        }
+        info!(in_flight = remaining, "Draining in-flight requests...");
+    }
+}
+
 /// Listens for shutdown signals (Ctrl+C, SIGTERM).
 ///
 /// This function waits for either:
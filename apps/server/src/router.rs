@@ -1,5 +1,7 @@
+use crate::cors;
 use axum::Router;
-use mhub::kernel::prelude::ApiState;
+use mhub::kernel::config::ConfigError;
+use mhub::kernel::prelude::{ApiState, RateLimiter, rate_limit, request_id, track_in_flight};
 use tower_http::trace::TraceLayer;
 use utoipa::OpenApi;
 use utoipa_axum::router::OpenApiRouter;
@@ -9,13 +11,22 @@ use utoipa_scalar::{Scalar, Servable};
 struct ApiDoc;
 
 #[allow(unreachable_pub)]
-pub fn init(state: ApiState) -> Router {
+pub fn init(state: ApiState) -> Result<Router, ConfigError> {
     let api = ApiDoc::openapi();
+    let cors = state.config.server.cors.as_ref().map(cors::layer).transpose()?;
+    let in_flight = state.in_flight.clone();
+    let server = &state.config.server;
+    let limiter = RateLimiter::new(&server.rate_limits)
+        .trusted_proxies(server.trusted_proxies.iter().copied())
+        .trust_unix_peer(server.unix_socket.is_some());
 
     // Separate the OpenAPI routes and the API documentation object
     let (openapi_routes, api_doc) = OpenApiRouter::with_openapi(api)
         .merge(mhub::server::router::system_router())
+        .merge(mhub::server::router::probe_router())
         .layer(TraceLayer::new_for_http())
+        .layer(axum::middleware::from_fn(request_id))
+        .layer(axum::middleware::from_fn_with_state(in_flight, track_in_flight))
         .with_state(state)
         .split_for_parts();
 
@@ -23,5 +34,12 @@ pub fn init(state: ApiState) -> Router {
     let scalar_routes = Scalar::with_url("/api", api_doc);
 
     // Merge all routes and then apply the state to the final router
-    Router::new().merge(openapi_routes).merge(scalar_routes)
+    let mut router = Router::new().merge(openapi_routes).merge(scalar_routes);
+    if !limiter.is_empty() {
+        router = router.layer(axum::middleware::from_fn_with_state(limiter, rate_limit));
+    }
+    Ok(match cors {
+        Some(cors) => router.layer(cors),
+        None => router,
+    })
 }
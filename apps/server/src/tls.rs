@@ -0,0 +1,132 @@
+//! # TLS Hot-Reload
+//!
+//! Swaps the served certificate in place when `server.ssl.watch` is enabled, so renewals
+//! (e.g., Let's Encrypt) take effect without dropping connections.
+
+use axum_server::tls_rustls::RustlsConfig;
+use mhub::domain::config::SslConfig;
+use std::future::Future;
+use std::io;
+use std::path::Path;
+use std::time::{Duration, SystemTime};
+use tokio::task::JoinHandle;
+use tracing::{info, warn};
+
+/// How often the certificate and key files are checked for changes.
+pub const POLL_INTERVAL: Duration = Duration::from_secs(10);
+
+/// How long a failed reload after a file change waits for the rest of the pair to land before
+/// it is retried, capped at the poll interval.
+pub const SETTLE_DELAY: Duration = Duration::from_secs(1);
+
+/// Spawns a task reloading `config` from the configured PEM files whenever they change or the
+/// process receives `SIGHUP`.
+///
+/// The new pair is parsed before it replaces the current one, so a half-written or invalid
+/// renewal is logged and the previous certificate keeps being served. The returned handle
+/// must be aborted on shutdown.
+pub fn spawn_reloader(config: RustlsConfig, ssl: &SslConfig) -> JoinHandle<()> {
+    let (cert, key) = (ssl.cert.clone(), ssl.key.clone());
+    tokio::spawn(async move {
+        watch(&cert, &key, POLL_INTERVAL, || config.reload_from_pem_file(&cert, &key)).await;
+    })
+}
+
+/// Polls `cert` and `key` every `interval` and calls `reload` when either file's size or
+/// modification time changes, or when `SIGHUP` is received on Unix. Runs until the task is
+/// dropped.
+///
+/// Renewals usually write the certificate and the key one after the other, so a reload
+/// triggered between the two writes sees a mismatched pair. A failed reload after a file
+/// change is therefore retried once after [`SETTLE_DELAY`] before it is reported.
+pub async fn watch<F, Fut>(cert: &Path, key: &Path, interval: Duration, mut reload: F)
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = io::Result<()>>,
+{
+    let mut hangup = hangup_signal();
+    let mut ticker = tokio::time::interval(interval);
+    let mut last = fingerprint(cert, key);
+
+    loop {
+        let trigger = tokio::select! {
+            _ = ticker.tick() => {
+                let current = fingerprint(cert, key);
+                if current == last {
+                    continue;
+                }
+                last = current;
+                "file change"
+            },
+            () = hangup.recv() => "SIGHUP",
+        };
+
+        let mut result = reload().await;
+        if result.is_err() && trigger == "file change" {
+            tokio::time::sleep(SETTLE_DELAY.min(interval)).await;
+            last = fingerprint(cert, key);
+            result = reload().await;
+        }
+
+        match result {
+            Ok(()) => info!(%trigger, cert = %cert.display(), "TLS certificate reloaded"),
+            Err(e) => warn!(
+                %trigger,
+                cert = %cert.display(),
+                error = %e,
+                "TLS certificate reload failed, keeping the current certificate"
+            ),
+        }
+    }
+}
+
+type Fingerprint = Option<[(SystemTime, u64); 2]>;
+
+fn fingerprint(cert: &Path, key: &Path) -> Fingerprint {
+    let stamp = |path: &Path| {
+        let meta = path.metadata().ok()?;
+        Some((meta.modified().ok()?, meta.len()))
+    };
+    Some([stamp(cert)?, stamp(key)?])
+}
+
+#[cfg(unix)]
+struct Hangup(Option<tokio::signal::unix::Signal>);
+
+#[cfg(unix)]
+impl Hangup {
+    async fn recv(&mut self) {
+        if let Some(signal) = &mut self.0
+            && signal.recv().await.is_some()
+        {
+            return;
+        }
+        std::future::pending().await
+    }
+}
+
+#[cfg(unix)]
+fn hangup_signal() -> Hangup {
+    use tokio::signal::unix::{SignalKind, signal};
+
+    Hangup(
+        signal(SignalKind::hangup())
+            .inspect_err(|e| warn!(error = %e, "Failed to install SIGHUP handler"))
+            .ok(),
+    )
+}
+
+#[cfg(not(unix))]
+struct Hangup;
+
+#[cfg(not(unix))]
+impl Hangup {
+    async fn recv(&mut self) {
+        std::future::pending().await
+    }
+}
+
+#[cfg(not(unix))]
+const fn hangup_signal() -> Hangup {
+    Hangup
+}
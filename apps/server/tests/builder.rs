@@ -0,0 +1,23 @@
+use mhub::domain::config::ApiConfig;
+use mhub_server::Server;
+use std::time::Duration;
+
+#[test]
+fn shutdown_grace_defaults_to_config() {
+    assert_eq!(Server::builder().shutdown_grace_period(), Duration::from_secs(30));
+}
+
+#[test]
+fn shutdown_grace_keeps_sub_second_precision() {
+    let builder = Server::builder().shutdown_grace(Duration::from_millis(500));
+    assert_eq!(builder.shutdown_grace_period(), Duration::from_millis(500));
+}
+
+#[test]
+fn config_replaces_earlier_shutdown_grace() {
+    let mut cfg = ApiConfig::default();
+    cfg.server.shutdown_grace_seconds = 5;
+
+    let builder = Server::builder().shutdown_grace(Duration::from_millis(500)).config(cfg);
+    assert_eq!(builder.shutdown_grace_period(), Duration::from_secs(5));
+}
@@ -0,0 +1,54 @@
+use axum::Router;
+use axum::body::Body;
+use axum::http::{Request, header};
+use axum::routing::get;
+use mhub::domain::config::CorsConfig;
+use mhub_server::cors;
+use tower::ServiceExt;
+
+const ALLOWED: &str = "https://app.example.com";
+
+fn config(origins: &[&str], allow_credentials: bool) -> CorsConfig {
+    CorsConfig {
+        allowed_origins: origins.iter().map(|&origin| origin.to_owned()).collect(),
+        allowed_methods: vec!["GET".to_owned()],
+        allowed_headers: vec!["content-type".to_owned()],
+        allow_credentials,
+    }
+}
+
+async fn allow_origin_for(origin: &str) -> Option<String> {
+    let layer = cors::layer(&config(&[ALLOWED], true)).expect("valid CORS config");
+    let app = Router::new().route("/", get(|| async { "ok" })).layer(layer);
+
+    let request = Request::get("/").header(header::ORIGIN, origin).body(Body::empty()).unwrap();
+    let response = app.oneshot(request).await.expect("request");
+    response
+        .headers()
+        .get(header::ACCESS_CONTROL_ALLOW_ORIGIN)
+        .map(|value| value.to_str().unwrap().to_owned())
+}
+
+#[tokio::test]
+async fn permitted_origin_is_echoed() {
+    assert_eq!(allow_origin_for(ALLOWED).await.as_deref(), Some(ALLOWED));
+}
+
+#[tokio::test]
+async fn unknown_origin_gets_no_cors_headers() {
+    assert_eq!(allow_origin_for("https://evil.example.com").await, None);
+}
+
+#[test]
+fn wildcard_with_credentials_is_rejected() {
+    assert!(cors::layer(&config(&["*"], true)).is_err());
+    assert!(cors::layer(&config(&["*"], false)).is_ok());
+}
+
+#[test]
+fn invalid_method_is_rejected() {
+    let mut config = config(&[ALLOWED], false);
+    config.allowed_methods = vec!["NOT A METHOD".to_owned()];
+
+    assert!(cors::layer(&config).is_err());
+}
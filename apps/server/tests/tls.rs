@@ -0,0 +1,90 @@
+use mhub_server::tls;
+use std::fs::File;
+use std::io::Write;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, SystemTime};
+
+#[tokio::test]
+async fn file_change_triggers_reload_attempt() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let (cert, key) = (dir.path().join("cert.pem"), dir.path().join("key.pem"));
+    std::fs::write(&cert, "old cert").unwrap();
+    std::fs::write(&key, "old key").unwrap();
+
+    let attempts = Arc::new(AtomicUsize::new(0));
+    let counter = Arc::clone(&attempts);
+    let watcher = {
+        let (cert, key) = (cert.clone(), key.clone());
+        tokio::spawn(async move {
+            tls::watch(&cert, &key, Duration::from_millis(20), || {
+                counter.fetch_add(1, Ordering::SeqCst);
+                async { Ok(()) }
+            })
+            .await;
+        })
+    };
+
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    assert_eq!(attempts.load(Ordering::SeqCst), 0, "unchanged files must not reload");
+
+    let mut file = File::options().append(true).open(&cert).unwrap();
+    file.write_all(b" renewed").unwrap();
+    file.set_modified(SystemTime::now() + Duration::from_secs(1)).unwrap();
+    drop(file);
+
+    tokio::time::timeout(Duration::from_secs(5), async {
+        while attempts.load(Ordering::SeqCst) == 0 {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+    })
+    .await
+    .expect("reload attempted after the certificate changed");
+
+    watcher.abort();
+}
+
+#[tokio::test]
+async fn failed_reload_after_change_is_retried_once() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let (cert, key) = (dir.path().join("cert.pem"), dir.path().join("key.pem"));
+    std::fs::write(&cert, "old cert").unwrap();
+    std::fs::write(&key, "old key").unwrap();
+
+    let attempts = Arc::new(AtomicUsize::new(0));
+    let counter = Arc::clone(&attempts);
+    let watcher = {
+        let (cert, key) = (cert.clone(), key.clone());
+        tokio::spawn(async move {
+            tls::watch(&cert, &key, Duration::from_millis(20), || {
+                let attempt = counter.fetch_add(1, Ordering::SeqCst);
+                async move {
+                    if attempt == 0 {
+                        Err(std::io::Error::other("key not written yet"))
+                    } else {
+                        Ok(())
+                    }
+                }
+            })
+            .await;
+        })
+    };
+    tokio::time::sleep(Duration::from_millis(100)).await;
+
+    let file = File::options().append(true).open(&cert).unwrap();
+    file.set_modified(SystemTime::now() + Duration::from_secs(1)).unwrap();
+    drop(file);
+
+    tokio::time::timeout(Duration::from_secs(5), async {
+        while attempts.load(Ordering::SeqCst) < 2 {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+    })
+    .await
+    .expect("failed reload retried");
+
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    assert_eq!(attempts.load(Ordering::SeqCst), 2, "a settled pair must not reload again");
+
+    watcher.abort();
+}
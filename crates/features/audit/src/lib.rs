@@ -2,7 +2,10 @@
 mod error;
 
 pub use crate::error::{AuditError, AuditErrorExt};
-use mhub_kernel::domain::registry::InitializedSlice;
+#[cfg(feature = "server")]
+use mhub_kernel::domain::registry::SliceBootstrap;
+#[cfg(feature = "server")]
+use mhub_kernel::server::SliceContext;
 
 /// Audit feature inner state.
 #[mhub_derive::mhub_slice]
@@ -10,14 +13,15 @@ pub struct Audit {}
 
 /// Initialize the audit feature.
 ///
-/// # Errors
-///
+/// Awaited by the host through `InitializedSlice::init_async::<Audit, _>`; extend it to wire
+/// the trail repository.
 #[cfg(feature = "server")]
-pub fn init() -> Result<InitializedSlice, AuditError> {
-    tracing::info!("Audit slice initialized");
+impl SliceBootstrap<SliceContext> for AuditInner {
+    type Error = AuditError;
 
-    let inner = AuditInner {};
+    async fn bootstrap(_context: &SliceContext) -> Result<Self, AuditError> {
+        tracing::info!("Audit slice initialized");
 
-    let slice = Audit::new(inner);
-    Ok(InitializedSlice::new(slice))
+        Ok(Self {})
+    }
 }
@@ -0,0 +1,147 @@
+//! # Offline Activation
+//!
+//! Challenge-response activation for air-gapped installations:
+//!
+//! 1. The client creates an [`ActivationRequest`] with [`ActivationRequest::new`]: its
+//!    compound machine id plus a fresh random nonce. It keeps the request and hands the JSON
+//!    to the vendor out of band (file, QR code, support ticket).
+//! 2. The vendor signs it with `generator::issue_activation` (`issuance` feature), producing
+//!    an [`ActivationToken`] that embeds both the machine id and the nonce.
+//! 3. The client checks [`ActivationToken::answers`] against its pending request, then calls
+//!    [`validator::verify_activation`](crate::validator::verify_activation), which verifies the
+//!    signature and that the token was issued for this machine, and caches the token.
+//!
+//! Because the nonce and machine id are covered by the signature, a token cannot be replayed
+//! on another machine or substituted for a different request.
+
+use crate::constraints::{HwidProfile, generate_machine_id_compound_v2};
+use crate::error::{LicenseError, LicenseErrorExt};
+use crate::{bytes_as_base64, tagged_bytes};
+use ed25519_dalek::{Signature, Verifier, VerifyingKey};
+use serde::{Deserialize, Serialize};
+
+/// Domain-separation tag prefixed to the signed bytes of an [`ActivationData`].
+const ACTIVATION_TAG: &[u8] = b"mhub-licensing/activation/v1";
+
+/// Length of the random nonce in an [`ActivationRequest`].
+pub const NONCE_LEN: usize = 32;
+
+/// A client's request for an activation token.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct ActivationRequest {
+    /// Compound machine id of the requesting machine.
+    pub machine_id: String,
+    /// Random challenge, encoded as a Base64 string in JSON.
+    #[serde(with = "bytes_as_base64")]
+    pub nonce: Vec<u8>,
+}
+
+impl ActivationRequest {
+    /// Creates a request for the current machine with a fresh random nonce.
+    ///
+    /// # Errors
+    /// Returns [`LicenseError::MachineIDGeneration`] if the machine id cannot be derived, or
+    /// [`LicenseError::Internal`] if the system RNG fails.
+    pub fn new() -> Result<Self, LicenseError> {
+        let mut nonce = vec![0u8; NONCE_LEN];
+        getrandom::fill(&mut nonce).map_err(|e| LicenseError::Internal {
+            message: e.to_string().into(),
+            context: Some("Failed to generate activation nonce".into()),
+        })?;
+
+        Ok(Self { machine_id: generate_machine_id_compound_v2(&HwidProfile::extended())?, nonce })
+    }
+
+    /// Serializes the request into a JSON string.
+    ///
+    /// # Errors
+    /// Returns [`LicenseError::SerdeSerialize`] if serialization fails.
+    pub fn to_json(&self) -> Result<String, LicenseError> {
+        serde_json::to_string(self).map_err(LicenseError::from)
+    }
+
+    /// Deserializes a request from a JSON string.
+    ///
+    /// # Errors
+    /// Returns [`LicenseError::SerdeSerialize`] if the JSON is malformed.
+    pub fn from_json(json: &str) -> Result<Self, LicenseError> {
+        serde_json::from_str(json).map_err(LicenseError::from)
+    }
+}
+
+/// The signed payload of an [`ActivationToken`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct ActivationData {
+    /// Compound machine id copied from the request.
+    pub machine_id: String,
+    /// Nonce copied from the request.
+    #[serde(with = "bytes_as_base64")]
+    pub nonce: Vec<u8>,
+    /// UNIX timestamp when the token was issued.
+    pub issued: i64,
+}
+
+impl ActivationData {
+    /// Returns the bytes covered by the activation token signature: a per-type
+    /// domain-separation tag followed by the Postcard encoding.
+    ///
+    /// # Errors
+    /// Returns [`LicenseError::PostcardSerialize`] if serialization fails.
+    pub fn signing_bytes(&self) -> Result<Vec<u8>, LicenseError> {
+        tagged_bytes(ACTIVATION_TAG, self)
+    }
+}
+
+/// A vendor-signed answer to an [`ActivationRequest`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ActivationToken {
+    /// The activated machine and the request it answers.
+    pub data: ActivationData,
+    /// The Ed25519 signature of the `data` field, encoded as a Base64 string in JSON.
+    #[serde(with = "bytes_as_base64")]
+    pub signature: Vec<u8>,
+}
+
+impl ActivationToken {
+    /// Serializes the token into a JSON string.
+    ///
+    /// # Errors
+    /// Returns [`LicenseError::SerdeSerialize`] if serialization fails.
+    pub fn to_json(&self) -> Result<String, LicenseError> {
+        serde_json::to_string(self).map_err(LicenseError::from)
+    }
+
+    /// Deserializes a token from a JSON string.
+    ///
+    /// # Errors
+    /// Returns [`LicenseError::SerdeSerialize`] if the JSON is malformed or contains invalid
+    /// Base64 data.
+    pub fn from_json(json: &str) -> Result<Self, LicenseError> {
+        serde_json::from_str(json).map_err(LicenseError::from)
+    }
+
+    /// Returns `true` if this token was issued for `request`.
+    #[must_use]
+    pub fn answers(&self, request: &ActivationRequest) -> bool {
+        self.data.machine_id == request.machine_id && self.data.nonce == request.nonce
+    }
+
+    /// Verifies that the token was signed by the holder of the master key.
+    ///
+    /// This does not check the machine; see
+    /// [`validator::verify_activation`](crate::validator::verify_activation).
+    ///
+    /// # Errors
+    /// Returns [`LicenseError::InvalidSignature`] if the token has been tampered with or was
+    /// signed by another key.
+    pub fn verify(&self, public_key: &[u8; 32]) -> Result<(), LicenseError> {
+        let verifying_key = VerifyingKey::from_bytes(public_key)?;
+        let signature = Signature::from_slice(&self.signature)?;
+        let data_bytes =
+            self.data.signing_bytes().context("Activation token serialization failed")?;
+        verifying_key.verify(&data_bytes, &signature)?;
+        Ok(())
+    }
+}
@@ -6,14 +6,15 @@
 //! may invalidate an otherwise legitimate license. To support **fuzzy matching**, we derive multiple
 //! independent fingerprints and require a **threshold** of matches.
 //!
-//! This implementation generates **three** component fingerprints:
+//! By default this implementation generates **three** component fingerprints:
 //!
 //! - **CPU ID** (`CPUID`)
 //! - **MAC Address** (`MacAddress`)
 //! - **System ID** (`SystemID`)
 //!
 //! Each component fingerprint is derived deterministically using `machineid_rs` with `SHA256`,
-//! salted by a constant `KEY`.
+//! salted by a constant `KEY`. A [`HwidProfile`] selects a different set of components (e.g.,
+//! disk + system for VMs with unstable MACs) and may override the salt.
 //!
 //! ## Data Format (Single String Encoding)
 //!
@@ -28,9 +29,19 @@
 //! - The `v1:` prefix allows future format upgrades (`v2:...`) without breaking old licenses.
 //! - `|` is used as a separator because it does not appear in typical hex/base16 digests.
 //!
+//! The `v2:` format labels each component and adds optional ones (disk serial, motherboard
+//! UUID):
+//!
+//! ```text
+//! v2:cpuid=<cpuid>;mac=<mac>;system=<system_id>;disk=<disk>;board=<board_uuid>
+//! ```
+//!
+//! Both formats are accepted in allowed-lists and compared by component label, so licenses
+//! bound with `v1:` ids keep working on machines that now report `v2:` components.
+//!
 //! ## Parsing
 //!
-//! Use [`parse_machine_id_compound`] to convert a compound id back into the 3 part ids.
+//! Use [`parse_machine_id_compound`] to convert a compound id back into labeled components.
 //! This is intended for:
 //!
 //! - Verifying a license on the client/server,
@@ -47,10 +58,10 @@
 //!
 //! Verification strategy:
 //!
-//! 1. Compute current machine components: `current_machine_components()` -> `[CpuId, Mac, System]`
+//! 1. Compute current machine components: `current_machine_components(&profile)` -> labeled components
 //! 2. For each allowed compound ID in `ids`:
-//!    - parse it into 3 parts.
-//!    - compute intersection count with current components.
+//!    - parse it into labeled components.
+//!    - count components whose fingerprint matches the current one with the same label.
 //!    - Accept if `matches >= min_matches`.
 //!
 //! Example thresholds:
@@ -81,6 +92,8 @@
 
 use crate::error::LicenseError;
 use machineid_rs::{Encryption, HWIDComponent, IdBuilder};
+use parking_lot::Mutex;
+use std::borrow::Cow;
 
 /// Constant salt used to derive deterministic machine fingerprints.
 ///
@@ -99,12 +112,144 @@ const SEP: char = '|';
 /// Encoding prefix to support forward-compatible format upgrades.
 const PREFIX: &str = "v1:";
 
+/// Prefix of the labeled compound machine id format.
+const PREFIX_V2: &str = "v2:";
+
+/// Separator between labeled components in the `v2:` format.
+const V2_SEP: char = ';';
+
+/// Separator between a component label and its fingerprint in the `v2:` format.
+const ASSIGN: char = '=';
+
+/// Motherboard UUID exposed by the kernel's DMI interface.
+#[cfg(target_os = "linux")]
+const BOARD_UUID_PATH: &str = "/sys/class/dmi/id/product_uuid";
+
+/// A hardware component that can contribute to a compound machine id.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MachineComponent {
+    /// Processor identification (`HWIDComponent::CPUID`).
+    CpuId,
+    /// Primary network adapter (`HWIDComponent::MacAddress`).
+    MacAddress,
+    /// OS-level machine id (`HWIDComponent::SystemID`).
+    SystemId,
+    /// System drive serial (`HWIDComponent::DriveSerial`). Optional.
+    DriveSerial,
+    /// Motherboard UUID (Linux DMI only). Optional.
+    BoardUuid,
+}
+
+impl MachineComponent {
+    /// Label used for this component in the `v2:` format.
+    #[must_use]
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::CpuId => "cpuid",
+            Self::MacAddress => "mac",
+            Self::SystemId => "system",
+            Self::DriveSerial => "disk",
+            Self::BoardUuid => "board",
+        }
+    }
+
+    /// Optional components are omitted from a `v2:` id when they cannot be read, instead of
+    /// failing the whole id.
+    #[must_use]
+    pub const fn is_optional(self) -> bool {
+        matches!(self, Self::DriveSerial | Self::BoardUuid)
+    }
+
+    const fn hwid(self) -> Option<HWIDComponent> {
+        match self {
+            Self::CpuId => Some(HWIDComponent::CPUID),
+            Self::MacAddress => Some(HWIDComponent::MacAddress),
+            Self::SystemId => Some(HWIDComponent::SystemID),
+            Self::DriveSerial => Some(HWIDComponent::DriveSerial),
+            #[cfg(target_os = "linux")]
+            Self::BoardUuid => Some(HWIDComponent::FileToken(BOARD_UUID_PATH)),
+            #[cfg(not(target_os = "linux"))]
+            Self::BoardUuid => None,
+        }
+    }
+}
+
+/// Which components make up a machine id, and the salt their fingerprints are derived with.
+///
+/// The default profile is the original `CPUID + MAC + SystemID` set with the built-in salt,
+/// which keeps existing `v1:` bindings valid. Deployments where a component is unstable
+/// (e.g., MAC addresses in VMs) can choose another set; those ids use the `v2:` format.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HwidProfile {
+    components: Vec<MachineComponent>,
+    salt: Cow<'static, str>,
+}
+
+impl Default for HwidProfile {
+    fn default() -> Self {
+        Self::new([
+            MachineComponent::CpuId,
+            MachineComponent::MacAddress,
+            MachineComponent::SystemId,
+        ])
+    }
+}
+
+impl HwidProfile {
+    /// Creates a profile from the given components (duplicates are ignored) with the built-in
+    /// salt.
+    #[must_use]
+    pub fn new(components: impl IntoIterator<Item = MachineComponent>) -> Self {
+        let mut unique = Vec::new();
+        for component in components {
+            if !unique.contains(&component) {
+                unique.push(component);
+            }
+        }
+        Self { components: unique, salt: Cow::Borrowed(KEY) }
+    }
+
+    /// The default components plus the optional disk serial and motherboard UUID.
+    #[must_use]
+    pub fn extended() -> Self {
+        Self::new([
+            MachineComponent::CpuId,
+            MachineComponent::MacAddress,
+            MachineComponent::SystemId,
+            MachineComponent::DriveSerial,
+            MachineComponent::BoardUuid,
+        ])
+    }
+
+    /// Overrides the fingerprint salt, for deployments running a forked binary.
+    ///
+    /// Ids generated with a different salt never match ids generated with the built-in one.
+    #[must_use]
+    pub fn salt(mut self, salt: impl Into<Cow<'static, str>>) -> Self {
+        self.salt = salt.into();
+        self
+    }
+
+    /// Returns the components included in this profile.
+    #[must_use]
+    pub fn components(&self) -> &[MachineComponent] {
+        &self.components
+    }
+
+    /// Whether ids of this profile are encoded in the positional `v1:` format.
+    fn is_v1(&self) -> bool {
+        self.components
+            == [MachineComponent::CpuId, MachineComponent::MacAddress, MachineComponent::SystemId]
+    }
+}
+
 /// Derives a deterministic fingerprint for a single hardware component.
 ///
-/// This uses `machineid_rs` in SHA256 mode with the constant `KEY` salt.
+/// This uses `machineid_rs` in SHA256 mode with the given salt.
 ///
 /// # Arguments
 /// - `component`: which hardware component to fingerprint.
+/// - `salt`: the profile salt, `KEY` unless overridden.
 ///
 /// # Returns
 /// A deterministic component identifier string.
@@ -115,8 +260,14 @@ const PREFIX: &str = "v1:";
 ///
 /// # Notes
 /// This function returns a fingerprint, not the raw hardware value.
-fn build_component(component: HWIDComponent) -> Result<String, LicenseError> {
-    IdBuilder::new(Encryption::SHA256).add_component(component).build(KEY).map_err(|e| {
+fn build_component(component: MachineComponent, salt: &str) -> Result<String, LicenseError> {
+    let hwid = component.hwid().ok_or_else(|| LicenseError::MachineIDGeneration {
+        message: format!("Component `{}` is not supported on this platform", component.label())
+            .into(),
+        context: Some("machineid_rs build failed".into()),
+    })?;
+
+    IdBuilder::new(Encryption::SHA256).add_component(hwid).build(salt).map_err(|e| {
         LicenseError::MachineIDGeneration {
             message: e.to_string().into(),
             context: Some("machineid_rs build failed".into()),
@@ -124,20 +275,21 @@ fn build_component(component: HWIDComponent) -> Result<String, LicenseError> {
     })
 }
 
-/// Generates the compound machine id as a single versioned string.
+/// Generates the compound machine id for `profile` as a single versioned string.
 ///
-/// Format:
+/// The default profile produces the positional format; any other profile produces the
+/// labeled `v2:` format (see [`generate_machine_id_compound_v2`]):
 ///
 /// ```text
 /// v1:<cpuid>|<mac>|<system_id>
 /// ```
 ///
 /// # Returns
-/// A single string containing three component fingerprints.
+/// A single string containing the component fingerprints.
 ///
 /// # Errors
-/// Returns [`LicenseError::MachineIDGeneration`] if any of the three hardware
-/// component fingerprints cannot be derived.
+/// Returns [`LicenseError::MachineIDGeneration`] if a required hardware component
+/// fingerprint cannot be derived.
 ///
 /// # When to use
 /// - Storing machine binding in license files as a single string.
@@ -145,40 +297,93 @@ fn build_component(component: HWIDComponent) -> Result<String, LicenseError> {
 ///
 /// # Privacy
 /// Avoid logging the returned value in plaintext.
-pub fn generate_machine_id_compound() -> Result<String, LicenseError> {
-    let cpuid = build_component(HWIDComponent::CPUID)?;
-    let mac = build_component(HWIDComponent::MacAddress)?;
-    let system_id = build_component(HWIDComponent::SystemID)?;
-    Ok(format!("{PREFIX}{cpuid}{SEP}{mac}{SEP}{system_id}"))
+pub fn generate_machine_id_compound(profile: &HwidProfile) -> Result<String, LicenseError> {
+    if !profile.is_v1() {
+        return generate_machine_id_compound_v2(profile);
+    }
+
+    let parts = profile
+        .components
+        .iter()
+        .map(|component| build_component(*component, &profile.salt))
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(format!("{PREFIX}{}", parts.join(&SEP.to_string())))
+}
+
+/// Generates the labeled `v2:` compound machine id for `profile`.
+///
+/// Format (for [`HwidProfile::extended`]):
+///
+/// ```text
+/// v2:cpuid=<cpuid>;mac=<mac>;system=<system_id>[;disk=<disk>][;board=<board_uuid>]
+/// ```
+///
+/// With the same salt, the `cpuid`, `mac` and `system` fingerprints are identical to their
+/// `v1:` counterparts, so machines bound with either format match by label. Optional
+/// components are omitted when the platform does not expose them (or permissions prevent
+/// reading them).
+///
+/// # Errors
+/// Returns [`LicenseError::MachineIDGeneration`] if a required component fingerprint cannot
+/// be derived, or if no component could be derived at all.
+///
+/// # Privacy
+/// Avoid logging the returned value in plaintext.
+pub fn generate_machine_id_compound_v2(profile: &HwidProfile) -> Result<String, LicenseError> {
+    let mut parts = Vec::with_capacity(profile.components.len());
+    for component in &profile.components {
+        match build_component(*component, &profile.salt) {
+            Ok(value) => parts.push(format!("{}{ASSIGN}{value}", component.label())),
+            Err(_) if component.is_optional() => {},
+            Err(e) => return Err(e),
+        }
+    }
+
+    if parts.is_empty() {
+        return Err(LicenseError::MachineIDGeneration {
+            message: "No machine id component could be derived".into(),
+            context: Some("Empty hardware profile".into()),
+        });
+    }
+
+    Ok(format!("{PREFIX_V2}{}", parts.join(&V2_SEP.to_string())))
 }
 
-/// Parses a compound machine id into its component fingerprints.
+/// Parses a compound machine id into its labeled component fingerprints.
 ///
-/// Expects a format:
+/// Dispatches by prefix:
 ///
 /// ```text
 /// v1:<cpuid>|<mac>|<system_id>
+/// v2:<label>=<fingerprint>;<label>=<fingerprint>;...
 /// ```
 ///
+/// `v1:` components are labeled `cpuid`, `mac` and `system` by position.
+///
 /// # Arguments
 /// - `s`: compound machine ID string.
 ///
 /// # Returns
-/// A vector of exactly three strings: `[CpuId, Mac, SystemId]`.
+/// A vector of `(label, fingerprint)` pairs.
 ///
 /// # Errors
-/// Returns [`LicenseError::MachineIDGeneration`] if the prefix is invalid, the
-/// separator count is wrong, or any component is empty.
-///
-/// # Forward compatibility
-/// The parser is strict for `v1:`. If you introduce `v2:`, add a separate parser
-/// and dispatch by prefix.
-pub fn parse_machine_id_compound(s: &str) -> Result<Vec<String>, LicenseError> {
-    let s = s.strip_prefix(PREFIX).ok_or_else(|| LicenseError::MachineIDGeneration {
-        message: "Invalid machine id prefix".into(),
-        context: Some("Expected v1: prefix".into()),
-    })?;
+/// Returns [`LicenseError::MachineIDGeneration`] if the prefix is unknown or the body is
+/// malformed for its version.
+pub fn parse_machine_id_compound(s: &str) -> Result<Vec<(String, String)>, LicenseError> {
+    if let Some(body) = s.strip_prefix(PREFIX) {
+        parse_v1(body)
+    } else if let Some(body) = s.strip_prefix(PREFIX_V2) {
+        parse_v2(body)
+    } else {
+        Err(LicenseError::MachineIDGeneration {
+            message: "Invalid machine id prefix".into(),
+            context: Some("Expected v1: or v2: prefix".into()),
+        })
+    }
+}
 
+/// Parses the strict `v1:` body: exactly three positional, non-empty parts.
+fn parse_v1(s: &str) -> Result<Vec<(String, String)>, LicenseError> {
     let parts: Vec<&str> = s.split(SEP).collect();
     if parts.len() != 3 || parts.iter().any(|p| p.is_empty()) {
         return Err(LicenseError::MachineIDGeneration {
@@ -187,19 +392,67 @@ pub fn parse_machine_id_compound(s: &str) -> Result<Vec<String>, LicenseError> {
         });
     }
 
-    Ok(parts.into_iter().map(str::to_owned).collect())
+    Ok([MachineComponent::CpuId, MachineComponent::MacAddress, MachineComponent::SystemId]
+        .into_iter()
+        .zip(parts)
+        .map(|(component, value)| (component.label().to_owned(), value.to_owned()))
+        .collect())
 }
 
-/// Returns the current machine's component identifiers.
+/// Parses the `v2:` body: one or more uniquely labeled, non-empty components.
+fn parse_v2(s: &str) -> Result<Vec<(String, String)>, LicenseError> {
+    let invalid = || LicenseError::MachineIDGeneration {
+        message: "Invalid compound machine id format".into(),
+        context: Some("Expected 'label=value' parts separated by ';'".into()),
+    };
+
+    let mut components: Vec<(String, String)> = Vec::new();
+    for part in s.split(V2_SEP) {
+        let (label, value) = part.split_once(ASSIGN).ok_or_else(invalid)?;
+        if label.is_empty() || value.is_empty() || components.iter().any(|(l, _)| l == label) {
+            return Err(invalid());
+        }
+        components.push((label.to_owned(), value.to_owned()));
+    }
+
+    Ok(components)
+}
+
+/// Counts the components of `allowed` whose fingerprint matches the `current` component with
+/// the same label.
 ///
-/// This is a convenience helper used during license validation to compute fuzzy matches.
+/// Components present on only one side never match, so a `v1:` id compared with a `v2:` id
+/// matches on the three shared labels.
+#[must_use]
+pub fn matching_components(allowed: &[(String, String)], current: &[(String, String)]) -> usize {
+    allowed
+        .iter()
+        .filter(|(label, value)| current.iter().any(|(l, v)| l == label && v == value))
+        .count()
+}
+
+/// Returns the current machine's labeled component identifiers for `profile`.
+///
+/// This is a convenience helper used during license validation to compute fuzzy matches. It
+/// uses the `v2:` format, so the result includes available optional components. Hardware
+/// does not change at runtime, so the first successful result per profile is memoized for
+/// the lifetime of the process.
 ///
 /// # Returns
-/// A vector of exactly three strings: `[cpuid, mac, system_id]`.
+/// A vector of `(label, fingerprint)` pairs.
 ///
 /// # Errors
 /// Returns [`LicenseError::MachineIDGeneration`] if the compound id cannot be generated
 /// or parsed.
-pub fn current_machine_components() -> Result<Vec<String>, LicenseError> {
-    parse_machine_id_compound(&generate_machine_id_compound()?)
+pub fn current_machine_components(
+    profile: &HwidProfile,
+) -> Result<Vec<(String, String)>, LicenseError> {
+    static CURRENT: Mutex<Vec<(HwidProfile, Vec<(String, String)>)>> = Mutex::new(Vec::new());
+
+    if let Some((_, components)) = CURRENT.lock().iter().find(|(p, _)| p == profile) {
+        return Ok(components.clone());
+    }
+    let components = parse_machine_id_compound(&generate_machine_id_compound_v2(profile)?)?;
+    CURRENT.lock().push((profile.clone(), components.clone()));
+    Ok(components)
 }
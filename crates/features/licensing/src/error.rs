@@ -10,6 +10,18 @@ pub enum LicenseError {
     #[error("License signature is invalid or tampered{}: {source}", format_context(.context))]
     InvalidSignature { source: ed25519_dalek::SignatureError, context: Option<Cow<'static, str>> },
 
+    /// The license expired but is still inside the configured grace period; every other check
+    /// passed.
+    #[error("License is in its grace period{}: {message}", format_context(.context))]
+    InGracePeriod { message: Cow<'static, str>, context: Option<Cow<'static, str>> },
+
+    #[error("License has been revoked{}: {message}", format_context(.context))]
+    Revoked { message: Cow<'static, str>, context: Option<Cow<'static, str>> },
+
+    /// The revocation list is older than the last accepted one, i.e. it is being replayed.
+    #[error("Revocation list is stale{}: {message}", format_context(.context))]
+    StaleRevocationList { message: Cow<'static, str>, context: Option<Cow<'static, str>> },
+
     #[error("MachineID mismatch{}: {message}", format_context(.context))]
     HardwareMismatch { message: Cow<'static, str>, context: Option<Cow<'static, str>> },
 
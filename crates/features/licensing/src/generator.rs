@@ -10,12 +10,16 @@
 //! * Uses `Ed25519` for deterministic, high-security digital signatures.
 
 use crate::error::LicenseError;
-use crate::{LicenseData, MachineConstraint, SignedLicense};
+use crate::{
+    ActivationData, ActivationRequest, ActivationToken, LicenseData, MachineConstraint,
+    RevocationData, RevocationList, SignedLicense,
+};
 use ed25519_dalek::{Signer, SigningKey, VerifyingKey};
 use getrandom::fill;
 use mhub_domain::features::FeatureSet;
+use std::path::Path;
 use std::time::{SystemTime, UNIX_EPOCH};
-use zeroize::Zeroize;
+use zeroize::{Zeroize, Zeroizing};
 
 /// Generates a high-entropy 32-byte secret using the operating system's Cryptographically
 /// Secure Pseudo-Random Number Generator (CSPRNG).
@@ -95,6 +99,11 @@ pub struct UniversalLicenseConfig {
     pub days: u64,
     /// List of feature slugs to enable (e.g., `["quiz", "survey"]`).
     pub features: Vec<String>,
+    /// Per-feature terms in days for add-ons that expire independently of the license
+    /// (e.g., `[("survey", 30)]`). Features not listed share the license term.
+    pub feature_days: Vec<(String, u64)>,
+    /// Maximum number of concurrently activated machines, or `None` for unlimited.
+    pub max_seats: Option<u32>,
     /// Unique salt for this specific license.
     pub salt: [u8; 32],
 }
@@ -105,17 +114,14 @@ pub struct UniversalLicenseConfig {
 /// 1. Calculates the expiration timestamp based on `days_valid`.
 /// 2. Maps string-based feature slugs to the internal [`Features`] bitflags.
 /// 3. Serializes the payload to JSON.
-/// 4. Signs the payload using the provided 32-byte Ed25519 private key.
+/// 4. Signs the payload with `signing_key`, typically obtained from [`load_signing_key`].
 ///
 /// # Errors
-/// Returns an error if the private key is invalid, time calculation fails,
-/// or serialization encounters an issue.
+/// Returns an error if time calculation fails or serialization encounters an issue.
 pub fn generate_universal_license(
-    private_key: &[u8; 32],
+    signing_key: &SigningKey,
     config: UniversalLicenseConfig,
 ) -> Result<SignedLicense, LicenseError> {
-    let signing_key = SigningKey::from_bytes(private_key);
-
     let now = SystemTime::now()
         .duration_since(UNIX_EPOCH)
         .map_err(|e| LicenseError::Internal {
@@ -131,6 +137,12 @@ pub fn generate_universal_license(
         features.insert(FeatureSet::from(feature.to_lowercase().as_str()));
     });
 
+    let feature_expiry = config
+        .feature_days
+        .into_iter()
+        .map(|(feature, days)| (feature.to_lowercase(), (now + days * 24 * 3600).cast_signed()))
+        .collect();
+
     let data = LicenseData {
         customer: config.customer,
         alias: config.alias,
@@ -139,10 +151,118 @@ pub fn generate_universal_license(
         expires,
         features,
         salt: config.salt.to_vec(),
+        feature_expiry,
+        max_seats: config.max_seats,
     };
 
-    let bytes = postcard::to_stdvec(&data)?;
+    let bytes = data.signing_bytes()?;
     let signature = signing_key.sign(&bytes).to_bytes().to_vec();
 
     Ok(SignedLicense { data, signature })
 }
+
+/// Like [`generate_universal_license`], but takes ownership of the signing key so it is
+/// wiped as soon as the license is signed.
+///
+/// Pair with [`load_signing_key`] so the master key never lives in an unzeroized buffer.
+///
+/// # Errors
+/// Returns an error if time calculation fails or serialization encounters an issue.
+pub fn sign_with_key(
+    signing_key: SigningKey,
+    config: UniversalLicenseConfig,
+) -> Result<SignedLicense, LicenseError> {
+    generate_universal_license(&signing_key, config)
+}
+
+/// Reads the master signing key from `path` without leaving copies in memory.
+///
+/// Accepts either a raw 32-byte key or the 64-byte `xtask` keyset (master key followed by the
+/// public key). The file contents are read into a zeroizing buffer, and the returned
+/// [`SigningKey`] wipes itself on drop: `ed25519-dalek` is built with its `zeroize` feature,
+/// so wrapping the key in [`Zeroizing`] would add nothing.
+///
+/// # Errors
+/// Returns [`LicenseError::Internal`] if the file cannot be read or has an unexpected length.
+pub fn load_signing_key(path: impl AsRef<Path>) -> Result<SigningKey, LicenseError> {
+    let path = path.as_ref();
+    let bytes = Zeroizing::new(std::fs::read(path).map_err(|e| LicenseError::Internal {
+        message: e.to_string().into(),
+        context: Some(format!("Failed to read signing key from {}", path.display()).into()),
+    })?);
+
+    let mut seed = Zeroizing::new([0u8; 32]);
+    match bytes.len() {
+        32 | 64 => seed.copy_from_slice(&bytes[..32]),
+        len => {
+            return Err(LicenseError::Internal {
+                message: format!("Expected a 32-byte key or 64-byte keyset, found {len} bytes")
+                    .into(),
+                context: Some(format!("Invalid signing key file {}", path.display()).into()),
+            });
+        },
+    }
+
+    Ok(SigningKey::from_bytes(&seed))
+}
+
+/// Signs a revocation list withdrawing the given licenses.
+///
+/// `license_ids` are the values returned by [`SignedLicense::license_id`]. The list replaces
+/// any earlier one, so it must contain every license that should stay revoked. Sign with the
+/// key returned by [`load_signing_key`].
+///
+/// # Errors
+/// Returns [`LicenseError::Internal`] if the current time cannot be read, or
+/// [`LicenseError::PostcardSerialize`] if the payload cannot be serialized.
+pub fn issue_revocation_list(
+    signing_key: &SigningKey,
+    license_ids: Vec<String>,
+) -> Result<RevocationList, LicenseError> {
+    let issued = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_err(|e| LicenseError::Internal {
+            message: e.to_string().into(),
+            context: Some("Failed to calculate current time".into()),
+        })?
+        .as_secs()
+        .cast_signed();
+
+    let data = RevocationData { revoked: license_ids, issued };
+    let bytes = data.signing_bytes()?;
+    let signature = signing_key.sign(&bytes).to_bytes().to_vec();
+
+    Ok(RevocationList { data, signature })
+}
+
+/// Signs an activation token answering a client's offline [`ActivationRequest`].
+///
+/// The request's machine id and nonce are copied into the signed payload, binding the token
+/// to that machine and that request. Sign with the key returned by [`load_signing_key`].
+///
+/// # Errors
+/// Returns [`LicenseError::Internal`] if the current time cannot be read, or
+/// [`LicenseError::PostcardSerialize`] if the payload cannot be serialized.
+pub fn issue_activation(
+    signing_key: &SigningKey,
+    request: &ActivationRequest,
+) -> Result<ActivationToken, LicenseError> {
+    let issued = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_err(|e| LicenseError::Internal {
+            message: e.to_string().into(),
+            context: Some("Failed to calculate current time".into()),
+        })?
+        .as_secs()
+        .cast_signed();
+
+    let data = ActivationData {
+        machine_id: request.machine_id.clone(),
+        nonce: request.nonce.clone(),
+        issued,
+    };
+    let bytes = data.signing_bytes()?;
+    let signature = signing_key.sign(&bytes).to_bytes().to_vec();
+
+    Ok(ActivationToken { data, signature })
+}
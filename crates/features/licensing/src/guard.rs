@@ -0,0 +1,140 @@
+//! # Cached Validation
+//!
+//! Full validation fingerprints the hardware and verifies signatures, which is too expensive
+//! to run on every request. [`LicenseGuard`] validates on first use, caches the verdict and
+//! lazily re-validates when `recheck_interval` has elapsed, so expiry and revocation are still
+//! picked up by long-running processes.
+
+use crate::validator::{ValidationOptions, validate_license_any};
+use crate::{LicenseError, SignedLicense};
+use mhub_domain::features::FeatureSet;
+use parking_lot::Mutex;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// Source of monotonic time for [`LicenseGuard`], replaceable in tests.
+pub trait Clock: Send + Sync {
+    /// Returns the current instant.
+    fn now(&self) -> Instant;
+}
+
+/// The real monotonic clock.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+}
+
+impl<C: Clock + ?Sized> Clock for Arc<C> {
+    fn now(&self) -> Instant {
+        (**self).now()
+    }
+}
+
+#[derive(Debug)]
+struct GuardState {
+    checked_at: Option<Instant>,
+    valid: bool,
+    checks: u64,
+}
+
+/// A validated license whose verdict is cached for `recheck_interval`.
+///
+/// A license inside its grace period counts as valid. Validation runs without holding the
+/// cache lock, so a slow check never blocks callers served from cache; callers that find the
+/// verdict stale at the same time may each validate.
+#[derive(Debug)]
+pub struct LicenseGuard<C: Clock = SystemClock> {
+    license: SignedLicense,
+    keys: Vec<[u8; 32]>,
+    grace: Duration,
+    recheck_interval: Duration,
+    clock: C,
+    state: Mutex<GuardState>,
+}
+
+impl LicenseGuard {
+    /// Guards `license` with the trusted `keys`. The first [`LicenseGuard::is_valid`] call
+    /// validates it and caches the verdict.
+    #[must_use]
+    pub fn new(license: SignedLicense, keys: Vec<[u8; 32]>, recheck_interval: Duration) -> Self {
+        Self::with_clock(license, keys, recheck_interval, SystemClock)
+    }
+}
+
+impl<C: Clock> LicenseGuard<C> {
+    /// Like [`LicenseGuard::new`], reading time from `clock`.
+    #[must_use]
+    pub fn with_clock(
+        license: SignedLicense,
+        keys: Vec<[u8; 32]>,
+        recheck_interval: Duration,
+        clock: C,
+    ) -> Self {
+        Self {
+            license,
+            keys,
+            grace: Duration::ZERO,
+            recheck_interval,
+            clock,
+            state: Mutex::new(GuardState { checked_at: None, valid: false, checks: 0 }),
+        }
+    }
+
+    /// Sets the grace period after expiry.
+    #[must_use]
+    pub const fn grace(mut self, grace: Duration) -> Self {
+        self.grace = grace;
+        self
+    }
+
+    /// Returns the cached verdict, validating first if there is none yet or
+    /// `recheck_interval` has elapsed.
+    #[must_use]
+    pub fn is_valid(&self) -> bool {
+        {
+            let state = self.state.lock();
+            if let Some(checked_at) = state.checked_at
+                && self.clock.now().duration_since(checked_at) < self.recheck_interval
+            {
+                return state.valid;
+            }
+        }
+
+        let valid = self.validate();
+        let mut state = self.state.lock();
+        state.valid = valid;
+        state.checked_at = Some(self.clock.now());
+        state.checks += 1;
+        valid
+    }
+
+    /// Returns `true` if the license is valid and every feature in `features` is active.
+    #[must_use]
+    pub fn feature_enabled(&self, features: FeatureSet) -> bool {
+        self.is_valid() && self.license.active_features().contains(features)
+    }
+
+    /// Returns the guarded license.
+    #[must_use]
+    pub const fn license(&self) -> &SignedLicense {
+        &self.license
+    }
+
+    /// Returns how many times the license has been validated.
+    #[must_use]
+    pub fn checks(&self) -> u64 {
+        self.state.lock().checks
+    }
+
+    fn validate(&self) -> bool {
+        let options = ValidationOptions { grace: self.grace, ..ValidationOptions::default() };
+        matches!(
+            validate_license_any(&self.license, &self.keys, &options),
+            Ok(()) | Err(LicenseError::InGracePeriod { .. })
+        )
+    }
+}
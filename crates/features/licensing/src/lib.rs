@@ -18,17 +18,28 @@
 //! * **Cryptographic Security**: Ed25519 signatures via the `ed25519-dalek` crate.
 //! * **Machine Binding**: Licenses can be bound to specific hardware IDs or issued as site licenses.
 //! * **Feature Flags**: Uses bitflags to define which features are unlocked by a specific license.
+//! * **Revocation**: Signed revocation lists withdraw licenses before they expire.
+//! * **Offline Activation**: Challenge-response activation for air-gapped installations.
+//! * **Cached Validation**: [`LicenseGuard`] caches the verdict and re-validates periodically.
 //! * **Serialization**: Licenses are serialized to JSON with Base64 encoding for cryptographic bytes.
 
+pub mod activation;
 pub mod constraints;
 mod error;
 #[cfg(feature = "issuance")]
 pub mod generator;
+pub mod guard;
+pub mod revocation;
+pub mod seats;
 pub mod validator;
 
+pub use crate::activation::{ActivationData, ActivationRequest, ActivationToken};
 pub use crate::error::{LicenseError, LicenseErrorExt};
+pub use crate::guard::LicenseGuard;
+pub use crate::revocation::{RevocationData, RevocationList};
 use mhub_domain::features::FeatureSet;
 use serde::{Deserialize, Serialize};
+use std::time::{SystemTime, UNIX_EPOCH};
 
 /// A container for a license payload and its corresponding cryptographic signature.
 ///
@@ -56,10 +67,17 @@ impl SignedLicense {
 
     /// Deserializes a signed license from a binary buffer.
     ///
+    /// Buffers encoded before [`LicenseData::feature_expiry`] and [`LicenseData::max_seats`]
+    /// existed are decoded with the original layout, with both fields left unset.
+    ///
     /// # Errors
     /// Returns [`LicenseError::Postcard`] if the buffer is corrupted or invalid.
     pub fn decode_bin(bytes: &[u8]) -> Result<Self, LicenseError> {
-        postcard::from_bytes(bytes).map_err(LicenseError::from)
+        postcard::from_bytes(bytes).or_else(|err| {
+            postcard::from_bytes::<LegacySignedLicense>(bytes)
+                .map(Self::from)
+                .map_err(|_| LicenseError::from(err))
+        })
     }
 
     /// Serializes the signed license into a human-readable JSON string.
@@ -99,7 +117,52 @@ impl SignedLicense {
     /// * [`LicenseError::InvalidSignature`] (via `ed25519_dalek`) if the data has been tampered with.
     /// * [`LicenseError::Internal`] if the system clock cannot be accessed.
     pub fn validate(&self, key: &[u8; 32]) -> Result<(), LicenseError> {
-        validator::validate_license(self, key)
+        validator::validate_license(self, key, &validator::ValidationOptions::default())
+    }
+
+    /// Validates the license against several trusted public keys, succeeding if any of them
+    /// verifies the signature.
+    ///
+    /// Use this during signing key rotation, listing the new key first.
+    ///
+    /// # Errors
+    /// Same as [`SignedLicense::validate`]; [`LicenseError::Expired`] takes precedence over
+    /// [`LicenseError::InvalidSignature`].
+    pub fn validate_any(&self, keys: &[[u8; 32]]) -> Result<(), LicenseError> {
+        validator::validate_license_any(self, keys, &validator::ValidationOptions::default())
+    }
+
+    /// Returns the stable identifier of this license: its salt, Base64-encoded as in JSON.
+    ///
+    /// Re-issuing a license for the same customer keeps the salt, so the ID also identifies
+    /// renewals. This is the value listed in a [`RevocationList`].
+    #[must_use]
+    pub fn license_id(&self) -> String {
+        use base64::{Engine as _, engine::general_purpose};
+        general_purpose::STANDARD_NO_PAD.encode(&self.data.salt)
+    }
+
+    /// Returns the features currently unlocked by this license.
+    ///
+    /// Features listed in [`LicenseData::feature_expiry`] are dropped once their own term has
+    /// passed; all other features share the top-level `expires`. This only looks at the
+    /// clock: call [`SignedLicense::validate`] first to establish that the license is genuine.
+    #[must_use]
+    pub fn active_features(&self) -> FeatureSet {
+        // A clock set before the epoch fails closed: every feature is treated as expired.
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(i64::MAX, |elapsed| elapsed.as_secs().cast_signed());
+
+        let mut active = self.data.features;
+        if now > self.data.expires {
+            active = FeatureSet::empty();
+        }
+        for (feature, expires) in &self.data.feature_expiry {
+            let feature = FeatureSet::from(feature.as_str()) & self.data.features;
+            active.set(feature, now <= *expires);
+        }
+        active
     }
 
     /// Securely wipes the license data from memory and consumes the instance.
@@ -137,6 +200,89 @@ pub struct LicenseData {
     pub issued: i64,
     /// UNIX timestamp (in seconds) indicating when the license expires.
     pub expires: i64,
+    /// Per-feature expiry timestamps for add-ons sold on independent terms, keyed by feature
+    /// slug. Features not listed here expire together with the license.
+    #[serde(default)]
+    pub feature_expiry: Vec<(String, i64)>,
+    /// Maximum number of concurrently activated machines, or `None` for unlimited. Enforced
+    /// server-side; see [`seats`].
+    #[serde(default)]
+    pub max_seats: Option<u32>,
+}
+
+/// Binary layout of a [`SignedLicense`] encoded before per-feature terms and seat limits.
+#[derive(Deserialize)]
+struct LegacySignedLicense {
+    data: LegacyLicenseData,
+    #[serde(with = "bytes_as_base64")]
+    signature: Vec<u8>,
+}
+
+/// [`LicenseData`] without the trailing `feature_expiry` and `max_seats` fields.
+#[derive(Deserialize)]
+struct LegacyLicenseData {
+    customer: String,
+    alias: String,
+    constraint: MachineConstraint,
+    features: FeatureSet,
+    #[serde(with = "bytes_as_base64")]
+    salt: Vec<u8>,
+    issued: i64,
+    expires: i64,
+}
+
+impl From<LegacySignedLicense> for SignedLicense {
+    fn from(legacy: LegacySignedLicense) -> Self {
+        let LegacyLicenseData { customer, alias, constraint, features, salt, issued, expires } =
+            legacy.data;
+        Self {
+            data: LicenseData {
+                customer,
+                alias,
+                constraint,
+                features,
+                salt,
+                issued,
+                expires,
+                feature_expiry: Vec::new(),
+                max_seats: None,
+            },
+            signature: legacy.signature,
+        }
+    }
+}
+
+/// Domain-separation tag prefixed to the signed bytes of a [`LicenseData`].
+const LICENSE_TAG: &[u8] = b"mhub-licensing/license/v1";
+
+impl LicenseData {
+    /// Returns the bytes covered by the license signature: a per-type domain-separation tag
+    /// followed by the Postcard encoding, so a signature over a revocation list or an
+    /// activation token can never pass as a license signature.
+    ///
+    /// # Errors
+    /// Returns [`LicenseError::PostcardSerialize`] if serialization fails.
+    pub fn signing_bytes(&self) -> Result<Vec<u8>, LicenseError> {
+        let mut bytes = LICENSE_TAG.to_vec();
+        bytes.extend(self.legacy_signing_bytes()?);
+        Ok(bytes)
+    }
+
+    /// Returns the untagged bytes signed by licenses issued before domain separation.
+    ///
+    /// Optional fields added after the original layout are only signed once set, so licenses
+    /// issued before [`LicenseData::feature_expiry`] and [`LicenseData::max_seats`] existed
+    /// keep verifying. Both encode as a single zero byte when unset.
+    pub(crate) fn legacy_signing_bytes(&self) -> Result<Vec<u8>, LicenseError> {
+        let mut bytes = postcard::to_stdvec(self)?;
+        if self.max_seats.is_none() {
+            bytes.pop();
+            if self.feature_expiry.is_empty() {
+                bytes.pop();
+            }
+        }
+        Ok(bytes)
+    }
 }
 
 /// Defines hardware binding rules for a license.
@@ -154,6 +300,11 @@ pub enum MachineConstraint {
     },
 }
 
+/// Encodes `value` with Postcard behind the domain-separation `tag`.
+fn tagged_bytes<T: Serialize>(tag: &[u8], value: &T) -> Result<Vec<u8>, LicenseError> {
+    postcard::to_extend(value, tag.to_vec()).map_err(LicenseError::from)
+}
+
 /// Helper module for transparently serializing byte buffers to Base64 strings.
 #[allow(clippy::redundant_pub_crate)]
 pub mod bytes_as_base64 {
@@ -0,0 +1,93 @@
+//! # Revocation Lists
+//!
+//! Signed licenses stay cryptographically valid until they expire, so licenses that must be
+//! withdrawn early (refunds, fraud) are listed in a [`RevocationList`]: a signed JSON bundle
+//! of revoked license IDs that ships to installations alongside (or after) the license.
+//!
+//! The list is signed with the same master key as licenses and verified against the same
+//! public key, so it cannot be edited to un-revoke a license. To stop an older list from
+//! being replayed in place of a newer one, persist [`RevocationData::issued`] of the last
+//! accepted list and pass it as
+//! [`ValidationOptions::last_revocation_issued`](crate::validator::ValidationOptions).
+
+use crate::bytes_as_base64;
+use crate::error::{LicenseError, LicenseErrorExt};
+use crate::{SignedLicense, tagged_bytes};
+use ed25519_dalek::{Signature, Verifier, VerifyingKey};
+use serde::{Deserialize, Serialize};
+
+/// The signed payload of a [`RevocationList`].
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct RevocationData {
+    /// Revoked license IDs, as returned by [`SignedLicense::license_id`].
+    pub revoked: Vec<String>,
+    /// UNIX timestamp when the list was issued.
+    pub issued: i64,
+}
+
+/// Domain-separation tag prefixed to the signed bytes of a [`RevocationData`].
+const REVOCATION_TAG: &[u8] = b"mhub-licensing/revocation/v1";
+
+impl RevocationData {
+    /// Returns the bytes covered by the revocation list signature: a per-type
+    /// domain-separation tag followed by the Postcard encoding.
+    ///
+    /// # Errors
+    /// Returns [`LicenseError::PostcardSerialize`] if serialization fails.
+    pub fn signing_bytes(&self) -> Result<Vec<u8>, LicenseError> {
+        tagged_bytes(REVOCATION_TAG, self)
+    }
+}
+
+/// A vendor-signed list of revoked licenses.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RevocationList {
+    /// The list of revoked license IDs.
+    pub data: RevocationData,
+    /// The Ed25519 signature of the `data` field, encoded as a Base64 string in JSON.
+    #[serde(with = "bytes_as_base64")]
+    pub signature: Vec<u8>,
+}
+
+impl RevocationList {
+    /// Serializes the revocation list into a JSON string.
+    ///
+    /// # Errors
+    /// Returns [`LicenseError::SerdeSerialize`] if serialization fails.
+    pub fn to_json(&self) -> Result<String, LicenseError> {
+        serde_json::to_string(self).map_err(LicenseError::from)
+    }
+
+    /// Deserializes a revocation list from a JSON string.
+    ///
+    /// # Errors
+    /// Returns [`LicenseError::SerdeSerialize`] if the JSON is malformed or contains invalid
+    /// Base64 data.
+    pub fn from_json(json: &str) -> Result<Self, LicenseError> {
+        serde_json::from_str(json).map_err(LicenseError::from)
+    }
+
+    /// Verifies that the list was signed by the holder of the master key.
+    ///
+    /// # Errors
+    /// Returns [`LicenseError::InvalidSignature`] if the list has been tampered with or was
+    /// signed by another key.
+    pub fn verify(&self, public_key: &[u8; 32]) -> Result<(), LicenseError> {
+        let verifying_key = VerifyingKey::from_bytes(public_key)?;
+        let signature = Signature::from_slice(&self.signature)?;
+        let data_bytes =
+            self.data.signing_bytes().context("Revocation list serialization failed")?;
+        verifying_key.verify(&data_bytes, &signature).context("Revocation list signature")?;
+        Ok(())
+    }
+
+    /// Returns `true` if `license` appears in the list.
+    ///
+    /// This does not verify the list's signature; see [`RevocationList::verify`].
+    #[must_use]
+    pub fn contains(&self, license: &SignedLicense) -> bool {
+        let id = license.license_id();
+        self.data.revoked.iter().any(|revoked| *revoked == id)
+    }
+}
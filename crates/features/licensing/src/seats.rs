@@ -0,0 +1,32 @@
+//! # Seat Counting
+//!
+//! Concurrent-user licenses carry a [`LicenseData::max_seats`] limit. Enforcing it requires
+//! knowing which machines are currently activated, which only the server can coordinate, so
+//! the validator merely exposes the limit and the counting lives here.
+
+use crate::LicenseData;
+
+/// Returns `true` if activating `machine_id` would exceed the license's seat limit.
+///
+/// `active` holds the compound machine ids currently holding a seat; duplicates count once.
+/// Re-activating a machine that already holds a seat never exceeds the limit, and licenses
+/// without a limit never do.
+#[must_use]
+pub fn would_exceed<'a>(
+    license: &LicenseData,
+    active: impl IntoIterator<Item = &'a str>,
+    machine_id: &str,
+) -> bool {
+    let Some(max_seats) = license.max_seats else {
+        return false;
+    };
+
+    let mut seats: Vec<&str> = active.into_iter().collect();
+    seats.sort_unstable();
+    seats.dedup();
+    if seats.binary_search(&machine_id).is_ok() {
+        return false;
+    }
+
+    u32::try_from(seats.len()).map_or(true, |used| used >= max_seats)
+}
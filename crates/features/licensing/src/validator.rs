@@ -4,17 +4,37 @@
 //! It is designed to be lightweight and included in all production builds (client and server).
 //!
 //! ## Validation Logic
-//! The validation process follows two strict steps:
-//! 1. **Temporal Check**: Ensures the license has not expired against the current system time.
+//! The validation process follows three strict steps:
+//! 1. **Temporal Check**: Ensures the license has not expired against the current system time,
+//!    allowing an optional grace period after expiry.
 //! 2. **Cryptographic Check**: Verifies that the license data was signed by the official
 //!    *Master Private Key* using the corresponding public key.
+//! 3. **Revocation Check**: If a [`RevocationList`] is supplied, verifies its signature,
+//!    rejects it if it is older than the last accepted list, and rejects licenses it lists.
 
-use crate::constraints::{generate_machine_id_compound, parse_machine_id_compound};
+use crate::constraints::{
+    HwidProfile, current_machine_components, matching_components, parse_machine_id_compound,
+};
 use crate::error::{LicenseError, LicenseErrorExt};
-use crate::{MachineConstraint, SignedLicense};
+use crate::{ActivationToken, MachineConstraint, RevocationList, SignedLicense};
 use ed25519_dalek::{Signature, Verifier, VerifyingKey};
-use fxhash::FxHashSet;
-use std::time::{SystemTime, UNIX_EPOCH};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Optional knobs for [`validate_license`]; the default performs a strict check.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ValidationOptions<'a> {
+    /// How long after `expires` a license is still accepted, reported as
+    /// [`LicenseError::InGracePeriod`].
+    pub grace: Duration,
+    /// Revocation list to check against, signed with the same key as the license.
+    pub revocations: Option<&'a RevocationList>,
+    /// [`RevocationData::issued`](crate::RevocationData::issued) of the newest revocation
+    /// list accepted so far. Older lists are rejected as replays with
+    /// [`LicenseError::StaleRevocationList`]; persist the timestamp of each accepted list.
+    pub last_revocation_issued: Option<i64>,
+    /// Hardware profile used to fingerprint this machine; [`HwidProfile::extended`] if `None`.
+    pub hwid: Option<&'a HwidProfile>,
+}
 
 /// Validates a signed license against the provided public key.
 ///
@@ -24,20 +44,118 @@ use std::time::{SystemTime, UNIX_EPOCH};
 /// # Arguments
 /// * `license` - A reference to the [`SignedLicense`] structure containing the data and signature.
 /// * `key` - A 32-byte array representing the trusted public key for verification.
+/// * `options` - Grace period and revocation list; see [`ValidationOptions`].
 ///
 /// # Returns
 /// * `Ok(())` if the license is authentic and valid.
 ///
 /// # Errors
-/// * [`LicenseError::Expired`] if the current system time is past the `expires_at` timestamp.
-/// * [`LicenseError::InvalidSignature`] (via `ed25519_dalek`) if the data has been tampered with.
+/// * [`LicenseError::Expired`] if the current system time is past the `expires_at` timestamp
+///   plus the grace period.
+/// * [`LicenseError::InGracePeriod`] if the license expired within the grace period but all
+///   other checks passed.
+/// * [`LicenseError::InvalidSignature`] (via `ed25519_dalek`) if the data or the revocation
+///   list has been tampered with.
+/// * [`LicenseError::StaleRevocationList`] if the revocation list is older than
+///   [`ValidationOptions::last_revocation_issued`].
+/// * [`LicenseError::Revoked`] if the license appears in the revocation list.
 /// * [`LicenseError::Internal`] if the system clock cannot be accessed.
-pub fn validate_license(license: &SignedLicense, key: &[u8; 32]) -> Result<(), LicenseError> {
+pub fn validate_license(
+    license: &SignedLicense,
+    key: &[u8; 32],
+    options: &ValidationOptions<'_>,
+) -> Result<(), LicenseError> {
+    validate_license_any(license, std::slice::from_ref(key), options)
+}
+
+/// Validates a signed license against a set of trusted public keys.
+///
+/// Behaves like [`validate_license`], but the signature only has to verify against one of
+/// `keys`, tried in order. This allows rotating the signing key while still honoring
+/// licenses issued with a previous one. A revocation list may likewise be signed by any of
+/// the trusted keys.
+///
+/// # Errors
+/// Same as [`validate_license`]. Expiry and hardware failures are reported before signature
+/// failures; if no key verifies, the error from the last key is returned.
+pub fn validate_license_any(
+    license: &SignedLicense,
+    keys: &[[u8; 32]],
+    options: &ValidationOptions<'_>,
+) -> Result<(), LicenseError> {
+    if keys.is_empty() {
+        return Err(LicenseError::Internal {
+            message: "No trusted public keys provided".into(),
+            context: Some("Signature Check".into()),
+        });
+    }
+
     // 1. Check expiry
-    check_expiry(license)?;
+    let in_grace = check_expiry(license, options.grace, options.hwid)?;
 
     // 2. Verify signature
-    verify_signature(license, key)?;
+    any_key(keys, |key| verify_signature(license, key))?;
+
+    // 3. Check revocation
+    if let Some(list) = options.revocations {
+        check_revocation(license, list, keys, options.last_revocation_issued)?;
+    }
+
+    // Only report the grace period once the license is otherwise known to be genuine.
+    if in_grace {
+        return Err(LicenseError::InGracePeriod {
+            message: format!("License expired at Unix timestamp {}", license.data.expires).into(),
+            context: Some("Expiration Check".into()),
+        });
+    }
+
+    Ok(())
+}
+
+/// Internal helper succeeding if `check` passes for any of `keys`, otherwise returning the
+/// last failure.
+fn any_key(
+    keys: &[[u8; 32]],
+    check: impl Fn(&[u8; 32]) -> Result<(), LicenseError>,
+) -> Result<(), LicenseError> {
+    let mut last = Ok(());
+    for key in keys {
+        last = check(key);
+        if last.is_ok() {
+            break;
+        }
+    }
+    last
+}
+
+/// Internal helper rejecting licenses listed in an authentic, current revocation list.
+fn check_revocation(
+    license: &SignedLicense,
+    list: &RevocationList,
+    keys: &[[u8; 32]],
+    last_issued: Option<i64>,
+) -> Result<(), LicenseError> {
+    any_key(keys, |key| list.verify(key))?;
+
+    if let Some(last_issued) = last_issued
+        && list.data.issued < last_issued
+    {
+        return Err(LicenseError::StaleRevocationList {
+            message: format!(
+                "List issued at {} is older than the accepted list issued at {last_issued}",
+                list.data.issued
+            )
+            .into(),
+            context: Some("Revocation Check".into()),
+        });
+    }
+
+    if list.contains(license) {
+        return Err(LicenseError::Revoked {
+            message: format!("License {} is on the revocation list", license.license_id()).into(),
+            context: Some("Revocation Check".into()),
+        });
+    }
 
     Ok(())
 }
@@ -45,7 +163,12 @@ pub fn validate_license(license: &SignedLicense, key: &[u8; 32]) -> Result<(), L
 /// Internal helper to check the license expiration date.
 ///
 /// Compares the current UNIX timestamp with the `expires_at` value stored in the license.
-fn check_expiry(license: &SignedLicense) -> Result<(), LicenseError> {
+/// Returns `true` if the license expired but is still within `grace`.
+fn check_expiry(
+    license: &SignedLicense,
+    grace: Duration,
+    hwid: Option<&HwidProfile>,
+) -> Result<bool, LicenseError> {
     let now = SystemTime::now()
         .duration_since(UNIX_EPOCH)
         .map_err(|e| LicenseError::Internal {
@@ -62,37 +185,69 @@ fn check_expiry(license: &SignedLicense) -> Result<(), LicenseError> {
         });
     }
 
-    if now > license.data.expires {
+    let grace = i64::try_from(grace.as_secs()).unwrap_or(i64::MAX);
+    if now > license.data.expires.saturating_add(grace) {
         return Err(LicenseError::Expired {
             message: format!("License expired at Unix timestamp {}", license.data.expires).into(),
             context: Some("Expiration Check".into()),
         });
     }
 
-    validate_hardware(&license.data.constraint)?;
+    validate_hardware(&license.data.constraint, hwid)?;
+
+    Ok(now > license.data.expires)
+}
+
+/// Verifies an offline activation token for the current machine.
+///
+/// The signature is checked first; the token must then have been issued for this machine,
+/// i.e. every component of its machine id must match the current machine's component with
+/// the same label. Callers should also confirm [`ActivationToken::answers`] their pending
+/// request before caching the token.
+///
+/// # Errors
+/// * [`LicenseError::InvalidSignature`] if the token has been tampered with.
+/// * [`LicenseError::HardwareMismatch`] if the token was issued for another machine.
+/// * [`LicenseError::MachineIDGeneration`] if the machine ids cannot be derived or parsed.
+pub fn verify_activation(
+    token: &ActivationToken,
+    public_key: &[u8; 32],
+) -> Result<(), LicenseError> {
+    token.verify(public_key)?;
+
+    let activated = parse_machine_id_compound(&token.data.machine_id)?;
+    let current = current_machine_components(&HwidProfile::extended())?;
+    if matching_components(&activated, &current) != activated.len() {
+        return Err(LicenseError::HardwareMismatch {
+            message: "Activation token was issued for another machine".into(),
+            context: Some("Activation Check".into()),
+        });
+    }
 
     Ok(())
 }
 
 /// Checks if the current machine satisfies the license hardware constraints.
-fn validate_hardware(constraint: &MachineConstraint) -> Result<(), LicenseError> {
+fn validate_hardware(
+    constraint: &MachineConstraint,
+    hwid: Option<&HwidProfile>,
+) -> Result<(), LicenseError> {
     match constraint {
         MachineConstraint::Any => Ok(()),
         MachineConstraint::Threshold { ids, min_matches } => {
-            // Current machine: compound -> 3 components
-            let current_compound = generate_machine_id_compound()?;
-            let current_parts = parse_machine_id_compound(&current_compound)?;
-            let current_set: FxHashSet<&str> = current_parts.iter().map(String::as_str).collect();
+            // Current machine: labeled components (v1 components plus optional extras)
+            let current = match hwid {
+                Some(profile) => current_machine_components(profile)?,
+                None => current_machine_components(&HwidProfile::extended())?,
+            };
 
             // For each allowed machine (compound string), compute how many components match.
             let mut best: u16 = 0;
 
             for allowed_compound in ids {
-                let allowed_parts = parse_machine_id_compound(allowed_compound)?;
-                let allowed_set: FxHashSet<&str> =
-                    allowed_parts.iter().map(String::as_str).collect();
+                let allowed = parse_machine_id_compound(allowed_compound)?;
 
-                let matches = allowed_set.intersection(&current_set).count();
+                let matches = matching_components(&allowed, &current);
                 let matches_u16 = u16::try_from(matches).unwrap_or(u16::MIN);
                 best = best.max(matches_u16);
 
@@ -115,14 +270,20 @@ fn validate_hardware(constraint: &MachineConstraint) -> Result<(), LicenseError>
 /// Internal helper to verify the Ed25519 cryptographic signature.
 ///
 /// It reconstructs the signed payload by serializing the [`LicenseData`] and
-/// checking it against the signature using the provided public key.
+/// checking it against the signature using the provided public key. Licenses issued before
+/// domain separation signed the untagged payload and are still accepted.
 fn verify_signature(license: &SignedLicense, public_key: &[u8; 32]) -> Result<(), LicenseError> {
     let verifying_key = VerifyingKey::from_bytes(public_key)?;
     let signature = Signature::from_slice(&license.signature)?;
 
-    let data_bytes = postcard::to_stdvec(&license.data).context("Binary serialization failed")?;
+    let data_bytes = license.data.signing_bytes().context("Binary serialization failed")?;
+    if verifying_key.verify(&data_bytes, &signature).is_ok() {
+        return Ok(());
+    }
 
-    verifying_key.verify(&data_bytes, &signature)?;
+    let legacy_bytes =
+        license.data.legacy_signing_bytes().context("Binary serialization failed")?;
+    verifying_key.verify(&legacy_bytes, &signature)?;
 
     Ok(())
 }
@@ -1,6 +1,9 @@
 use ed25519_dalek::{Signer, SigningKey};
-use mhub_licensing::validator::validate_license;
+use mhub_domain::features::FeatureSet;
+use mhub_licensing::validator::{ValidationOptions, validate_license};
 use mhub_licensing::*;
+use std::sync::Arc;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 
 fn keypair() -> (SigningKey, [u8; 32]) {
     let seed = [7u8; 32];
@@ -18,6 +21,8 @@ fn sample_license() -> LicenseData {
         salt: vec![1, 2, 3],
         issued: 0,
         expires: i64::MAX,
+        feature_expiry: Vec::new(),
+        max_seats: None,
     }
 }
 
@@ -25,7 +30,7 @@ fn sample_license() -> LicenseData {
 fn signed_license_roundtrip_json_and_bin() {
     let (signing, public) = keypair();
     let data = sample_license();
-    let signature = signing.sign(&postcard::to_stdvec(&data).unwrap()).to_bytes().to_vec();
+    let signature = signing.sign(&data.signing_bytes().unwrap()).to_bytes().to_vec();
     let signed = SignedLicense { data, signature };
 
     let json = signed.to_json().unwrap();
@@ -38,7 +43,7 @@ fn signed_license_roundtrip_json_and_bin() {
     assert_eq!(from_bin.data.customer, "test");
     assert_eq!(from_bin.data.alias, "test-ns");
 
-    validate_license(&from_bin, &public).unwrap();
+    validate_license(&from_bin, &public, &ValidationOptions::default()).unwrap();
 }
 
 #[test]
@@ -47,9 +52,458 @@ fn expired_license_is_rejected() {
     let mut data = sample_license();
     data.issued = 0;
     data.expires = 1;
-    let signature = signing.sign(&postcard::to_stdvec(&data).unwrap()).to_bytes().to_vec();
+    let signature = signing.sign(&data.signing_bytes().unwrap()).to_bytes().to_vec();
     let signed = SignedLicense { data, signature };
 
-    let err = validate_license(&signed, &public).unwrap_err();
+    let err = validate_license(&signed, &public, &ValidationOptions::default()).unwrap_err();
     assert!(matches!(err, LicenseError::Expired { .. }));
 }
+
+fn revocations(list: &RevocationList) -> ValidationOptions<'_> {
+    ValidationOptions { revocations: Some(list), ..ValidationOptions::default() }
+}
+
+fn sign_license(signing: &SigningKey, data: LicenseData) -> SignedLicense {
+    let signature = signing.sign(&data.signing_bytes().unwrap()).to_bytes().to_vec();
+    SignedLicense { data, signature }
+}
+
+fn revocation_list(signing: &SigningKey, revoked: Vec<String>) -> RevocationList {
+    issued_revocation_list(signing, revoked, 0)
+}
+
+fn issued_revocation_list(
+    signing: &SigningKey,
+    revoked: Vec<String>,
+    issued: i64,
+) -> RevocationList {
+    let data = RevocationData { revoked, issued };
+    let signature = signing.sign(&data.signing_bytes().unwrap()).to_bytes().to_vec();
+    RevocationList { data, signature }
+}
+
+#[test]
+fn revoked_license_is_rejected() {
+    let (signing, public) = keypair();
+    let revoked = sign_license(&signing, sample_license());
+    let list = revocation_list(&signing, vec![revoked.license_id()]);
+    let list = RevocationList::from_json(&list.to_json().unwrap()).unwrap();
+
+    let err = validate_license(&revoked, &public, &revocations(&list)).unwrap_err();
+    assert!(matches!(err, LicenseError::Revoked { .. }));
+}
+
+#[test]
+fn unlisted_license_passes_revocation_check() {
+    let (signing, public) = keypair();
+    let license = sign_license(&signing, LicenseData { salt: vec![9, 9, 9], ..sample_license() });
+    let list =
+        revocation_list(&signing, vec![sign_license(&signing, sample_license()).license_id()]);
+
+    validate_license(&license, &public, &revocations(&list)).unwrap();
+}
+
+#[test]
+fn tampered_revocation_list_is_rejected() {
+    let (signing, public) = keypair();
+    let license = sign_license(&signing, sample_license());
+    let mut list = revocation_list(&signing, vec![license.license_id()]);
+    list.data.revoked.clear();
+
+    let err = validate_license(&license, &public, &revocations(&list)).unwrap_err();
+    assert!(matches!(err, LicenseError::InvalidSignature { .. }));
+}
+
+#[test]
+fn replayed_revocation_list_is_rejected() {
+    let (signing, public) = keypair();
+    let license = sign_license(&signing, sample_license());
+    let old = issued_revocation_list(&signing, Vec::new(), 100);
+    let current = issued_revocation_list(&signing, Vec::new(), 200);
+
+    let after = |last| ValidationOptions {
+        revocations: Some(&old),
+        last_revocation_issued: Some(last),
+        ..ValidationOptions::default()
+    };
+    let err = validate_license(&license, &public, &after(200)).unwrap_err();
+    assert!(matches!(err, LicenseError::StaleRevocationList { .. }));
+    validate_license(&license, &public, &after(100)).unwrap();
+
+    let options = ValidationOptions { revocations: Some(&current), ..after(200) };
+    validate_license(&license, &public, &options).unwrap();
+}
+
+#[test]
+fn untagged_revocation_signature_is_rejected() {
+    let (signing, public) = keypair();
+    let data = RevocationData { revoked: Vec::new(), issued: 0 };
+    // Signatures must cover the domain-separation tag, not just the bare payload.
+    let signature = signing.sign(&postcard::to_stdvec(&data).unwrap()).to_bytes().to_vec();
+
+    let err = RevocationList { data, signature }.verify(&public).unwrap_err();
+    assert!(matches!(err, LicenseError::InvalidSignature { .. }));
+}
+
+fn expired_secs_ago(signing: &SigningKey, secs: i64) -> SignedLicense {
+    let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs().cast_signed();
+    sign_license(signing, LicenseData { expires: now - secs, ..sample_license() })
+}
+
+#[test]
+fn license_expired_within_grace_reports_grace_period() {
+    let (signing, public) = keypair();
+    let license = expired_secs_ago(&signing, 60);
+    let options = ValidationOptions { grace: Duration::from_secs(3600), ..Default::default() };
+
+    let err = validate_license(&license, &public, &options).unwrap_err();
+    assert!(matches!(err, LicenseError::InGracePeriod { .. }));
+}
+
+#[test]
+fn license_expired_beyond_grace_is_rejected() {
+    let (signing, public) = keypair();
+    let license = expired_secs_ago(&signing, 7200);
+    let options = ValidationOptions { grace: Duration::from_secs(3600), ..Default::default() };
+
+    let err = validate_license(&license, &public, &options).unwrap_err();
+    assert!(matches!(err, LicenseError::Expired { .. }));
+}
+
+#[test]
+fn tampered_license_in_grace_is_rejected() {
+    let (signing, public) = keypair();
+    let mut license = expired_secs_ago(&signing, 60);
+    license.data.customer = "someone-else".into();
+    let options = ValidationOptions { grace: Duration::from_secs(3600), ..Default::default() };
+
+    let err = validate_license(&license, &public, &options).unwrap_err();
+    assert!(matches!(err, LicenseError::InvalidSignature { .. }));
+}
+
+#[test]
+fn legacy_license_signature_still_verifies() {
+    let (signing, public) = keypair();
+    let data = sample_license();
+    let mut legacy = postcard::to_stdvec(&data).unwrap();
+    // Licenses issued before domain separation signed the untagged payload, and those issued
+    // before per-feature terms and seat limits lacked both trailing fields.
+    assert_eq!(legacy.pop(), Some(0));
+    assert_eq!(legacy.pop(), Some(0));
+    let signature = signing.sign(&legacy).to_bytes().to_vec();
+
+    SignedLicense { data, signature }.validate(&public).unwrap();
+}
+
+#[test]
+fn legacy_binary_license_still_decodes() {
+    let (signing, public) = keypair();
+    let data = sample_license();
+    let mut legacy_data = postcard::to_stdvec(&data).unwrap();
+    assert_eq!(legacy_data.pop(), Some(0));
+    assert_eq!(legacy_data.pop(), Some(0));
+    let signature = signing.sign(&legacy_data).to_bytes().to_vec();
+    let bin = SignedLicense { data, signature }.encode_bin().unwrap();
+
+    // The original struct encoded the same data fields, then the signature right after them.
+    let mut legacy_bin = legacy_data.clone();
+    legacy_bin.extend_from_slice(&bin[legacy_data.len() + 2..]);
+
+    let decoded = SignedLicense::decode_bin(&legacy_bin).unwrap();
+    assert!(decoded.data.feature_expiry.is_empty());
+    assert_eq!(decoded.data.max_seats, None);
+    assert_eq!(decoded.data.customer, "test");
+    decoded.validate(&public).unwrap();
+}
+
+#[test]
+fn mixed_expiry_license_drops_lapsed_features() {
+    let (signing, public) = keypair();
+    let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs().cast_signed();
+    let license = sign_license(
+        &signing,
+        LicenseData {
+            features: FeatureSet::QUIZ | FeatureSet::SURVEY,
+            feature_expiry: vec![("survey".into(), now - 60)],
+            ..sample_license()
+        },
+    );
+
+    license.validate(&public).unwrap();
+    assert_eq!(license.active_features(), FeatureSet::QUIZ);
+
+    let renewed = sign_license(
+        &signing,
+        LicenseData { feature_expiry: vec![("survey".into(), now + 3600)], ..license.data.clone() },
+    );
+    assert_eq!(renewed.active_features(), FeatureSet::QUIZ | FeatureSet::SURVEY);
+}
+
+#[test]
+fn feature_terms_can_outlive_the_license() {
+    let (signing, _) = keypair();
+    let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs().cast_signed();
+    let license = sign_license(
+        &signing,
+        LicenseData {
+            features: FeatureSet::QUIZ | FeatureSet::SURVEY,
+            expires: now - 60,
+            feature_expiry: vec![("survey".into(), now + 3600)],
+            ..sample_license()
+        },
+    );
+
+    assert_eq!(license.active_features(), FeatureSet::SURVEY);
+}
+
+#[test]
+fn validate_any_accepts_license_signed_with_rotated_key() {
+    let (old_signing, old_public) = keypair();
+    let new_signing = SigningKey::from_bytes(&[8u8; 32]);
+    let new_public = new_signing.verifying_key().to_bytes();
+    let keys = [new_public, old_public];
+
+    sign_license(&old_signing, sample_license()).validate_any(&keys).unwrap();
+    sign_license(&new_signing, sample_license()).validate_any(&keys).unwrap();
+
+    let stranger = SigningKey::from_bytes(&[9u8; 32]);
+    let err = sign_license(&stranger, sample_license()).validate_any(&keys).unwrap_err();
+    assert!(matches!(err, LicenseError::InvalidSignature { .. }));
+}
+
+#[test]
+fn validate_any_reports_expiry_before_signature() {
+    let (_, old_public) = keypair();
+    let stranger = SigningKey::from_bytes(&[9u8; 32]);
+    let license = expired_secs_ago(&stranger, 60);
+
+    let err = license.validate_any(&[old_public, [0u8; 32]]).unwrap_err();
+    assert!(matches!(err, LicenseError::Expired { .. }));
+}
+
+#[test]
+fn v1_machine_ids_match_by_label() {
+    let allowed = constraints::parse_machine_id_compound("v1:aaa|bbb|ccc").unwrap();
+    let current = constraints::parse_machine_id_compound("v1:aaa|xxx|ccc").unwrap();
+
+    assert_eq!(allowed[1], ("mac".to_owned(), "bbb".to_owned()));
+    assert_eq!(constraints::matching_components(&allowed, &current), 2);
+}
+
+#[test]
+fn v2_machine_ids_match_by_label_regardless_of_order() {
+    let allowed =
+        constraints::parse_machine_id_compound("v2:cpuid=aaa;disk=ddd;board=eee").unwrap();
+    let current =
+        constraints::parse_machine_id_compound("v2:board=eee;cpuid=aaa;mac=bbb;disk=zzz").unwrap();
+
+    assert_eq!(constraints::matching_components(&allowed, &current), 2);
+}
+
+#[test]
+fn mixed_machine_id_versions_match_on_shared_labels() {
+    let current = constraints::parse_machine_id_compound(
+        "v2:cpuid=aaa;mac=bbb;system=ccc;disk=ddd;board=eee",
+    )
+    .unwrap();
+    let v1 = constraints::parse_machine_id_compound("v1:aaa|bbb|ccc").unwrap();
+    let v2 = constraints::parse_machine_id_compound("v2:disk=ddd;board=eee").unwrap();
+
+    assert_eq!(constraints::matching_components(&v1, &current), 3);
+    assert_eq!(constraints::matching_components(&v2, &current), 2);
+}
+
+#[test]
+fn malformed_machine_ids_are_rejected() {
+    for id in ["v3:aaa", "v1:aaa|bbb", "v2:", "v2:cpuid", "v2:cpuid=a;cpuid=b", "v2:=a"] {
+        let err = constraints::parse_machine_id_compound(id).unwrap_err();
+        assert!(matches!(err, LicenseError::MachineIDGeneration { .. }), "{id}");
+    }
+}
+
+#[cfg(feature = "issuance")]
+#[test]
+fn activation_token_roundtrip() {
+    let (signing, public) = keypair();
+    let request = ActivationRequest {
+        machine_id: "v2:cpuid=aaa;mac=bbb;system=ccc".into(),
+        nonce: vec![5; activation::NONCE_LEN],
+    };
+    let request = ActivationRequest::from_json(&request.to_json().unwrap()).unwrap();
+
+    let token = generator::issue_activation(&signing, &request).unwrap();
+    let token = ActivationToken::from_json(&token.to_json().unwrap()).unwrap();
+
+    token.verify(&public).unwrap();
+    assert!(token.answers(&request));
+    assert!(!token.answers(&ActivationRequest { nonce: vec![6; 32], ..request }));
+}
+
+#[test]
+fn tampered_activation_token_is_rejected() {
+    let (signing, public) = keypair();
+    let data = ActivationData {
+        machine_id: "v2:cpuid=aaa;mac=bbb;system=ccc".into(),
+        nonce: vec![5; activation::NONCE_LEN],
+        issued: 0,
+    };
+    let signature = signing.sign(&data.signing_bytes().unwrap()).to_bytes().to_vec();
+    let mut token = ActivationToken { data, signature };
+    token.data.machine_id = "v2:cpuid=xxx;mac=yyy;system=zzz".into();
+
+    let err = validator::verify_activation(&token, &public).unwrap_err();
+    assert!(matches!(err, LicenseError::InvalidSignature { .. }));
+}
+
+#[derive(Debug)]
+struct MockClock {
+    base: Instant,
+    elapsed: parking_lot::Mutex<Duration>,
+}
+
+impl MockClock {
+    fn advance(&self, by: Duration) {
+        *self.elapsed.lock() += by;
+    }
+}
+
+impl guard::Clock for MockClock {
+    fn now(&self) -> Instant {
+        self.base + *self.elapsed.lock()
+    }
+}
+
+#[test]
+fn license_guard_revalidates_after_interval() {
+    let (signing, public) = keypair();
+    let clock = Arc::new(MockClock { base: Instant::now(), elapsed: Default::default() });
+    let guard = LicenseGuard::with_clock(
+        sign_license(&signing, LicenseData { features: FeatureSet::QUIZ, ..sample_license() }),
+        vec![public],
+        Duration::from_secs(60),
+        Arc::clone(&clock),
+    )
+    .grace(Duration::from_secs(3600));
+    assert_eq!(guard.checks(), 0, "validation is deferred to the first use");
+
+    assert!(guard.is_valid());
+    assert!(guard.feature_enabled(FeatureSet::QUIZ));
+    assert!(!guard.feature_enabled(FeatureSet::SURVEY));
+    assert_eq!(guard.checks(), 1);
+
+    clock.advance(Duration::from_secs(61));
+    assert!(guard.is_valid());
+    assert_eq!(guard.checks(), 2);
+}
+
+#[test]
+fn license_guard_caches_invalid_verdict() {
+    let (_, public) = keypair();
+    let stranger = SigningKey::from_bytes(&[9u8; 32]);
+    let guard = LicenseGuard::new(
+        sign_license(&stranger, sample_license()),
+        vec![public],
+        Duration::from_secs(60),
+    );
+
+    assert!(!guard.is_valid());
+    assert!(!guard.feature_enabled(FeatureSet::QUIZ));
+    assert_eq!(guard.checks(), 1);
+}
+
+#[test]
+fn custom_hwid_profile_builds_labeled_compound_id() {
+    use constraints::{HwidProfile, MachineComponent};
+
+    let profile = HwidProfile::new([MachineComponent::SystemId]);
+    let id = match constraints::generate_machine_id_compound(&profile) {
+        Ok(id) => id,
+        // Containers without a machine id cannot fingerprint, so there is nothing to compare.
+        Err(err @ LicenseError::MachineIDGeneration { .. }) => {
+            eprintln!("skipping custom_hwid_profile_builds_labeled_compound_id: {err}");
+            return;
+        },
+        Err(err) => panic!("unexpected fingerprinting error: {err}"),
+    };
+
+    assert!(id.starts_with("v2:system="));
+    assert_eq!(constraints::generate_machine_id_compound(&profile).unwrap(), id);
+
+    let parsed = constraints::parse_machine_id_compound(&id).unwrap();
+    assert_eq!(parsed.len(), 1);
+    assert_eq!(parsed[0].0, "system");
+
+    let forked = constraints::generate_machine_id_compound(&profile.clone().salt("fork")).unwrap();
+    assert_ne!(forked, id);
+    let forked = constraints::parse_machine_id_compound(&forked).unwrap();
+    assert_eq!(constraints::matching_components(&parsed, &forked), 0);
+}
+
+#[test]
+fn default_hwid_profile_keeps_v1_components() {
+    use constraints::{HwidProfile, MachineComponent};
+
+    let profile = HwidProfile::default();
+    assert_eq!(
+        profile.components(),
+        [MachineComponent::CpuId, MachineComponent::MacAddress, MachineComponent::SystemId]
+    );
+    assert_eq!(
+        HwidProfile::new([MachineComponent::SystemId, MachineComponent::SystemId]).components(),
+        [MachineComponent::SystemId]
+    );
+}
+
+#[test]
+fn seat_limit_survives_roundtrip() {
+    let (signing, public) = keypair();
+    let license = sign_license(&signing, LicenseData { max_seats: Some(5), ..sample_license() });
+
+    let from_json = SignedLicense::from_json(&license.to_json().unwrap()).unwrap();
+    let from_bin = SignedLicense::decode_bin(&license.encode_bin().unwrap()).unwrap();
+    assert_eq!(from_json.data.max_seats, Some(5));
+    assert_eq!(from_bin.data.max_seats, Some(5));
+    from_bin.validate(&public).unwrap();
+
+    let mut tampered = from_json;
+    tampered.data.max_seats = Some(500);
+    assert!(tampered.validate(&public).is_err());
+}
+
+#[test]
+fn seat_limit_rejects_activation_over_limit() {
+    let license = LicenseData { max_seats: Some(2), ..sample_license() };
+
+    assert!(!seats::would_exceed(&license, ["v1:a|b|c"], "v1:d|e|f"));
+    assert!(seats::would_exceed(&license, ["v1:a|b|c", "v1:d|e|f"], "v1:g|h|i"));
+    // Re-activation and duplicate entries do not consume extra seats.
+    assert!(!seats::would_exceed(&license, ["v1:a|b|c", "v1:d|e|f"], "v1:a|b|c"));
+    assert!(!seats::would_exceed(&license, ["v1:a|b|c", "v1:a|b|c"], "v1:d|e|f"));
+    assert!(!seats::would_exceed(&sample_license(), ["v1:a|b|c", "v1:d|e|f"], "v1:g|h|i"));
+}
+
+#[cfg(feature = "issuance")]
+#[test]
+fn sign_with_loaded_key_produces_verifiable_license() {
+    let (signing, public) = keypair();
+    let path = std::env::temp_dir().join(format!("mhub-licensing-key-{}", std::process::id()));
+    std::fs::write(&path, [signing.to_bytes(), public].concat()).unwrap();
+
+    let key = generator::load_signing_key(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    let config = generator::UniversalLicenseConfig {
+        customer: "test".into(),
+        alias: "test-ns".into(),
+        constraint: MachineConstraint::Any,
+        days: 30,
+        features: vec!["quiz".into()],
+        feature_days: Vec::new(),
+        max_seats: None,
+        salt: [3; 32],
+    };
+    let license = generator::sign_with_key(key, config).unwrap();
+
+    license.validate(&public).unwrap();
+    assert_eq!(license.active_features(), FeatureSet::QUIZ);
+}
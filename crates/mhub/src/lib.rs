@@ -9,15 +9,19 @@
 use mhub_database::Database;
 pub use mhub_domain as domain;
 use mhub_domain::config::ApiConfig;
+#[cfg(feature = "server")]
+use mhub_domain::registry::InitializedSlice;
 use mhub_event_bus::EventBus;
 pub use mhub_kernel as kernel;
 #[cfg(feature = "server")]
+use mhub_kernel::server::SliceContext;
+#[cfg(feature = "server")]
 pub use mhub_licensing as licensing;
 
 #[cfg(feature = "server")]
 pub mod server {
     pub mod router {
-        pub use mhub_kernel::server::router::system_router;
+        pub use mhub_kernel::server::router::{probe_router, system_router};
     }
 }
 
@@ -51,18 +55,22 @@ pub mod features {
 
 /// Initialize all enabled features for server mode.
 ///
+/// Slices with async bootstrap are awaited in registration order.
+///
 /// # Errors
 /// Returns an error if any feature initialization fails.
 #[cfg(feature = "server")]
-pub fn init(
+pub async fn init(
     config: &ApiConfig,
     database: &Database,
     events: &EventBus,
-) -> Result<Vec<domain::registry::InitializedSlice>, Box<dyn std::error::Error>> {
+) -> Result<Vec<InitializedSlice>, Box<dyn std::error::Error>> {
+    let context =
+        SliceContext { config: config.clone(), database: database.clone(), events: events.clone() };
     let mut slices = Vec::new();
 
     // Audit
-    slices.push(features::audit::init()?);
+    slices.push(InitializedSlice::init_async::<features::audit::Audit, _>(&context).await?);
 
     // Organization
     slices.push(features::organization::init()?);
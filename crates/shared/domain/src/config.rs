@@ -1,8 +1,10 @@
 use serde::Deserialize;
+use std::collections::BTreeMap;
 use std::net::{IpAddr, Ipv4Addr};
 use std::ops::{Deref, DerefMut};
 use std::path::PathBuf;
 use std::sync::Arc;
+use std::time::Duration;
 
 /// Top-level API configuration shared across services.
 #[derive(Default, Debug, Clone, Deserialize)]
@@ -42,6 +44,29 @@ pub struct ServerConfig {
     pub address: IpAddr,
     pub port: u16,
     pub ssl: Option<SslConfig>,
+    /// Listen on this Unix domain socket instead of `address:port`.
+    ///
+    /// Mutually exclusive with `ssl`: TLS is expected to terminate at the fronting proxy.
+    pub unix_socket: Option<PathBuf>,
+    /// How long in-flight requests may drain after a shutdown signal before they are
+    /// forcibly dropped.
+    pub shutdown_grace_seconds: u64,
+    /// Cross-origin policy; CORS headers are not sent when unset.
+    pub cors: Option<CorsConfig>,
+    /// Rate limits keyed by route prefix; the longest matching prefix applies.
+    pub rate_limits: BTreeMap<String, RateLimitRule>,
+    /// Reverse proxies whose `X-Forwarded-For` header identifies the client for rate limiting.
+    ///
+    /// Requests from other peers are keyed by their own address. Peers of `unix_socket` are
+    /// always trusted, since only processes allowed to open the socket can connect.
+    pub trusted_proxies: Vec<IpAddr>,
+}
+
+impl ServerConfig {
+    #[must_use]
+    pub const fn shutdown_grace(&self) -> Duration {
+        Duration::from_secs(self.shutdown_grace_seconds)
+    }
 }
 
 /// TLS certificate/key paths.
@@ -50,6 +75,37 @@ pub struct ServerConfig {
 pub struct SslConfig {
     pub cert: PathBuf,
     pub key: PathBuf,
+    /// Reload the certificate and key in place when the files change or on `SIGHUP`,
+    /// e.g. after a Let's Encrypt renewal.
+    pub watch: bool,
+}
+
+/// CORS policy for browser clients served from other origins.
+///
+/// `"*"` in any list allows everything, which browsers refuse together with credentials.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct CorsConfig {
+    pub allowed_origins: Vec<String>,
+    pub allowed_methods: Vec<String>,
+    pub allowed_headers: Vec<String>,
+    pub allow_credentials: bool,
+}
+
+/// Token-bucket limit for requests under a route prefix.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub struct RateLimitRule {
+    /// Requests allowed per window, which is also the burst size.
+    pub requests: u32,
+    /// Seconds it takes to refill `requests` tokens.
+    pub window_seconds: u64,
+}
+
+impl RateLimitRule {
+    #[must_use]
+    pub const fn window(&self) -> Duration {
+        Duration::from_secs(self.window_seconds)
+    }
 }
 
 /// `SurrealDB` connection configuration.
@@ -120,13 +176,22 @@ pub struct DpopConfig {
 
 impl Default for ServerConfig {
     fn default() -> Self {
-        Self { address: IpAddr::V4(Ipv4Addr::UNSPECIFIED), port: 4583, ssl: None }
+        Self {
+            address: IpAddr::V4(Ipv4Addr::UNSPECIFIED),
+            port: 4583,
+            ssl: None,
+            unix_socket: None,
+            shutdown_grace_seconds: 30,
+            cors: None,
+            rate_limits: BTreeMap::new(),
+            trusted_proxies: Vec::new(),
+        }
     }
 }
 
 impl Default for SslConfig {
     fn default() -> Self {
-        Self { cert: PathBuf::from("cert.pem"), key: PathBuf::from("key.pem") }
+        Self { cert: PathBuf::from("cert.pem"), key: PathBuf::from("key.pem"), watch: false }
     }
 }
 
@@ -3,6 +3,7 @@
 
 use std::any::{Any, TypeId};
 use std::fmt::Debug;
+use std::future::Future;
 
 /// Marker trait for feature state that can be shared across threads.
 pub trait FeatureSlice: Any + Debug + Send + Sync {
@@ -10,6 +11,26 @@ pub trait FeatureSlice: Any + Debug + Send + Sync {
     fn as_any(&self) -> &dyn Any;
 }
 
+/// Async bootstrap contract for slices that need I/O (repositories, event subscriptions)
+/// before they can be registered. `C` is the dependency set handed over by the host.
+///
+/// `#[mhub_slice]` implements it for every slice whose inner state implements
+/// [`SliceBootstrap`].
+pub trait FeatureSliceAsync<C: ?Sized>: FeatureSlice + Sized {
+    type Error;
+
+    /// Builds the slice from the host dependencies.
+    fn init_async(context: &C) -> impl Future<Output = Result<Self, Self::Error>> + Send;
+}
+
+/// Async constructor for the inner state of a slice.
+pub trait SliceBootstrap<C: ?Sized>: Sized {
+    type Error;
+
+    /// Builds the inner state from the host dependencies.
+    fn bootstrap(context: &C) -> impl Future<Output = Result<Self, Self::Error>> + Send;
+}
+
 /// A container for an initialized feature.
 #[derive(Debug)]
 pub struct InitializedSlice {
@@ -22,4 +43,16 @@ impl InitializedSlice {
     pub fn new<T: FeatureSlice>(state: T) -> Self {
         Self { id: TypeId::of::<T>(), state: Box::new(state) }
     }
+
+    /// Runs the async bootstrap of `T` and wraps the resulting state.
+    ///
+    /// # Errors
+    /// Returns the error reported by [`FeatureSliceAsync::init_async`].
+    pub async fn init_async<T, C>(context: &C) -> Result<Self, T::Error>
+    where
+        T: FeatureSliceAsync<C>,
+        C: ?Sized,
+    {
+        T::init_async(context).await.map(Self::new)
+    }
 }
@@ -1,4 +1,4 @@
-use mhub_domain::config::{ApiConfig, DatabaseConfig, ServerConfig, StorageConfig};
+use mhub_domain::config::{ApiConfig, DatabaseConfig, RateLimitRule, ServerConfig, StorageConfig};
 use serde_json::json;
 
 #[test]
@@ -6,6 +6,8 @@ fn config_defaults_are_sane() {
     let server = ServerConfig::default();
     assert_eq!(server.port, 4583);
     assert!(server.ssl.is_none());
+    assert!(server.unix_socket.is_none());
+    assert_eq!(server.shutdown_grace(), std::time::Duration::from_secs(30));
 
     let db = DatabaseConfig::default();
     assert_eq!(db.url, "mem://");
@@ -30,3 +32,30 @@ fn api_config_deserializes() {
     assert_eq!(cfg.database.namespace, "n");
     assert_eq!(cfg.storage.static_dir, std::path::PathBuf::from("/tmp/static"));
 }
+
+#[test]
+fn shutdown_grace_is_configurable() {
+    let cfg: ServerConfig =
+        serde_json::from_value(json!({ "shutdown_grace_seconds": 5 })).expect("config deserialize");
+
+    assert_eq!(cfg.shutdown_grace(), std::time::Duration::from_secs(5));
+    assert_eq!(cfg.port, 4583);
+}
+
+#[test]
+fn unix_socket_is_configurable() {
+    let cfg: ServerConfig = serde_json::from_value(json!({ "unix_socket": "/run/mhub/api.sock" }))
+        .expect("config deserialize");
+
+    assert_eq!(cfg.unix_socket, Some(std::path::PathBuf::from("/run/mhub/api.sock")));
+}
+
+#[test]
+fn rate_limits_deserialize_by_prefix() {
+    let cfg: ServerConfig = serde_json::from_value(json!({
+        "rate_limits": { "/api/auth": { "requests": 5, "window_seconds": 60 } }
+    }))
+    .expect("config deserialize");
+
+    assert_eq!(cfg.rate_limits["/api/auth"], RateLimitRule { requests: 5, window_seconds: 60 });
+}
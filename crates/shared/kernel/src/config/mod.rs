@@ -4,11 +4,33 @@ use std::borrow::Cow;
 use std::path::{Path, PathBuf};
 use tracing::info;
 
+mod watch;
+
+pub use watch::{ConfigWatcher, watch_config};
+
 /// Custom error type for config loading.
 #[mhub_derive::mhub_error]
 pub enum ConfigError {
     #[error("Config error{}: {source}", format_context(.context))]
     Config { source: config::ConfigError, context: Option<Cow<'static, str>> },
+    #[error("Config IO error{}: {source}", format_context(.context))]
+    Io { source: std::io::Error, context: Option<Cow<'static, str>> },
+    #[error("Config validation error{}: {message}", format_context(.context))]
+    Validation { message: Cow<'static, str>, context: Option<Cow<'static, str>> },
+}
+
+/// Semantic checks run on a configuration after it has been deserialized.
+///
+/// The default implementation accepts everything, so `impl Validate for AppConfig {}` opts a
+/// type into [`load_config_validated`] without extra rules.
+pub trait Validate {
+    /// Returns a description of the first invalid setting, if any.
+    ///
+    /// # Errors
+    /// Returns a human-readable message when the configuration is unusable.
+    fn validate(&self) -> Result<(), String> {
+        Ok(())
+    }
 }
 
 /// A reusable configuration loader that combines file-based settings with environment overrides.
@@ -52,11 +74,7 @@ where
 
     let builder = Config::builder()
         .add_source(File::from(effective_path.as_path()).required(true))  // Now required since we checked existence
-        .add_source(
-            Environment::with_prefix("MHUB")
-                .separator("__")
-                .convert_case(config::Case::Snake),  // Env var overrides (e.g., MHUB__API__KEY)
-        );
+        .add_source(environment());
 
     info!("Loading config from {}", effective_path.display());
 
@@ -68,3 +86,116 @@ where
 
     Ok(config)
 }
+
+/// Loads configuration like [`load_config`], then runs [`Validate::validate`] on it.
+///
+/// This catches parseable but unusable settings (e.g., port `0` or an empty URL) at startup.
+///
+/// # Errors
+/// Returns the errors of [`load_config`], or [`ConfigError::Validation`] if the loaded
+/// configuration is rejected.
+///
+/// # Example
+/// ```rust,no_run
+/// use mhub_kernel::config::{Validate, load_config_validated};
+///
+/// #[derive(serde::Deserialize)]
+/// struct AppConfig {
+///     port: u16,
+/// }
+///
+/// impl Validate for AppConfig {
+///     fn validate(&self) -> Result<(), String> {
+///         if self.port == 0 { Err("port must not be 0".to_owned()) } else { Ok(()) }
+///     }
+/// }
+///
+/// let cfg: AppConfig = load_config_validated(Some("server")).unwrap();
+/// ```
+pub fn load_config_validated<T>(path: Option<impl AsRef<Path>>) -> Result<T, ConfigError>
+where
+    T: DeserializeOwned + Validate,
+{
+    let config = load_config::<T>(path)?;
+    validate(&config)?;
+    Ok(config)
+}
+
+fn validate<T: Validate>(config: &T) -> Result<(), ConfigError> {
+    config.validate().map_err(|message| ConfigError::Validation {
+        message: message.into(),
+        context: Some(format!("Invalid {}", std::any::type_name::<T>()).into()),
+    })
+}
+
+/// Loads configuration from several files layered in order, followed by `MHUB__` environment
+/// overrides.
+///
+/// Later files override earlier ones (e.g., `["server", "server.local"]` lets a local file
+/// patch the shared base). Missing layers are skipped, but at least one must exist.
+///
+/// # Errors
+/// Returns an error if none of the files exist, a file cannot be parsed, or the merged
+/// configuration does not match `T`.
+///
+/// # Example
+/// ```rust,no_run
+/// use mhub_kernel::config::load_config_layered;
+///
+/// #[derive(serde::Deserialize)]
+/// struct AppConfig {
+///     port: u16,
+/// }
+///
+/// let cfg: AppConfig = load_config_layered(&["server", "server.local"]).unwrap();
+/// ```
+pub fn load_config_layered<T, P>(paths: &[P]) -> Result<T, ConfigError>
+where
+    T: DeserializeOwned,
+    P: AsRef<Path>,
+{
+    let mut builder = Config::builder();
+    let mut found = Vec::new();
+    for path in paths.iter().map(AsRef::as_ref) {
+        // Each layer is parsed once and merged as an already-built source.
+        match Config::builder().add_source(File::from(path).required(true)).build() {
+            Ok(layer) => {
+                builder = builder.add_source(layer);
+                found.push(path);
+            },
+            Err(e) if is_missing(&e) => {},
+            Err(e) => {
+                return Err(e).context(format!("Failed to load layer {}", path.display()));
+            },
+        }
+    }
+
+    if found.is_empty() {
+        return Err(config::ConfigError::Message("no configuration file found".to_owned()))
+            .context(format!("Tried {} layer(s)", paths.len()));
+    }
+
+    info!(layers = ?found, "Loading layered config");
+
+    builder
+        .add_source(environment())
+        .build()
+        .context("Failed to build config")?
+        .try_deserialize::<T>()
+        .context("Failed to deserialize config")
+}
+
+/// Environment overrides (e.g., `MHUB__API__KEY` maps to `api.key`).
+fn environment() -> Environment {
+    Environment::with_prefix("MHUB").separator("__").convert_case(config::Case::Snake)
+}
+
+/// Returns `true` if `error` reports a required file that does not exist.
+fn is_missing(error: &config::ConfigError) -> bool {
+    let config::ConfigError::Foreign(source) = error else {
+        return false;
+    };
+    source
+        .downcast_ref::<std::io::Error>()
+        .is_some_and(|e| e.kind() == std::io::ErrorKind::NotFound)
+}
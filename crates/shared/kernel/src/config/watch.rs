@@ -0,0 +1,167 @@
+use super::{ConfigError, ConfigErrorExt, load_config};
+use config::{FileFormat, FileStoredFormat};
+use notify::{Event, RecommendedWatcher, RecursiveMode, Watcher};
+use serde::de::DeserializeOwned;
+use std::ffi::OsStr;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{self, RecvTimeoutError, Sender};
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+use tracing::{debug, info, warn};
+
+/// How long the file must stay unchanged before it is reloaded.
+const DEBOUNCE: Duration = Duration::from_millis(300);
+
+/// Formats probed for extensionless paths, mirroring the `config` crate's file lookup.
+const FORMATS: [FileFormat; 6] = [
+    FileFormat::Toml,
+    FileFormat::Json,
+    FileFormat::Yaml,
+    FileFormat::Ini,
+    FileFormat::Ron,
+    FileFormat::Json5,
+];
+
+enum Signal {
+    Changed,
+    Stop,
+}
+
+/// Keeps a config file watched; dropping it stops the watcher thread.
+#[must_use = "watching stops when the guard is dropped"]
+#[derive(Debug)]
+pub struct ConfigWatcher {
+    watcher: Option<RecommendedWatcher>,
+    signals: Sender<Signal>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl Drop for ConfigWatcher {
+    fn drop(&mut self) {
+        // Stop filesystem notifications first so no change can race the stop signal.
+        self.watcher.take();
+        let _ = self.signals.send(Signal::Stop);
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+/// Watches a config file and calls `on_change` with the reloaded value after each edit.
+///
+/// `path` is resolved like [`load_config`] does: an extensionless path such as `"server"`
+/// watches the first existing `server.toml`, `server.json`, and so on. Changes are picked up
+/// through the platform's filesystem notifier (inotify, `FSEvents`, `ReadDirectoryChangesW`)
+/// on the containing directory, so editors that save by replacing the file are followed.
+/// Rapid successive writes are debounced: the file is reloaded with [`load_config`] once it
+/// has been stable for a short window, so environment overrides still apply. Reloads that
+/// fail to parse are logged and skipped; the callback only sees valid configurations.
+///
+/// # Errors
+/// Returns [`ConfigError::Io`] if `path` cannot be found, or if the notifier or the watcher
+/// thread cannot be started.
+///
+/// # Example
+/// ```rust,no_run
+/// use mhub_kernel::config::watch_config;
+///
+/// #[derive(serde::Deserialize)]
+/// struct AppConfig {
+///     log_level: String,
+/// }
+///
+/// let _watcher = watch_config("server", |cfg: AppConfig| {
+///     tracing::info!(level = %cfg.log_level, "Config reloaded");
+/// })
+/// .unwrap();
+/// ```
+pub fn watch_config<T>(
+    path: impl AsRef<Path>,
+    on_change: impl Fn(T) + Send + 'static,
+) -> Result<ConfigWatcher, ConfigError>
+where
+    T: DeserializeOwned + 'static,
+{
+    let requested = path.as_ref();
+    let path = resolve_file(requested).context(format!("Watching {}", requested.display()))?;
+    let (dir, name) = match (path.parent(), path.file_name()) {
+        (Some(dir), Some(name)) => (dir.to_path_buf(), name.to_os_string()),
+        _ => {
+            return Err(std::io::Error::from(std::io::ErrorKind::InvalidInput))
+                .context(format!("Watching {}", path.display()));
+        },
+    };
+
+    let (signals, received) = mpsc::channel::<Signal>();
+    let notifier = signals.clone();
+    let mut watcher =
+        notify::recommended_watcher(move |event: notify::Result<Event>| match event {
+            Ok(event) if touches(&event, &name) => {
+                let _ = notifier.send(Signal::Changed);
+            },
+            Ok(_) => {},
+            Err(e) => warn!(error = %e, "Config watcher notification failed"),
+        })
+        .map_err(std::io::Error::other)
+        .context("Creating config watcher")?;
+    watcher
+        .watch(&dir, RecursiveMode::NonRecursive)
+        .map_err(std::io::Error::other)
+        .context(format!("Watching {}", dir.display()))?;
+
+    let thread = thread::Builder::new()
+        .name("mhub-config-watch".to_owned())
+        .spawn(move || {
+            info!(path = %path.display(), "Watching config for changes");
+            while let Ok(Signal::Changed) = received.recv() {
+                // Wait for writers to finish before reloading.
+                loop {
+                    match received.recv_timeout(DEBOUNCE) {
+                        Ok(Signal::Changed) => {},
+                        Err(RecvTimeoutError::Timeout) => break,
+                        Ok(Signal::Stop) | Err(RecvTimeoutError::Disconnected) => return,
+                    }
+                }
+                reload(&path, &on_change);
+            }
+            debug!(path = %path.display(), "Config watcher stopped");
+        })
+        .context("Spawning config watcher")?;
+
+    Ok(ConfigWatcher { watcher: Some(watcher), signals, thread: Some(thread) })
+}
+
+/// Returns the absolute path of the file [`load_config`] reads for `path`: the path itself
+/// if it is a file, otherwise the first existing `path.<ext>` for the supported formats.
+fn resolve_file(path: &Path) -> std::io::Result<PathBuf> {
+    if path.is_file() {
+        return std::fs::canonicalize(path);
+    }
+    FORMATS
+        .iter()
+        .flat_map(FileStoredFormat::file_extensions)
+        .map(|ext| {
+            let mut candidate = path.as_os_str().to_owned();
+            candidate.push(".");
+            candidate.push(ext);
+            PathBuf::from(candidate)
+        })
+        .find(|candidate| candidate.is_file())
+        .map_or_else(|| Err(std::io::ErrorKind::NotFound.into()), std::fs::canonicalize)
+}
+
+/// Returns whether `event` created or modified the watched file `name`.
+fn touches(event: &Event, name: &OsStr) -> bool {
+    (event.kind.is_create() || event.kind.is_modify())
+        && event.paths.iter().any(|path| path.file_name() == Some(name))
+}
+
+fn reload<T: DeserializeOwned>(path: &Path, on_change: &impl Fn(T)) {
+    match load_config::<T>(Some(path)) {
+        Ok(config) => {
+            info!(path = %path.display(), "Config changed, reloaded");
+            on_change(config);
+        },
+        Err(e) => warn!(path = %path.display(), error = %e, "Ignoring invalid config change"),
+    }
+}
@@ -0,0 +1,46 @@
+//! Unambiguous ID generation beyond the [`safe_nanoid!`](crate::safe_nanoid) defaults.
+
+use crate::SAFE_ALPHABET;
+use std::borrow::Cow;
+
+/// Number of candidates [`safe_nanoid_unique`] generates before giving up.
+pub const UNIQUE_ID_ATTEMPTS: usize = 8;
+
+#[mhub_derive::mhub_error]
+pub enum IdError {
+    #[error("Unique ID generation failed after {attempts} attempts{}", format_context(.context))]
+    Exhausted { attempts: usize, context: Option<Cow<'static, str>> },
+}
+
+/// Generates a `NanoID` of `size` characters drawn from `alphabet`.
+///
+/// # Panics
+/// Panics if `alphabet` is empty or has more than 255 characters.
+#[must_use]
+pub fn safe_nanoid_with(alphabet: &[char], size: usize) -> String {
+    assert!(
+        !alphabet.is_empty() && alphabet.len() <= usize::from(u8::MAX),
+        "alphabet must have between 1 and 255 characters"
+    );
+    nanoid::format(nanoid::rngs::default, alphabet, size)
+}
+
+/// Generates an unambiguous `NanoID` that `exists` reports as unused.
+///
+/// A fresh candidate is drawn for every collision, up to [`UNIQUE_ID_ATTEMPTS`] in total.
+///
+/// # Errors
+/// Returns [`IdError::Exhausted`] if every candidate collided.
+pub fn safe_nanoid_unique(size: usize, exists: impl Fn(&str) -> bool) -> Result<String, IdError> {
+    for _ in 0..UNIQUE_ID_ATTEMPTS {
+        let id = safe_nanoid_with(SAFE_ALPHABET, size);
+        if !exists(&id) {
+            return Ok(id);
+        }
+    }
+
+    Err(IdError::Exhausted {
+        attempts: UNIQUE_ID_ATTEMPTS,
+        context: Some(format!("{size}-character IDs").into()),
+    })
+}
@@ -9,6 +9,16 @@
 //! assert_eq!(id.len(), 12);
 //! ```
 //!
+//! `safe_nanoid_with` takes a custom alphabet, and `safe_nanoid_unique` retries on collisions
+//! reported by the caller:
+//! ```rust
+//! # use mhub_kernel::{safe_nanoid_unique, safe_nanoid_with};
+//! let lower = safe_nanoid_with(&['a', 'b', 'c', 'd'], 8);
+//! assert!(lower.chars().all(|c| c.is_ascii_lowercase()));
+//! let id = safe_nanoid_unique(12, |candidate| candidate == "taken").unwrap();
+//! assert_eq!(id.len(), 12);
+//! ```
+//!
 //! ## Config loading (non-wasm)
 //! ```rust,ignore
 //! #[cfg(not(target_arch = "wasm32"))]
@@ -19,6 +29,7 @@
 //! ```
 #[cfg(not(target_arch = "wasm32"))]
 pub mod config;
+pub mod id;
 pub mod prelude;
 pub mod security;
 #[cfg(feature = "server")]
@@ -31,6 +42,7 @@ pub const SAFE_ALPHABET: &[char; 55] = &[
     'g', 'h', 'j', 'k', 'm', 'n', 'p', 'q', 'r', 's', 't', 'u', 'v', 'w', 'x', 'y', 'z',
 ];
 
+pub use id::{IdError, safe_nanoid_unique, safe_nanoid_with};
 pub use mhub_domain as domain;
 pub use nanoid::nanoid;
 
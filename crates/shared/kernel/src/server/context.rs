@@ -0,0 +1,13 @@
+use mhub_database::Database;
+use mhub_domain::config::ApiConfig;
+use mhub_event_bus::EventBus;
+
+/// Host dependencies handed to feature slices during async bootstrap.
+///
+/// See [`FeatureSliceAsync`](mhub_domain::registry::FeatureSliceAsync).
+#[derive(Debug, Clone)]
+pub struct SliceContext {
+    pub config: ApiConfig,
+    pub database: Database,
+    pub events: EventBus,
+}
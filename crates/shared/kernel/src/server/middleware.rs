@@ -1 +1,94 @@
+use crate::safe_nanoid;
+use axum::extract::{Request, State};
+use axum::http::HeaderValue;
+use axum::middleware::Next;
+use axum::response::Response;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use tracing::{Instrument, info_span};
 
+/// Header carrying the request ID in both directions.
+pub const REQUEST_ID_HEADER: &str = "x-request-id";
+/// Longest client-supplied request ID that is propagated; longer ones are replaced.
+pub const MAX_REQUEST_ID_LEN: usize = 128;
+
+/// Request ID of the current request, available to handlers as an Axum extension.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RequestId(pub String);
+
+/// Middleware propagating `X-Request-Id`.
+///
+/// Reuses the client-supplied ID when it is printable ASCII of at most
+/// [`MAX_REQUEST_ID_LEN`] bytes, and generates one with [`safe_nanoid!`] otherwise. The ID is
+/// recorded on a `request` span wrapping the rest of the stack, inserted as a [`RequestId`]
+/// extension, and echoed in the response headers.
+///
+/// ```rust,ignore
+/// let router = router.layer(axum::middleware::from_fn(request_id));
+/// ```
+pub async fn request_id(mut request: Request, next: Next) -> Response {
+    let id = request
+        .headers()
+        .get(REQUEST_ID_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .filter(|value| is_valid_request_id(value))
+        .map_or_else(|| safe_nanoid!(), ToOwned::to_owned);
+
+    let span = info_span!(
+        "request",
+        request_id = %id,
+        method = %request.method(),
+        uri = %request.uri(),
+    );
+    request.extensions_mut().insert(RequestId(id.clone()));
+
+    let mut response = next.run(request).instrument(span).await;
+    if let Ok(value) = HeaderValue::from_str(&id) {
+        response.headers_mut().insert(REQUEST_ID_HEADER, value);
+    }
+    response
+}
+
+fn is_valid_request_id(id: &str) -> bool {
+    !id.is_empty() && id.len() <= MAX_REQUEST_ID_LEN && id.bytes().all(|b| b.is_ascii_graphic())
+}
+
+/// Shared count of requests currently being handled, used to report drain progress.
+#[derive(Debug, Clone, Default)]
+pub struct InFlight(Arc<AtomicUsize>);
+
+impl InFlight {
+    /// Number of requests that have entered [`track_in_flight`] and not yet completed.
+    #[must_use]
+    pub fn count(&self) -> usize {
+        self.0.load(Ordering::Acquire)
+    }
+
+    fn enter(&self) -> InFlightGuard {
+        self.0.fetch_add(1, Ordering::AcqRel);
+        InFlightGuard(Arc::clone(&self.0))
+    }
+}
+
+/// Decrements the counter on drop, so cancelled and panicking requests are released too.
+struct InFlightGuard(Arc<AtomicUsize>);
+
+impl Drop for InFlightGuard {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::AcqRel);
+    }
+}
+
+/// Middleware counting in-flight requests in the shared [`InFlight`] state.
+///
+/// ```rust,ignore
+/// let router = router.layer(axum::middleware::from_fn_with_state(in_flight, track_in_flight));
+/// ```
+pub async fn track_in_flight(
+    State(in_flight): State<InFlight>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let _guard = in_flight.enter();
+    next.run(request).await
+}
@@ -1,8 +1,16 @@
+mod context;
 mod extractors;
 mod health;
 mod middleware;
+mod probes;
+mod rate_limit;
 mod responders;
 pub mod router;
 mod state;
 
+pub use context::SliceContext;
+pub use middleware::{
+    InFlight, MAX_REQUEST_ID_LEN, REQUEST_ID_HEADER, RequestId, request_id, track_in_flight,
+};
+pub use rate_limit::{RateLimiter, rate_limit};
 pub use state::ApiState;
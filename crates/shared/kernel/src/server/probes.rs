@@ -0,0 +1,66 @@
+use crate::server::InFlight;
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::{Json, response::IntoResponse};
+use mhub_database::Database;
+use mhub_derive::{api_handler, api_model};
+use mhub_domain::constants::SYSTEM_TAG;
+use tracing::warn;
+
+#[api_model]
+/// Probe response
+struct ProbeResponse {
+    /// Probe status
+    status: &'static str,
+}
+
+#[api_model]
+/// Readiness probe response
+struct ReadinessResponse {
+    /// Probe status
+    status: &'static str,
+    /// Requests currently being handled
+    in_flight: usize,
+}
+
+/// Liveness probe: succeeds as long as the process serves requests.
+#[api_handler(
+    get,
+    path = "/livez",
+    responses((status = OK, description = "Process is alive", body = ProbeResponse)),
+    tag = SYSTEM_TAG,
+)]
+pub(super) async fn livez_handler() -> impl IntoResponse {
+    Json(ProbeResponse { status: "alive" })
+}
+
+/// Readiness probe: succeeds once the database answers health checks.
+///
+/// The server only starts after migrations have been applied, so a healthy connection means
+/// the schema is ready too. The body also reports the number of in-flight requests.
+#[api_handler(
+    get,
+    path = "/readyz",
+    responses(
+        (status = OK, description = "Ready to serve traffic", body = ReadinessResponse),
+        (
+            status = SERVICE_UNAVAILABLE,
+            description = "Database unreachable",
+            body = ReadinessResponse
+        ),
+    ),
+    tag = SYSTEM_TAG,
+)]
+pub(super) async fn readyz_handler(
+    State(database): State<Database>,
+    State(in_flight): State<InFlight>,
+) -> impl IntoResponse {
+    let (code, status) = match database.health().await {
+        Ok(()) => (StatusCode::OK, "ready"),
+        Err(e) => {
+            warn!(error = %e, "Readiness probe failed");
+            (StatusCode::SERVICE_UNAVAILABLE, "unavailable")
+        },
+    };
+    (code, Json(ReadinessResponse { status, in_flight: in_flight.count() }))
+}
@@ -0,0 +1,229 @@
+use axum::extract::{ConnectInfo, Request, State};
+use axum::http::{HeaderMap, HeaderValue, StatusCode, header};
+use axum::middleware::Next;
+use axum::response::{IntoResponse, Response};
+use fxhash::{FxHashMap, hash64};
+use mhub_domain::config::RateLimitRule;
+use parking_lot::Mutex;
+use std::collections::BTreeMap;
+use std::net::{IpAddr, SocketAddr};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tracing::debug;
+
+const SHARDS: usize = 16;
+/// Minimum time between sweeps of idle buckets.
+const SWEEP_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Per-route token-bucket rate limiter shared by all requests.
+///
+/// Buckets live in a sharded map and are swept at most once per minute: a bucket that has
+/// refilled completely carries no state worth keeping and is evicted, which bounds memory by
+/// the number of clients active within one window.
+#[derive(Debug, Clone)]
+pub struct RateLimiter {
+    inner: Arc<RateLimiterInner>,
+    /// Peers whose `X-Forwarded-For` header is honored.
+    trusted_proxies: Arc<[IpAddr]>,
+    /// Whether requests without a peer address (Unix socket connections) come from a proxy.
+    trust_unix_peer: bool,
+}
+
+#[derive(Debug)]
+struct RateLimiterInner {
+    /// Rules ordered by descending prefix length, so the first match is the most specific.
+    rules: Vec<(String, RateLimitRule)>,
+    shards: Box<[Mutex<FxHashMap<BucketKey, Bucket>>]>,
+    last_sweep: Mutex<Instant>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct BucketKey {
+    rule: usize,
+    client: Client,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum Client {
+    Ip(IpAddr),
+    Unknown,
+}
+
+#[derive(Debug)]
+struct Bucket {
+    tokens: f64,
+    updated: Instant,
+}
+
+impl RateLimiter {
+    /// Builds a limiter from `server.rate_limits`. Rules allowing zero requests or using a
+    /// zero-length window are ignored.
+    #[must_use]
+    pub fn new(rules: &BTreeMap<String, RateLimitRule>) -> Self {
+        let mut rules: Vec<_> = rules
+            .iter()
+            .filter(|(_, rule)| rule.requests > 0 && rule.window_seconds > 0)
+            .map(|(prefix, rule)| (prefix.clone(), *rule))
+            .collect();
+        rules.sort_by(|(a, _), (b, _)| b.len().cmp(&a.len()));
+
+        let shards = (0..SHARDS).map(|_| Mutex::new(FxHashMap::default())).collect();
+        Self {
+            inner: Arc::new(RateLimiterInner {
+                rules,
+                shards,
+                last_sweep: Mutex::new(Instant::now()),
+            }),
+            trusted_proxies: Arc::from([]),
+            trust_unix_peer: false,
+        }
+    }
+
+    /// Honors `X-Forwarded-For` on requests from `proxies` (`server.trusted_proxies`).
+    ///
+    /// Only the last entry, the address the proxy itself received the request from, is used;
+    /// earlier entries are client-controlled. Requests from other peers are keyed by the peer
+    /// address, whatever headers they carry.
+    #[must_use]
+    pub fn trusted_proxies(mut self, proxies: impl IntoIterator<Item = IpAddr>) -> Self {
+        self.trusted_proxies = proxies.into_iter().collect();
+        self
+    }
+
+    /// Honors `X-Forwarded-For` on requests that have no peer address, i.e. connections
+    /// accepted on `server.unix_socket`, where only processes allowed to open the socket can
+    /// connect.
+    #[must_use]
+    pub fn trust_unix_peer(mut self, trusted: bool) -> Self {
+        self.trust_unix_peer = trusted;
+        self
+    }
+
+    /// Returns `true` if no rule is configured, in which case the middleware can be skipped.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.inner.rules.is_empty()
+    }
+
+    /// Takes a token for the request, or returns how long to wait until one is available.
+    fn acquire(&self, path: &str, client: impl FnOnce() -> Client) -> Result<(), Duration> {
+        let Some((index, rule)) =
+            self.inner.rules.iter().enumerate().find_map(|(index, (prefix, rule))| {
+                matches_prefix(path, prefix).then_some((index, rule))
+            })
+        else {
+            return Ok(());
+        };
+
+        let now = Instant::now();
+        self.sweep(now);
+
+        let capacity = f64::from(rule.requests);
+        let refill_per_sec = capacity / rule.window().as_secs_f64();
+        let key = BucketKey { rule: index, client: client() };
+        let mut shard = self.shard(&key).lock();
+        let bucket = shard.entry(key).or_insert(Bucket { tokens: capacity, updated: now });
+
+        let elapsed = now.duration_since(bucket.updated).as_secs_f64();
+        bucket.tokens = elapsed.mul_add(refill_per_sec, bucket.tokens).min(capacity);
+        bucket.updated = now;
+
+        if bucket.tokens >= 1.0 {
+            bucket.tokens -= 1.0;
+            Ok(())
+        } else {
+            Err(Duration::from_secs_f64((1.0 - bucket.tokens) / refill_per_sec))
+        }
+    }
+
+    fn shard(&self, key: &BucketKey) -> &Mutex<FxHashMap<BucketKey, Bucket>> {
+        #[allow(clippy::cast_possible_truncation)]
+        let index = hash64(key) as usize % SHARDS;
+        &self.inner.shards[index]
+    }
+
+    /// Evicts buckets that have been idle long enough to refill completely.
+    fn sweep(&self, now: Instant) {
+        {
+            let mut last_sweep = self.inner.last_sweep.lock();
+            if now.duration_since(*last_sweep) < SWEEP_INTERVAL {
+                return;
+            }
+            *last_sweep = now;
+        }
+
+        let mut evicted = 0;
+        for shard in &self.inner.shards {
+            let mut shard = shard.lock();
+            let before = shard.len();
+            shard.retain(|key, bucket| {
+                now.duration_since(bucket.updated) < self.inner.rules[key.rule].1.window()
+            });
+            evicted += before - shard.len();
+        }
+        debug!(evicted, "Swept idle rate limit buckets");
+    }
+}
+
+/// Middleware enforcing the [`RateLimiter`] rules.
+///
+/// Requests are keyed by the peer address from [`ConnectInfo`], so the router must be served
+/// with `into_make_service_with_connect_info::<SocketAddr>()`. The last `X-Forwarded-For`
+/// entry replaces it only when the peer is a [trusted proxy](RateLimiter::trusted_proxies)
+/// or, without a peer address, when [Unix socket peers](RateLimiter::trust_unix_peer) are
+/// trusted. Rejected requests get `429 Too Many Requests` with a `Retry-After` header.
+///
+/// ```rust,ignore
+/// let router = router.layer(axum::middleware::from_fn_with_state(limiter, rate_limit));
+/// ```
+pub async fn rate_limit(
+    State(limiter): State<RateLimiter>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let client = || limiter.client_key(&request);
+    match limiter.acquire(request.uri().path(), client) {
+        Ok(()) => next.run(request).await,
+        Err(retry_after) => {
+            debug!(path = request.uri().path(), ?retry_after, "Rate limit exceeded");
+            let seconds = retry_after.as_secs() + u64::from(retry_after.subsec_nanos() > 0);
+            (StatusCode::TOO_MANY_REQUESTS, [(header::RETRY_AFTER, HeaderValue::from(seconds))])
+                .into_response()
+        },
+    }
+}
+
+impl RateLimiter {
+    fn client_key(&self, request: &Request) -> Client {
+        let peer = request
+            .extensions()
+            .get::<ConnectInfo<SocketAddr>>()
+            .map(|ConnectInfo(addr)| addr.ip());
+        let trusted = peer.map_or(self.trust_unix_peer, |ip| self.trusted_proxies.contains(&ip));
+        trusted
+            .then(|| forwarded_for(request.headers()))
+            .flatten()
+            .or(peer)
+            .map_or(Client::Unknown, Client::Ip)
+    }
+}
+
+/// Returns whether `path` lies under the route `prefix`, matching whole path segments so
+/// `/api` covers `/api` and `/api/login` but not `/apix`.
+fn matches_prefix(path: &str, prefix: &str) -> bool {
+    path.strip_prefix(prefix)
+        .is_some_and(|rest| rest.is_empty() || rest.starts_with('/') || prefix.ends_with('/'))
+}
+
+/// The last `X-Forwarded-For` hop, as appended by the proxy in front of the server.
+fn forwarded_for(headers: &HeaderMap) -> Option<IpAddr> {
+    headers
+        .get_all("x-forwarded-for")
+        .iter()
+        .next_back()?
+        .to_str()
+        .ok()?
+        .rsplit(',')
+        .next()
+        .and_then(|ip| ip.trim().parse().ok())
+}
@@ -1,4 +1,4 @@
-use super::health;
+use super::{ApiState, health, probes};
 use utoipa_axum::router::OpenApiRouter;
 use utoipa_axum::routes;
 
@@ -8,3 +8,10 @@ where
 {
     OpenApiRouter::<S>::new().routes(routes!(health::health_handler))
 }
+
+/// Kubernetes-style `/livez` and `/readyz` probes, independent of feature slices.
+pub fn probe_router() -> OpenApiRouter<ApiState> {
+    OpenApiRouter::new()
+        .routes(routes!(probes::livez_handler))
+        .routes(routes!(probes::readyz_handler))
+}
@@ -1,3 +1,4 @@
+use crate::server::InFlight;
 use axum::extract::FromRef;
 use fxhash::FxHashMap;
 use mhub_database::Database;
@@ -22,6 +23,9 @@ pub struct ApiStateInner {
     pub config: ApiConfig,
     pub database: Database,
     pub events: EventBus,
+    /// Requests currently being handled, maintained by
+    /// [`track_in_flight`](crate::server::track_in_flight).
+    pub in_flight: InFlight,
     //    pub storage: Storage,
     slices: FxHashMap<TypeId, InitializedSlice>,
 }
@@ -88,6 +92,12 @@ impl FromRef<ApiState> for EventBus {
     }
 }
 
+impl FromRef<ApiState> for InFlight {
+    fn from_ref(state: &ApiState) -> Self {
+        state.inner.in_flight.clone()
+    }
+}
+
 #[derive(Debug, Default)]
 pub struct ApiStateBuilder {
     config: Option<ApiConfig>,
@@ -140,7 +150,13 @@ impl ApiStateBuilder {
         let events = self.events.unwrap_or_default();
 
         Ok(ApiState {
-            inner: Arc::new(ApiStateInner { config, database, events, slices: self.slices }),
+            inner: Arc::new(ApiStateInner {
+                config,
+                database,
+                events,
+                in_flight: InFlight::default(),
+                slices: self.slices,
+            }),
         })
     }
 }
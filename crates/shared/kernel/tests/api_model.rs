@@ -0,0 +1,35 @@
+#![cfg(feature = "server")]
+
+use mhub_derive::api_model;
+use utoipa::PartialSchema;
+
+#[api_model]
+struct Item {
+    display_name: String,
+}
+
+#[api_model]
+struct Page<T> {
+    items: Vec<T>,
+    total_count: u64,
+}
+
+#[test]
+fn generic_models_serialize_with_defaults() {
+    let page = Page { items: vec![Item { display_name: "Alpha".to_owned() }], total_count: 1 };
+
+    let json = serde_json::to_value(&page).unwrap();
+    assert_eq!(json, serde_json::json!({ "items": [{ "displayName": "Alpha" }], "totalCount": 1 }));
+
+    let parsed: Page<Item> = serde_json::from_value(json).unwrap();
+    assert_eq!(parsed.items[0].display_name, "Alpha");
+    assert!(
+        serde_json::from_str::<Page<Item>>(r#"{"items":[],"totalCount":0,"extra":1}"#).is_err()
+    );
+}
+
+#[test]
+fn generic_models_derive_schema() {
+    let schema = serde_json::to_value(Page::<Item>::schema()).unwrap();
+    assert!(schema["properties"]["totalCount"].is_object());
+}
@@ -0,0 +1,142 @@
+use mhub_kernel::config::{
+    ConfigError, Validate, load_config_layered, load_config_validated, watch_config,
+};
+use serde::Deserialize;
+use std::fs;
+use std::sync::mpsc;
+use std::time::Duration;
+
+#[derive(Debug, Deserialize)]
+struct AppConfig {
+    host: String,
+    port: u16,
+}
+
+impl Validate for AppConfig {
+    fn validate(&self) -> Result<(), String> {
+        if self.port == 0 { Err("port must not be 0".to_owned()) } else { Ok(()) }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct Unchecked {
+    port: u16,
+}
+
+impl Validate for Unchecked {}
+
+#[test]
+fn validation_rejects_bad_values() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("server.toml");
+    fs::write(&path, "host = \"localhost\"\nport = 0\n").unwrap();
+
+    let result = load_config_validated::<AppConfig>(Some(&path));
+    let Err(ConfigError::Validation { message, .. }) = result else {
+        panic!("expected a validation error, got {result:?}");
+    };
+    assert_eq!(message, "port must not be 0");
+
+    // The default implementation accepts any parseable config.
+    let cfg: Unchecked = load_config_validated(Some(&path)).unwrap();
+    assert_eq!(cfg.port, 0);
+}
+
+#[test]
+fn later_layers_override_earlier_ones() {
+    let dir = tempfile::tempdir().unwrap();
+    let base = dir.path().join("server.toml");
+    let local = dir.path().join("server.local.toml");
+    fs::write(&base, "host = \"0.0.0.0\"\nport = 8080\n").unwrap();
+    fs::write(&local, "port = 9090\n").unwrap();
+
+    let cfg: AppConfig = load_config_layered(&[&base, &local]).unwrap();
+
+    assert_eq!(cfg.host, "0.0.0.0");
+    assert_eq!(cfg.port, 9090);
+}
+
+#[test]
+fn missing_optional_layers_are_skipped() {
+    let dir = tempfile::tempdir().unwrap();
+    let base = dir.path().join("server.toml");
+    fs::write(&base, "host = \"localhost\"\nport = 8080\n").unwrap();
+
+    let cfg: AppConfig =
+        load_config_layered(&[base, dir.path().join("server.local.toml")]).unwrap();
+
+    assert_eq!(cfg.port, 8080);
+}
+
+#[test]
+fn malformed_layers_are_not_skipped() {
+    let dir = tempfile::tempdir().unwrap();
+    let base = dir.path().join("server.toml");
+    let local = dir.path().join("server.local.toml");
+    fs::write(&base, "host = \"localhost\"\nport = 8080\n").unwrap();
+    fs::write(&local, "port = \n").unwrap();
+
+    let result = load_config_layered::<AppConfig, _>(&[base, local]);
+
+    assert!(matches!(result, Err(ConfigError::Config { .. })));
+}
+
+#[test]
+fn at_least_one_layer_must_exist() {
+    let dir = tempfile::tempdir().unwrap();
+
+    let result = load_config_layered::<AppConfig, _>(&[dir.path().join("missing.toml")]);
+
+    assert!(matches!(result, Err(ConfigError::Config { .. })));
+}
+
+#[test]
+fn watcher_reports_changes() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("server.toml");
+    fs::write(&path, "host = \"localhost\"\nport = 80\n").unwrap();
+
+    let (tx, rx) = mpsc::channel();
+    let watcher = watch_config(&path, move |cfg: AppConfig| {
+        let _ = tx.send(cfg.port);
+    })
+    .unwrap();
+
+    // A broken intermediate write is ignored; the final valid content is delivered.
+    fs::write(&path, "port = \"broken").unwrap();
+    fs::write(&path, "host = \"localhost\"\nport = 9090\n").unwrap();
+
+    assert_eq!(rx.recv_timeout(Duration::from_secs(5)).unwrap(), 9090);
+
+    drop(watcher);
+    assert!(rx.recv_timeout(Duration::from_millis(100)).is_err());
+}
+
+#[test]
+fn watcher_resolves_extensionless_paths() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("server.toml");
+    fs::write(&path, "host = \"localhost\"\nport = 80\n").unwrap();
+
+    let (tx, rx) = mpsc::channel();
+    let _watcher = watch_config(dir.path().join("server"), move |cfg: AppConfig| {
+        let _ = tx.send(cfg.port);
+    })
+    .unwrap();
+
+    // Saving by replacing the file is followed as well.
+    let staged = dir.path().join("server.toml.new");
+    fs::write(&staged, "host = \"localhost\"\nport = 8080\n").unwrap();
+    fs::rename(&staged, &path).unwrap();
+
+    assert_eq!(rx.recv_timeout(Duration::from_secs(5)).unwrap(), 8080);
+}
+
+#[test]
+fn watcher_requires_existing_file() {
+    let dir = tempfile::tempdir().unwrap();
+
+    let result = watch_config(dir.path().join("missing.toml"), |_: AppConfig| {});
+
+    assert!(matches!(result, Err(ConfigError::Io { .. })));
+}
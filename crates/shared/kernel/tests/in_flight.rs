@@ -0,0 +1,42 @@
+#![cfg(feature = "server")]
+
+use axum::Router;
+use axum::body::Body;
+use axum::extract::State;
+use axum::http::Request;
+use axum::routing::get;
+use mhub_kernel::server::{InFlight, track_in_flight};
+use std::sync::Arc;
+use tokio::sync::Notify;
+use tower::ServiceExt;
+
+type Gates = (Arc<Notify>, Arc<Notify>);
+
+/// Signals that it started, then blocks until released.
+async fn blocking(State((entered, release)): State<Gates>) -> &'static str {
+    entered.notify_one();
+    release.notified().await;
+    "done"
+}
+
+#[tokio::test]
+async fn counter_reflects_concurrent_request() {
+    let in_flight = InFlight::default();
+    let entered = Arc::new(Notify::new());
+    let release = Arc::new(Notify::new());
+
+    let app = Router::new()
+        .route("/", get(blocking))
+        .with_state((Arc::clone(&entered), Arc::clone(&release)))
+        .layer(axum::middleware::from_fn_with_state(in_flight.clone(), track_in_flight));
+
+    assert_eq!(in_flight.count(), 0);
+    let request = tokio::spawn(app.oneshot(Request::get("/").body(Body::empty()).unwrap()));
+
+    entered.notified().await;
+    assert_eq!(in_flight.count(), 1);
+
+    release.notify_one();
+    request.await.expect("join").expect("request");
+    assert_eq!(in_flight.count(), 0);
+}
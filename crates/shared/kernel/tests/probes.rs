@@ -0,0 +1,49 @@
+#![cfg(feature = "server")]
+
+use axum::Router;
+use axum::body::Body;
+use axum::http::{Request, StatusCode};
+use mhub_database::Database;
+use mhub_domain::config::ApiConfig;
+use mhub_kernel::server::ApiState;
+use mhub_kernel::server::router::probe_router;
+use tower::ServiceExt;
+
+async fn app() -> Router {
+    let database = Database::builder()
+        .url("mem://")
+        .session("probe_ns", "probe_db")
+        .init()
+        .await
+        .expect("connect to mem://");
+    let state =
+        ApiState::builder().config(ApiConfig::default()).db(database).build().expect("state");
+
+    let (router, _) = probe_router().with_state(state).split_for_parts();
+    router
+}
+
+async fn get(app: Router, uri: &str) -> (StatusCode, serde_json::Value) {
+    let response =
+        app.oneshot(Request::get(uri).body(Body::empty()).unwrap()).await.expect("request");
+    let status = response.status();
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.expect("body");
+    (status, serde_json::from_slice(&body).expect("json body"))
+}
+
+#[tokio::test]
+async fn livez_reports_alive() {
+    let (status, body) = get(app().await, "/livez").await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["status"], "alive");
+}
+
+#[tokio::test]
+async fn readyz_reports_ready_with_healthy_database() {
+    let (status, body) = get(app().await, "/readyz").await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["status"], "ready");
+    assert_eq!(body["in_flight"], 0);
+}
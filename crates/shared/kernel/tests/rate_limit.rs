@@ -0,0 +1,131 @@
+#![cfg(feature = "server")]
+
+use axum::Router;
+use axum::body::Body;
+use axum::extract::ConnectInfo;
+use axum::http::{Request, StatusCode, header};
+use axum::response::Response;
+use axum::routing::get;
+use mhub_domain::config::RateLimitRule;
+use mhub_kernel::server::{RateLimiter, rate_limit};
+use std::collections::BTreeMap;
+use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+use tower::ServiceExt;
+
+const CLIENT: [u8; 4] = [203, 0, 113, 7];
+const PROXY: [u8; 4] = [10, 0, 0, 1];
+
+fn limiter(requests: u32) -> RateLimiter {
+    let rules =
+        BTreeMap::from([("/auth".to_owned(), RateLimitRule { requests, window_seconds: 60 })]);
+    RateLimiter::new(&rules)
+}
+
+fn app(requests: u32) -> Router {
+    app_with(limiter(requests))
+}
+
+fn app_with(limiter: RateLimiter) -> Router {
+    Router::new()
+        .route("/auth/login", get(|| async { "ok" }))
+        .route("/open", get(|| async { "ok" }))
+        .route("/authx", get(|| async { "ok" }))
+        .layer(axum::middleware::from_fn_with_state(limiter, rate_limit))
+}
+
+async fn send(app: &Router, uri: &str) -> Response {
+    send_from(app, uri, Some(CLIENT), &[]).await
+}
+
+async fn send_from(
+    app: &Router,
+    uri: &str,
+    peer: Option<[u8; 4]>,
+    headers: &[(&str, &str)],
+) -> Response {
+    let mut builder = Request::get(uri);
+    for (name, value) in headers {
+        builder = builder.header(*name, *value);
+    }
+    let mut request = builder.body(Body::empty()).unwrap();
+    if let Some(peer) = peer {
+        request.extensions_mut().insert(ConnectInfo(SocketAddr::from((peer, 50_000))));
+    }
+    app.clone().oneshot(request).await.expect("request")
+}
+
+#[tokio::test]
+async fn requests_under_the_limit_pass() {
+    let app = app(3);
+
+    for _ in 0..3 {
+        assert_eq!(send(&app, "/auth/login").await.status(), StatusCode::OK);
+    }
+}
+
+#[tokio::test]
+async fn requests_over_the_limit_are_rejected_with_retry_after() {
+    let app = app(2);
+    send(&app, "/auth/login").await;
+    send(&app, "/auth/login").await;
+
+    let response = send(&app, "/auth/login").await;
+    assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+    let retry_after: u64 =
+        response.headers()[header::RETRY_AFTER].to_str().unwrap().parse().expect("seconds");
+    assert!((1..=30).contains(&retry_after), "retry after {retry_after}s");
+
+    // Routes outside the limited prefix are unaffected, including ones that only share its
+    // leading characters.
+    assert_eq!(send(&app, "/open").await.status(), StatusCode::OK);
+    assert_eq!(send(&app, "/authx").await.status(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn forwarded_for_is_ignored_from_untrusted_peers() {
+    let app = app(1);
+
+    let spoofed = [("x-forwarded-for", "198.51.100.1")];
+    assert_eq!(
+        send_from(&app, "/auth/login", Some(CLIENT), &spoofed).await.status(),
+        StatusCode::OK
+    );
+
+    let spoofed = [("x-forwarded-for", "198.51.100.2")];
+    let response = send_from(&app, "/auth/login", Some(CLIENT), &spoofed).await;
+    assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+}
+
+#[tokio::test]
+async fn trusted_proxy_uses_the_last_forwarded_hop() {
+    let proxy = IpAddr::V4(Ipv4Addr::from(PROXY));
+    let app = app_with(limiter(1).trusted_proxies([proxy]));
+
+    // The client controls every entry but the last one, which the proxy appended.
+    let first = [("x-forwarded-for", "198.51.100.1, 203.0.113.7")];
+    assert_eq!(send_from(&app, "/auth/login", Some(PROXY), &first).await.status(), StatusCode::OK);
+
+    let rotated = [("x-forwarded-for", "198.51.100.2, 203.0.113.7")];
+    let response = send_from(&app, "/auth/login", Some(PROXY), &rotated).await;
+    assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+
+    let other_client = [("x-forwarded-for", "203.0.113.8")];
+    let response = send_from(&app, "/auth/login", Some(PROXY), &other_client).await;
+    assert_eq!(response.status(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn unix_socket_peers_are_trusted_only_when_configured() {
+    let untrusted = app(1);
+    let first = [("x-forwarded-for", "203.0.113.7")];
+    let second = [("x-forwarded-for", "203.0.113.8")];
+    assert_eq!(send_from(&untrusted, "/auth/login", None, &first).await.status(), StatusCode::OK);
+    assert_eq!(
+        send_from(&untrusted, "/auth/login", None, &second).await.status(),
+        StatusCode::TOO_MANY_REQUESTS
+    );
+
+    let trusted = app_with(limiter(1).trust_unix_peer(true));
+    assert_eq!(send_from(&trusted, "/auth/login", None, &first).await.status(), StatusCode::OK);
+    assert_eq!(send_from(&trusted, "/auth/login", None, &second).await.status(), StatusCode::OK);
+}
@@ -0,0 +1,52 @@
+#![cfg(feature = "server")]
+
+use axum::body::Body;
+use axum::http::Request;
+use axum::routing::get;
+use axum::{Extension, Router};
+use mhub_kernel::server::{MAX_REQUEST_ID_LEN, REQUEST_ID_HEADER, RequestId, request_id};
+use tower::ServiceExt;
+
+fn app() -> Router {
+    Router::new()
+        .route("/", get(|Extension(RequestId(id)): Extension<RequestId>| async move { id }))
+        .layer(axum::middleware::from_fn(request_id))
+}
+
+async fn send(header: Option<&str>) -> (String, String) {
+    let mut request = Request::get("/");
+    if let Some(value) = header {
+        request = request.header(REQUEST_ID_HEADER, value);
+    }
+    let response = app().oneshot(request.body(Body::empty()).unwrap()).await.expect("request");
+
+    let echoed = response.headers()[REQUEST_ID_HEADER].to_str().unwrap().to_owned();
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.expect("body");
+    (echoed, String::from_utf8(body.to_vec()).unwrap())
+}
+
+#[tokio::test]
+async fn client_request_id_round_trips() {
+    let (echoed, seen_by_handler) = send(Some("trace-abc-123")).await;
+
+    assert_eq!(echoed, "trace-abc-123");
+    assert_eq!(seen_by_handler, "trace-abc-123");
+}
+
+#[tokio::test]
+async fn request_id_is_generated_when_absent() {
+    let (echoed, seen_by_handler) = send(None).await;
+
+    assert_eq!(echoed.len(), 12);
+    assert_eq!(echoed, seen_by_handler);
+}
+
+#[tokio::test]
+async fn oversized_request_id_is_replaced() {
+    let oversized = "a".repeat(MAX_REQUEST_ID_LEN + 1);
+    let (echoed, seen_by_handler) = send(Some(&oversized)).await;
+
+    assert_ne!(echoed, oversized);
+    assert_eq!(echoed.len(), 12);
+    assert_eq!(echoed, seen_by_handler);
+}
@@ -1,5 +1,6 @@
-use mhub_kernel::SAFE_ALPHABET;
-use mhub_kernel::safe_nanoid;
+use mhub_kernel::id::UNIQUE_ID_ATTEMPTS;
+use mhub_kernel::{IdError, SAFE_ALPHABET, safe_nanoid, safe_nanoid_unique, safe_nanoid_with};
+use std::cell::Cell;
 
 #[test]
 fn generates_expected_length_and_charset() {
@@ -16,3 +17,38 @@ fn custom_length() {
     let id = safe_nanoid!(20);
     assert_eq!(id.len(), 20);
 }
+
+#[test]
+fn custom_alphabet_is_respected() {
+    let alphabet = ['a', 'b', 'c', 'x', 'y', 'z'];
+    let id = safe_nanoid_with(&alphabet, 32);
+
+    assert_eq!(id.len(), 32);
+    assert!(id.chars().all(|ch| alphabet.contains(&ch)), "unexpected character in {id}");
+}
+
+#[test]
+fn unique_retries_on_collision() {
+    let calls = Cell::new(0);
+    let id = safe_nanoid_unique(10, |_| {
+        calls.set(calls.get() + 1);
+        calls.get() < 3
+    })
+    .unwrap();
+
+    assert_eq!(calls.get(), 3);
+    assert_eq!(id.len(), 10);
+    assert!(id.chars().all(|ch| SAFE_ALPHABET.contains(&ch)));
+}
+
+#[test]
+fn unique_gives_up_after_max_attempts() {
+    let calls = Cell::new(0);
+    let result = safe_nanoid_unique(10, |_| {
+        calls.set(calls.get() + 1);
+        true
+    });
+
+    assert!(matches!(result, Err(IdError::Exhausted { attempts: UNIQUE_ID_ATTEMPTS, .. })));
+    assert_eq!(calls.get(), UNIQUE_ID_ATTEMPTS);
+}
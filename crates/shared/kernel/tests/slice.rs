@@ -0,0 +1,44 @@
+use mhub_kernel::domain::registry::{FeatureSliceAsync, InitializedSlice, SliceBootstrap};
+use std::pin::pin;
+use std::task::{Context, Poll, Waker};
+
+#[mhub_derive::mhub_slice]
+pub struct Counter {
+    pub start: u32,
+}
+
+impl SliceBootstrap<u32> for CounterInner {
+    type Error = String;
+
+    async fn bootstrap(context: &u32) -> Result<Self, String> {
+        if *context == 0 {
+            Err("start must be positive".to_owned())
+        } else {
+            Ok(Self { start: *context })
+        }
+    }
+}
+
+/// Polls a future that never suspends to completion.
+fn block_on<F: Future>(future: F) -> F::Output {
+    let mut future = pin!(future);
+    match future.as_mut().poll(&mut Context::from_waker(Waker::noop())) {
+        Poll::Ready(output) => output,
+        Poll::Pending => panic!("future suspended"),
+    }
+}
+
+#[test]
+fn async_bootstrap_builds_slice() {
+    let counter = block_on(Counter::init_async(&7)).expect("bootstrap failed");
+    assert_eq!(counter.start, 7);
+
+    let slice = block_on(InitializedSlice::init_async::<Counter, _>(&7)).expect("bootstrap failed");
+    assert_eq!(slice.id, std::any::TypeId::of::<Counter>());
+}
+
+#[test]
+fn async_bootstrap_propagates_errors() {
+    let result = block_on(InitializedSlice::init_async::<Counter, _>(&0));
+    assert_eq!(result.unwrap_err(), "start must be positive");
+}
@@ -1,11 +1,15 @@
 use crate::error::DatabaseError;
 use ed25519_dalek::SigningKey;
 use getrandom::fill;
-use jsonwebtoken::EncodingKey;
+use jsonwebtoken::{Algorithm, EncodingKey};
 use serde::Serialize;
 use surrealdb::Surreal;
 use surrealdb::engine::any::Any;
 
+/// Value of the `scope` claim carried by sessions from
+/// [`Database::authenticate_readonly`](crate::Database::authenticate_readonly).
+pub(crate) const READONLY_SCOPE: &str = "readonly";
+
 #[derive(Debug, Serialize)]
 pub(crate) struct Claims<'a> {
     pub ns: &'a str,
@@ -13,34 +17,91 @@ pub(crate) struct Claims<'a> {
     pub ac: &'static str,
     pub id: String,
     pub exp: i64,
+    /// Custom claim exposed to permission clauses as `$token.scope`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scope: Option<&'static str>,
 }
 
 #[derive(Debug)]
 pub(crate) struct AuthProvider {
     pub encoding_key: EncodingKey,
-    pub public_key: String,
+    /// The Ed25519 public key (hex) or the shared HMAC secret registered with `SurrealDB`.
+    pub verification_key: String,
+    pub algorithm: Algorithm,
 }
 
 impl AuthProvider {
-    pub(crate) fn init() -> Result<Self, DatabaseError> {
-        let mut seed = [0u8; 32];
-
-        fill(&mut seed).map_err(|e| DatabaseError::Internal {
-            message: e.to_string().into(),
-            context: Some("Failed to generate seed".into()),
-        })?;
-
-        let signing_key = SigningKey::from_bytes(&seed);
-        let public_key_bytes = signing_key.verifying_key().to_bytes();
-        let public_key_hex = hex::encode(public_key_bytes);
-        let encoding_key = EncodingKey::from_ed_der(signing_key.to_bytes().as_ref());
+    /// Generates an ephemeral signing key matching `algorithm`.
+    ///
+    /// # Errors
+    /// Returns [`DatabaseError::Validation`] if `algorithm` is not supported.
+    pub(crate) fn init(algorithm: Algorithm) -> Result<Self, DatabaseError> {
+        let (encoding_key, verification_key) = match validate_algorithm(algorithm)? {
+            KeyKind::Ed25519 => {
+                let signing_key = SigningKey::from_bytes(&random_seed()?);
+                let public_key_hex = hex::encode(signing_key.verifying_key().to_bytes());
+                (EncodingKey::from_ed_der(signing_key.to_bytes().as_ref()), public_key_hex)
+            },
+            KeyKind::Hmac => {
+                let secret = hex::encode(random_seed()?);
+                (EncodingKey::from_secret(secret.as_bytes()), secret)
+            },
+        };
 
-        Ok(Self { encoding_key, public_key: public_key_hex })
+        Ok(Self { encoding_key, verification_key, algorithm })
     }
 
     pub(crate) async fn setup_database(&self, db: &Surreal<Any>) -> Result<(), DatabaseError> {
-        db.query("DEFINE ACCESS OVERWRITE user ON DATABASE TYPE RECORD WITH JWT ALGORITHM EDDSA KEY $public_key;")
-            .bind(("public_key", self.public_key.clone())).await?;
+        let algorithm = surreal_algorithm(self.algorithm)?;
+        db.query(format!(
+            "DEFINE ACCESS OVERWRITE user ON DATABASE TYPE RECORD WITH JWT ALGORITHM {algorithm} KEY $key;"
+        ))
+        .bind(("key", self.verification_key.clone()))
+        .await?;
         Ok(())
     }
 }
+
+/// The kind of key generated for a JWT signing algorithm.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum KeyKind {
+    Ed25519,
+    Hmac,
+}
+
+/// Checks that `algorithm` can be paired with a key this provider generates.
+///
+/// `EdDSA` is only ever paired with an Ed25519 key; RSA and EC algorithms need externally
+/// provisioned keys and are rejected.
+///
+/// # Errors
+/// Returns [`DatabaseError::Validation`] for unsupported algorithms.
+pub(crate) fn validate_algorithm(algorithm: Algorithm) -> Result<KeyKind, DatabaseError> {
+    match algorithm {
+        Algorithm::EdDSA => Ok(KeyKind::Ed25519),
+        Algorithm::HS256 | Algorithm::HS384 | Algorithm::HS512 => Ok(KeyKind::Hmac),
+        other => Err(DatabaseError::Validation {
+            message: format!("Unsupported JWT algorithm {other:?}").into(),
+            context: Some("Only EdDSA (Ed25519) and HMAC (HS256/HS384/HS512) are supported".into()),
+        }),
+    }
+}
+
+fn surreal_algorithm(algorithm: Algorithm) -> Result<&'static str, DatabaseError> {
+    validate_algorithm(algorithm)?;
+    Ok(match algorithm {
+        Algorithm::HS256 => "HS256",
+        Algorithm::HS384 => "HS384",
+        Algorithm::HS512 => "HS512",
+        _ => "EDDSA",
+    })
+}
+
+fn random_seed() -> Result<[u8; 32], DatabaseError> {
+    let mut seed = [0u8; 32];
+    fill(&mut seed).map_err(|e| DatabaseError::Internal {
+        message: e.to_string().into(),
+        context: Some("Failed to generate seed".into()),
+    })?;
+    Ok(seed)
+}
@@ -12,6 +12,7 @@ pub(crate) fn builtin_migrations() -> Vec<Migration> {
             Some("Database infrastructure layer providing SurrealDB integration"),
             "0000-init",
             include_str!("../../../../infra/database/migrations/0000-init.surql"),
+            None,
             "ef54327c2847362c4a09b8fe00e0ed6fea94bb949ee7b60b761c57f1861fcdc9",
             true,
         ),
@@ -21,6 +22,7 @@ pub(crate) fn builtin_migrations() -> Vec<Migration> {
             Some("Audit feature slice"),
             "0000-init",
             include_str!("../../../../crates/features/audit/migrations/0000-init.surql"),
+            None,
             "970fb244f0c4d2d53a1ae0287f787d72a70f03b51577543778a135e05bcf1ff1",
             false,
         ),
@@ -30,6 +32,7 @@ pub(crate) fn builtin_migrations() -> Vec<Migration> {
             Some("Organization tree slice"),
             "0000-init",
             include_str!("../../../../crates/features/organization/migrations/0000-init.surql"),
+            None,
             "131a55ff83dd8181f3c9ed038f5d3fa75ed16170b759fb4a68b141c1a6452acb",
             false,
         ),
@@ -39,6 +42,7 @@ pub(crate) fn builtin_migrations() -> Vec<Migration> {
             Some("Identity feature slice"),
             "0000-init",
             include_str!("../../../../crates/features/identity/migrations/0000-init.surql"),
+            None,
             "8738d5b5284749e4e29d78ab9abdf805aeaf82573a44ee4557a4b0a4ec449d3d",
             false,
         ),
@@ -48,6 +52,7 @@ pub(crate) fn builtin_migrations() -> Vec<Migration> {
             Some("IAM feature slice"),
             "0000-init",
             include_str!("../../../../crates/features/iam/migrations/0000-init.surql"),
+            None,
             "9f6456f97d4aa95561776c6dfbcb33b4fbb2ac40a7916bceea679f6a00638302",
             false,
         ),
@@ -7,6 +7,7 @@
 //! - **Engine Agnostic**: Supports `mem://`, `rocksdb://`, `ws://`, and `http://` via the `any` engine.
 //! - **Resilient Connectivity**: Built-in retry logic for health checks during engine startup.
 //! - **Builder Pattern**: Fluent API for configuring connections and authentication.
+//! - **Live Queries**: `LIVE SELECT` notifications bridged onto the slice `EventBus`.
 //!
 //! ## Example
 //!
@@ -31,25 +32,43 @@
 mod auth;
 mod error;
 mod generated;
+mod live;
 mod migrations;
+mod transaction;
 
-use crate::auth::{AuthProvider, Claims};
+use crate::auth::{AuthProvider, Claims, READONLY_SCOPE};
 pub use error::{DatabaseError, DatabaseErrorExt};
-use jsonwebtoken::{Header, encode};
+use jsonwebtoken::{Algorithm, Header, encode};
+pub use live::{LiveAction, LiveEvent, LiveQuery};
+pub use migrations::DriftPolicy;
 use migrations::MigrationRunner;
 use moka::future::Cache;
+use std::collections::HashSet;
+use std::future::Future;
 use std::ops::Deref;
 use std::sync::Arc;
-use std::time::Duration;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant};
 use surrealdb::Surreal;
 use surrealdb::engine::any::{Any, connect};
 use surrealdb::opt::auth::Root;
 use tracing::{info, instrument, trace, warn};
+pub use transaction::Transaction;
 
-/// TTL in seconds for external JWTs issued for the database.
-static JWT_TTL_SECONDS: i64 = 3600;
+/// Default TTL for external JWTs issued for the database.
+const DEFAULT_JWT_TTL: Duration = Duration::from_secs(3600);
+/// How long before token expiry a cached session is evicted.
+const SESSION_TTL_MARGIN: Duration = Duration::from_secs(60);
 /// Max bound cache sessions to prevent 'slow lori'
 static MAX_CACHE_CAPACITY: u64 = 10_000;
+/// Default number of health-check attempts during [`DatabaseBuilder::init`].
+const DEFAULT_RETRY_ATTEMPTS: u32 = 3;
+/// Default delay before the first health-check retry.
+const DEFAULT_RETRY_BASE_DELAY: Duration = Duration::from_millis(500);
+/// Default upper bound for the delay between health-check retries.
+const DEFAULT_RETRY_MAX_DELAY: Duration = Duration::from_secs(5);
+/// Default duration above which [`Database::query_timed`] reports a slow query.
+const DEFAULT_SLOW_QUERY_THRESHOLD: Duration = Duration::from_secs(1);
 
 /// Inner state of the [`Database`] wrapper.
 #[derive(Debug)]
@@ -57,10 +76,35 @@ pub struct DatabaseInner {
     instance: Surreal<Any>,
     auth: AuthProvider,
     cache: Cache<String, Surreal<Any>>,
+    jwt_ttl: Duration,
+    jwt_algorithm: Algorithm,
+    slow_query_threshold: Duration,
+    stats: QueryCounters,
     ns: String,
     db: String,
 }
 
+/// Counters updated by [`Database::query_timed`].
+#[derive(Debug, Default)]
+struct QueryCounters {
+    total: AtomicU64,
+    failed: AtomicU64,
+    slow: AtomicU64,
+}
+
+/// A point-in-time snapshot of the query counters, see [`Database::stats`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DbStats {
+    /// Queries executed successfully through [`Database::query_timed`].
+    pub total_queries: u64,
+    /// Queries through [`Database::query_timed`] that failed to execute or returned a
+    /// statement error. Not included in `total_queries`.
+    pub failed_queries: u64,
+    /// Queries that took at least the configured slow-query threshold, whether they
+    /// succeeded or not.
+    pub slow_queries: u64,
+}
+
 impl Drop for DatabaseInner {
     fn drop(&mut self) {
         info!(ns = %self.ns, db = %self.db, "SurrealDB session handle dropped");
@@ -88,6 +132,24 @@ impl Deref for Database {
     }
 }
 
+/// Health-check retry policy with capped exponential backoff.
+#[derive(Debug, Clone, Copy)]
+struct RetryPolicy {
+    attempts: u32,
+    base_delay: Duration,
+    max_delay: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            attempts: DEFAULT_RETRY_ATTEMPTS,
+            base_delay: DEFAULT_RETRY_BASE_DELAY,
+            max_delay: DEFAULT_RETRY_MAX_DELAY,
+        }
+    }
+}
+
 /// A fluent builder for configuring and establishing a `SurrealDB` connection.
 ///
 /// This builder ensures that fundamental parameters like the connection URL,
@@ -99,6 +161,13 @@ pub struct DatabaseBuilder {
     ns: Option<String>,
     db: Option<String>,
     auth: Option<(String, String)>,
+    retry: Option<RetryPolicy>,
+    jwt_ttl: Option<Duration>,
+    jwt_algorithm: Option<Algorithm>,
+    slow_query_threshold: Option<Duration>,
+    drift_policy: DriftPolicy,
+    migrate_dry_run: bool,
+    verify_schema: bool,
 }
 
 impl DatabaseBuilder {
@@ -126,6 +195,70 @@ impl DatabaseBuilder {
         self
     }
 
+    /// Configures the health-check retry policy used by [`DatabaseBuilder::init`].
+    ///
+    /// The engine is probed up to `attempts` times. The delay starts at `base_delay` and
+    /// doubles after every failed attempt, never exceeding `max_delay`. Defaults to 3 attempts
+    /// starting at 500ms.
+    pub const fn retry(mut self, attempts: u32, base_delay: Duration, max_delay: Duration) -> Self {
+        self.retry = Some(RetryPolicy { attempts, base_delay, max_delay });
+        self
+    }
+
+    /// Sets the lifetime of JWTs issued by [`Database::authenticate`]. Defaults to one hour.
+    ///
+    /// Cached user sessions are evicted one minute before their token expires (or halfway
+    /// through very short lifetimes), so a cached session never outlives its token.
+    pub const fn jwt_ttl(mut self, ttl: Duration) -> Self {
+        self.jwt_ttl = Some(ttl);
+        self
+    }
+
+    /// Sets the JWT signing algorithm. Defaults to [`Algorithm::EdDSA`].
+    ///
+    /// `EdDSA` signs with an ephemeral Ed25519 key; `HS256`, `HS384`, and `HS512` use an
+    /// ephemeral shared secret. Other algorithms are rejected by [`DatabaseBuilder::init`].
+    pub const fn jwt_algorithm(mut self, algorithm: Algorithm) -> Self {
+        self.jwt_algorithm = Some(algorithm);
+        self
+    }
+
+    /// Sets the duration at or above which [`Database::query_timed`] logs a warning and counts
+    /// the query as slow. Defaults to one second.
+    pub const fn slow_query_threshold(mut self, threshold: Duration) -> Self {
+        self.slow_query_threshold = Some(threshold);
+        self
+    }
+
+    /// Sets how [`DatabaseBuilder::init`] reacts when an applied migration's stored checksum
+    /// no longer matches the compiled script. Defaults to [`DriftPolicy::Fail`].
+    ///
+    /// [`DriftPolicy::Warn`] and [`DriftPolicy::Reapply`] are meant for development databases
+    /// that picked up an accidental edit; keep `Fail` in production.
+    pub const fn on_checksum_drift(mut self, policy: DriftPolicy) -> Self {
+        self.drift_policy = policy;
+        self
+    }
+
+    /// When enabled, [`DatabaseBuilder::init`] logs the migration plan instead of applying it.
+    ///
+    /// Pending migrations are reported at `info` level and nothing is written, which lets CI
+    /// gate on unexpected schema changes. Checksum drift is resolved by the
+    /// [drift policy](DatabaseBuilder::on_checksum_drift): it fails under the default
+    /// [`DriftPolicy::Fail`] and is reported as pending under [`DriftPolicy::Reapply`].
+    pub const fn migrate_dry_run(mut self, enabled: bool) -> Self {
+        self.migrate_dry_run = enabled;
+        self
+    }
+
+    /// When enabled, [`DatabaseBuilder::init`] runs [`Database::verify_schema`] once migrations
+    /// are handled, so startup fails fast on a half-provisioned database instead of on the
+    /// first query that needs a missing schema function. Disabled by default.
+    pub const fn verify_schema(mut self, enabled: bool) -> Self {
+        self.verify_schema = enabled;
+        self
+    }
+
     /// Consumes the builder and attempts to establish a connection to the database.
     ///
     /// This method executes the full connection lifecycle, including engine initialization,
@@ -134,20 +267,29 @@ impl DatabaseBuilder {
     /// # Process
     /// 1. **Validation**: Ensures URL, Namespace, and Database name are provided.
     /// 2. **Engine Initialization**: Connects to the underlying `SurrealDB` engine (Any).
-    /// 3. **Resilience**: Performs up to 3 health checks using `INFO FOR DB`. If the first check fails,
-    ///    it retries with exponential backoff (starting at 500ms).
+    /// 3. **Resilience**: Performs health checks using `INFO FOR DB` according to the
+    ///    [`retry`](DatabaseBuilder::retry) policy (3 attempts, backoff starting at 500ms by
+    ///    default).
     /// 4. **Authentication**: If credentials were provided via [`auth`], signs in as a Root user.
     /// 5. **Session Activation**: Sets the global namespace and database for the connection.
+    /// 6. **Migrations**: Applies pending migrations, or only logs them in
+    ///    [dry-run](DatabaseBuilder::migrate_dry_run) mode.
+    /// 7. **Schema Check**: Optionally [verifies](DatabaseBuilder::verify_schema) the schema
+    ///    functions the migration runner depends on.
     ///
     /// # Returns
     /// * `Ok(Database)` - A thread-safe, cloned handle to the established session.
     /// * `Err(DatabaseError)` - Detailed error information if any step of the process fails.
     ///
     /// # Errors
-    /// * [`DatabaseError::Validation`] if required parameters are missing.
+    /// * [`DatabaseError::Validation`] if required parameters are missing, the retry policy
+    ///   allows zero attempts, the JWT TTL is shorter than a second, or the JWT algorithm is
+    ///   not supported.
     /// * [`DatabaseError::Connection`] if the engine fails to start or remains unhealthy.
     /// * [`DatabaseError::Auth`] if the provided credentials are rejected.
     /// * [`DatabaseError::Surreal`] if the session activation (`use_ns`/`use_db`) fails.
+    /// * [`DatabaseError::Migration`] if a migration fails or, with
+    ///   [`verify_schema`](DatabaseBuilder::verify_schema), a schema function is missing.
     #[instrument(skip(self), fields(url = self.url, ns = self.ns, db = self.db))]
     pub async fn init(self) -> Result<Database, DatabaseError> {
         let url = self.url.ok_or(DatabaseError::Validation {
@@ -163,26 +305,44 @@ impl DatabaseBuilder {
             context: None,
         })?;
 
+        let retry = self.retry.unwrap_or_default();
+        if retry.attempts == 0 {
+            return Err(DatabaseError::Validation {
+                message: "Retry attempts must be greater than zero".into(),
+                context: None,
+            });
+        }
+
+        let jwt_ttl = self.jwt_ttl.unwrap_or(DEFAULT_JWT_TTL);
+        if jwt_ttl < Duration::from_secs(1) {
+            return Err(DatabaseError::Validation {
+                message: "JWT TTL must be at least one second".into(),
+                context: None,
+            });
+        }
+        let jwt_algorithm = self.jwt_algorithm.unwrap_or(Algorithm::EdDSA);
+        auth::validate_algorithm(jwt_algorithm)?;
+
         let instance = connect(&url).await.map_err(|e| DatabaseError::Connection {
             message: e.to_string().into(),
             context: Some("Initializing engine".into()),
         })?;
 
         // 1. Connectivity & Health Check with Retries
-        let mut delay = Duration::from_millis(500);
-        for attempt in 1..=3 {
+        let mut delay = retry.base_delay.min(retry.max_delay);
+        for attempt in 1..=retry.attempts {
             if instance.health().await.is_ok() {
                 break;
             }
-            if attempt == 3 {
+            if attempt == retry.attempts {
                 return Err(DatabaseError::Connection {
                     message: "Unhealthy after retries".into(),
-                    context: Some(url.into()),
+                    context: Some(format!("{url} (gave up after {attempt} attempts)").into()),
                 });
             }
             warn!(attempt, ?delay, "Database not ready, retrying...");
             tokio::time::sleep(delay).await;
-            delay *= 2;
+            delay = delay.saturating_mul(2).min(retry.max_delay);
         }
 
         // 2. Authentication
@@ -199,28 +359,84 @@ impl DatabaseBuilder {
             instance.version().await.map_or_else(|_| "unknown".to_owned(), |v| v.to_string());
         info!(namespace = %ns, database = %db, %version, "SurrealDB connection established");
 
-        info!("Applying database migrations...");
-        let migration_report = MigrationRunner::new(instance.clone()).run().await?;
-        for skipped in migration_report.skipped {
-            trace!(slice = skipped.slice_key, version = skipped.version, "Skipping migration");
+        if self.migrate_dry_run {
+            info!("Planning database migrations (dry run)...");
+            let plan = MigrationRunner::new(instance.clone())
+                .with_drift_policy(self.drift_policy)
+                .plan()
+                .await?;
+            for skipped in &plan.skipped {
+                trace!(slice = skipped.slice_key, version = skipped.version, "Already applied");
+            }
+            for pending in &plan.applied {
+                info!(
+                    slice = pending.slice_key,
+                    version = pending.version,
+                    "Would apply migration"
+                );
+            }
+            info!(pending = plan.applied.len(), "Dry run complete, no migrations applied");
+        } else {
+            info!("Applying database migrations...");
+            let migration_report = MigrationRunner::new(instance.clone())
+                .with_drift_policy(self.drift_policy)
+                .run()
+                .await?;
+            for skipped in migration_report.skipped {
+                trace!(slice = skipped.slice_key, version = skipped.version, "Skipping migration");
+            }
+            for applied in migration_report.applied {
+                info!(slice = applied.slice_key, version = applied.version, "Applied migration");
+            }
+            info!("Database migrations applied successfully");
         }
-        for applied in migration_report.applied {
-            info!(slice = applied.slice_key, version = applied.version, "Applied migration");
+
+        if self.verify_schema {
+            MigrationRunner::new(instance.clone()).verify_schema().await?;
+            info!("Database schema verified");
         }
-        info!("Database migrations applied successfully");
 
-        let auth = AuthProvider::init()?;
+        let auth = AuthProvider::init(jwt_algorithm)?;
         auth.setup_database(&instance).await?;
 
         let cache = Cache::builder()
             .max_capacity(MAX_CACHE_CAPACITY)
-            .time_to_live(Duration::from_secs(JWT_TTL_SECONDS.cast_unsigned() - 60)) // (-1 minute of JWT)
+            .time_to_live(session_ttl(jwt_ttl))
             .build();
 
-        Ok(Database { inner: Arc::new(DatabaseInner { instance, auth, cache, ns, db }) })
+        Ok(Database {
+            inner: Arc::new(DatabaseInner {
+                instance,
+                auth,
+                cache,
+                jwt_ttl,
+                jwt_algorithm,
+                slow_query_threshold: self
+                    .slow_query_threshold
+                    .unwrap_or(DEFAULT_SLOW_QUERY_THRESHOLD),
+                stats: QueryCounters::default(),
+                ns,
+                db,
+            }),
+        })
     }
 }
 
+/// Normalizes a user id to the `user:`-prefixed key used for cached sessions.
+fn session_key(user_id: &str) -> String {
+    if user_id.starts_with("user:") { user_id.to_owned() } else { format!("user:{user_id}") }
+}
+
+/// Cache key of the read-only session of `user_id`, kept apart from the read-write one.
+fn readonly_session_key(user_id: &str) -> String {
+    format!("{READONLY_SCOPE}:{}", session_key(user_id))
+}
+
+/// Cache lifetime for sessions backed by tokens valid for `jwt_ttl`.
+fn session_ttl(jwt_ttl: Duration) -> Duration {
+    if jwt_ttl > SESSION_TTL_MARGIN * 2 { jwt_ttl - SESSION_TTL_MARGIN } else { jwt_ttl / 2 }
+}
+
 impl Database {
     /// Authenticates as a specific user and returns a scoped `SurrealDB` client session.
     ///
@@ -256,26 +472,64 @@ impl Database {
         &self,
         user_id: impl AsRef<str>,
     ) -> Result<Surreal<Any>, DatabaseError> {
-        let user_id_ref = if user_id.as_ref().starts_with("user:") {
-            user_id.as_ref()
-        } else {
-            &format!("user:{}", user_id.as_ref())
-        };
+        let user_id_ref = session_key(user_id.as_ref());
+        self.authenticate_scoped(user_id_ref.clone(), &user_id_ref, None).await
+    }
 
+    /// Authenticates as a specific user and returns a session meant to only run `SELECT`s.
+    ///
+    /// Works like [`Database::authenticate`], but the JWT carries an additional
+    /// `scope = "readonly"` claim and the session is cached under its own key, so it never
+    /// collides with the user's read-write session.
+    ///
+    /// # Enforcement
+    /// The claim alone does not restrict anything: `SurrealDB` only enforces it through the
+    /// schema's permission definitions, e.g.
+    /// `PERMISSIONS FOR select FULL FOR create, update, delete WHERE $token.scope != "readonly"`.
+    /// Tables without such a clause remain writable through this session.
+    ///
+    /// # Errors
+    /// Same as [`Database::authenticate`].
+    #[instrument(skip(self), fields(user_id = %user_id.as_ref()))]
+    pub async fn authenticate_readonly(
+        &self,
+        user_id: impl AsRef<str>,
+    ) -> Result<Surreal<Any>, DatabaseError> {
+        let user_id_ref = session_key(user_id.as_ref());
+        self.authenticate_scoped(
+            readonly_session_key(&user_id_ref),
+            &user_id_ref,
+            Some(READONLY_SCOPE),
+        )
+        .await
+    }
+
+    fn claims(&self, user_id_ref: &str, scope: Option<&'static str>) -> Claims<'_> {
+        Claims {
+            ns: &self.inner.ns,
+            db: &self.inner.db,
+            ac: "user",
+            id: format!("user:{user_id_ref}"),
+            exp: (chrono::Utc::now()
+                + chrono::Duration::from_std(self.inner.jwt_ttl).unwrap_or_default())
+            .timestamp(),
+            scope,
+        }
+    }
+
+    async fn authenticate_scoped(
+        &self,
+        cache_key: String,
+        user_id_ref: &str,
+        scope: Option<&'static str>,
+    ) -> Result<Surreal<Any>, DatabaseError> {
         self.inner
             .cache
-            .try_get_with(user_id_ref.to_owned(), async {
-                let claims = Claims {
-                    ns: &self.inner.ns,
-                    db: &self.inner.db,
-                    ac: "user",
-                    id: format!("user:{user_id_ref}"),
-                    exp: (chrono::Utc::now() + chrono::Duration::seconds(JWT_TTL_SECONDS))
-                        .timestamp(),
-                };
+            .try_get_with(cache_key, async {
+                let claims = self.claims(user_id_ref, scope);
 
                 let token = encode(
-                    &Header::new(jsonwebtoken::Algorithm::EdDSA),
+                    &Header::new(self.inner.jwt_algorithm),
                     &claims,
                     &self.inner.auth.encoding_key,
                 )
@@ -301,3 +555,430 @@ impl Database {
             })
     }
 }
+
+impl Database {
+    /// Executes `sql` and records its duration in the query statistics.
+    ///
+    /// Queries taking at least the [slow-query threshold](DatabaseBuilder::slow_query_threshold)
+    /// are logged at `warn` level and counted as slow. Prefer this over the raw
+    /// [`Surreal::query`] reached through `Deref`, which is not measured.
+    ///
+    /// Only the statement kind (e.g. `SELECT`) is recorded on the tracing span; the query text
+    /// may inline literal values and is never logged.
+    ///
+    /// # Errors
+    /// Returns [`DatabaseError::Surreal`] if the query cannot be executed or any of its
+    /// statements fails; both are counted as failed queries.
+    #[instrument(skip_all, fields(statement = %statement_kind(sql)))]
+    pub async fn query_timed(&self, sql: &str) -> Result<surrealdb::IndexedResults, DatabaseError> {
+        let started = Instant::now();
+        let result = match self.inner.instance.query(sql).await {
+            Ok(response) => response.check(),
+            Err(e) => Err(e),
+        };
+        let elapsed = started.elapsed();
+
+        let outcome =
+            if result.is_ok() { &self.inner.stats.total } else { &self.inner.stats.failed };
+        outcome.fetch_add(1, Ordering::Relaxed);
+        if elapsed >= self.inner.slow_query_threshold {
+            self.inner.stats.slow.fetch_add(1, Ordering::Relaxed);
+            warn!(?elapsed, threshold = ?self.inner.slow_query_threshold, "Slow query");
+        } else {
+            trace!(?elapsed, "Query executed");
+        }
+
+        result.context("Executing timed query")
+    }
+
+    /// Returns a snapshot of the counters maintained by [`Database::query_timed`].
+    #[must_use]
+    pub fn stats(&self) -> DbStats {
+        DbStats {
+            total_queries: self.inner.stats.total.load(Ordering::Relaxed),
+            failed_queries: self.inner.stats.failed.load(Ordering::Relaxed),
+            slow_queries: self.inner.stats.slow.load(Ordering::Relaxed),
+        }
+    }
+}
+
+/// Returns the leading keyword of `sql` in upper case, e.g. `SELECT` for `select * from user`.
+fn statement_kind(sql: &str) -> String {
+    sql.trim_start()
+        .split(|c: char| !c.is_ascii_alphabetic())
+        .next()
+        .unwrap_or_default()
+        .to_ascii_uppercase()
+}
+
+impl Database {
+    /// Evicts the cached authenticated session of `user_id`.
+    ///
+    /// Call this when a user's permissions change or the user is deactivated; the next
+    /// [`Database::authenticate`] call issues a fresh token instead of reusing the cached
+    /// session until its TTL expires. Both `alice` and `user:alice` address the same entry.
+    /// The user's [read-only session](Database::authenticate_readonly) is evicted as well.
+    #[instrument(skip(self), fields(user_id = %user_id.as_ref()))]
+    pub async fn invalidate_session(&self, user_id: impl AsRef<str>) {
+        let user_id_ref = session_key(user_id.as_ref());
+        self.inner.cache.invalidate(&readonly_session_key(&user_id_ref)).await;
+        self.inner.cache.invalidate(&user_id_ref).await;
+    }
+
+    /// Evicts every cached authenticated session, e.g., after a global permission change.
+    pub fn invalidate_all_sessions(&self) {
+        self.inner.cache.invalidate_all();
+    }
+}
+
+impl Database {
+    /// Begins a transaction, runs `f` with a [`Transaction`] handle, and commits once `f`
+    /// returns `Ok`.
+    ///
+    /// Statements issued through the handle execute immediately inside the transaction, so
+    /// `f` can read their results. If `f` returns an error or panics, the transaction is
+    /// cancelled and the database is left unchanged. Check each response (e.g. with
+    /// `.check()`) so a failing statement aborts the closure.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// db.transaction(|tx| async move {
+    ///     tx.query("UPDATE account:a SET balance -= 100; UPDATE account:b SET balance += 100")
+    ///         .await?
+    ///         .check()
+    ///         .map_err(surrealdb::Error::from)?;
+    ///     Ok(())
+    /// })
+    /// .await?;
+    /// ```
+    ///
+    /// # Errors
+    /// - Any error returned by `f`, unchanged.
+    /// - [`DatabaseError::Surreal`] if the transaction cannot begin or fails to commit; the
+    ///   context names the failing phase.
+    /// - [`DatabaseError::Internal`] if a clone of the handle outlives `f`.
+    #[instrument(skip_all)]
+    pub async fn transaction<F, Fut, T>(&self, f: F) -> Result<T, DatabaseError>
+    where
+        F: FnOnce(Transaction) -> Fut,
+        Fut: Future<Output = Result<T, DatabaseError>>,
+    {
+        transaction::run(&self.inner.instance, f).await
+    }
+
+    /// Checks that the schema functions the migration runner depends on (`fn::ensure_slice`,
+    /// `fn::confirm_migration`, and `fn::sync_permissions`) are defined.
+    ///
+    /// Doubles as a warm-up: the round trip pays the engine's cold-start cost before the first
+    /// real query does. See [`DatabaseBuilder::verify_schema`] to run it during startup.
+    ///
+    /// # Errors
+    /// - [`DatabaseError::Migration`] naming every missing function.
+    /// - [`DatabaseError::Surreal`] if the database info cannot be loaded.
+    #[instrument(skip(self))]
+    pub async fn verify_schema(&self) -> Result<(), DatabaseError> {
+        MigrationRunner::new(self.inner.instance.clone()).verify_schema().await
+    }
+
+    /// Reverts every migration applied after `slice_key:version` using their down scripts.
+    ///
+    /// Migrations are reverted in reverse execution order, across all slices, inside a single
+    /// transaction; the target migration itself stays applied. Returns the reverted
+    /// migrations as `(slice_key, version)` pairs, most recent first.
+    ///
+    /// # Errors
+    /// - [`DatabaseError::Migration`] if the target is unknown or not applied, or if a
+    ///   migration to revert has no `*.down.surql` script. The database is left unchanged.
+    /// - [`DatabaseError::Surreal`] if a down script fails; the transaction is rolled back.
+    #[instrument(skip(self))]
+    pub async fn rollback_migrations_to(
+        &self,
+        slice_key: &str,
+        version: &str,
+    ) -> Result<Vec<(String, String)>, DatabaseError> {
+        let reverted = MigrationRunner::new(self.inner.instance.clone())
+            .rollback_to(slice_key, version)
+            .await?;
+        for migration in &reverted {
+            info!(slice = migration.slice_key, version = migration.version, "Reverted migration");
+        }
+        Ok(reverted.into_iter().map(|m| (m.slice_key, m.version)).collect())
+    }
+
+    /// Hard-deletes a record together with every record reachable through the named edges.
+    ///
+    /// The graph is walked breadth-first along the outgoing side of each relation
+    /// (`thing->relation->?`), so children, grandchildren, and deeper descendants are all
+    /// collected; records reached twice or through a cycle are visited once. The descendants
+    /// are deleted deepest first, followed by the record itself, and `SurrealDB` removes the
+    /// edge records once either endpoint is gone. The walk and the deletes run inside a single
+    /// [`Database::transaction`], so either the whole graph is removed or nothing is.
+    ///
+    /// # Parameters
+    /// - `thing`: The record id of the root record (e.g. `organization:acme`).
+    /// - `relations`: Edge table names to follow (e.g. `["has_unit", "has_member"]`).
+    ///
+    /// # Errors
+    /// - [`DatabaseError::Validation`] if a relation name is not a plain identifier.
+    /// - [`DatabaseError::Surreal`] if any statement fails; the transaction is rolled back.
+    #[instrument(skip(self))]
+    pub async fn delete_cascade(
+        &self,
+        thing: &str,
+        relations: &[&str],
+    ) -> Result<(), DatabaseError> {
+        let mut edges = String::new();
+        for relation in relations {
+            if relation.is_empty()
+                || !relation.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+            {
+                return Err(DatabaseError::Validation {
+                    message: format!("Invalid relation name '{relation}'").into(),
+                    context: Some("Relation names must be alphanumeric identifiers".into()),
+                });
+            }
+            edges.push_str(&format!(
+                "SELECT VALUE <string> out FROM {relation} WHERE <string> in INSIDE $ids;\n"
+            ));
+        }
+
+        self.transaction(|tx| async move {
+            let root = tx
+                .query("RETURN <string> <record> $thing")
+                .bind(("thing", thing.to_owned()))
+                .await
+                .context(format!("Cascade delete of {thing}"))?
+                .take::<Option<String>>(0)
+                .context(format!("Parsing record id {thing}"))?
+                .unwrap_or_else(|| thing.to_owned());
+
+            let mut visited = HashSet::from([root.clone()]);
+            let mut doomed = vec![root];
+            let mut frontier = doomed.clone();
+            while !frontier.is_empty() && !relations.is_empty() {
+                let mut response = tx
+                    .query(edges.clone())
+                    .bind(("ids", frontier))
+                    .await
+                    .context(format!("Walking the graph of {thing}"))?;
+                let mut next = Vec::new();
+                for index in 0..relations.len() {
+                    let targets = response
+                        .take::<Vec<String>>(index)
+                        .context(format!("Walking the graph of {thing}"))?;
+                    next.extend(targets.into_iter().filter(|id| visited.insert(id.clone())));
+                }
+                doomed.extend(next.iter().cloned());
+                frontier = next;
+            }
+            doomed.reverse();
+
+            tx.query("FOR $id IN $ids { DELETE <record> $id; };")
+                .bind(("ids", doomed))
+                .await
+                .context(format!("Cascade delete of {thing}"))?
+                .check()
+                .map_err(surrealdb::Error::from)
+                .context(format!("Cascade delete of {thing} rolled back"))?;
+            Ok(())
+        })
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::migrations::Migration;
+
+    async fn test_db(name: &str) -> Database {
+        Database::builder()
+            .url("mem://")
+            .session("test_ns", name)
+            .init()
+            .await
+            .expect("connect to mem://")
+    }
+
+    async fn tamper_checksum(db: &Database, slice: &str) {
+        db.query(
+            "LET $m = type::record(\"migration\", [type::record(\"slice\", $slice), \"0000-init\"]);
+            DELETE $m;
+            CREATE $m SET checksum = \"tampered\";",
+        )
+        .bind(("slice", slice.to_owned()))
+        .await
+        .expect("tamper query")
+        .check()
+        .expect("tamper checksum");
+    }
+
+    #[tokio::test]
+    async fn test_checksum_drift_policies() {
+        let db = test_db("drift_db").await;
+        tamper_checksum(&db, "iam").await;
+        let runner = || MigrationRunner::new(db.inner.instance.clone());
+
+        let err = runner().run().await.unwrap_err();
+        assert!(matches!(err, DatabaseError::Migration { .. }));
+
+        let report = runner().with_drift_policy(DriftPolicy::Warn).run().await.expect("warn");
+        assert!(report.applied.is_empty());
+        assert!(report.skipped.iter().any(|m| m.slice_key == "iam"));
+
+        let plan = runner().with_drift_policy(DriftPolicy::Reapply).plan().await.expect("plan");
+        assert_eq!(plan.applied.len(), 1);
+        assert_eq!(plan.applied[0].slice_key, "iam");
+    }
+
+    async fn table_names(db: &Database) -> Vec<String> {
+        db.query("RETURN object::keys((INFO FOR DB).tables)")
+            .await
+            .expect("info query")
+            .take::<Vec<String>>(0)
+            .expect("table names")
+    }
+
+    #[tokio::test]
+    async fn test_rollback_reverts_applied_migration() {
+        let db = test_db("rollback_revert_db").await;
+        let runner = MigrationRunner::new(db.inner.instance.clone());
+        let migrations = [
+            Migration::new(
+                "widgets",
+                "Widgets",
+                Some("Rollback test slice"),
+                "0000-init",
+                "DEFINE TABLE widget SCHEMALESS;",
+                Some("REMOVE TABLE widget;"),
+                "widgets-0000",
+                false,
+            ),
+            Migration::new(
+                "widgets",
+                "Widgets",
+                Some("Rollback test slice"),
+                "0001-gadgets",
+                "DEFINE TABLE gadget SCHEMALESS;",
+                Some("REMOVE TABLE gadget;"),
+                "widgets-0001",
+                false,
+            ),
+        ];
+        for migration in &migrations {
+            runner.apply_migration(migration, false).await.expect("apply migration");
+        }
+        assert!(table_names(&db).await.iter().any(|table| table == "gadget"));
+
+        let reverted =
+            runner.rollback_within(&migrations, "widgets", "0000-init").await.expect("rollback");
+        assert_eq!(reverted.len(), 1);
+        assert_eq!(reverted[0].version, "0001-gadgets");
+
+        let tables = table_names(&db).await;
+        assert!(tables.iter().any(|table| table == "widget"), "{tables:?}");
+        assert!(!tables.iter().any(|table| table == "gadget"), "{tables:?}");
+
+        let versions = db
+            .query("SELECT VALUE version FROM migration WHERE id[0] = slice:widgets")
+            .await
+            .expect("migration query")
+            .take::<Vec<String>>(0)
+            .expect("migration versions");
+        assert_eq!(versions, ["0000-init"]);
+    }
+
+    #[tokio::test]
+    async fn test_checksum_drift_reapply_reruns_script() {
+        let db = test_db("drift_reapply_db").await;
+        let runner = || MigrationRunner::new(db.inner.instance.clone());
+        let migrations = || {
+            vec![Migration::new(
+                "gadgets",
+                "Gadgets",
+                Some("Drift test slice"),
+                "0000-init",
+                "DEFINE TABLE OVERWRITE gadget SCHEMALESS;",
+                None,
+                "gadgets-0000",
+                false,
+            )]
+        };
+        runner().run_within(migrations()).await.expect("initial apply");
+        tamper_checksum(&db, "gadgets").await;
+
+        let err = runner().run_within(migrations()).await.unwrap_err();
+        assert!(matches!(err, DatabaseError::Migration { .. }));
+
+        let report = runner()
+            .with_drift_policy(DriftPolicy::Reapply)
+            .run_within(migrations())
+            .await
+            .expect("reapply");
+        assert_eq!(report.applied.len(), 1);
+        assert_eq!(report.applied[0].slice_key, "gadgets");
+        assert!(table_names(&db).await.iter().any(|table| table == "gadget"));
+
+        let report = runner().run_within(migrations()).await.expect("checksum restored");
+        assert!(report.applied.is_empty());
+        assert!(report.skipped.iter().any(|m| m.slice_key == "gadgets"));
+    }
+
+    #[tokio::test]
+    async fn test_invalidate_session_forces_reload() {
+        let db = test_db("invalidate_db").await;
+
+        db.authenticate("alice").await.expect("authenticate");
+        db.authenticate("user:bob").await.expect("authenticate");
+        assert!(db.inner.cache.contains_key("user:alice"));
+
+        db.invalidate_session("user:alice").await;
+        assert!(!db.inner.cache.contains_key("user:alice"));
+        assert!(db.inner.cache.contains_key("user:bob"));
+
+        db.authenticate("alice").await.expect("re-authenticate");
+        assert!(db.inner.cache.contains_key("user:alice"), "the loader should run again");
+    }
+
+    #[tokio::test]
+    async fn test_readonly_claims_differ_from_standard() {
+        let db = test_db("readonly_claims_db").await;
+
+        let standard = serde_json::to_value(db.claims("user:alice", None)).unwrap();
+        let readonly = serde_json::to_value(db.claims("user:alice", Some(READONLY_SCOPE))).unwrap();
+
+        assert_ne!(standard, readonly);
+        assert!(standard.get("scope").is_none());
+        assert_eq!(readonly["scope"], READONLY_SCOPE);
+        assert_eq!(standard["id"], readonly["id"]);
+        assert_eq!(standard["ac"], readonly["ac"]);
+    }
+
+    #[tokio::test]
+    async fn test_readonly_session_is_cached_separately() {
+        let db = test_db("readonly_cache_db").await;
+
+        db.authenticate_readonly("alice").await.expect("authenticate readonly");
+        assert!(db.inner.cache.contains_key("readonly:user:alice"));
+        assert!(!db.inner.cache.contains_key("user:alice"));
+
+        db.authenticate("alice").await.expect("authenticate");
+        assert!(db.inner.cache.contains_key("user:alice"));
+
+        db.invalidate_session("alice").await;
+        assert!(!db.inner.cache.contains_key("readonly:user:alice"));
+        assert!(!db.inner.cache.contains_key("user:alice"));
+    }
+
+    #[tokio::test]
+    async fn test_invalidate_all_sessions() {
+        let db = test_db("invalidate_all_db").await;
+
+        db.authenticate("alice").await.expect("authenticate");
+        db.authenticate("bob").await.expect("authenticate");
+
+        db.invalidate_all_sessions();
+        assert!(!db.inner.cache.contains_key("user:alice"));
+        assert!(!db.inner.cache.contains_key("user:bob"));
+    }
+}
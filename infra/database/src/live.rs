@@ -0,0 +1,166 @@
+use crate::error::{DatabaseError, DatabaseErrorExt};
+use crate::{DEFAULT_RETRY_BASE_DELAY, DEFAULT_RETRY_MAX_DELAY, Database};
+use futures::StreamExt;
+use mhub_event_bus::{Event, EventBus};
+use serde::de::DeserializeOwned;
+use std::marker::PhantomData;
+use surrealdb::engine::any::Any;
+use surrealdb::method::QueryStream;
+use surrealdb::types::Value;
+use surrealdb::{Action, Notification, Surreal};
+use tokio::task::JoinHandle;
+use tracing::{debug, instrument, warn};
+
+/// Kind of change reported by a live query.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LiveAction {
+    Create,
+    Update,
+    Delete,
+}
+
+/// A live query notification republished on the [`EventBus`] by [`Database::live`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LiveEvent<T> {
+    /// What happened to the record.
+    pub action: LiveAction,
+    /// The record after the change, or the deleted record for [`LiveAction::Delete`].
+    pub data: T,
+}
+
+/// Keeps a live query bridged to the [`EventBus`]; dropping it kills the live query.
+#[must_use = "the live query is killed when the guard is dropped"]
+#[derive(Debug)]
+pub struct LiveQuery<T> {
+    task: JoinHandle<()>,
+    _event: PhantomData<fn() -> T>,
+}
+
+impl<T> Drop for LiveQuery<T> {
+    fn drop(&mut self) {
+        // Aborting drops the notification stream, which sends `KILL` for the live query.
+        self.task.abort();
+    }
+}
+
+impl Database {
+    /// Starts a `LIVE SELECT` query and republishes each notification on `bus` as a broadcast
+    /// [`LiveEvent<T>`].
+    ///
+    /// The bridge runs on the global runtime. If the notification stream ends (e.g. the
+    /// connection dropped), the query is restarted with exponential backoff capped at five
+    /// seconds. Notifications whose payload cannot be deserialized into `T` are logged and
+    /// skipped.
+    ///
+    /// # Delivery semantics
+    /// Delivery is **at-most-once**, not at-least-once or exactly-once:
+    /// - changes made while the query is being restarted are not replayed;
+    /// - events published while no subscriber is attached, or that a lagging broadcast
+    ///   receiver skips, are lost.
+    ///
+    /// Consumers that need a complete view should reload the affected records after a
+    /// `warn`-level restart message, or treat events as cache invalidation hints.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let mut rx = bus.subscribe::<LiveEvent<Unit>>()?;
+    /// let _guard = db.live::<Unit>("LIVE SELECT * FROM unit", &bus).await?;
+    /// while let Some(event) = rx.recv().await { /* ... */ }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns [`DatabaseError::Surreal`] if the initial live query is rejected.
+    #[instrument(skip(self, bus))]
+    pub async fn live<T>(&self, query: &str, bus: &EventBus) -> Result<LiveQuery<T>, DatabaseError>
+    where
+        T: DeserializeOwned + Event,
+    {
+        let instance = self.inner.instance.clone();
+        let stream = start_live(&instance, query).await?;
+        let task = mhub_runtime::get_global_runtime().spawn(bridge::<T>(
+            instance,
+            query.to_owned(),
+            bus.clone(),
+            stream,
+        ));
+
+        Ok(LiveQuery { task, _event: PhantomData })
+    }
+}
+
+async fn start_live(
+    instance: &Surreal<Any>,
+    query: &str,
+) -> Result<QueryStream<Notification<Value>>, DatabaseError> {
+    instance
+        .query(query)
+        .await
+        .context(format!("Starting live query `{query}`"))?
+        .stream::<Notification<Value>>(0)
+        .context(format!("Opening notification stream for `{query}`"))
+}
+
+async fn bridge<T>(
+    instance: Surreal<Any>,
+    query: String,
+    bus: EventBus,
+    mut stream: QueryStream<Notification<Value>>,
+) where
+    T: DeserializeOwned + Event,
+{
+    let mut delay = DEFAULT_RETRY_BASE_DELAY;
+    loop {
+        while let Some(notification) = stream.next().await {
+            match notification {
+                Ok(notification) => {
+                    delay = DEFAULT_RETRY_BASE_DELAY;
+                    publish::<T>(&bus, notification);
+                },
+                Err(e) => warn!(%query, error = %e, "Live query notification failed"),
+            }
+        }
+
+        loop {
+            warn!(%query, ?delay, "Live query stream ended, restarting...");
+            tokio::time::sleep(delay).await;
+            delay = delay.saturating_mul(2).min(DEFAULT_RETRY_MAX_DELAY);
+            match start_live(&instance, &query).await {
+                Ok(restarted) => {
+                    stream = restarted;
+                    break;
+                },
+                Err(e) => warn!(%query, error = %e, "Failed to restart live query"),
+            }
+        }
+    }
+}
+
+fn publish<T>(bus: &EventBus, notification: Notification<Value>)
+where
+    T: DeserializeOwned + Event,
+{
+    let action = match notification.action {
+        Action::Create => LiveAction::Create,
+        Action::Update => LiveAction::Update,
+        Action::Delete => LiveAction::Delete,
+        _ => return,
+    };
+
+    let data = match serde_json::from_value::<T>(notification.data.into_json_value()) {
+        Ok(data) => data,
+        Err(e) => {
+            warn!(
+                event = std::any::type_name::<T>(),
+                error = %e,
+                "Skipping live query notification with unexpected payload"
+            );
+            return;
+        },
+    };
+
+    if let Err(e) = bus.publish(LiveEvent { action, data }) {
+        warn!(event = std::any::type_name::<T>(), error = %e, "Failed to publish live event");
+    } else {
+        debug!(event = std::any::type_name::<T>(), ?action, "Live event published");
+    }
+}
@@ -1,9 +1,34 @@
 use crate::error::{DatabaseError, DatabaseErrorExt};
 use crate::generated::migrations_manifest::{builtin_migrations, builtin_registry};
+use crate::transaction;
 use fxhash::FxHashMap;
 use surrealdb::Surreal;
 use surrealdb::engine::any::Any;
 use surrealdb::types::SurrealValue;
+use tracing::warn;
+
+/// How [`MigrationRunner`] reacts when an applied migration's stored checksum differs from the
+/// compiled script, see
+/// [`DatabaseBuilder::on_checksum_drift`](crate::DatabaseBuilder::on_checksum_drift).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DriftPolicy {
+    /// Abort startup with [`DatabaseError::Migration`]. Recommended for production.
+    #[default]
+    Fail,
+    /// Log a warning and keep the applied version.
+    Warn,
+    /// Re-execute the compiled script in a transaction and store its checksum. Only scripts
+    /// that can run twice (e.g. `DEFINE ... OVERWRITE`) can be reapplied.
+    Reapply,
+}
+
+/// Schema functions defined by the bootstrap migration that [`MigrationRunner`] calls.
+pub(crate) const REQUIRED_FUNCTIONS: [&str; 3] =
+    ["ensure_slice", "confirm_migration", "sync_permissions"];
+
+/// Deletes the `migration` record of `$slice:$version`.
+const FORGET_MIGRATION: &str =
+    "DELETE type::record(\"migration\", [type::record(\"slice\", $slice), $version])";
 
 #[derive(Debug, SurrealValue)]
 pub(crate) struct Permissions {
@@ -25,6 +50,7 @@ pub(crate) struct Migration {
     pub slice_description: Option<&'static str>,
     pub version: &'static str,
     pub script: &'static str,
+    pub down_script: Option<&'static str>,
     pub checksum: &'static str,
     pub is_bootstrap: bool,
 }
@@ -37,10 +63,20 @@ impl Migration {
         slice_description: Option<&'static str>,
         version: &'static str,
         script: &'static str,
+        down_script: Option<&'static str>,
         checksum: &'static str,
         is_bootstrap: bool,
     ) -> Self {
-        Self { slice_key, slice_name, slice_description, version, script, checksum, is_bootstrap }
+        Self {
+            slice_key,
+            slice_name,
+            slice_description,
+            version,
+            script,
+            down_script,
+            checksum,
+            is_bootstrap,
+        }
     }
 
     fn to_applied(&self) -> AppliedMigration {
@@ -68,72 +104,244 @@ pub(crate) struct AppliedMigration {
 #[derive(Debug)]
 pub(crate) struct MigrationRunner {
     db: Surreal<Any>,
+    drift_policy: DriftPolicy,
 }
 
 impl MigrationRunner {
     #[must_use]
     pub(crate) const fn new(db: Surreal<Any>) -> Self {
-        Self { db }
+        Self { db, drift_policy: DriftPolicy::Fail }
+    }
+
+    #[must_use]
+    pub(crate) const fn with_drift_policy(mut self, drift_policy: DriftPolicy) -> Self {
+        self.drift_policy = drift_policy;
+        self
     }
+
     pub(crate) async fn run(&self) -> Result<MigrationReport, DatabaseError> {
+        self.run_within(builtin_migrations()).await
+    }
+
+    /// Runs [`MigrationRunner::run`] over an explicit, execution-ordered migration list
+    /// instead of the built-in one.
+    pub(crate) async fn run_within(
+        &self,
+        migrations: Vec<Migration>,
+    ) -> Result<MigrationReport, DatabaseError> {
         let mut report = MigrationReport::default();
-        let migrations = builtin_migrations();
         let applied_migrations = self.get_migrations_map().await?;
 
         for migration in migrations {
+            let key = format!("{}:{}", migration.slice_key, migration.version);
+            let reapply = match applied_migrations.get(&key) {
+                Some(applied) if !self.resolve_drift(&migration, &applied.checksum)? => {
+                    report.skipped.push(migration.to_applied());
+                    continue;
+                },
+                Some(applied) => {
+                    warn!(
+                        slice = migration.slice_key,
+                        version = migration.version,
+                        stored = applied.checksum,
+                        compiled = migration.checksum,
+                        "MIGRATION CHECKSUM DRIFT: re-applying the compiled script"
+                    );
+                    true
+                },
+                None => false,
+            };
+
+            self.apply_migration(&migration, reapply).await?;
+            report.applied.push(migration.to_applied());
+        }
+
+        self.sync_permissions().await?;
+
+        Ok(report)
+    }
+
+    /// Computes which migrations [`MigrationRunner::run`] would apply or skip without
+    /// executing any of them.
+    ///
+    /// # Errors
+    /// - [`DatabaseError::Migration`] if an applied migration's checksum has drifted under
+    ///   [`DriftPolicy::Fail`].
+    /// - [`DatabaseError::Surreal`] if the applied migrations cannot be loaded.
+    pub(crate) async fn plan(&self) -> Result<MigrationReport, DatabaseError> {
+        let mut report = MigrationReport::default();
+        let applied_migrations = self.get_migrations_map().await?;
+
+        for migration in builtin_migrations() {
             if let Some(applied) =
                 applied_migrations.get(&format!("{}:{}", migration.slice_key, migration.version))
+                && !self.resolve_drift(&migration, &applied.checksum)?
             {
-                ensure_checksum_match(&migration, &applied.checksum)?;
                 report.skipped.push(migration.to_applied());
-                continue;
+            } else {
+                report.applied.push(migration.to_applied());
             }
-
-            self.apply_migration(&migration).await?;
-            report.applied.push(migration.to_applied());
         }
 
-        self.sync_permissions().await?;
-
         Ok(report)
     }
 
-    async fn apply_migration(&self, migration: &Migration) -> Result<(), DatabaseError> {
+    /// Decides whether an applied migration must be re-executed under the drift policy.
+    fn resolve_drift(&self, migration: &Migration, existing: &str) -> Result<bool, DatabaseError> {
+        if existing == migration.checksum {
+            return Ok(false);
+        }
+        match self.drift_policy {
+            DriftPolicy::Fail => ensure_checksum_match(migration, existing).map(|()| false),
+            DriftPolicy::Warn => {
+                warn!(
+                    slice = migration.slice_key,
+                    version = migration.version,
+                    stored = existing,
+                    compiled = migration.checksum,
+                    "Migration checksum drift ignored; keeping the applied version"
+                );
+                Ok(false)
+            },
+            DriftPolicy::Reapply => Ok(true),
+        }
+    }
+
+    /// Executes `migration` in a transaction. With `reapply`, the stored record (whose
+    /// checksum is read-only) is replaced so it carries the compiled checksum.
+    pub(crate) async fn apply_migration(
+        &self,
+        migration: &Migration,
+        reapply: bool,
+    ) -> Result<(), DatabaseError> {
+        let forget = if reapply { format!("{FORGET_MIGRATION};") } else { String::new() };
         let query = if migration.is_bootstrap {
             format!(
-                "BEGIN TRANSACTION;
-                {}
+                "{}
                 fn::ensure_slice($slice, $name, $desc);
-                RETURN fn::confirm_migration($slice, $version, $checksum);
-                COMMIT TRANSACTION;",
+                {forget}
+                RETURN fn::confirm_migration($slice, $version, $checksum);",
                 migration.script,
             )
         } else {
             format!(
-                "BEGIN TRANSACTION;
-                fn::ensure_slice($slice, $name, $description);
+                "fn::ensure_slice($slice, $name, $description);
                 {}
-                RETURN fn::confirm_migration($slice, $version, $checksum);
-                COMMIT TRANSACTION;",
+                {forget}
+                RETURN fn::confirm_migration($slice, $version, $checksum);",
                 migration.script,
             )
         };
+        let location = format!("{}:{}", migration.slice_key, migration.version);
 
-        let _ = self
-            .db
-            .query(&query)
-            .bind(("slice", migration.slice_key))
-            .bind(("name", migration.slice_name))
-            .bind(("description", migration.slice_description))
-            .bind(("version", migration.version))
-            .bind(("checksum", migration.checksum))
-            .await
-            .context(format!(
-                "SQL execution failed at {}:{}",
-                migration.slice_key, migration.version
-            ))?;
+        transaction::run(&self.db, |tx| async move {
+            tx.query(query)
+                .bind(("slice", migration.slice_key))
+                .bind(("name", migration.slice_name))
+                .bind(("description", migration.slice_description))
+                .bind(("version", migration.version))
+                .bind(("checksum", migration.checksum))
+                .await
+                .context(format!("SQL execution failed at {location}"))?
+                .check()
+                .map_err(surrealdb::Error::from)
+                .context(format!("Migration {location} failed; rolled back"))?;
+            Ok(())
+        })
+        .await
+    }
 
-        Ok(())
+    /// Reverts every applied migration that runs after `slice_key:version`, leaving the
+    /// target itself applied.
+    ///
+    /// Down scripts execute in reverse execution order inside a single transaction, and the
+    /// matching `migration` records are deleted alongside them, so either every migration is
+    /// reverted or none is.
+    ///
+    /// # Errors
+    /// - [`DatabaseError::Migration`] if the target is unknown or not applied, or if a
+    ///   migration that needs reverting has no down script. Nothing is executed in that case.
+    /// - [`DatabaseError::Surreal`] if a down script fails; the transaction is rolled back.
+    pub(crate) async fn rollback_to(
+        &self,
+        slice_key: &str,
+        version: &str,
+    ) -> Result<Vec<AppliedMigration>, DatabaseError> {
+        self.rollback_within(&builtin_migrations(), slice_key, version).await
+    }
+
+    /// Runs [`MigrationRunner::rollback_to`] over an explicit, execution-ordered migration
+    /// list instead of the built-in one.
+    pub(crate) async fn rollback_within(
+        &self,
+        migrations: &[Migration],
+        slice_key: &str,
+        version: &str,
+    ) -> Result<Vec<AppliedMigration>, DatabaseError> {
+        let applied_migrations = self.get_migrations_map().await?;
+        let target = format!("{slice_key}:{version}");
+
+        let position = migrations
+            .iter()
+            .position(|m| m.slice_key == slice_key && m.version == version)
+            .ok_or_else(|| DatabaseError::Migration {
+                message: format!("Unknown migration {target}").into(),
+                context: Some("Rollback target must be a built-in migration".into()),
+            })?;
+        if !applied_migrations.contains_key(&target) {
+            return Err(DatabaseError::Migration {
+                message: format!("Migration {target} is not applied").into(),
+                context: Some("Rollback target must be applied".into()),
+            });
+        }
+
+        let reverting: Vec<&Migration> = migrations[position + 1..]
+            .iter()
+            .rev()
+            .filter(|m| applied_migrations.contains_key(&format!("{}:{}", m.slice_key, m.version)))
+            .collect();
+        if reverting.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let steps = reverting
+            .iter()
+            .map(|&migration| {
+                let down = migration.down_script.ok_or_else(|| DatabaseError::Migration {
+                    message: format!(
+                        "Missing down script for {}:{}",
+                        migration.slice_key, migration.version
+                    )
+                    .into(),
+                    context: Some(format!("Cannot roll back to {target}").into()),
+                })?;
+                Ok((migration, down))
+            })
+            .collect::<Result<Vec<_>, DatabaseError>>()?;
+
+        transaction::run(&self.db, |tx| async move {
+            for (migration, down) in steps {
+                let location = format!("{}:{}", migration.slice_key, migration.version);
+                tx.query(down)
+                    .await
+                    .context(format!("Reverting {location}"))?
+                    .check()
+                    .map_err(surrealdb::Error::from)
+                    .context(format!("Down script of {location} failed; rolled back"))?;
+                tx.query(FORGET_MIGRATION)
+                    .bind(("slice", migration.slice_key))
+                    .bind(("version", migration.version))
+                    .await
+                    .context(format!("Forgetting {location}"))?
+                    .check()
+                    .map_err(surrealdb::Error::from)
+                    .context(format!("Forgetting {location} failed; rolled back"))?;
+            }
+            Ok(())
+        })
+        .await?;
+
+        Ok(reverting.into_iter().map(Migration::to_applied).collect())
     }
 
     async fn is_system_ready(&self) -> Result<bool, DatabaseError> {
@@ -170,6 +378,35 @@ impl MigrationRunner {
             .collect())
     }
 
+    /// Checks that every function in [`REQUIRED_FUNCTIONS`] is defined on the database.
+    ///
+    /// # Errors
+    /// - [`DatabaseError::Migration`] naming the missing functions.
+    /// - [`DatabaseError::Surreal`] if the database info cannot be loaded.
+    pub(crate) async fn verify_schema(&self) -> Result<(), DatabaseError> {
+        let defined = self
+            .db
+            .query("RETURN object::keys((INFO FOR DB).functions)")
+            .await
+            .context("Loading schema functions")?
+            .take::<Vec<String>>(0)
+            .context("Parsing schema functions")?;
+
+        let missing: Vec<_> = REQUIRED_FUNCTIONS
+            .iter()
+            .filter(|name| !defined.iter().any(|defined| defined == *name))
+            .map(|name| format!("fn::{name}"))
+            .collect();
+
+        if missing.is_empty() {
+            return Ok(());
+        }
+        Err(DatabaseError::Migration {
+            message: format!("Missing schema functions: {}", missing.join(", ")).into(),
+            context: Some("The bootstrap migration has not been applied".into()),
+        })
+    }
+
     pub(crate) async fn sync_permissions(&self) -> Result<(), DatabaseError> {
         let registry = builtin_registry();
 
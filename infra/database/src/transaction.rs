@@ -0,0 +1,79 @@
+use crate::error::{DatabaseError, DatabaseErrorExt};
+use futures::FutureExt;
+use std::future::Future;
+use std::ops::Deref;
+use std::panic::{AssertUnwindSafe, resume_unwind};
+use std::sync::Arc;
+use surrealdb::Surreal;
+use surrealdb::engine::any::Any;
+use tracing::{trace, warn};
+
+/// A transaction-scoped handle passed to [`Database::transaction`](crate::Database::transaction).
+///
+/// Dereferences to the open `SurrealDB` transaction, so statements execute as they are
+/// issued and their results, including the transaction's own uncommitted writes, are
+/// readable inside the closure. Nothing is visible outside the transaction until it commits.
+///
+/// Clones share the same transaction and must not outlive the closure; a handle that escapes
+/// prevents the commit.
+#[derive(Debug, Clone)]
+pub struct Transaction {
+    session: Arc<surrealdb::method::Transaction<Any>>,
+}
+
+impl Deref for Transaction {
+    type Target = surrealdb::method::Transaction<Any>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.session
+    }
+}
+
+/// Begins a transaction on `db`, runs `f` with it, and commits once `f` returns `Ok`.
+///
+/// The transaction is cancelled if `f` returns an error or panics; the panic is resumed
+/// afterwards.
+pub(crate) async fn run<F, Fut, T>(db: &Surreal<Any>, f: F) -> Result<T, DatabaseError>
+where
+    F: FnOnce(Transaction) -> Fut,
+    Fut: Future<Output = Result<T, DatabaseError>>,
+{
+    let session = db.clone().begin().await.context("Beginning transaction")?;
+    let tx = Transaction { session: Arc::new(session) };
+
+    let outcome = AssertUnwindSafe(async { f(tx.clone()).await }).catch_unwind().await;
+
+    let Ok(session) = Arc::try_unwrap(tx.session) else {
+        return match outcome {
+            Err(panic) => resume_unwind(panic),
+            Ok(_) => Err(DatabaseError::Internal {
+                message: "Transaction handle outlived the closure".into(),
+                context: Some("Transaction was neither committed nor cancelled".into()),
+            }),
+        };
+    };
+
+    match outcome {
+        Ok(Ok(value)) => {
+            session.commit().await.context("Committing transaction; rolled back")?;
+            Ok(value)
+        },
+        Ok(Err(e)) => {
+            trace!(error = %e, "Transaction body failed, rolling back");
+            cancel(session).await;
+            Err(e)
+        },
+        Err(panic) => {
+            trace!("Transaction body panicked, rolling back");
+            cancel(session).await;
+            resume_unwind(panic)
+        },
+    }
+}
+
+/// Cancels `session`, logging instead of failing so the original error is the one reported.
+async fn cancel(session: surrealdb::method::Transaction<Any>) {
+    if let Err(e) = session.cancel().await {
+        warn!(error = %e, "Failed to cancel transaction");
+    }
+}
@@ -1,4 +1,5 @@
 use mhub_database::*;
+use std::time::Duration;
 
 #[tokio::test]
 async fn connect_in_memory_and_health_check() {
@@ -19,3 +20,376 @@ async fn missing_parameters_fail_validation() {
     let err = Database::builder().init().await.unwrap_err();
     assert!(matches!(err, DatabaseError::Validation { .. }));
 }
+
+#[tokio::test]
+async fn custom_retry_policy_connects() {
+    let db = Database::builder()
+        .url("mem://")
+        .session("test_ns", "retry_db")
+        .retry(1, Duration::from_millis(10), Duration::from_millis(20))
+        .init()
+        .await
+        .expect("connect to mem://");
+
+    db.health().await.expect("health check");
+}
+
+#[tokio::test]
+async fn zero_retry_attempts_fail_validation() {
+    let err = Database::builder()
+        .url("mem://")
+        .session("test_ns", "retry_db")
+        .retry(0, Duration::from_millis(10), Duration::from_millis(20))
+        .init()
+        .await
+        .unwrap_err();
+    assert!(matches!(err, DatabaseError::Validation { .. }));
+}
+
+#[tokio::test]
+async fn hmac_jwt_algorithm_authenticates() {
+    let db = Database::builder()
+        .url("mem://")
+        .session("test_ns", "jwt_hmac_db")
+        .jwt_algorithm(jsonwebtoken::Algorithm::HS512)
+        .jwt_ttl(Duration::from_secs(300))
+        .init()
+        .await
+        .expect("connect to mem://");
+
+    let session = db.authenticate("alice").await.expect("authenticate with HS512");
+    session.version().await.expect("scoped session should work");
+}
+
+#[tokio::test]
+async fn unsupported_jwt_algorithm_fails_validation() {
+    let err = Database::builder()
+        .url("mem://")
+        .session("test_ns", "jwt_rsa_db")
+        .jwt_algorithm(jsonwebtoken::Algorithm::RS256)
+        .init()
+        .await
+        .unwrap_err();
+    assert!(matches!(err, DatabaseError::Validation { .. }));
+}
+
+async fn count(db: &Database, table: &str) -> i64 {
+    db.query(format!("RETURN array::len((SELECT id FROM {table}))"))
+        .await
+        .expect("count query")
+        .take::<Option<i64>>(0)
+        .expect("count value")
+        .unwrap_or_default()
+}
+
+#[tokio::test]
+async fn delete_cascade_removes_related_records() {
+    let db = Database::builder()
+        .url("mem://")
+        .session("test_ns", "cascade_db")
+        .init()
+        .await
+        .expect("connect to mem://");
+
+    db.query(
+        "CREATE parent:p; CREATE child:a; CREATE child:b;
+         RELATE parent:p->has_child->child:a;
+         RELATE parent:p->has_child->child:b;",
+    )
+    .await
+    .expect("seed graph");
+
+    db.delete_cascade("parent:p", &["has_child"]).await.expect("cascade delete");
+
+    assert_eq!(count(&db, "parent").await, 0);
+    assert_eq!(count(&db, "child").await, 0);
+}
+
+#[tokio::test]
+async fn delete_cascade_follows_edges_recursively() {
+    let db = Database::builder()
+        .url("mem://")
+        .session("test_ns", "cascade_deep_db")
+        .init()
+        .await
+        .expect("connect to mem://");
+
+    db.query(
+        "CREATE parent:p; CREATE child:a; CREATE child:b; CREATE child:keep;
+         RELATE parent:p->has_child->child:a;
+         RELATE child:a->has_child->child:b;
+         RELATE child:b->has_child->child:a;",
+    )
+    .await
+    .expect("seed graph");
+
+    db.delete_cascade("parent:p", &["has_child"]).await.expect("cascade delete");
+
+    assert_eq!(count(&db, "parent").await, 0);
+    assert_eq!(count(&db, "child").await, 1, "only the unrelated child survives");
+}
+
+#[tokio::test]
+async fn delete_cascade_rolls_back_on_failure() {
+    let db = Database::builder()
+        .url("mem://")
+        .session("test_ns", "cascade_rollback_db")
+        .init()
+        .await
+        .expect("connect to mem://");
+
+    db.query(
+        "CREATE parent:p; CREATE child:a; CREATE child:b SET locked = true;
+         RELATE parent:p->has_child->child:a;
+         RELATE parent:p->has_child->child:b;
+         DEFINE EVENT guard ON child WHEN $event = 'DELETE' AND $before.locked = true THEN {
+             THROW 'child is locked';
+         };",
+    )
+    .await
+    .expect("seed graph");
+
+    let result = db.delete_cascade("parent:p", &["has_child"]).await;
+    assert!(result.is_err(), "locked child must abort the cascade");
+
+    assert_eq!(count(&db, "parent").await, 1);
+    assert_eq!(count(&db, "child").await, 2);
+}
+
+#[tokio::test]
+async fn delete_cascade_rejects_invalid_relation() {
+    let db = Database::builder()
+        .url("mem://")
+        .session("test_ns", "cascade_invalid_db")
+        .init()
+        .await
+        .expect("connect to mem://");
+
+    let err = db.delete_cascade("parent:p", &["edge; DELETE slice"]).await.unwrap_err();
+    assert!(matches!(err, DatabaseError::Validation { .. }));
+}
+
+#[tokio::test]
+async fn transaction_commits_and_reads_its_own_writes() {
+    let db = Database::builder()
+        .url("mem://")
+        .session("test_ns", "tx_commit_db")
+        .init()
+        .await
+        .expect("connect to mem://");
+
+    let balance = db
+        .transaction(|tx| async move {
+            tx.query("CREATE account:a SET balance = $amount; CREATE account:b SET balance = 0")
+                .bind(("amount", 100))
+                .await?
+                .check()
+                .map_err(surrealdb::Error::from)?;
+            let balance = tx
+                .query("RETURN account:a.balance")
+                .await?
+                .take::<Option<i64>>(0)?
+                .unwrap_or_default();
+            Ok(balance)
+        })
+        .await
+        .expect("transaction should commit");
+
+    assert_eq!(balance, 100);
+    assert_eq!(count(&db, "account").await, 2);
+}
+
+#[tokio::test]
+async fn transaction_failing_closure_leaves_db_unchanged() {
+    let db = Database::builder()
+        .url("mem://")
+        .session("test_ns", "tx_abort_db")
+        .init()
+        .await
+        .expect("connect to mem://");
+
+    let result: Result<(), _> = db
+        .transaction(|tx| async move {
+            tx.query("CREATE account:a SET balance = 100").await?;
+            Err(DatabaseError::Validation { message: "insufficient funds".into(), context: None })
+        })
+        .await;
+
+    assert!(matches!(result, Err(DatabaseError::Validation { .. })));
+    assert_eq!(count(&db, "account").await, 0);
+}
+
+#[tokio::test]
+#[allow(unreachable_code)]
+async fn transaction_panicking_closure_leaves_db_unchanged() {
+    let db = Database::builder()
+        .url("mem://")
+        .session("test_ns", "tx_panic_db")
+        .init()
+        .await
+        .expect("connect to mem://");
+
+    let task_db = db.clone();
+    let result = tokio::spawn(async move {
+        task_db
+            .transaction(|tx| async move {
+                tx.query("CREATE account:a SET balance = 100").await?;
+                panic!("closure panicked mid-transaction");
+                Ok(())
+            })
+            .await
+    })
+    .await;
+
+    assert!(result.expect_err("panic is resumed").is_panic());
+    assert_eq!(count(&db, "account").await, 0);
+}
+
+#[tokio::test]
+async fn transaction_failing_statement_rolls_back() {
+    let db = Database::builder()
+        .url("mem://")
+        .session("test_ns", "tx_rollback_db")
+        .init()
+        .await
+        .expect("connect to mem://");
+
+    let result = db
+        .transaction(|tx| async move {
+            tx.query("CREATE account:a SET balance = 100; THROW 'boom'")
+                .await?
+                .check()
+                .map_err(surrealdb::Error::from)?;
+            Ok(())
+        })
+        .await;
+
+    assert!(matches!(result, Err(DatabaseError::Surreal { .. })));
+    assert_eq!(count(&db, "account").await, 0);
+}
+
+#[tokio::test]
+async fn rollback_to_latest_migration_is_a_no_op() {
+    let db = Database::builder()
+        .url("mem://")
+        .session("test_ns", "rollback_noop_db")
+        .init()
+        .await
+        .expect("connect to mem://");
+
+    let reverted = db.rollback_migrations_to("iam", "0000-init").await.expect("rollback");
+    assert!(reverted.is_empty());
+}
+
+#[tokio::test]
+async fn rollback_without_down_script_fails() {
+    let db = Database::builder()
+        .url("mem://")
+        .session("test_ns", "rollback_missing_db")
+        .init()
+        .await
+        .expect("connect to mem://");
+
+    let err = db.rollback_migrations_to("sys.database", "0000-init").await.unwrap_err();
+    assert!(matches!(err, DatabaseError::Migration { .. }));
+    assert!(err.to_string().contains("Missing down script"), "{err}");
+
+    let err = db.rollback_migrations_to("sys.database", "9999-unknown").await.unwrap_err();
+    assert!(matches!(err, DatabaseError::Migration { .. }));
+}
+
+#[tokio::test]
+async fn migrate_dry_run_writes_nothing() {
+    let db = Database::builder()
+        .url("mem://")
+        .session("test_ns", "dry_run_db")
+        .migrate_dry_run(true)
+        .init()
+        .await
+        .expect("connect to mem://");
+
+    assert_eq!(count(&db, "migration").await, 0);
+    assert_eq!(count(&db, "slice").await, 0);
+    assert_eq!(count(&db, "permission").await, 0);
+}
+
+#[tokio::test]
+async fn verify_schema_reports_missing_functions() {
+    let db = Database::builder()
+        .url("mem://")
+        .session("test_ns", "verify_schema_db")
+        .verify_schema(true)
+        .init()
+        .await
+        .expect("migrated schema passes verification");
+
+    db.verify_schema().await.expect("schema functions present");
+
+    db.query("REMOVE FUNCTION fn::sync_permissions").await.expect("remove function");
+    let err = db.verify_schema().await.unwrap_err();
+    assert!(matches!(err, DatabaseError::Migration { .. }));
+    let message = err.to_string();
+    assert!(message.contains("fn::sync_permissions"), "{message}");
+    assert!(!message.contains("fn::ensure_slice"), "{message}");
+}
+
+#[tokio::test]
+async fn verify_schema_fails_init_on_unprovisioned_database() {
+    let err = Database::builder()
+        .url("mem://")
+        .session("test_ns", "verify_unprovisioned_db")
+        .migrate_dry_run(true)
+        .verify_schema(true)
+        .init()
+        .await
+        .unwrap_err();
+    assert!(matches!(err, DatabaseError::Migration { .. }));
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize)]
+struct Item {
+    name: String,
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn live_query_publishes_to_event_bus() {
+    let db = Database::builder()
+        .url("mem://")
+        .session("test_ns", "live_db")
+        .init()
+        .await
+        .expect("connect to mem://");
+    let bus = mhub_event_bus::EventBus::new();
+    let mut rx = bus.subscribe::<LiveEvent<Item>>().expect("subscribe");
+
+    let guard = db.live::<Item>("LIVE SELECT * FROM item", &bus).await.expect("live query");
+    db.query("CREATE item:one SET name = 'first'").await.expect("create item");
+
+    let event = tokio::time::timeout(Duration::from_secs(5), rx.recv())
+        .await
+        .expect("live event in time")
+        .expect("live event");
+    assert_eq!(event.action, LiveAction::Create);
+    assert_eq!(event.data, Item { name: "first".to_owned() });
+
+    drop(guard);
+}
+
+#[tokio::test]
+async fn query_timed_counts_slow_queries() {
+    let db = Database::builder()
+        .url("mem://")
+        .session("test_ns", "stats_db")
+        .slow_query_threshold(Duration::ZERO)
+        .init()
+        .await
+        .expect("connect to mem://");
+    assert_eq!(db.stats(), DbStats::default());
+
+    db.query_timed("RETURN 1").await.expect("timed query");
+    db.query_timed("RETURN 2").await.expect("timed query");
+    let _ = db.query("RETURN 3").await.expect("raw query");
+    db.query_timed("THROW 'boom'").await.expect_err("failing statement");
+
+    assert_eq!(db.stats(), DbStats { total_queries: 2, failed_queries: 1, slow_queries: 3 });
+}
@@ -31,7 +31,9 @@ use syn::{DeriveInput, ItemFn, ItemStruct, parse_macro_input};
 ///
 /// * `high_performance` - Optimized for high-throughput server environments.
 /// * `memory_efficient` - Optimized for low-footprint client or edge environments.
+/// * `current_thread` - Single-threaded scheduler for CLI tools and tests.
 /// * `default` - Uses the default configuration (worker threads auto-detected based on available parallelism).
+/// * `config = <expr>` - Uses an expression evaluating to a `RuntimeConfig`.
 ///
 /// # Examples
 ///
@@ -40,6 +42,11 @@ use syn::{DeriveInput, ItemFn, ItemStruct, parse_macro_input};
 /// async fn main() -> Result<(), ()> {
 /// # Ok(())
 /// }
+///
+/// #[mhub_runtime::main(config = RuntimeConfig::default().with_worker_threads(2))]
+/// async fn main() -> anyhow::Result<()> {
+/// # Ok(())
+/// }
 /// ```
 #[proc_macro_attribute]
 pub fn main(args: TokenStream, item: TokenStream) -> TokenStream {
@@ -56,6 +63,7 @@ pub fn main(args: TokenStream, item: TokenStream) -> TokenStream {
 ///
 /// * **Derives**: Automatically adds `Debug`, `Serialize`, and `Deserialize` if missing.
 /// * **`OpenAPI`**: Conditionally adds `utoipa::ToSchema` when the `server` feature is enabled.
+///   Generic type parameters are bound by `ToSchema` unless `#[schema(bound = "...")]` is set.
 /// * **Serde Policy**:
 ///     * `rename_all = "camelCase"` by default (can be overridden).
 ///     * `deny_unknown_fields` by default (can be disabled).
@@ -91,11 +99,16 @@ pub fn api_model(attr: TokenStream, item: TokenStream) -> TokenStream {
 /// Accepts standard `utoipa::path` arguments such as `get`, `post`, `path = "..."`,
 /// `responses(...)`, and `tag = "..."`.
 ///
+/// The extra `instrument` flag is consumed by the macro and not forwarded to `utoipa`.
+///
 /// # Features
 ///
 /// * **Documentation**: Registers handler metadata via `utoipa::path` when the `server` feature is enabled.
 /// * **Linting**: Applies `#[allow(clippy::unused_async)]` to the handler to satisfy boilerplate
 ///   requirements of certain Axum extractors.
+/// * **Tracing**: With `instrument`, runs the handler body inside a `tracing::info_span!` named
+///   after the function that records `http.method` and `http.route`. The calling crate must
+///   depend on `tracing`.
 ///
 /// # Example
 ///
@@ -103,6 +116,7 @@ pub fn api_model(attr: TokenStream, item: TokenStream) -> TokenStream {
 /// use mhub_derive::api_handler;
 ///
 /// #[api_handler(
+///     instrument,
 ///     get,
 ///     path = "/health",
 ///     responses((status = OK, body = HealthResponse)),
@@ -119,19 +133,27 @@ pub fn api_handler(args: TokenStream, item: TokenStream) -> TokenStream {
     macros::api::expand_api_handler(args.into(), input).into()
 }
 
-/// Attribute macro to generate vault-aware Serde behavior for structs.
+/// Attribute macro to generate vault-aware Serde behavior for structs and enums.
 ///
 /// This macro generates `Serialize` and `Deserialize` impls that preserve field
 /// behavior and serde attributes. It also implements `mhub_vault::Tagged` using
 /// the optional `tag = "..."` argument or the struct name, marks the type as
 /// `mhub_vault::VaultSerde`, and provides `Debug`, `PartialEq`, `Eq`, and `Hash`.
 ///
+/// Fields marked `#[vault_skip]` or `#[serde(skip)]` are left out of the sealed payload and
+/// of the generated `Debug`/`PartialEq`/`Eq`/`Hash` impls; they must implement `Default`,
+/// which is used to reconstruct them on deserialization.
+///
+/// Enums use serde's externally tagged representation, the only one postcard can decode;
+/// variants must be unit variants or have named fields.
+///
 /// # Results
-/// Expands to `Serialize`/`Deserialize` impls for the annotated struct.
+/// Expands to `Serialize`/`Deserialize` impls for the annotated struct or enum.
 ///
 /// # Errors
-/// Emits a compile-time error if the macro is applied to a non-struct or
-/// a struct without named fields.
+/// Emits a compile-time error if the tag is empty or contains control characters, or if
+/// the macro is applied to a union, a struct without named fields, an enum with tuple
+/// variants, or an enum marked `#[serde(tag)]`, `#[serde(content)]` or `#[serde(untagged)]`.
 ///
 /// # Example
 /// ```rust,ignore
@@ -165,6 +187,11 @@ pub fn vault_model(args: TokenStream, item: TokenStream) -> TokenStream {
 /// * **Internal Fallback**: Provides specialized `From<&str>` and `From<String>` implementations
 ///   if an `Internal` variant is present.
 ///
+/// # Arguments
+///
+/// * `with_internal` - Appends a standard `Internal { message, context }` variant displayed as
+///   `Internal error (context): message`. The enum must not declare its own `Internal`.
+///
 /// # Requirements
 ///
 /// 1. The macro must be applied to an **enum**.
@@ -180,6 +207,8 @@ pub fn vault_model(args: TokenStream, item: TokenStream) -> TokenStream {
 ///   `Result<T, SourceError>` when a source field exists.
 /// * `From<SourceError>` impls for variants with a source field and a context field.
 /// * `From<&'static str>` and `From<String>` when an `Internal` variant is present.
+/// * `<ErrorName>IntoInternal` trait with `.into_internal()` for any `Result<T, E: Display>`
+///   when an `Internal` variant is present.
 ///
 /// # Example
 ///
@@ -209,9 +238,9 @@ pub fn vault_model(args: TokenStream, item: TokenStream) -> TokenStream {
 /// }
 /// ```
 #[proc_macro_attribute]
-pub fn mhub_error(_args: TokenStream, item: TokenStream) -> TokenStream {
+pub fn mhub_error(args: TokenStream, item: TokenStream) -> TokenStream {
     let input = parse_macro_input!(item as DeriveInput);
-    macros::error::expand_derive(input).into()
+    macros::error::expand_derive(args.into(), input).into()
 }
 
 /// Attribute macro to define a Vertical Slice handle.
@@ -220,6 +249,8 @@ pub fn mhub_error(_args: TokenStream, item: TokenStream) -> TokenStream {
 /// 1. Generates a thread-safe `Arc` wrapper.
 /// 2. Implements `Deref` for transparent access to the inner state.
 /// 3. Implements `FeatureSlice` for registration in the Kernel.
+/// 4. Implements `FeatureSliceAsync<C>` when the inner state implements `SliceBootstrap<C>`,
+///    so slices needing async setup can be awaited with `InitializedSlice::init_async`.
 ///
 /// # Example
 /// ```rust,ignore
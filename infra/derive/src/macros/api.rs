@@ -20,7 +20,7 @@ pub fn expand_api_model(args: TokenStream, input: ItemStruct) -> TokenStream {
     };
 
     let derive_attr = derive_attr(&derives);
-    let to_schema_attr = to_schema_attr(&derives);
+    let to_schema_attr = to_schema_attr(&derives, &input);
 
     let rename_attr = match rename_attr(rename_all, &serde_meta) {
         Ok(attr) => attr,
@@ -49,14 +49,88 @@ pub fn expand_api_handler(args: TokenStream, input: ItemFn) -> TokenStream {
     let sig = &input.sig;
     let vis = &input.vis;
     let attrs = &input.attrs;
+    let ApiHandlerArgs { path_args, instrument } = parse_api_handler_args(args);
+
+    if instrument.is_some() && sig.asyncness.is_none() {
+        return syn::Error::new_spanned(&sig.fn_token, "`instrument` requires an async handler")
+            .to_compile_error();
+    }
+
+    let body = match instrument {
+        Some(InstrumentInfo { method, route }) => {
+            let name = LitStr::new(&sig.ident.to_string(), sig.ident.span());
+            let route = route.map_or_else(|| quote! { "" }, |route| quote! { #route });
+            quote! {{
+                let __mhub_span = ::tracing::info_span!(
+                    #name,
+                    http.method = #method,
+                    http.route = #route,
+                );
+                ::tracing::Instrument::instrument(async move #body, __mhub_span).await
+            }}
+        },
+        None => quote! { #body },
+    };
 
     quote! {
         #(#attrs)*
         #[allow(clippy::unused_async)]
-        #[cfg_attr(feature = "server", ::utoipa::path(#args))]
-        #vis #sig {
-            #body
+        #[cfg_attr(feature = "server", ::utoipa::path(#path_args))]
+        #vis #sig #body
+    }
+}
+
+const HTTP_METHODS: [&str; 8] =
+    ["get", "post", "put", "delete", "patch", "head", "options", "trace"];
+
+struct ApiHandlerArgs {
+    path_args: TokenStream,
+    instrument: Option<InstrumentInfo>,
+}
+
+struct InstrumentInfo {
+    method: LitStr,
+    route: Option<syn::Expr>,
+}
+
+/// Splits the `instrument` flag off the arguments forwarded to `utoipa::path`.
+///
+/// Arguments that do not parse as metas are forwarded untouched for `utoipa` to report.
+fn parse_api_handler_args(args: TokenStream) -> ApiHandlerArgs {
+    let parser = syn::punctuated::Punctuated::<Meta, syn::Token![,]>::parse_terminated;
+    let Ok(metas) = parser.parse2(args.clone()) else {
+        return ApiHandlerArgs { path_args: args, instrument: None };
+    };
+
+    let mut instrument = false;
+    let mut method = LitStr::new("", proc_macro2::Span::call_site());
+    let mut route = None;
+    let mut path_args = Vec::new();
+
+    for meta in metas {
+        match &meta {
+            Meta::Path(path) if path.is_ident("instrument") => {
+                instrument = true;
+                continue;
+            },
+            Meta::Path(path) => {
+                if let Some(ident) = path.get_ident()
+                    && HTTP_METHODS.contains(&ident.to_string().as_str())
+                {
+                    method = LitStr::new(&ident.to_string().to_uppercase(), ident.span());
+                }
+            },
+            Meta::NameValue(name_value) if name_value.path.is_ident("path") => {
+                route = Some(name_value.value.clone());
+            },
+            _ => {},
         }
+        path_args.push(meta);
+    }
+
+    ApiHandlerArgs {
+        path_args: quote! { #(#path_args),* },
+        instrument: instrument.then_some(InstrumentInfo { method, route }),
     }
 }
 
@@ -175,12 +249,43 @@ fn derive_attr(derives: &FxHashSet<String>) -> TokenStream {
     }
 }
 
-fn to_schema_attr(derives: &FxHashSet<String>) -> TokenStream {
+fn to_schema_attr(derives: &FxHashSet<String>, input: &ItemStruct) -> TokenStream {
     if derives.contains("ToSchema") {
-        quote! {}
-    } else {
-        quote! { #[cfg_attr(feature = "server", derive(::utoipa::ToSchema))] }
+        return quote! {};
+    }
+
+    let type_params: Vec<_> = input.generics.type_params().map(|param| &param.ident).collect();
+    if type_params.is_empty() || has_schema_bound(&input.attrs) {
+        return quote! { #[cfg_attr(feature = "server", derive(::utoipa::ToSchema))] };
+    }
+
+    // Generic parameters must be schemas themselves so utoipa can compose the generic type.
+    let bound = type_params
+        .iter()
+        .map(|ident| format!("{ident}: ::utoipa::ToSchema"))
+        .collect::<Vec<_>>()
+        .join(", ");
+    quote! {
+        #[cfg_attr(feature = "server", derive(::utoipa::ToSchema))]
+        #[cfg_attr(feature = "server", schema(bound = #bound))]
+    }
+}
+
+/// Returns `true` if the struct already sets `#[schema(bound = "...")]`.
+fn has_schema_bound(attrs: &[Attribute]) -> bool {
+    let mut found = false;
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("schema")) {
+        let _ = attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("bound") {
+                found = true;
+            }
+            if meta.input.peek(syn::Token![=]) {
+                meta.value()?.parse::<syn::Expr>()?;
+            }
+            Ok(())
+        });
     }
+    found
 }
 
 fn rename_attr(
@@ -1,7 +1,8 @@
 use fxhash::FxHashSet;
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote};
-use syn::{Attribute, Data, DeriveInput, Fields, Ident, Type, Variant};
+use syn::parse::Parser;
+use syn::{Attribute, Data, DeriveInput, Fields, Ident, Type, Variant, parse_quote};
 
 struct VariantMeta<'a> {
     ident: &'a Ident,
@@ -11,7 +12,15 @@ struct VariantMeta<'a> {
     cfg_attrs: Vec<Attribute>,
 }
 
-pub fn expand_derive(input: DeriveInput) -> TokenStream {
+pub fn expand_derive(args: TokenStream, mut input: DeriveInput) -> TokenStream {
+    let with_internal = match parse_args(args) {
+        Ok(with_internal) => with_internal,
+        Err(err) => return err,
+    };
+    if with_internal && let Err(err) = add_internal_variant(&mut input) {
+        return err;
+    }
+
     let name = &input.ident;
     let trait_name = format_ident!("{}Ext", name);
 
@@ -44,6 +53,7 @@ pub fn expand_derive(input: DeriveInput) -> TokenStream {
     let context_impl = generate_context_trait(name, &trait_name, &variants);
     let from_impls = variants.iter().filter_map(|v| generate_from_impl(name, &trait_name, v));
     let internal_impls = generate_internal_impls(name, &variants);
+    let into_internal_impl = generate_into_internal_trait(name, &variants);
 
     quote! {
         #[allow(non_shorthand_field_patterns)]
@@ -53,6 +63,7 @@ pub fn expand_derive(input: DeriveInput) -> TokenStream {
         #context_impl
         #(#from_impls)*
         #internal_impls
+        #into_internal_impl
 
         #[allow(dead_code)]
         fn format_context(context: &Option<std::borrow::Cow<'static, str>>) -> std::borrow::Cow<'static, str> {
@@ -61,6 +72,48 @@ pub fn expand_derive(input: DeriveInput) -> TokenStream {
     }
 }
 
+/// Parses the macro arguments, returning whether `with_internal` was requested.
+fn parse_args(args: TokenStream) -> Result<bool, TokenStream> {
+    let parser = syn::punctuated::Punctuated::<syn::Path, syn::Token![,]>::parse_terminated;
+    let paths = parser.parse2(args).map_err(|err| err.to_compile_error())?;
+
+    let mut with_internal = false;
+    for path in paths {
+        if !path.is_ident("with_internal") {
+            return Err(syn::Error::new_spanned(
+                path,
+                "Unsupported argument; expected `with_internal`",
+            )
+            .to_compile_error());
+        }
+        with_internal = true;
+    }
+    Ok(with_internal)
+}
+
+/// Appends the standard `Internal { message, context }` variant for `with_internal`.
+fn add_internal_variant(input: &mut DeriveInput) -> Result<(), TokenStream> {
+    let Data::Enum(data) = &mut input.data else {
+        return Ok(());
+    };
+    if let Some(existing) = data.variants.iter().find(|v| v.ident == "Internal") {
+        return Err(syn::Error::new_spanned(
+            &existing.ident,
+            "`with_internal` generates the Internal variant; remove this one",
+        )
+        .to_compile_error());
+    }
+
+    data.variants.push(parse_quote! {
+        #[error("Internal error{}: {message}", format_context(.context))]
+        Internal {
+            message: ::std::borrow::Cow<'static, str>,
+            context: ::core::option::Option<::std::borrow::Cow<'static, str>>,
+        }
+    });
+    Ok(())
+}
+
 fn parse_variant(v: &Variant) -> Result<VariantMeta<'_>, TokenStream> {
     let Fields::Named(fields) = &v.fields else {
         return Err(syn::Error::new_spanned(
@@ -193,6 +246,35 @@ fn generate_internal_impls(name: &Ident, variants: &[VariantMeta<'_>]) -> TokenS
     }
 }
 
+fn generate_into_internal_trait(name: &Ident, variants: &[VariantMeta<'_>]) -> TokenStream {
+    let internal = variants.iter().find(|v| v.ident == "Internal");
+    let Some(internal) = internal else {
+        return quote!();
+    };
+    let cfg_attrs = &internal.cfg_attrs;
+    let trait_name = format_ident!("{}IntoInternal", name);
+
+    quote! {
+        #(#cfg_attrs)*
+        pub trait #trait_name<T> {
+            /// Maps any displayable error into the `Internal` variant.
+            fn into_internal(self) -> Result<T, #name>;
+        }
+
+        #(#cfg_attrs)*
+        #[automatically_derived]
+        impl<T, E: ::core::fmt::Display> #trait_name<T> for Result<T, E> {
+            #[inline]
+            fn into_internal(self) -> Result<T, #name> {
+                self.map_err(|e| #name::Internal {
+                    message: std::borrow::Cow::Owned(e.to_string()),
+                    context: None,
+                })
+            }
+        }
+    }
+}
+
 fn field_has_attr(field: &syn::Field, name: &str) -> bool {
     field.attrs.iter().any(|attr| attr.path().is_ident(name))
 }
@@ -28,7 +28,7 @@ pub fn expand_main(args: TokenStream, input: ItemFn) -> TokenStream {
     let attrs = &input.attrs;
     let output = &input.sig.output;
 
-    // 2. Parse arguments to determine the RuntimeConfig preset
+    // 2. Parse arguments to determine the RuntimeConfig preset or explicit expression
     let runtime_call = match parse_profile(args) {
         Ok(profile) => profile,
         Err(err) => return err,
@@ -38,7 +38,7 @@ pub fn expand_main(args: TokenStream, input: ItemFn) -> TokenStream {
     quote! {
         #(#attrs)*
         #vis fn #name() #output {
-            let config = #runtime_call;
+            let config: ::mhub_runtime::RuntimeConfig = #runtime_call;
             let rt = ::mhub_runtime::build_runtime_with_config(&config)?;
             rt.block_on(async { #body })
         }
@@ -50,14 +50,27 @@ fn parse_profile(args: TokenStream) -> Result<TokenStream, TokenStream> {
         return Ok(quote! { ::mhub_runtime::RuntimeConfig::default() });
     }
 
+    if let Ok(name_value) = syn::parse2::<syn::MetaNameValue>(args.clone()) {
+        if !name_value.path.is_ident("config") {
+            return Err(Error::new_spanned(
+                name_value.path,
+                "Unknown runtime argument. Use `config = <expr>` or a profile name",
+            )
+            .to_compile_error());
+        }
+        let expr = name_value.value;
+        return Ok(quote! { #expr });
+    }
+
     let ident: syn::Ident = syn::parse2(args).map_err(|err| err.to_compile_error())?;
     match ident.to_string().as_str() {
         "high_performance" => Ok(quote! { ::mhub_runtime::RuntimeConfig::high_performance() }),
         "memory_efficient" => Ok(quote! { ::mhub_runtime::RuntimeConfig::memory_efficient() }),
+        "current_thread" => Ok(quote! { ::mhub_runtime::RuntimeConfig::current_thread() }),
         "default" => Ok(quote! { ::mhub_runtime::RuntimeConfig::default() }),
         _ => Err(Error::new_spanned(
             ident,
-            "Unknown runtime profile. Use: high_performance, memory_efficient, or default",
+            "Unknown runtime profile. Use: high_performance, memory_efficient, current_thread, or default",
         )
         .to_compile_error()),
     }
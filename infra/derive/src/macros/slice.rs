@@ -40,5 +40,25 @@ pub fn expand_slice(input: ItemStruct) -> TokenStream {
                 self
             }
         }
+
+        impl<C> ::mhub_kernel::domain::registry::FeatureSliceAsync<C> for #wrapper_ident
+        where
+            C: ?Sized + Sync,
+            #inner_ident: ::mhub_kernel::domain::registry::SliceBootstrap<C>,
+        {
+            type Error = <#inner_ident as ::mhub_kernel::domain::registry::SliceBootstrap<C>>::Error;
+
+            fn init_async(
+                context: &C,
+            ) -> impl ::core::future::Future<Output = Result<Self, Self::Error>> + Send {
+                async move {
+                    <#inner_ident as ::mhub_kernel::domain::registry::SliceBootstrap<C>>::bootstrap(
+                        context,
+                    )
+                    .await
+                    .map(Self::new)
+                }
+            }
+        }
     }
 }
@@ -10,6 +10,55 @@ struct FieldTokens {
     deserialize_helper_fields: Vec<TokenStream>,
     field_idents: Vec<Ident>,
     field_types: Vec<Type>,
+    skipped_idents: Vec<Ident>,
+}
+
+/// Returns `true` for fields marked `#[vault_skip]` or `#[serde(skip)]`.
+fn is_skipped(attrs: &[syn::Attribute]) -> syn::Result<bool> {
+    let mut skipped = false;
+
+    for attr in attrs {
+        if attr.path().is_ident("vault_skip") {
+            skipped = true;
+        } else if attr.path().is_ident("serde") {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("skip") {
+                    skipped = true;
+                } else if meta.input.peek(syn::Token![=]) {
+                    meta.value()?.parse::<syn::Expr>()?;
+                } else if meta.input.peek(syn::token::Paren) {
+                    meta.parse_nested_meta(|_| Ok(()))?;
+                }
+                Ok(())
+            })?;
+        }
+    }
+
+    Ok(skipped)
+}
+
+/// Removes the attributes consumed by the macro from the emitted struct: `#[vault_skip]` is
+/// not a real attribute, and `#[serde(...)]` only applies to the generated helpers.
+fn strip_field_attributes(input: &mut DeriveInput) {
+    let consumed =
+        |attr: &syn::Attribute| attr.path().is_ident("vault_skip") || attr.path().is_ident("serde");
+
+    match &mut input.data {
+        Data::Struct(data) => {
+            for field in &mut data.fields {
+                field.attrs.retain(|attr| !consumed(attr));
+            }
+        },
+        Data::Enum(data) => {
+            for variant in &mut data.variants {
+                variant.attrs.retain(|attr| !consumed(attr));
+                for field in &mut variant.fields {
+                    field.attrs.retain(|attr| !consumed(attr));
+                }
+            }
+        },
+        Data::Union(_) => {},
+    }
 }
 
 fn parse_tag_literal(args: TokenStream, input: &DeriveInput) -> Result<LitStr, TokenStream> {
@@ -62,6 +111,7 @@ fn parse_tag_literal(args: TokenStream, input: &DeriveInput) -> Result<LitStr, T
             },
         };
 
+        validate_tag(&lit).map_err(|err| err.to_compile_error())?;
         tag_literal = Some(lit);
     }
 
@@ -69,6 +119,18 @@ fn parse_tag_literal(args: TokenStream, input: &DeriveInput) -> Result<LitStr, T
         .unwrap_or_else(|| LitStr::new(&name.to_string(), proc_macro2::Span::call_site())))
 }
 
+/// Rejects tags that would produce an empty or unprintable vault context.
+fn validate_tag(lit: &LitStr) -> syn::Result<()> {
+    let tag = lit.value();
+    if tag.trim().is_empty() {
+        return Err(syn::Error::new_spanned(lit, "Vault tag must not be empty"));
+    }
+    if tag.chars().any(char::is_control) {
+        return Err(syn::Error::new_spanned(lit, "Vault tag must not contain control characters"));
+    }
+    Ok(())
+}
+
 fn derived_trait_names(input: &DeriveInput) -> FxHashSet<String> {
     let mut traits = FxHashSet::default();
 
@@ -98,8 +160,11 @@ fn named_fields(input: &DeriveInput) -> Result<Vec<syn::Field>, TokenStream> {
             )
             .to_compile_error()),
         },
-        _ => Err(syn::Error::new_spanned(&input.ident, "Vault derive only supports structs")
-            .to_compile_error()),
+        _ => Err(syn::Error::new_spanned(
+            &input.ident,
+            "Vault derive only supports structs and enums",
+        )
+        .to_compile_error()),
     }
 }
 
@@ -112,8 +177,10 @@ fn build_field_tokens(
     let mut deserialize_helper_fields = Vec::new();
     let mut field_idents = Vec::new();
     let mut field_types = Vec::new();
+    let mut skipped_idents = Vec::new();
 
     for field in fields {
+        let skipped = is_skipped(&field.attrs).map_err(|err| err.to_compile_error())?;
         let attrs = field.attrs;
         let attrs_for_serialize = attrs.clone();
         let attrs_for_deserialize = attrs;
@@ -126,6 +193,11 @@ fn build_field_tokens(
         };
         let ty = &field.ty;
 
+        if skipped {
+            skipped_idents.push(ident);
+            continue;
+        }
+
         serialize_fields.push(quote! {
             #ident: &self.#ident,
         });
@@ -148,6 +220,7 @@ fn build_field_tokens(
         deserialize_helper_fields,
         field_idents,
         field_types,
+        skipped_idents,
     })
 }
 
@@ -187,7 +260,7 @@ fn serde_impl_tokens(
     helper_mod: &Ident,
     fields: &FieldTokens,
 ) -> TokenStream {
-    let FieldTokens { serialize_fields, field_idents, .. } = fields;
+    let FieldTokens { serialize_fields, field_idents, skipped_idents, .. } = fields;
 
     quote! {
         #[automatically_derived]
@@ -213,6 +286,7 @@ fn serde_impl_tokens(
                     #helper_mod::DeserializeHelper #ty_generics ::deserialize(deserializer)?;
                 Ok(Self {
                     #(#field_idents: helper.#field_idents,)*
+                    #(#skipped_idents: ::core::default::Default::default(),)*
                 })
             }
         }
@@ -288,9 +362,8 @@ fn eq_impl_tokens(
     impl_generics: &impl quote::ToTokens,
     ty_generics: &impl quote::ToTokens,
     where_clause: Option<&syn::WhereClause>,
-    fields: &FieldTokens,
+    field_types: &[Type],
 ) -> TokenStream {
-    let FieldTokens { field_types, .. } = fields;
     let bounds = field_types.iter().map(|ty| syn::parse_quote!(#ty: ::core::cmp::Eq));
     let where_clause = where_clause_with_bounds(where_clause, bounds);
 
@@ -340,9 +413,10 @@ fn tagged_impl_tokens(
 }
 
 /// Expands the `#[vault_model]` macro.
-pub fn expand_derive(args: TokenStream, input: DeriveInput) -> TokenStream {
-    let name = &input.ident;
-    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+pub fn expand_derive(args: TokenStream, mut input: DeriveInput) -> TokenStream {
+    let name = &input.ident.clone();
+    let generics = input.generics.clone();
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
     let mut helper_generics = input.generics.clone();
     helper_generics.params.insert(0, parse_quote!('__mhub_vault_serde));
     let (helper_impl_generics, _helper_ty_generics, helper_where_clause) =
@@ -354,6 +428,67 @@ pub fn expand_derive(args: TokenStream, input: DeriveInput) -> TokenStream {
         Err(err) => return err,
     };
     let derived_traits = derived_trait_names(&input);
+    let tagged_tokens =
+        tagged_impl_tokens(name, &impl_generics, &ty_generics, where_clause, &tag_literal);
+
+    if matches!(input.data, Data::Enum(_)) {
+        let variants = match enum_variants(&input) {
+            Ok(variants) => variants,
+            Err(err) => return err,
+        };
+        strip_field_attributes(&mut input);
+
+        let helper_mod_tokens = enum_helper_mod_tokens(
+            &helper_mod,
+            &helper_impl_generics,
+            helper_where_clause,
+            &impl_generics,
+            where_clause,
+            &variants,
+        );
+        let serde_tokens = enum_serde_impl_tokens(
+            name,
+            &impl_generics,
+            &ty_generics,
+            where_clause,
+            &helper_mod,
+            &variants,
+        );
+        let debug_tokens = if derived_traits.contains("Debug") {
+            quote! {}
+        } else {
+            enum_debug_impl_tokens(name, &impl_generics, &ty_generics, where_clause, &variants)
+        };
+        let partial_eq_tokens = if derived_traits.contains("PartialEq") {
+            quote! {}
+        } else {
+            enum_partial_eq_impl_tokens(name, &impl_generics, &ty_generics, where_clause, &variants)
+        };
+        let eq_tokens = if derived_traits.contains("Eq") {
+            quote! {}
+        } else {
+            let field_types: Vec<Type> =
+                variants.iter().flat_map(|variant| variant.field_types.clone()).collect();
+            eq_impl_tokens(name, &impl_generics, &ty_generics, where_clause, &field_types)
+        };
+        let hash_tokens = if derived_traits.contains("Hash") {
+            quote! {}
+        } else {
+            enum_hash_impl_tokens(name, &impl_generics, &ty_generics, where_clause, &variants)
+        };
+
+        return quote! {
+            #input
+            #helper_mod_tokens
+            #serde_tokens
+            #tagged_tokens
+            #debug_tokens
+            #partial_eq_tokens
+            #eq_tokens
+            #hash_tokens
+        };
+    }
+
     let fields = match named_fields(&input) {
         Ok(fields) => fields,
         Err(err) => return err,
@@ -362,6 +497,7 @@ pub fn expand_derive(args: TokenStream, input: DeriveInput) -> TokenStream {
         Ok(tokens) => tokens,
         Err(err) => return err,
     };
+    strip_field_attributes(&mut input);
 
     let helper_mod_tokens = helper_mod_tokens(
         &helper_mod,
@@ -379,8 +515,6 @@ pub fn expand_derive(args: TokenStream, input: DeriveInput) -> TokenStream {
         &helper_mod,
         &field_tokens,
     );
-    let tagged_tokens =
-        tagged_impl_tokens(name, &impl_generics, &ty_generics, where_clause, &tag_literal);
     let debug_tokens = if derived_traits.contains("Debug") {
         quote! {}
     } else {
@@ -394,7 +528,7 @@ pub fn expand_derive(args: TokenStream, input: DeriveInput) -> TokenStream {
     let eq_tokens = if derived_traits.contains("Eq") {
         quote! {}
     } else {
-        eq_impl_tokens(name, &impl_generics, &ty_generics, where_clause, &field_tokens)
+        eq_impl_tokens(name, &impl_generics, &ty_generics, where_clause, &field_tokens.field_types)
     };
     let hash_tokens = if derived_traits.contains("Hash") {
         quote! {}
@@ -413,3 +547,320 @@ pub fn expand_derive(args: TokenStream, input: DeriveInput) -> TokenStream {
         #hash_tokens
     }
 }
+
+struct VariantTokens {
+    ident: Ident,
+    attrs: Vec<syn::Attribute>,
+    unit: bool,
+    field_idents: Vec<Ident>,
+    field_types: Vec<Type>,
+    field_attrs: Vec<Vec<syn::Attribute>>,
+    skipped_idents: Vec<Ident>,
+}
+
+impl VariantTokens {
+    /// Pattern binding every kept field of the variant, prefixing the bindings with `prefix`.
+    fn pattern(&self, prefix: &str) -> TokenStream {
+        let ident = &self.ident;
+        if self.unit {
+            return quote! { Self::#ident };
+        }
+        let fields = &self.field_idents;
+        let bindings = fields.iter().map(|field| format_ident!("{prefix}{field}"));
+        quote! { Self::#ident { #(#fields: #bindings,)* .. } }
+    }
+}
+
+/// Rejects enum representations that postcard cannot round-trip.
+fn check_enum_representation(input: &DeriveInput) -> Result<(), TokenStream> {
+    for attr in input.attrs.iter().filter(|attr| attr.path().is_ident("serde")) {
+        let mut unsupported = None;
+        let _ = attr.parse_nested_meta(|meta| {
+            if ["tag", "content", "untagged"].iter().any(|key| meta.path.is_ident(key)) {
+                unsupported = Some(meta.path.clone());
+            }
+            if meta.input.peek(syn::Token![=]) {
+                meta.value()?.parse::<syn::Expr>()?;
+            }
+            Ok(())
+        });
+        if let Some(path) = unsupported {
+            return Err(syn::Error::new_spanned(
+                path,
+                "Vault derive only supports externally tagged enums; internally tagged, \
+                 adjacently tagged and untagged enums need a self-describing format and \
+                 cannot be decoded from postcard",
+            )
+            .to_compile_error());
+        }
+    }
+    Ok(())
+}
+
+fn enum_variants(input: &DeriveInput) -> Result<Vec<VariantTokens>, TokenStream> {
+    let Data::Enum(data) = &input.data else {
+        return Err(syn::Error::new_spanned(&input.ident, "Expected an enum").to_compile_error());
+    };
+    check_enum_representation(input)?;
+
+    let mut variants = Vec::new();
+    for variant in &data.variants {
+        let unit = match &variant.fields {
+            Fields::Named(_) => false,
+            Fields::Unit => true,
+            Fields::Unnamed(_) => {
+                return Err(syn::Error::new_spanned(
+                    variant,
+                    "Vault derive only supports enum variants with named fields or unit variants",
+                )
+                .to_compile_error());
+            },
+        };
+
+        let mut tokens = VariantTokens {
+            ident: variant.ident.clone(),
+            attrs: variant.attrs.iter().filter(|a| a.path().is_ident("serde")).cloned().collect(),
+            unit,
+            field_idents: Vec::new(),
+            field_types: Vec::new(),
+            field_attrs: Vec::new(),
+            skipped_idents: Vec::new(),
+        };
+        for field in &variant.fields {
+            let Some(ident) = field.ident.clone() else { continue };
+            if is_skipped(&field.attrs).map_err(|err| err.to_compile_error())? {
+                tokens.skipped_idents.push(ident);
+                continue;
+            }
+            tokens.field_idents.push(ident);
+            tokens.field_types.push(field.ty.clone());
+            tokens.field_attrs.push(
+                field.attrs.iter().filter(|a| !a.path().is_ident("vault_skip")).cloned().collect(),
+            );
+        }
+        variants.push(tokens);
+    }
+
+    Ok(variants)
+}
+
+fn enum_helper_mod_tokens(
+    helper_mod: &Ident,
+    helper_impl_generics: &impl quote::ToTokens,
+    helper_where_clause: Option<&syn::WhereClause>,
+    impl_generics: &impl quote::ToTokens,
+    where_clause: Option<&syn::WhereClause>,
+    variants: &[VariantTokens],
+) -> TokenStream {
+    let variant_defs = |by_ref: bool| {
+        variants.iter().map(move |variant| {
+            let VariantTokens { ident, attrs, field_idents, field_types, field_attrs, .. } =
+                variant;
+            if variant.unit {
+                return quote! { #(#attrs)* #ident };
+            }
+            let types = field_types.iter().map(|ty| {
+                if by_ref {
+                    quote! { &'__mhub_vault_serde #ty }
+                } else {
+                    quote! { #ty }
+                }
+            });
+            quote! {
+                #(#attrs)*
+                #ident { #( #(#field_attrs)* #field_idents: #types, )* }
+            }
+        })
+    };
+    let serialize_variants = variant_defs(true);
+    let deserialize_variants = variant_defs(false);
+
+    quote! {
+        #[allow(non_snake_case, non_camel_case_types, unused_imports)]
+        mod #helper_mod {
+            use super::*;
+
+            #[derive(::mhub_vault::serde::Serialize)]
+            pub enum SerializeHelper #helper_impl_generics #helper_where_clause {
+                #(#serialize_variants,)*
+                #[serde(skip)]
+                __MhubVaultLifetime(
+                    ::core::marker::PhantomData<&'__mhub_vault_serde ()>,
+                    ::core::convert::Infallible,
+                ),
+            }
+
+            #[derive(::mhub_vault::serde::Deserialize)]
+            pub enum DeserializeHelper #impl_generics #where_clause {
+                #(#deserialize_variants,)*
+            }
+        }
+    }
+}
+
+fn enum_serde_impl_tokens(
+    name: &Ident,
+    impl_generics: &impl quote::ToTokens,
+    ty_generics: &impl quote::ToTokens,
+    where_clause: Option<&syn::WhereClause>,
+    helper_mod: &Ident,
+    variants: &[VariantTokens],
+) -> TokenStream {
+    let serialize_arms = variants.iter().map(|variant| {
+        let ident = &variant.ident;
+        let pattern = variant.pattern("");
+        let fields = &variant.field_idents;
+        if variant.unit {
+            quote! { #pattern => #helper_mod::SerializeHelper::#ident }
+        } else {
+            quote! { #pattern => #helper_mod::SerializeHelper::#ident { #(#fields,)* } }
+        }
+    });
+    let deserialize_arms = variants.iter().map(|variant| {
+        let ident = &variant.ident;
+        let fields = &variant.field_idents;
+        let skipped = &variant.skipped_idents;
+        if variant.unit {
+            quote! { #helper_mod::DeserializeHelper::#ident => Self::#ident }
+        } else {
+            quote! {
+                #helper_mod::DeserializeHelper::#ident { #(#fields,)* } => Self::#ident {
+                    #(#fields,)*
+                    #(#skipped: ::core::default::Default::default(),)*
+                }
+            }
+        }
+    });
+
+    quote! {
+        #[automatically_derived]
+        impl #impl_generics ::mhub_vault::serde::Serialize for #name #ty_generics #where_clause {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: ::mhub_vault::serde::Serializer,
+            {
+                let helper = match self {
+                    #(#serialize_arms,)*
+                };
+                ::mhub_vault::serde::Serialize::serialize(&helper, serializer)
+            }
+        }
+
+        #[automatically_derived]
+        impl<'de> ::mhub_vault::serde::Deserialize<'de> for #name #ty_generics #where_clause {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: ::mhub_vault::serde::Deserializer<'de>,
+            {
+                let helper =
+                    #helper_mod::DeserializeHelper #ty_generics ::deserialize(deserializer)?;
+                Ok(match helper {
+                    #(#deserialize_arms,)*
+                })
+            }
+        }
+    }
+}
+
+fn enum_debug_impl_tokens(
+    name: &Ident,
+    impl_generics: &impl quote::ToTokens,
+    ty_generics: &impl quote::ToTokens,
+    where_clause: Option<&syn::WhereClause>,
+    variants: &[VariantTokens],
+) -> TokenStream {
+    let field_types = variants.iter().flat_map(|variant| &variant.field_types);
+    let bounds = field_types.map(|ty| syn::parse_quote!(#ty: ::core::fmt::Debug));
+    let where_clause = where_clause_with_bounds(where_clause, bounds);
+
+    let arms = variants.iter().map(|variant| {
+        let ident = &variant.ident;
+        let pattern = variant.pattern("");
+        let fields = &variant.field_idents;
+        if variant.unit {
+            quote! { #pattern => f.write_str(stringify!(#ident)) }
+        } else {
+            quote! {
+                #pattern => {
+                    let mut builder = f.debug_struct(stringify!(#ident));
+                    #(builder.field(stringify!(#fields), #fields);)*
+                    builder.finish()
+                }
+            }
+        }
+    });
+
+    quote! {
+        #[automatically_derived]
+        impl #impl_generics ::core::fmt::Debug for #name #ty_generics #where_clause {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                match self {
+                    #(#arms,)*
+                }
+            }
+        }
+    }
+}
+
+fn enum_partial_eq_impl_tokens(
+    name: &Ident,
+    impl_generics: &impl quote::ToTokens,
+    ty_generics: &impl quote::ToTokens,
+    where_clause: Option<&syn::WhereClause>,
+    variants: &[VariantTokens],
+) -> TokenStream {
+    let field_types = variants.iter().flat_map(|variant| &variant.field_types);
+    let bounds = field_types.map(|ty| syn::parse_quote!(#ty: ::core::cmp::PartialEq));
+    let where_clause = where_clause_with_bounds(where_clause, bounds);
+
+    let arms = variants.iter().map(|variant| {
+        let left = variant.pattern("__left_");
+        let right = variant.pattern("__right_");
+        let lefts = variant.field_idents.iter().map(|field| format_ident!("__left_{field}"));
+        let rights = variant.field_idents.iter().map(|field| format_ident!("__right_{field}"));
+        quote! { (#left, #right) => true #(&& #lefts == #rights)* }
+    });
+
+    quote! {
+        #[automatically_derived]
+        impl #impl_generics ::core::cmp::PartialEq for #name #ty_generics #where_clause {
+            fn eq(&self, other: &Self) -> bool {
+                #[allow(unreachable_patterns)]
+                match (self, other) {
+                    #(#arms,)*
+                    _ => false,
+                }
+            }
+        }
+    }
+}
+
+fn enum_hash_impl_tokens(
+    name: &Ident,
+    impl_generics: &impl quote::ToTokens,
+    ty_generics: &impl quote::ToTokens,
+    where_clause: Option<&syn::WhereClause>,
+    variants: &[VariantTokens],
+) -> TokenStream {
+    let field_types = variants.iter().flat_map(|variant| &variant.field_types);
+    let bounds = field_types.map(|ty| syn::parse_quote!(#ty: ::core::hash::Hash));
+    let where_clause = where_clause_with_bounds(where_clause, bounds);
+
+    let arms = variants.iter().map(|variant| {
+        let pattern = variant.pattern("");
+        let fields = &variant.field_idents;
+        quote! { #pattern => { #(::core::hash::Hash::hash(#fields, state);)* } }
+    });
+
+    quote! {
+        #[automatically_derived]
+        impl #impl_generics ::core::hash::Hash for #name #ty_generics #where_clause {
+            fn hash<H: ::core::hash::Hasher>(&self, state: &mut H) {
+                ::core::hash::Hash::hash(&::core::mem::discriminant(self), state);
+                match self {
+                    #(#arms,)*
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,6 @@
+#[test]
+fn api_handler_ui() {
+    let t = trybuild::TestCases::new();
+    t.pass("tests/ui/api_handler_instrument.rs");
+    t.compile_fail("tests/ui/api_handler_instrument_sync.rs");
+}
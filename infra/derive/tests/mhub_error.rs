@@ -1,3 +1,22 @@
+use mhub_derive::mhub_error;
+use std::borrow::Cow;
+
+#[mhub_error(with_internal)]
+pub enum ParseError {
+    #[error("Invalid input{}: {message}", format_context(.context))]
+    Invalid { message: Cow<'static, str>, context: Option<Cow<'static, str>> },
+}
+
+#[test]
+fn into_internal_maps_displayable_errors() {
+    let result: Result<u8, ParseError> = "300".parse::<u8>().into_internal();
+    let err = result.unwrap_err();
+
+    assert!(matches!(err, ParseError::Internal { context: None, .. }));
+    assert_eq!(err.to_string(), "Internal error: number too large to fit in target type");
+    assert_eq!("7".parse::<u8>().into_internal().unwrap(), 7);
+}
+
 #[test]
 fn mhub_error_ui() {
     let t = trybuild::TestCases::new();
@@ -5,4 +24,6 @@ fn mhub_error_ui() {
     t.compile_fail("tests/ui/mhub_error_no_context.rs");
     t.compile_fail("tests/ui/mhub_error_bad_context_type.rs");
     t.compile_fail("tests/ui/mhub_error_tuple_variant.rs");
+    t.pass("tests/ui/mhub_error_with_internal.rs");
+    t.compile_fail("tests/ui/mhub_error_with_internal_duplicate.rs");
 }
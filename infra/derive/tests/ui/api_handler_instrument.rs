@@ -0,0 +1,11 @@
+use mhub_derive::api_handler;
+
+#[api_handler(instrument, get, path = "/items/{id}")]
+async fn get_item(id: u32) -> Result<u32, String> {
+    let parsed: u32 = id.to_string().parse().map_err(|_| "bad id".to_owned())?;
+    Ok(parsed)
+}
+
+fn main() {
+    drop(get_item(7));
+}
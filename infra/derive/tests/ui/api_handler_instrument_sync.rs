@@ -0,0 +1,8 @@
+use mhub_derive::api_handler;
+
+#[api_handler(instrument, get, path = "/items")]
+fn list_items() -> Vec<u32> {
+    Vec::new()
+}
+
+fn main() {}
@@ -0,0 +1,21 @@
+use mhub_derive::mhub_error;
+use std::borrow::Cow;
+
+#[mhub_error(with_internal)]
+pub enum DemoError {
+    #[error("IO error{}: {source}", format_context(.context))]
+    Io {
+        #[source]
+        source: std::io::Error,
+        context: Option<Cow<'static, str>>,
+    },
+}
+
+fn main() {
+    let err = DemoError::from("boom");
+    assert!(matches!(err, DemoError::Internal { .. }));
+    assert_eq!(err.to_string(), "Internal error: boom");
+
+    let err = Err::<(), _>(DemoError::from("boom".to_owned())).context("Loading").unwrap_err();
+    assert_eq!(err.to_string(), "Internal error (Loading): boom");
+}
@@ -0,0 +1,10 @@
+use mhub_derive::mhub_error;
+use std::borrow::Cow;
+
+#[mhub_error(with_internal)]
+pub enum DemoError {
+    #[error("Internal error{}: {message}", format_context(.context))]
+    Internal { message: Cow<'static, str>, context: Option<Cow<'static, str>> },
+}
+
+fn main() {}
@@ -0,0 +1,8 @@
+use mhub_derive::vault_model;
+
+#[vault_model(tag = "v1.sess\nion")]
+pub struct Session {
+    user: String,
+}
+
+fn main() {}
@@ -0,0 +1,8 @@
+use mhub_derive::vault_model;
+
+#[vault_model(tag = "")]
+pub struct Session {
+    user: String,
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+use mhub_derive::vault_model;
+use mhub_vault::Tagged;
+
+#[vault_model(tag = "v1.session")]
+pub struct Session {
+    user: String,
+    expires_at: i64,
+}
+
+fn main() {
+    assert_eq!(Session::TAG, "v1.session");
+}
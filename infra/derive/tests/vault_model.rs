@@ -0,0 +1,7 @@
+#[test]
+fn vault_model_ui() {
+    let t = trybuild::TestCases::new();
+    t.pass("tests/ui/vault_model_pass.rs");
+    t.compile_fail("tests/ui/vault_model_empty_tag.rs");
+    t.compile_fail("tests/ui/vault_model_control_tag.rs");
+}
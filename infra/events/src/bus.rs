@@ -1,8 +1,11 @@
 use crate::error::EventBusError;
+use crate::receiver::ScopedReceiver;
 use fxhash::FxHashMap;
-use parking_lot::RwLock;
+use parking_lot::{Mutex, RwLock};
 use std::any::{Any, TypeId};
+use std::collections::VecDeque;
 use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
 use tokio::sync::{broadcast, mpsc, watch};
 use tracing::{trace, warn};
 
@@ -28,10 +31,108 @@ pub enum ChannelKind {
 pub trait Event: Any + Send + Sync + 'static {}
 impl<T: Any + Send + Sync + 'static> Event for T {}
 
+/// A registered channel as reported by [`EventBus::channel_info`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChannelInfo {
+    /// Channel kind and capacity.
+    pub kind: ChannelKind,
+    /// Rust type name of the event, as reported by [`std::any::type_name`].
+    pub type_name: &'static str,
+    /// Live receivers; for MPSC channels, `1` while a receiver is taken and not dropped.
+    pub receivers: usize,
+}
+
+/// Delivery counters of a single channel, see [`EventBus::metrics`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct EventMetrics {
+    /// Publish calls made for the channel.
+    pub published: u64,
+    /// Receivers reached: one per broadcast subscriber or watch receiver, one per queued
+    /// MPSC message.
+    pub delivered: u64,
+    /// Events lost because no broadcast subscriber existed, the MPSC queue was full
+    /// or closed, or a [`ScopedReceiver`] lagged behind.
+    pub dropped: u64,
+    /// Live receivers obtained through [`EventBus::subscribe_scoped`].
+    pub subscribers: u64,
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct ChannelCounters {
+    published: AtomicU64,
+    delivered: AtomicU64,
+    pub(crate) dropped: AtomicU64,
+    pub(crate) subscribers: AtomicU64,
+}
+
+impl ChannelCounters {
+    /// Counts one publish that reached `delivered` receivers and lost `dropped` events.
+    fn record(&self, delivered: usize, dropped: u64) {
+        self.published.fetch_add(1, Ordering::Relaxed);
+        self.delivered.fetch_add(delivered as u64, Ordering::Relaxed);
+        self.dropped.fetch_add(dropped, Ordering::Relaxed);
+    }
+
+    fn record_mpsc(&self, queued: bool) {
+        if queued { self.record(1, 0) } else { self.record(0, 1) }
+    }
+}
+
 #[derive(Debug)]
 struct ChannelState {
     kind: ChannelKind,
+    type_name: &'static str,
+    receiver_count: fn(&(dyn Any + Send + Sync)) -> usize,
+    counters: Arc<ChannelCounters>,
     sender: Box<dyn Any + Send + Sync>,
+    /// A [`SharedReplay<T>`], for broadcast channels used with [`EventBus::subscribe_replay`].
+    replay: Option<Box<dyn Any + Send + Sync>>,
+}
+
+impl ChannelState {
+    fn new<T: Event>(kind: ChannelKind, sender: Box<dyn Any + Send + Sync>) -> Self {
+        Self {
+            kind,
+            type_name: std::any::type_name::<T>(),
+            receiver_count: receiver_count::<T>,
+            counters: Arc::default(),
+            sender,
+            replay: None,
+        }
+    }
+
+    fn replay<T: Event>(&self) -> Option<SharedReplay<T>> {
+        self.replay.as_ref()?.downcast_ref::<SharedReplay<T>>().cloned()
+    }
+
+    fn metrics(&self) -> EventMetrics {
+        EventMetrics {
+            published: self.counters.published.load(Ordering::Relaxed),
+            delivered: self.counters.delivered.load(Ordering::Relaxed),
+            dropped: self.counters.dropped.load(Ordering::Relaxed),
+            subscribers: self.counters.subscribers.load(Ordering::Relaxed),
+        }
+    }
+
+    fn info(&self) -> ChannelInfo {
+        ChannelInfo {
+            kind: self.kind,
+            type_name: self.type_name,
+            receivers: (self.receiver_count)(self.sender.as_ref()),
+        }
+    }
+}
+
+fn receiver_count<T: Event>(sender: &(dyn Any + Send + Sync)) -> usize {
+    if let Some(tx) = sender.downcast_ref::<broadcast::Sender<Arc<T>>>() {
+        return tx.receiver_count();
+    }
+    if let Some(tx) = sender.downcast_ref::<watch::Sender<Arc<T>>>() {
+        return tx.receiver_count();
+    }
+    sender
+        .downcast_ref::<MpscChannel<T>>()
+        .map_or(0, |chan| usize::from(chan.taken && !chan.sender.is_closed()))
 }
 
 #[derive(Debug)]
@@ -41,10 +142,50 @@ struct MpscChannel<T> {
     taken: bool,
 }
 
+impl<T> MpscChannel<T> {
+    /// Returns `true` if `rx` is the receiving half of this channel.
+    ///
+    /// Tokio receivers cannot be compared with a sender directly, so a weak sender is created
+    /// briefly: only a receiver of the same channel sees its weak sender count change. The bus
+    /// never holds weak senders otherwise, and callers hold its write lock.
+    fn owns(&self, rx: &mpsc::Receiver<Arc<T>>) -> bool {
+        let before = rx.sender_weak_count();
+        let probe = self.sender.downgrade();
+        let owned = rx.sender_weak_count() == before + 1;
+        drop(probe);
+        owned
+    }
+}
+
+/// Ring buffer of the latest broadcast events, see [`EventBus::subscribe_replay`].
+#[derive(Debug)]
+struct ReplayBuffer<T> {
+    events: VecDeque<Arc<T>>,
+    capacity: usize,
+}
+
+type SharedReplay<T> = Arc<Mutex<ReplayBuffer<T>>>;
+
+impl<T> ReplayBuffer<T> {
+    fn push(&mut self, event: Arc<T>) {
+        if self.events.len() == self.capacity {
+            self.events.pop_front();
+        }
+        self.events.push_back(event);
+    }
+
+    /// Returns up to the last `n` events, oldest first.
+    fn latest(&self, n: usize) -> Vec<Arc<T>> {
+        self.events.iter().skip(self.events.len().saturating_sub(n)).cloned().collect()
+    }
+}
+
+/// A sender cloned out of the channel map, carrying the channel's counters so publishing does
+/// not look the channel up again to record delivery.
 #[derive(Debug)]
 enum ChannelHandle<T> {
-    Broadcast(broadcast::Sender<Arc<T>>),
-    Watch(watch::Sender<Arc<T>>),
+    Broadcast(broadcast::Sender<Arc<T>>, Option<SharedReplay<T>>, Arc<ChannelCounters>),
+    Watch(watch::Sender<Arc<T>>, Arc<ChannelCounters>),
 }
 
 impl<T: Event> ChannelHandle<T> {
@@ -58,7 +199,7 @@ impl<T: Event> ChannelHandle<T> {
                             context: Some("Unexpected event type".into()),
                         }
                     })?;
-                Ok(Self::Broadcast(sender.clone()))
+                Ok(Self::Broadcast(sender.clone(), state.replay::<T>(), state.counters.clone()))
             },
             ChannelKind::Watch => {
                 let sender =
@@ -68,7 +209,7 @@ impl<T: Event> ChannelHandle<T> {
                             context: Some("Unexpected event type".into()),
                         }
                     })?;
-                Ok(Self::Watch(sender.clone()))
+                Ok(Self::Watch(sender.clone(), state.counters.clone()))
             },
             ChannelKind::Mpsc { .. } => unreachable!("MPSC channels are handled separately"),
         }
@@ -143,14 +284,123 @@ impl EventBus {
         let capacity = validate_capacity(capacity)?;
         let sender = self.ensure_channel::<T>(ChannelKind::Broadcast { capacity }, None)?;
         match sender {
-            ChannelHandle::Broadcast(tx) => Ok(tx.subscribe()),
-            ChannelHandle::Watch(_) => Err(EventBusError::TypeMismatch {
+            ChannelHandle::Broadcast(tx, ..) => Ok(tx.subscribe()),
+            ChannelHandle::Watch(..) => Err(EventBusError::TypeMismatch {
                 message: std::any::type_name::<T>().into(),
                 context: Some("Unexpected event type".into()),
             }),
         }
     }
 
+    /// Subscribes to `T` like [`EventBus::subscribe`], returning a receiver that is counted
+    /// in [`EventMetrics::subscribers`] until dropped.
+    ///
+    /// Events the receiver skips because it lagged behind are added to
+    /// [`EventMetrics::dropped`].
+    ///
+    /// # Errors
+    /// Returns [`EventBusError::ChannelKindMismatch`] if a different channel kind
+    /// was already registered for `T`, or [`EventBusError::ChannelNotFound`] if the channel
+    /// was closed concurrently.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use mhub_event_bus::EventBus;
+    ///
+    /// #[derive(Clone, Debug, PartialEq)]
+    /// struct UserCreated(u64);
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), mhub_event_bus::EventBusError> {
+    /// let bus = EventBus::new();
+    /// let mut rx = bus.subscribe_scoped::<UserCreated>()?;
+    /// bus.publish(UserCreated(1))?;
+    /// assert_eq!(rx.recv().await.unwrap().0, 1);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn subscribe_scoped<T: Event>(&self) -> Result<ScopedReceiver<T>, EventBusError> {
+        let receiver = self.subscribe::<T>()?;
+        let counters = self
+            .channels
+            .read()
+            .get(&TypeId::of::<T>())
+            .map(|state| state.counters.clone())
+            .ok_or_else(|| EventBusError::ChannelNotFound {
+                message: "Channel closed while subscribing".into(),
+                context: Some(std::any::type_name::<T>().into()),
+            })?;
+        Ok(ScopedReceiver::new(receiver, counters))
+    }
+
+    /// Subscribes to `T` like [`EventBus::subscribe`], also returning up to the last `n`
+    /// events published before the subscription, oldest first.
+    ///
+    /// The first call attaches a replay buffer to `T`'s broadcast channel; events published
+    /// before that are not retained. Later calls grow the buffer if they ask for more events,
+    /// and it never shrinks. Every event published after a replayed one is delivered to the
+    /// receiver, without gaps or duplicates. [`EventBus::close`] and
+    /// [`EventBus::reconfigure`] discard the buffer.
+    ///
+    /// # Memory
+    /// The buffer keeps the latest `n` events alive even when no subscriber holds them, so a
+    /// channel costs up to `n` times the size of `T` (plus one pointer per slot) for as long
+    /// as it exists. Keep `n` small for large events.
+    ///
+    /// # Errors
+    /// Returns [`EventBusError::ChannelKindMismatch`] if a different channel kind
+    /// was already registered for `T`, [`EventBusError::InvalidCapacity`] if `n` is zero, or
+    /// [`EventBusError::ChannelNotFound`] if the channel was closed concurrently.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use mhub_event_bus::EventBus;
+    ///
+    /// #[derive(Clone, Debug, PartialEq)]
+    /// struct Tick(u64);
+    ///
+    /// # fn main() -> Result<(), mhub_event_bus::EventBusError> {
+    /// let bus = EventBus::new();
+    /// let _ = bus.subscribe_replay::<Tick>(2)?;
+    /// for i in 0..3 {
+    ///     bus.publish(Tick(i))?;
+    /// }
+    ///
+    /// let (_rx, missed) = bus.subscribe_replay::<Tick>(2)?;
+    /// assert_eq!(missed.iter().map(|tick| tick.0).collect::<Vec<_>>(), [1, 2]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn subscribe_replay<T: Event>(
+        &self,
+        n: usize,
+    ) -> Result<(broadcast::Receiver<Arc<T>>, Vec<Arc<T>>), EventBusError> {
+        let n = validate_capacity(n)?;
+        let handle =
+            self.ensure_channel::<T>(ChannelKind::Broadcast { capacity: DEFAULT_CAPACITY }, None)?;
+        let (sender, replay) = match handle {
+            ChannelHandle::Broadcast(tx, Some(replay), _) => (tx, replay),
+            ChannelHandle::Broadcast(tx, None, _) => (tx, self.attach_replay::<T>(n)?),
+            ChannelHandle::Watch(..) => {
+                return Err(EventBusError::TypeMismatch {
+                    message: std::any::type_name::<T>().into(),
+                    context: Some("Unexpected event type".into()),
+                });
+            },
+        };
+
+        let mut replay = replay.lock();
+        if replay.capacity < n {
+            trace!(event = std::any::type_name::<T>(), capacity = n, "Replay buffer resized");
+            replay.capacity = n;
+        }
+        let missed = replay.latest(n);
+        let receiver = sender.subscribe();
+        drop(replay);
+
+        Ok((receiver, missed))
+    }
+
     /// Subscribe to a bounded MPSC channel (queue semantics).
     ///
     /// # Errors
@@ -179,6 +429,85 @@ impl EventBus {
         self.take_mpsc_receiver::<T>(capacity)
     }
 
+    /// Hands a previously taken MPSC receiver back to the bus.
+    ///
+    /// The channel becomes available to the next [`EventBus::subscribe_mpsc`] call, so a
+    /// supervisor can pass the queue to a replacement worker. Messages buffered in the
+    /// receiver are preserved.
+    ///
+    /// # Errors
+    /// Returns [`EventBusError::ChannelNotFound`] if no MPSC channel exists for `T`,
+    /// [`EventBusError::ChannelKindMismatch`] if the channel is not MPSC, its receiver was
+    /// not taken, or `rx` belongs to another channel (e.g. of a different bus), or
+    /// [`EventBusError::TypeMismatch`] if the stored channel type differs.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use mhub_event_bus::EventBus;
+    ///
+    /// #[derive(Clone, Debug, PartialEq)]
+    /// struct Job(u64);
+    ///
+    /// # fn main() -> Result<(), mhub_event_bus::EventBusError> {
+    /// let bus = EventBus::new();
+    /// let rx = bus.subscribe_mpsc::<Job>(8)?;
+    /// bus.return_mpsc_receiver(rx)?;
+    /// let _rx = bus.subscribe_mpsc::<Job>(8)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn return_mpsc_receiver<T: Event>(
+        &self,
+        rx: mpsc::Receiver<Arc<T>>,
+    ) -> Result<(), EventBusError> {
+        let id = TypeId::of::<T>();
+        let mut channels = self.channels.write();
+        let existing = channels.get_mut(&id).ok_or_else(|| EventBusError::ChannelNotFound {
+            message: "No MPSC channel to return the receiver to".into(),
+            context: Some(std::any::type_name::<T>().into()),
+        })?;
+
+        if !matches!(existing.kind, ChannelKind::Mpsc { .. }) {
+            return Err(EventBusError::ChannelKindMismatch {
+                message: format!(
+                    "Expected Mpsc but found {:?} for {}",
+                    existing.kind,
+                    std::any::type_name::<T>()
+                )
+                .into(),
+                context: None,
+            });
+        }
+
+        let chan = existing.sender.downcast_mut::<MpscChannel<T>>().ok_or_else(|| {
+            EventBusError::TypeMismatch {
+                message: std::any::type_name::<T>().into(),
+                context: Some("Unexpected event type".into()),
+            }
+        })?;
+
+        if !chan.taken || chan.receiver.is_some() {
+            return Err(EventBusError::ChannelKindMismatch {
+                message: "MPSC receiver was not taken".into(),
+                context: Some(std::any::type_name::<T>().into()),
+            });
+        }
+
+        if !chan.owns(&rx) {
+            return Err(EventBusError::ChannelKindMismatch {
+                message: "Receiver belongs to a different MPSC channel".into(),
+                context: Some(std::any::type_name::<T>().into()),
+            });
+        }
+
+        chan.receiver = Some(rx);
+        chan.taken = false;
+        drop(channels);
+
+        trace!(event = std::any::type_name::<T>(), "MPSC receiver returned to the bus");
+        Ok(())
+    }
+
     /// Subscribe to a watch channel (latest-value semantics). Initializes with the provided value if absent.
     ///
     /// # Errors
@@ -204,8 +533,8 @@ impl EventBus {
     ) -> Result<watch::Receiver<Arc<T>>, EventBusError> {
         let sender = self.ensure_channel::<T>(ChannelKind::Watch, Some(Arc::new(initial)))?;
         match sender {
-            ChannelHandle::Watch(tx) => Ok(tx.subscribe()),
-            ChannelHandle::Broadcast(_) => Err(EventBusError::TypeMismatch {
+            ChannelHandle::Watch(tx, _) => Ok(tx.subscribe()),
+            ChannelHandle::Broadcast(..) => Err(EventBusError::TypeMismatch {
                 message: std::any::type_name::<T>().into(),
                 context: Some("Unexpected event type".into()),
             }),
@@ -258,9 +587,9 @@ impl EventBus {
     pub fn publish_arc<T: Event>(&self, event: Arc<T>) -> Result<usize, EventBusError> {
         let sender =
             self.ensure_channel::<T>(ChannelKind::Broadcast { capacity: DEFAULT_CAPACITY }, None)?;
-        let sender = match sender {
-            ChannelHandle::Broadcast(tx) => tx,
-            ChannelHandle::Watch(_) => {
+        let (sender, replay, counters) = match sender {
+            ChannelHandle::Broadcast(tx, replay, counters) => (tx, replay, counters),
+            ChannelHandle::Watch(..) => {
                 return Err(EventBusError::TypeMismatch {
                     message: std::any::type_name::<T>().into(),
                     context: Some("Unexpected event type".into()),
@@ -268,12 +597,25 @@ impl EventBus {
             },
         };
 
-        sender.send(event).map_or_else(
+        let sent = match replay {
+            Some(replay) => {
+                // Sending under the buffer lock keeps the buffer and live delivery in the same
+                // order, so `subscribe_replay` never misses or duplicates an event.
+                let mut replay = replay.lock();
+                replay.push(event.clone());
+                sender.send(event)
+            },
+            None => sender.send(event),
+        };
+
+        sent.map_or_else(
             |_| {
+                counters.record(0, 1);
                 trace!(event = std::any::type_name::<T>(), "Event dropped: no active subscribers");
                 Ok(0)
             },
             |count| {
+                counters.record(count, 0);
                 trace!(event = std::any::type_name::<T>(), count, "Event dispatched");
                 Ok(count)
             },
@@ -328,11 +670,62 @@ impl EventBus {
     /// # }
     /// ```
     pub fn publish_mpsc_arc<T: Event>(&self, event: Arc<T>) -> Result<(), EventBusError> {
-        let sender = self.get_or_create_mpsc::<T>(DEFAULT_CAPACITY)?;
-        sender.try_send(event).map_err(|e| EventBusError::ChannelFull {
+        let (sender, counters) = self.get_or_create_mpsc::<T>(DEFAULT_CAPACITY)?;
+        let result = sender.try_send(event).map_err(|e| EventBusError::ChannelFull {
             message: e.to_string().into(),
             context: Some(std::any::type_name::<T>().into()),
-        })
+        });
+        counters.record_mpsc(result.is_ok());
+        result
+    }
+
+    /// Publishes to a bounded MPSC channel, waiting for free capacity instead of failing.
+    ///
+    /// Unlike [`EventBus::publish_mpsc`], a saturated queue applies backpressure: the call
+    /// completes once the consumer has made room. The bus lock is released before waiting.
+    ///
+    /// # Errors
+    /// Returns [`EventBusError::ChannelKindMismatch`] if a different channel kind
+    /// was already registered for `T`, or [`EventBusError::ChannelNotFound`] if the
+    /// receiver was dropped.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use mhub_event_bus::EventBus;
+    ///
+    /// #[derive(Clone, Debug, PartialEq)]
+    /// struct Job(u64);
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), mhub_event_bus::EventBusError> {
+    /// let bus = EventBus::new();
+    /// let rx = bus.subscribe_mpsc::<Job>(8)?;
+    /// bus.publish_mpsc_async(Job(1)).await?;
+    /// drop(rx);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn publish_mpsc_async<T: Event>(&self, event: T) -> Result<(), EventBusError> {
+        self.publish_mpsc_arc_async(Arc::new(event)).await
+    }
+
+    /// Publishes to a bounded MPSC channel without re-wrapping, waiting for free capacity.
+    ///
+    /// # Errors
+    /// Returns [`EventBusError::ChannelKindMismatch`] if a different channel kind
+    /// was already registered for `T`, or [`EventBusError::ChannelNotFound`] if the
+    /// receiver was dropped.
+    pub async fn publish_mpsc_arc_async<T: Event>(
+        &self,
+        event: Arc<T>,
+    ) -> Result<(), EventBusError> {
+        let (sender, counters) = self.get_or_create_mpsc::<T>(DEFAULT_CAPACITY)?;
+        let result = sender.send(event).await.map_err(|_| EventBusError::ChannelNotFound {
+            message: "MPSC receiver was dropped".into(),
+            context: Some(std::any::type_name::<T>().into()),
+        });
+        counters.record_mpsc(result.is_ok());
+        result
     }
 
     /// Publishes to a watch channel (latest-value semantics). Creates a channel if missing.
@@ -381,9 +774,9 @@ impl EventBus {
     pub fn publish_watch_arc<T: Event>(&self, event: Arc<T>) -> Result<(), EventBusError> {
         let arc = event;
         let sender = self.ensure_channel::<T>(ChannelKind::Watch, Some(arc.clone()))?;
-        let sender = match sender {
-            ChannelHandle::Watch(tx) => tx,
-            ChannelHandle::Broadcast(_) => {
+        let (sender, counters) = match sender {
+            ChannelHandle::Watch(tx, counters) => (tx, counters),
+            ChannelHandle::Broadcast(..) => {
                 return Err(EventBusError::TypeMismatch {
                     message: std::any::type_name::<T>().into(),
                     context: Some("Unexpected event type".into()),
@@ -391,6 +784,144 @@ impl EventBus {
             },
         };
         sender.send_replace(arc);
+        counters.record(sender.receiver_count(), 0);
+        Ok(())
+    }
+
+    /// Returns the delivery counters of `T`'s channel, or `None` if no channel exists.
+    ///
+    /// Counters start at zero when the channel is created and are discarded with it.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use mhub_event_bus::EventBus;
+    ///
+    /// #[derive(Clone, Debug, PartialEq)]
+    /// struct Ping;
+    ///
+    /// # fn main() -> Result<(), mhub_event_bus::EventBusError> {
+    /// let bus = EventBus::new();
+    /// bus.publish(Ping)?;
+    /// assert_eq!(bus.metrics::<Ping>().unwrap().dropped, 1);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn metrics<T: Event>(&self) -> Option<EventMetrics> {
+        self.channels.read().get(&TypeId::of::<T>()).map(ChannelState::metrics)
+    }
+
+    /// Lists the registered channels with their kind, event type, and receiver count,
+    /// sorted by type name.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use mhub_event_bus::EventBus;
+    ///
+    /// #[derive(Clone, Debug, PartialEq)]
+    /// struct Ping;
+    ///
+    /// # fn main() -> Result<(), mhub_event_bus::EventBusError> {
+    /// let bus = EventBus::new();
+    /// let _rx = bus.subscribe::<Ping>()?;
+    /// assert_eq!(bus.channel_info()[0].receivers, 1);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn channel_info(&self) -> Vec<ChannelInfo> {
+        let mut info: Vec<ChannelInfo> =
+            self.channels.read().values().map(ChannelState::info).collect();
+        info.sort_unstable_by_key(|channel| channel.type_name);
+        info
+    }
+
+    /// Removes the channel of `T`, so its receivers observe closure.
+    ///
+    /// Returns `true` if a channel existed. The next subscribe or publish call for `T`
+    /// creates a fresh channel.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use mhub_event_bus::EventBus;
+    ///
+    /// #[derive(Clone, Debug, PartialEq)]
+    /// struct Ping;
+    ///
+    /// # fn main() -> Result<(), mhub_event_bus::EventBusError> {
+    /// let bus = EventBus::new();
+    /// let _rx = bus.subscribe::<Ping>()?;
+    /// assert!(bus.close::<Ping>());
+    /// assert!(!bus.close::<Ping>());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn close<T: Event>(&self) -> bool {
+        let closed = self.channels.write().remove(&TypeId::of::<T>()).is_some();
+        if closed {
+            trace!(event = std::any::type_name::<T>(), "Event channel closed");
+        }
+        closed
+    }
+
+    /// Replaces the channel of `T` with a new broadcast or MPSC channel of the given kind,
+    /// e.g. to change its capacity.
+    ///
+    /// Existing receivers observe closure and buffered events are discarded.
+    ///
+    /// # Errors
+    /// Returns [`EventBusError::InvalidCapacity`] if `capacity` is zero,
+    /// [`EventBusError::ChannelKindMismatch`] if `kind` is [`ChannelKind::Watch`] (which has
+    /// no capacity; use [`EventBus::close`]) or if an MPSC receiver for `T` is still live.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use mhub_event_bus::{ChannelKind, EventBus};
+    ///
+    /// #[derive(Clone, Debug, PartialEq)]
+    /// struct Tick(u64);
+    ///
+    /// # fn main() -> Result<(), mhub_event_bus::EventBusError> {
+    /// let bus = EventBus::new();
+    /// let _rx = bus.subscribe_with_capacity::<Tick>(16)?;
+    /// bus.reconfigure::<Tick>(ChannelKind::Broadcast { capacity: 256 })?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn reconfigure<T: Event>(&self, kind: ChannelKind) -> Result<(), EventBusError> {
+        let sender: Box<dyn Any + Send + Sync> = match kind {
+            ChannelKind::Broadcast { capacity } => {
+                let (tx, _) = broadcast::channel::<Arc<T>>(validate_capacity(capacity)?);
+                Box::new(tx)
+            },
+            ChannelKind::Mpsc { capacity } => {
+                let (tx, rx) = mpsc::channel::<Arc<T>>(validate_capacity(capacity)?);
+                Box::new(MpscChannel { sender: tx, receiver: Some(rx), taken: false })
+            },
+            ChannelKind::Watch => {
+                return Err(EventBusError::ChannelKindMismatch {
+                    message: "Watch channels have no capacity to reconfigure".into(),
+                    context: Some(std::any::type_name::<T>().into()),
+                });
+            },
+        };
+
+        let id = TypeId::of::<T>();
+        let mut channels = self.channels.write();
+        if let Some(existing) = channels.get(&id)
+            && let Some(chan) = existing.sender.downcast_ref::<MpscChannel<T>>()
+            && chan.taken
+            && !chan.sender.is_closed()
+        {
+            return Err(EventBusError::ChannelKindMismatch {
+                message: "MPSC receiver is still live".into(),
+                context: Some(std::any::type_name::<T>().into()),
+            });
+        }
+        channels.insert(id, ChannelState::new::<T>(kind, sender));
+        drop(channels);
+
+        trace!(event = std::any::type_name::<T>(), ?kind, "Event channel reconfigured");
         Ok(())
     }
 
@@ -503,7 +1034,7 @@ impl EventBus {
                             Box::new(tx)
                         },
                     };
-                    ChannelState { kind, sender }
+                    ChannelState::new::<T>(kind, sender)
                 });
 
                 ChannelHandle::from_state(kind, entry)?
@@ -515,10 +1046,34 @@ impl EventBus {
         Ok(handle)
     }
 
+    /// Attaches an empty replay buffer holding up to `capacity` events to `T`'s broadcast
+    /// channel, or returns the one a concurrent caller attached first.
+    fn attach_replay<T: Event>(&self, capacity: usize) -> Result<SharedReplay<T>, EventBusError> {
+        let mut channels = self.channels.write();
+        let state =
+            channels.get_mut(&TypeId::of::<T>()).ok_or_else(|| EventBusError::ChannelNotFound {
+                message: "Channel closed while subscribing".into(),
+                context: Some(std::any::type_name::<T>().into()),
+            })?;
+
+        if let Some(replay) = state.replay::<T>() {
+            return Ok(replay);
+        }
+        let replay: SharedReplay<T> = Arc::new(Mutex::new(ReplayBuffer {
+            events: VecDeque::with_capacity(capacity),
+            capacity,
+        }));
+        state.replay = Some(Box::new(replay.clone()));
+        drop(channels);
+
+        trace!(event = std::any::type_name::<T>(), "Replay buffer attached");
+        Ok(replay)
+    }
+
     fn get_or_create_mpsc<T: Event>(
         &self,
         capacity: usize,
-    ) -> Result<mpsc::Sender<Arc<T>>, EventBusError> {
+    ) -> Result<(mpsc::Sender<Arc<T>>, Arc<ChannelCounters>), EventBusError> {
         let capacity = validate_capacity(capacity)?;
         let id = TypeId::of::<T>();
         let existing = {
@@ -539,7 +1094,7 @@ impl EventBus {
                                         "MPSC channel already initialized with a different capacity"
                                     );
                                 }
-                                Some(Ok(chan.sender.clone()))
+                                Some(Ok((chan.sender.clone(), existing.counters.clone())))
                             },
                             None => Some(Err(EventBusError::TypeMismatch {
                                 message: std::any::type_name::<T>().into(),
@@ -571,13 +1126,12 @@ impl EventBus {
             (tx, rx, ChannelKind::Mpsc { capacity })
         };
 
-        {
-            let mut channels = self.channels.write();
-            let channel = MpscChannel { sender: tx.clone(), receiver: Some(rx), taken: false };
-            channels.insert(id, ChannelState { kind, sender: Box::new(channel) });
-        }
+        let channel = MpscChannel { sender: tx.clone(), receiver: Some(rx), taken: false };
+        let state = ChannelState::new::<T>(kind, Box::new(channel));
+        let counters = state.counters.clone();
+        self.channels.write().insert(id, state);
 
-        Ok(tx)
+        Ok((tx, counters))
     }
 
     fn take_mpsc_receiver<T: Event>(
@@ -652,7 +1206,7 @@ impl EventBus {
         {
             let mut channels = self.channels.write();
             let channel = MpscChannel { sender: tx, receiver: None, taken: true };
-            channels.insert(id, ChannelState { kind, sender: Box::new(channel) });
+            channels.insert(id, ChannelState::new::<T>(kind, Box::new(channel)));
         }
 
         Ok(rx)
@@ -20,6 +20,10 @@ pub enum EventBusError {
     #[error("Channel full{}: {message}", format_context(.context))]
     ChannelFull { message: Cow<'static, str>, context: Option<Cow<'static, str>> },
 
+    /// No responder answered a request in time.
+    #[error("Request timed out{}: {message}", format_context(.context))]
+    Timeout { message: Cow<'static, str>, context: Option<Cow<'static, str>> },
+
     /// Capacity must be greater than zero for bounded channels.
     #[error("Invalid capacity{}: {message}", format_context(.context))]
     InvalidCapacity { message: Cow<'static, str>, context: Option<Cow<'static, str>> },
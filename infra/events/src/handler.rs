@@ -0,0 +1,101 @@
+use crate::bus::{Event, EventBus};
+use crate::error::EventBusError;
+use crate::receiver::EventReceiverExt;
+use std::fmt::Display;
+use std::future::Future;
+use std::sync::Arc;
+use tokio::task::JoinHandle;
+use tracing::{trace, warn};
+
+/// Output of an [`EventBus::on`] handler: `()`, or a `Result` whose error is logged.
+pub trait HandlerOutcome: Send + 'static {
+    /// Returns the error message to log, if the handler failed.
+    fn into_error(self) -> Option<String>;
+}
+
+impl HandlerOutcome for () {
+    fn into_error(self) -> Option<String> {
+        None
+    }
+}
+
+impl<E: Display + Send + 'static> HandlerOutcome for Result<(), E> {
+    fn into_error(self) -> Option<String> {
+        self.err().map(|e| e.to_string())
+    }
+}
+
+/// Keeps an [`EventBus::on`] handler running; dropping it aborts the handler task.
+#[must_use = "the handler stops when the guard is dropped"]
+#[derive(Debug)]
+pub struct HandlerGuard {
+    task: JoinHandle<()>,
+}
+
+impl HandlerGuard {
+    /// Returns `true` once the handler task has stopped, e.g. because the channel was closed.
+    #[must_use]
+    pub fn is_finished(&self) -> bool {
+        self.task.is_finished()
+    }
+}
+
+impl Drop for HandlerGuard {
+    fn drop(&mut self) {
+        // Aborting drops the receiver, which unsubscribes the handler from the channel.
+        self.task.abort();
+    }
+}
+
+impl EventBus {
+    /// Subscribes to the broadcast channel of `T` and runs `handler` for every event on a task
+    /// spawned on the global runtime.
+    ///
+    /// Events are handled one at a time in publish order; while the handler is busy, new
+    /// events wait in the channel buffer, and events it overflows are skipped with a warning.
+    /// A handler returning `Err` is logged and keeps receiving. The task ends when the
+    /// returned [`HandlerGuard`] is dropped or the channel is closed.
+    ///
+    /// # Errors
+    /// Returns [`EventBusError::ChannelKindMismatch`] if a different channel kind
+    /// was already registered for `T`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use mhub_event_bus::EventBus;
+    ///
+    /// #[derive(Clone, Debug, PartialEq)]
+    /// struct UserCreated(u64);
+    ///
+    /// # fn main() -> Result<(), mhub_event_bus::EventBusError> {
+    /// let bus = EventBus::new();
+    /// let _guard = bus.on(|event: std::sync::Arc<UserCreated>| async move {
+    ///     if event.0 == 0 {
+    ///         return Err("anonymous user");
+    ///     }
+    ///     Ok(())
+    /// })?;
+    /// bus.publish(UserCreated(1))?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn on<T, F, Fut>(&self, mut handler: F) -> Result<HandlerGuard, EventBusError>
+    where
+        T: Event,
+        F: FnMut(Arc<T>) -> Fut + Send + 'static,
+        Fut: Future + Send + 'static,
+        Fut::Output: HandlerOutcome,
+    {
+        let mut rx = self.subscribe::<T>()?;
+        let task = mhub_runtime::get_global_runtime().spawn(async move {
+            while let Some(event) = EventReceiverExt::recv(&mut rx).await {
+                if let Some(error) = handler(event).await.into_error() {
+                    warn!(event = std::any::type_name::<T>(), %error, "Event handler failed");
+                }
+            }
+            trace!(event = std::any::type_name::<T>(), "Event handler stopped: channel closed");
+        });
+
+        Ok(HandlerGuard { task })
+    }
+}
@@ -15,6 +15,9 @@
 //! * **High Performance**: `FxHashMap` + `parking_lot::RwLock`.
 //! * **Async Ready**: Built on top of `tokio`.
 //! * **Vertical Slice Friendly**: Share a single bus across slices.
+//! * **Request/Reply**: `EventBus::request` awaits a typed response from a subscriber.
+//! * **Replay**: `EventBus::subscribe_replay` hands late subscribers the last N events.
+//! * **Handlers**: `EventBus::on` runs an async handler per event until its guard is dropped.
 //!
 //! # Example
 //!
@@ -41,8 +44,12 @@
 
 mod bus;
 mod error;
+mod handler;
 mod receiver;
+mod request;
 
-pub use bus::{ChannelKind, Event, EventBus};
+pub use bus::{ChannelInfo, ChannelKind, Event, EventBus, EventMetrics};
 pub use error::{EventBusError, EventBusErrorExt};
-pub use receiver::EventReceiverExt;
+pub use handler::{HandlerGuard, HandlerOutcome};
+pub use receiver::{EventReceiverExt, ScopedReceiver};
+pub use request::RequestEnvelope;
@@ -1,8 +1,10 @@
-use crate::bus::Event;
+use crate::bus::{ChannelCounters, Event};
 use std::future::Future;
+use std::ops::{Deref, DerefMut};
 use std::sync::Arc;
+use std::sync::atomic::Ordering;
 use tokio::sync::{broadcast, mpsc, watch};
-use tracing::{debug, warn};
+use tracing::{debug, trace, warn};
 
 /// An extension trait for event receivers to provide a more ergonomic API.
 ///
@@ -65,3 +67,68 @@ impl<T: Event> EventReceiverExt<T> for watch::Receiver<Arc<T>> {
         }
     }
 }
+
+/// A broadcast receiver returned by [`EventBus::subscribe_scoped`](crate::EventBus::subscribe_scoped).
+///
+/// It derefs to the underlying [`broadcast::Receiver`], counts itself in the channel's
+/// [`EventMetrics::subscribers`](crate::EventMetrics::subscribers) while alive, and traces
+/// the event type when dropped.
+#[derive(Debug)]
+pub struct ScopedReceiver<T> {
+    inner: broadcast::Receiver<Arc<T>>,
+    counters: Arc<ChannelCounters>,
+}
+
+impl<T: Event> ScopedReceiver<T> {
+    pub(crate) fn new(inner: broadcast::Receiver<Arc<T>>, counters: Arc<ChannelCounters>) -> Self {
+        counters.subscribers.fetch_add(1, Ordering::Relaxed);
+        trace!(event = std::any::type_name::<T>(), "Scoped subscriber attached");
+        Self { inner, counters }
+    }
+
+    /// Receives the next event, skipping forward on lag, and returns `None` once the channel
+    /// is closed. Skipped events are counted as dropped.
+    pub async fn recv(&mut self) -> Option<Arc<T>> {
+        loop {
+            match self.inner.recv().await {
+                Ok(event) => return Some(event),
+                Err(broadcast::error::RecvError::Lagged(n)) => {
+                    self.counters.dropped.fetch_add(n, Ordering::Relaxed);
+                    warn!(
+                        event = std::any::type_name::<T>(),
+                        skipped = n,
+                        "Scoped subscriber lagged; continuing from latest message"
+                    );
+                },
+                Err(broadcast::error::RecvError::Closed) => return None,
+            }
+        }
+    }
+}
+
+impl<T: Event> EventReceiverExt<T> for ScopedReceiver<T> {
+    async fn recv(&mut self) -> Option<Arc<T>> {
+        Self::recv(self).await
+    }
+}
+
+impl<T> Deref for ScopedReceiver<T> {
+    type Target = broadcast::Receiver<Arc<T>>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl<T> DerefMut for ScopedReceiver<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.inner
+    }
+}
+
+impl<T> Drop for ScopedReceiver<T> {
+    fn drop(&mut self) {
+        self.counters.subscribers.fetch_sub(1, Ordering::Relaxed);
+        trace!(event = std::any::type_name::<T>(), "Scoped subscriber dropped");
+    }
+}
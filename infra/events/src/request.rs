@@ -0,0 +1,107 @@
+use crate::bus::{Event, EventBus};
+use crate::error::EventBusError;
+use parking_lot::Mutex;
+use std::time::Duration;
+use tokio::sync::oneshot;
+use tracing::trace;
+
+/// How long [`EventBus::request`] waits for a reply.
+const DEFAULT_REQUEST_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// A request published by [`EventBus::request`], carrying the channel for its reply.
+///
+/// Responders subscribe to `RequestEnvelope<Req, Resp>` like to any broadcast event and call
+/// [`RequestEnvelope::respond`]. When several responders receive the same request, the first
+/// reply wins.
+#[derive(Debug)]
+pub struct RequestEnvelope<Req, Resp> {
+    request: Req,
+    reply: Mutex<Option<oneshot::Sender<Resp>>>,
+}
+
+impl<Req, Resp> RequestEnvelope<Req, Resp> {
+    /// Returns the request payload.
+    pub const fn request(&self) -> &Req {
+        &self.request
+    }
+
+    /// Sends the reply to the requester.
+    ///
+    /// Returns `false` if another responder already replied or the requester stopped
+    /// waiting.
+    pub fn respond(&self, response: Resp) -> bool {
+        self.reply.lock().take().is_some_and(|reply| reply.send(response).is_ok())
+    }
+}
+
+impl EventBus {
+    /// Publishes `req` to the `RequestEnvelope<Req, Resp>` broadcast channel and waits up to
+    /// five seconds for a reply.
+    ///
+    /// # Errors
+    /// Returns [`EventBusError::Timeout`] if nobody is subscribed, every responder dropped
+    /// the request, or no reply arrived in time, and [`EventBusError::ChannelKindMismatch`]
+    /// if the envelope type is registered with a different channel kind.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use mhub_event_bus::{EventBus, RequestEnvelope};
+    ///
+    /// #[derive(Debug)]
+    /// struct Ping(u64);
+    /// #[derive(Debug, PartialEq)]
+    /// struct Pong(u64);
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), mhub_event_bus::EventBusError> {
+    /// let bus = EventBus::new();
+    /// let mut rx = bus.subscribe::<RequestEnvelope<Ping, Pong>>()?;
+    /// tokio::spawn(async move {
+    ///     while let Ok(envelope) = rx.recv().await {
+    ///         envelope.respond(Pong(envelope.request().0));
+    ///     }
+    /// });
+    ///
+    /// assert_eq!(bus.request::<Ping, Pong>(Ping(7)).await?, Pong(7));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn request<Req: Event, Resp: Event>(&self, req: Req) -> Result<Resp, EventBusError> {
+        self.request_with_timeout(req, DEFAULT_REQUEST_TIMEOUT).await
+    }
+
+    /// Like [`EventBus::request`], waiting at most `timeout` for the reply.
+    ///
+    /// # Errors
+    /// See [`EventBus::request`].
+    pub async fn request_with_timeout<Req: Event, Resp: Event>(
+        &self,
+        req: Req,
+        timeout: Duration,
+    ) -> Result<Resp, EventBusError> {
+        let (tx, rx) = oneshot::channel();
+        let envelope = RequestEnvelope { request: req, reply: Mutex::new(Some(tx)) };
+
+        if self.publish(envelope)? == 0 {
+            return Err(EventBusError::Timeout {
+                message: "No responder subscribed".into(),
+                context: Some(std::any::type_name::<Req>().into()),
+            });
+        }
+
+        match tokio::time::timeout(timeout, rx).await {
+            Ok(Ok(response)) => {
+                trace!(request = std::any::type_name::<Req>(), "Request answered");
+                Ok(response)
+            },
+            Ok(Err(_)) => Err(EventBusError::Timeout {
+                message: "Request dropped without a response".into(),
+                context: Some(std::any::type_name::<Req>().into()),
+            }),
+            Err(_) => Err(EventBusError::Timeout {
+                message: format!("No response within {timeout:?}").into(),
+                context: Some(std::any::type_name::<Req>().into()),
+            }),
+        }
+    }
+}
@@ -4,6 +4,7 @@ pub mod fixtures;
 mod tests {
     use super::fixtures::*;
     use mhub_event_bus::*;
+    use std::time::Duration;
 
     #[tokio::test]
     async fn test_event_flow() {
@@ -80,6 +81,170 @@ mod tests {
         assert_eq!(got_other.0, 13);
     }
 
+    #[tokio::test]
+    async fn test_channel_info_reports_channels() {
+        #[derive(Clone, Debug, PartialEq, Eq)]
+        struct Snapshot(pub usize);
+
+        let bus = EventBus::new();
+        let _rx_a = bus.subscribe::<TestEvent>().unwrap();
+        let _rx_b = bus.subscribe::<TestEvent>().unwrap();
+        let _rx_watch = bus.subscribe_watch(Snapshot(0)).unwrap();
+
+        let info = bus.channel_info();
+        assert_eq!(info.len(), 2);
+
+        let broadcast = info.iter().find(|c| c.type_name.ends_with("TestEvent")).unwrap();
+        assert!(matches!(broadcast.kind, ChannelKind::Broadcast { .. }));
+        assert_eq!(broadcast.receivers, 2);
+
+        let watch = info.iter().find(|c| c.type_name.ends_with("Snapshot")).unwrap();
+        assert_eq!(watch.kind, ChannelKind::Watch);
+        assert_eq!(watch.receivers, 1);
+    }
+
+    #[tokio::test]
+    async fn test_metrics_track_deliveries_and_drops() {
+        let bus = EventBus::new();
+        assert!(bus.metrics::<TestEvent>().is_none());
+
+        bus.publish(TestEvent(0)).unwrap();
+        let _rx_a = bus.subscribe::<TestEvent>().unwrap();
+        let _rx_b = bus.subscribe::<TestEvent>().unwrap();
+        bus.publish(TestEvent(1)).unwrap();
+
+        let metrics = bus.metrics::<TestEvent>().unwrap();
+        assert_eq!(
+            metrics,
+            EventMetrics { published: 2, delivered: 2, dropped: 1, subscribers: 0 }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_metrics_count_full_mpsc_queue_as_dropped() {
+        let bus = EventBus::new();
+        let _rx = bus.subscribe_mpsc::<TestEvent>(1).unwrap();
+
+        bus.publish_mpsc(TestEvent(0)).unwrap();
+        assert!(bus.publish_mpsc(TestEvent(1)).is_err());
+
+        let metrics = bus.metrics::<TestEvent>().unwrap();
+        assert_eq!(
+            metrics,
+            EventMetrics { published: 2, delivered: 1, dropped: 1, subscribers: 0 }
+        );
+    }
+
+    #[derive(Debug, PartialEq, Eq)]
+    struct Echo(usize);
+
+    #[tokio::test]
+    async fn test_request_response_echo() {
+        let bus = EventBus::new();
+        let mut rx = bus.subscribe::<RequestEnvelope<TestEvent, Echo>>().unwrap();
+        let responder = tokio::spawn(async move {
+            while let Some(envelope) = EventReceiverExt::recv(&mut rx).await {
+                assert!(envelope.respond(Echo(envelope.request().0)));
+            }
+        });
+
+        assert_eq!(bus.request::<TestEvent, Echo>(TestEvent(3)).await.unwrap(), Echo(3));
+        assert_eq!(bus.request::<TestEvent, Echo>(TestEvent(4)).await.unwrap(), Echo(4));
+
+        let _ = bus.shutdown();
+        responder.await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_request_without_reply_times_out() {
+        let bus = EventBus::new();
+        let result = bus.request::<TestEvent, Echo>(TestEvent(1)).await;
+        assert!(matches!(result, Err(EventBusError::Timeout { .. })));
+
+        let _rx = bus.subscribe::<RequestEnvelope<TestEvent, Echo>>().unwrap();
+        let result = bus
+            .request_with_timeout::<TestEvent, Echo>(TestEvent(2), Duration::from_millis(20))
+            .await;
+        assert!(matches!(result, Err(EventBusError::Timeout { .. })));
+    }
+
+    #[tokio::test]
+    async fn test_close_single_channel() {
+        let bus = EventBus::new();
+        let mut rx = bus.subscribe::<TestEvent>().unwrap();
+        let _other = bus.subscribe::<Echo>().unwrap();
+
+        assert!(bus.close::<TestEvent>());
+        assert!(!bus.close::<TestEvent>());
+        assert!(matches!(rx.recv().await, Err(tokio::sync::broadcast::error::RecvError::Closed)));
+        assert_eq!(bus.channel_info().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_reconfigure_replaces_channel() {
+        let bus = EventBus::new();
+        let mut rx = bus.subscribe_with_capacity::<TestEvent>(4).unwrap();
+
+        bus.reconfigure::<TestEvent>(ChannelKind::Broadcast { capacity: 64 }).unwrap();
+        assert!(matches!(rx.recv().await, Err(tokio::sync::broadcast::error::RecvError::Closed)));
+        assert_eq!(bus.channel_info()[0].kind, ChannelKind::Broadcast { capacity: 64 });
+
+        let mut rx = bus.subscribe_with_capacity::<TestEvent>(64).unwrap();
+        bus.publish(TestEvent(5)).unwrap();
+        assert_eq!(rx.recv().await.unwrap().0, 5);
+
+        assert!(matches!(
+            bus.reconfigure::<TestEvent>(ChannelKind::Watch),
+            Err(EventBusError::ChannelKindMismatch { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_reconfigure_rejects_live_mpsc_receiver() {
+        let bus = EventBus::new();
+        let rx = bus.subscribe_mpsc::<TestEvent>(4).unwrap();
+
+        let result = bus.reconfigure::<TestEvent>(ChannelKind::Mpsc { capacity: 8 });
+        assert!(matches!(result, Err(EventBusError::ChannelKindMismatch { .. })));
+
+        drop(rx);
+        bus.reconfigure::<TestEvent>(ChannelKind::Mpsc { capacity: 8 }).unwrap();
+        let mut rx = bus.subscribe_mpsc::<TestEvent>(8).unwrap();
+        bus.publish_mpsc(TestEvent(1)).unwrap();
+        assert_eq!(rx.recv().await.unwrap().0, 1);
+    }
+
+    #[tokio::test]
+    async fn test_scoped_receiver_tracks_subscribers() {
+        let bus = EventBus::new();
+        let mut first = bus.subscribe_scoped::<TestEvent>().unwrap();
+        let second = bus.subscribe_scoped::<TestEvent>().unwrap();
+        assert_eq!(bus.metrics::<TestEvent>().unwrap().subscribers, 2);
+        assert_eq!(second.len(), 0, "derefs to the broadcast receiver");
+
+        bus.publish(TestEvent(9)).unwrap();
+        assert_eq!(first.recv().await.unwrap().0, 9);
+
+        drop(second);
+        assert_eq!(bus.metrics::<TestEvent>().unwrap().subscribers, 1);
+        drop(first);
+        assert_eq!(bus.metrics::<TestEvent>().unwrap().subscribers, 0);
+    }
+
+    #[tokio::test]
+    async fn test_scoped_receiver_counts_lag_as_dropped() {
+        let bus = EventBus::new();
+        drop(bus.subscribe_with_capacity::<TestEvent>(2).unwrap());
+        let mut rx = bus.subscribe_scoped::<TestEvent>().unwrap();
+
+        for i in 0..5 {
+            bus.publish(TestEvent(i)).unwrap();
+        }
+
+        assert_eq!(rx.recv().await.unwrap().0, 3);
+        assert_eq!(bus.metrics::<TestEvent>().unwrap().dropped, 3);
+    }
+
     #[tokio::test]
     async fn test_bus_closure_detection() {
         let bus = EventBus::new();
@@ -126,6 +291,30 @@ mod tests {
         assert_eq!(c.0, 2);
     }
 
+    #[tokio::test]
+    async fn test_mpsc_async_publish_applies_backpressure() {
+        let bus = EventBus::new();
+        let mut rx = bus.subscribe_mpsc::<TestEvent>(1).unwrap();
+
+        let producer = tokio::spawn({
+            let bus = bus.clone();
+            async move {
+                for i in 0..3 {
+                    bus.publish_mpsc_async(TestEvent(i)).await.unwrap();
+                }
+            }
+        });
+
+        tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+        assert!(!producer.is_finished(), "producer should wait for the slow consumer");
+        assert!(matches!(bus.publish_mpsc(TestEvent(99)), Err(EventBusError::ChannelFull { .. })));
+
+        for i in 0..3 {
+            assert_eq!(rx.recv().await.unwrap().0, i);
+        }
+        producer.await.unwrap();
+    }
+
     #[tokio::test]
     async fn test_publish_arc_variants() {
         use std::sync::Arc;
@@ -252,4 +441,140 @@ mod tests {
         let result = bus.subscribe_mpsc::<TestEvent>(0);
         assert!(matches!(result, Err(EventBusError::InvalidCapacity { .. })));
     }
+
+    #[tokio::test]
+    async fn test_mpsc_receiver_handoff_preserves_messages() {
+        let bus = EventBus::new();
+        let rx = bus.subscribe_mpsc::<TestEvent>(4).unwrap();
+
+        bus.publish_mpsc(TestEvent(1)).unwrap();
+        bus.publish_mpsc(TestEvent(2)).unwrap();
+
+        bus.return_mpsc_receiver(rx).unwrap();
+        let mut rx = bus.subscribe_mpsc::<TestEvent>(4).unwrap();
+
+        assert_eq!(rx.recv().await.unwrap().0, 1);
+        assert_eq!(rx.recv().await.unwrap().0, 2);
+    }
+
+    #[tokio::test]
+    async fn test_subscribe_replay_returns_retained_events() {
+        let bus = EventBus::new();
+        let (_early, missed) = bus.subscribe_replay::<TestEvent>(3).unwrap();
+        assert!(missed.is_empty());
+
+        for i in 0..5 {
+            bus.publish(TestEvent(i)).unwrap();
+        }
+
+        let (mut late, missed) = bus.subscribe_replay::<TestEvent>(3).unwrap();
+        let missed: Vec<_> = missed.iter().map(|event| event.0).collect();
+        assert_eq!(missed, [2, 3, 4], "only the last 3 events are retained, oldest first");
+
+        let (_, fewer) = bus.subscribe_replay::<TestEvent>(1).unwrap();
+        assert_eq!(fewer.len(), 1);
+        assert_eq!(fewer[0].0, 4);
+
+        bus.publish(TestEvent(5)).unwrap();
+        assert_eq!(late.recv().await.unwrap().0, 5, "live events follow the replayed ones");
+    }
+
+    #[tokio::test]
+    async fn test_subscribe_replay_grows_buffer_and_rejects_zero() {
+        let bus = EventBus::new();
+        bus.subscribe_replay::<TestEvent>(1).unwrap();
+        bus.publish(TestEvent(0)).unwrap();
+
+        bus.subscribe_replay::<TestEvent>(4).unwrap();
+        for i in 1..=5 {
+            bus.publish(TestEvent(i)).unwrap();
+        }
+        let (_, missed) = bus.subscribe_replay::<TestEvent>(4).unwrap();
+        assert_eq!(missed.iter().map(|event| event.0).collect::<Vec<_>>(), [2, 3, 4, 5]);
+
+        let result = bus.subscribe_replay::<TestEvent>(0);
+        assert!(matches!(result, Err(EventBusError::InvalidCapacity { .. })));
+    }
+
+    /// Waits until `T`'s broadcast channel has exactly `expected` receivers.
+    async fn wait_for_receivers(bus: &EventBus, expected: usize) {
+        tokio::time::timeout(Duration::from_secs(1), async {
+            while bus.channel_info()[0].receivers != expected {
+                tokio::time::sleep(Duration::from_millis(5)).await;
+            }
+        })
+        .await
+        .expect("receiver count did not settle");
+    }
+
+    #[tokio::test]
+    async fn test_on_runs_handler_until_guard_drops() {
+        let bus = EventBus::new();
+        let (tx, mut handled) = tokio::sync::mpsc::unbounded_channel();
+        let guard = bus
+            .on(move |event: std::sync::Arc<TestEvent>| {
+                let tx = tx.clone();
+                async move {
+                    tx.send(event.0).unwrap();
+                    if event.0 == 2 { Err("even events are rejected") } else { Ok(()) }
+                }
+            })
+            .unwrap();
+
+        for i in 1..=3 {
+            assert_eq!(bus.publish(TestEvent(i)).unwrap(), 1);
+        }
+        for expected in 1..=3 {
+            let received = tokio::time::timeout(Duration::from_secs(1), handled.recv()).await;
+            assert_eq!(received.unwrap(), Some(expected), "handler errors must not stop it");
+        }
+        assert!(!guard.is_finished());
+
+        drop(guard);
+        wait_for_receivers(&bus, 0).await;
+        assert_eq!(bus.publish(TestEvent(4)).unwrap(), 0);
+        assert!(handled.recv().await.is_none(), "the aborted task dropped the handler");
+    }
+
+    #[tokio::test]
+    async fn test_on_stops_when_channel_closes() {
+        let bus = EventBus::new();
+        let guard = bus.on(|_: std::sync::Arc<TestEvent>| async {}).unwrap();
+
+        assert!(bus.close::<TestEvent>());
+        tokio::time::timeout(Duration::from_secs(1), async {
+            while !guard.is_finished() {
+                tokio::time::sleep(Duration::from_millis(5)).await;
+            }
+        })
+        .await
+        .expect("handler task should end once the channel is closed");
+    }
+
+    #[tokio::test]
+    async fn test_return_mpsc_receiver_requires_taken_channel() {
+        let bus = EventBus::new();
+        let rx = bus.subscribe_mpsc::<TestEvent>(4).unwrap();
+        bus.return_mpsc_receiver(rx).unwrap();
+
+        let (_tx, stray) = tokio::sync::mpsc::channel(1);
+        let result = bus.return_mpsc_receiver::<TestEvent>(stray);
+        assert!(matches!(result, Err(EventBusError::ChannelKindMismatch { .. })));
+    }
+
+    #[tokio::test]
+    async fn test_return_mpsc_receiver_rejects_foreign_receiver() {
+        let bus = EventBus::new();
+        let other = EventBus::new();
+        let rx = bus.subscribe_mpsc::<TestEvent>(4).unwrap();
+        let foreign = other.subscribe_mpsc::<TestEvent>(4).unwrap();
+
+        let result = bus.return_mpsc_receiver(foreign);
+        assert!(matches!(result, Err(EventBusError::ChannelKindMismatch { .. })));
+
+        bus.publish_mpsc(TestEvent(1)).unwrap();
+        bus.return_mpsc_receiver(rx).unwrap();
+        let mut rx = bus.subscribe_mpsc::<TestEvent>(4).unwrap();
+        assert_eq!(rx.recv().await.unwrap().0, 1);
+    }
 }
@@ -0,0 +1,93 @@
+//! # Rotated Log Compression
+//!
+//! Gzip-compresses log files closed by the [`RollingWriter`](crate::rolling::RollingWriter) on a
+//! dedicated thread, so compression never stalls the non-blocking logging worker.
+
+use crate::error::LoggerError;
+use crate::rolling::{COMPRESSED_SUFFIX, prune};
+use flate2::Compression;
+use flate2::write::GzEncoder;
+use parking_lot::Mutex;
+use std::ffi::OsString;
+use std::fs::{self, File};
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::sync::mpsc::{self, Sender};
+use std::thread::{self, JoinHandle};
+
+/// Handle to the background compression thread.
+///
+/// The thread exits once every [`Sender`] handed out by [`Compressor::spawn`] is dropped,
+/// which happens when the non-blocking worker shuts down and releases its writer.
+#[derive(Debug)]
+pub(crate) struct Compressor {
+    handle: Option<JoinHandle<()>>,
+    pass: Arc<Mutex<()>>,
+}
+
+impl Compressor {
+    pub(crate) fn spawn(
+        dir: PathBuf,
+        prefix: String,
+        max_files: usize,
+    ) -> Result<(Self, Sender<PathBuf>), LoggerError> {
+        let (sender, receiver) = mpsc::channel::<PathBuf>();
+        let pass = Arc::new(Mutex::new(()));
+        let worker_pass = Arc::clone(&pass);
+
+        let handle = thread::Builder::new()
+            .name(format!("{prefix}-log-compressor"))
+            .spawn(move || {
+                for path in receiver {
+                    let _pass = worker_pass.lock();
+                    if let Err(e) = gzip(&path) {
+                        tracing::warn!(path = %path.display(), error = %e, "Failed to compress log file");
+                    }
+                    prune(&dir, &prefix, max_files);
+                }
+            })
+            .map_err(|e| LoggerError::Internal {
+                message: e.to_string().into(),
+                context: Some("Failed to spawn log compression thread".into()),
+            })?;
+
+        Ok((Self { handle: Some(handle), pass }, sender))
+    }
+
+    /// Blocks until an in-flight compression pass, if any, has finished.
+    pub(crate) fn wait_idle(&self) {
+        drop(self.pass.lock());
+    }
+
+    /// Waits for every queued file to be compressed and the thread to exit.
+    pub(crate) fn join(mut self) {
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+/// Replaces `path` with a gzip-compressed `<path>.gz`.
+///
+/// The archive is written under a temporary name first, so a crash mid-pass never leaves a
+/// truncated `.gz` next to the deleted original.
+fn gzip(path: &Path) -> io::Result<()> {
+    let target = with_suffix(path, COMPRESSED_SUFFIX);
+    let partial = with_suffix(&target, "tmp");
+
+    let mut source = File::open(path)?;
+    let mut encoder = GzEncoder::new(File::create(&partial)?, Compression::default());
+    io::copy(&mut source, &mut encoder)?;
+    encoder.finish()?.sync_all()?;
+
+    fs::rename(&partial, &target)?;
+    fs::remove_file(path)
+}
+
+fn with_suffix(path: &Path, suffix: &str) -> PathBuf {
+    let mut name = OsString::from(path.as_os_str());
+    name.push(".");
+    name.push(suffix);
+    PathBuf::from(name)
+}
@@ -14,6 +14,10 @@ pub enum LoggerError {
         context: Option<Cow<'static, str>>,
     },
 
+    /// Failure when swapping the active filter of a running logger.
+    #[error("Filter reload error{}: {source}", format_context(context))]
+    Reload { source: tracing_subscriber::reload::Error, context: Option<Cow<'static, str>> },
+
     /// Internal logic errors.
     #[error("Internal logger error{}: {message}", format_context(context))]
     Internal { message: Cow<'static, str>, context: Option<Cow<'static, str>> },
@@ -0,0 +1,89 @@
+//! # Global Fields
+//!
+//! Stamps every event written to file and custom writer outputs with a fixed set of key/value
+//! pairs (e.g., `service`, `version`, `instance_id`). Values recorded on the event itself take
+//! precedence over global ones with the same key.
+
+use std::fmt;
+use std::sync::Arc;
+use tracing::{Event, Subscriber};
+use tracing_subscriber::fmt::format::Writer;
+use tracing_subscriber::fmt::{FmtContext, FormatEvent, FormatFields};
+use tracing_subscriber::registry::LookupSpan;
+
+/// An immutable, shareable set of fields applied to each event.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct GlobalFields(Arc<[(String, String)]>);
+
+impl GlobalFields {
+    /// Builds the set, keeping the last value for duplicate keys.
+    pub(crate) fn new(fields: Vec<(String, String)>) -> Self {
+        let mut unique: Vec<(String, String)> = Vec::with_capacity(fields.len());
+        for (key, value) in fields {
+            match unique.iter_mut().find(|(k, _)| *k == key) {
+                Some(existing) => existing.1 = value,
+                None => unique.push((key, value)),
+            }
+        }
+        Self(unique.into())
+    }
+
+    /// Wraps an event formatter so its output carries the global fields.
+    pub(crate) fn format<E>(&self, inner: E, json: bool) -> GlobalFieldsFormat<E> {
+        GlobalFieldsFormat { inner, fields: self.clone(), json }
+    }
+}
+
+/// Event formatter adding [`GlobalFields`] to the output of `E`.
+#[derive(Debug)]
+pub(crate) struct GlobalFieldsFormat<E> {
+    inner: E,
+    fields: GlobalFields,
+    json: bool,
+}
+
+impl<S, N, E> FormatEvent<S, N> for GlobalFieldsFormat<E>
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+    N: for<'a> FormatFields<'a> + 'static,
+    E: FormatEvent<S, N>,
+{
+    fn format_event(
+        &self,
+        ctx: &FmtContext<'_, S, N>,
+        mut writer: Writer<'_>,
+        event: &Event<'_>,
+    ) -> fmt::Result {
+        if self.fields.0.is_empty() {
+            return self.inner.format_event(ctx, writer, event);
+        }
+
+        let mut line = String::new();
+        self.inner.format_event(ctx, Writer::new(&mut line), event)?;
+
+        if self.json {
+            writer.write_str(&self.inject_json(&line)?)?;
+        } else {
+            writer.write_str(line.trim_end_matches('\n'))?;
+            for (key, value) in &*self.fields.0 {
+                if !event.fields().any(|field| field.name() == key) {
+                    write!(writer, " {key}={value:?}")?;
+                }
+            }
+        }
+        writeln!(writer)
+    }
+}
+
+impl<E> GlobalFieldsFormat<E> {
+    /// Inserts the global fields into the `fields` object of a JSON record.
+    fn inject_json(&self, line: &str) -> Result<String, fmt::Error> {
+        let mut record: serde_json::Value = serde_json::from_str(line).map_err(|_| fmt::Error)?;
+        if let Some(fields) = record.get_mut("fields").and_then(serde_json::Value::as_object_mut) {
+            for (key, value) in &*self.fields.0 {
+                fields.entry(key.clone()).or_insert_with(|| value.clone().into());
+            }
+        }
+        serde_json::to_string(&record).map_err(|_| fmt::Error)
+    }
+}
@@ -0,0 +1,102 @@
+//! # Flush Barrier
+//!
+//! `tracing-appender`'s non-blocking writer offers no way to wait for queued records. The
+//! [`Flusher`] pushes an empty record through the same channel as regular records; the worker
+//! hands it to [`Tracked::write_all`], which flushes the file and releases the waiter. Since the
+//! channel is FIFO, reaching the marker means every earlier record has been written.
+
+use parking_lot::{Condvar, Mutex};
+use std::io::{self, Write};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tracing_appender::non_blocking::NonBlocking;
+
+#[derive(Debug, Default)]
+struct BarrierState {
+    requested: Mutex<u64>,
+    reached: Mutex<u64>,
+    cond: Condvar,
+}
+
+/// Shared state between the worker-side [`Tracked`] writer and the [`Flusher`].
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FlushBarrier(Arc<BarrierState>);
+
+impl FlushBarrier {
+    fn mark(&self) {
+        let mut reached = self.0.reached.lock();
+        *reached += 1;
+        self.0.cond.notify_all();
+    }
+}
+
+/// Wraps the writer owned by the non-blocking worker and signals flush markers.
+#[derive(Debug)]
+pub(crate) struct Tracked<W> {
+    inner: W,
+    barrier: FlushBarrier,
+}
+
+impl<W> Tracked<W> {
+    pub(crate) const fn new(inner: W, barrier: FlushBarrier) -> Self {
+        Self { inner, barrier }
+    }
+}
+
+impl<W: Write> Write for Tracked<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.inner.write(buf)
+    }
+
+    fn write_all(&mut self, buf: &[u8]) -> io::Result<()> {
+        // Formatted events are never empty, so an empty record is always a flush marker.
+        if buf.is_empty() {
+            let result = self.inner.flush();
+            self.barrier.mark();
+            return result;
+        }
+        self.inner.write_all(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+/// Caller-side handle that waits for the non-blocking worker to drain.
+#[derive(Debug)]
+pub(crate) struct Flusher {
+    barrier: FlushBarrier,
+    writer: NonBlocking,
+}
+
+impl Flusher {
+    pub(crate) const fn new(barrier: FlushBarrier, writer: NonBlocking) -> Self {
+        Self { barrier, writer }
+    }
+
+    /// Blocks until every record queued before the call is written, or `timeout` elapses.
+    ///
+    /// Returns `false` on timeout, e.g. when the marker was dropped because the lossy queue
+    /// was full or the worker has already shut down.
+    pub(crate) fn flush(&self, timeout: Duration) -> bool {
+        let ticket = {
+            // Markers must enter the queue in ticket order.
+            let mut requested = self.barrier.0.requested.lock();
+            if self.writer.clone().write(&[]).is_err() {
+                return false;
+            }
+            *requested += 1;
+            *requested
+        };
+
+        let deadline = Instant::now() + timeout;
+        let mut reached = self.barrier.0.reached.lock();
+        while *reached < ticket {
+            if self.barrier.0.cond.wait_until(&mut reached, deadline).timed_out() {
+                return *reached >= ticket;
+            }
+        }
+        true
+    }
+}
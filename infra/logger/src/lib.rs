@@ -10,8 +10,15 @@
 //!   global `OpenTelemetry` tracer. Configure a tracer provider before calling
 //!   [`LoggerBuilder::init`].
 //! * Optional `opentelemetry-otlp` helper installs an `OTLP` tracer provider.
+//! * Optional `syslog` support ships RFC 5424 records over UDP, TCP, or a unix socket
+//!   (see [`LoggerBuilder::syslog`]).
 //! * Use [`LoggerBuilder::env_filter`] to set module-directed filters
 //!   (e.g., `"myapp=debug,hyper=info"`), in addition to `RUST_LOG`.
+//! * Use [`LoggerBuilder::max_file_size`] to roll files by size as well as by time.
+//! * Use [`LoggerBuilder::compress_rotated`] to gzip rotated files in the background.
+//! * Use [`LoggerBuilder::with_global_fields`] to stamp every file/writer record with fixed
+//!   fields such as `service` or `instance_id`.
+//! * Use [`Logger::set_level`] or [`Logger::set_filter`] to change verbosity at runtime.
 //!
 //! ## Example
 //!
@@ -26,28 +33,46 @@
 //!     .unwrap();
 //! ```
 
+mod compress;
 mod error;
+mod fields;
+mod flush;
 #[cfg(feature = "opentelemetry-otlp")]
 mod otlp;
+mod rolling;
+#[cfg(feature = "syslog")]
+mod syslog;
 
 pub use crate::error::{LoggerError, LoggerErrorExt};
 #[cfg(feature = "opentelemetry-otlp")]
 pub use crate::otlp::{OpenTelemetryGuard, init_otlp_tracer};
+#[cfg(feature = "syslog")]
+pub use crate::syslog::{Facility, SyslogConfig, SyslogTransport};
 pub use tracing::level_filters::LevelFilter;
 pub use tracing_appender::rolling::Rotation;
 
+use crate::compress::Compressor;
+use crate::fields::GlobalFields;
+use crate::flush::{FlushBarrier, Flusher, Tracked};
+use crate::rolling::RollingWriter;
 use private::Sealed;
 use std::fs;
 use std::path::PathBuf;
+use std::time::Duration;
 use tracing_appender::non_blocking::WorkerGuard;
 use tracing_appender::rolling::RollingFileAppender;
 use tracing_subscriber::fmt::layer;
+use tracing_subscriber::fmt::writer::{BoxMakeWriter, MakeWriter};
 use tracing_subscriber::layer::SubscriberExt;
 use tracing_subscriber::util::SubscriberInitExt;
-use tracing_subscriber::{EnvFilter, Layer};
+use tracing_subscriber::{EnvFilter, Layer, Registry, reload};
 
 const DEFAULT_MAX_FILES: usize = 10;
 const LOG_FILE_SUFFIX: &str = "log";
+const FLUSH_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// Handle to the active [`EnvFilter`] of an initialized logger.
+pub type ReloadHandle = reload::Handle<EnvFilter, Registry>;
 
 #[derive(Debug)]
 pub struct LoggerConfig {
@@ -56,10 +81,16 @@ pub struct LoggerConfig {
     level: LevelFilter,
     rotation: Rotation,
     max_files: usize,
+    max_file_size: Option<u64>,
+    compress_rotated: bool,
     json: bool,
     env_filter: Option<String>,
+    global_fields: Vec<(String, String)>,
+    writers: Vec<BoxMakeWriter>,
     #[cfg(feature = "opentelemetry")]
     opentelemetry: bool,
+    #[cfg(feature = "syslog")]
+    syslog: Option<SyslogConfig>,
 }
 
 impl Default for LoggerConfig {
@@ -70,10 +101,16 @@ impl Default for LoggerConfig {
             level: LevelFilter::INFO,
             rotation: Rotation::DAILY,
             max_files: DEFAULT_MAX_FILES,
+            max_file_size: None,
+            compress_rotated: false,
             json: false,
             env_filter: None,
+            global_fields: Vec::new(),
+            writers: Vec::new(),
             #[cfg(feature = "opentelemetry")]
             opentelemetry: false,
+            #[cfg(feature = "syslog")]
+            syslog: None,
         }
     }
 }
@@ -129,10 +166,26 @@ impl LoggerBuilder<WithName, WithFile> {
         self
     }
 
-    /// Enables JSON logging.
+    /// Rolls to a new log file once the active one would grow beyond `bytes`.
+    ///
+    /// Size and time rotation combine: a new file starts when either the
+    /// [`LoggerBuilder::rotation`] period ends or the limit is reached, whichever comes first.
+    /// Files rolled within the same period get a numeric suffix (e.g., `my-app.2023-10-27.1.log`)
+    /// and count towards [`LoggerBuilder::max_files`].
     #[must_use = "The builder must be configured before it can be used to initialize the logger."]
-    pub const fn json(mut self) -> Self {
-        self.config.json = true;
+    pub const fn max_file_size(mut self, bytes: u64) -> Self {
+        self.config.max_file_size = Some(bytes);
+        self
+    }
+
+    /// Gzip-compresses rotated log files into `<file>.log.gz`.
+    ///
+    /// Compression runs on a dedicated thread, never on the non-blocking logging worker, and
+    /// only touches files that are no longer written to. Compressed and uncompressed files
+    /// count together towards [`LoggerBuilder::max_files`].
+    #[must_use = "The builder must be configured before it can be used to initialize the logger."]
+    pub const fn compress_rotated(mut self, enabled: bool) -> Self {
+        self.config.compress_rotated = enabled;
         self
     }
 }
@@ -155,6 +208,44 @@ impl<F: Sealed> LoggerBuilder<WithName, F> {
         self
     }
 
+    /// Enables JSON logging for file and custom writer outputs.
+    ///
+    /// Console output always uses the compact human-readable format.
+    #[must_use = "The builder must be configured before it can be used to initialize the logger."]
+    pub const fn json(mut self) -> Self {
+        self.config.json = true;
+        self
+    }
+
+    /// Adds fields stamped onto every record written to files and custom writers.
+    ///
+    /// Useful for `service`, `version`, or `instance_id` labels that would otherwise need a
+    /// span on every task. Fields appear in the `fields` object in JSON mode and as trailing
+    /// `key="value"` pairs otherwise; console output is left unchanged. When an event records
+    /// a field with the same key, the per-event value wins.
+    #[must_use = "The builder must be configured before it can be used to initialize the logger."]
+    pub fn with_global_fields(
+        mut self,
+        fields: impl IntoIterator<Item = (String, String)>,
+    ) -> Self {
+        self.config.global_fields.extend(fields);
+        self
+    }
+
+    /// Adds an output that writes formatted records to a custom sink.
+    ///
+    /// Each call installs one more fmt layer alongside console and file output, e.g. an
+    /// in-memory buffer for tests or a channel in embedded scenarios. The layer honors
+    /// [`LoggerBuilder::json`] and never emits ANSI colors.
+    #[must_use = "The builder must be configured before it can be used to initialize the logger."]
+    pub fn writer<W>(mut self, writer: W) -> Self
+    where
+        W: for<'a> MakeWriter<'a> + Send + Sync + 'static,
+    {
+        self.config.writers.push(BoxMakeWriter::new(writer));
+        self
+    }
+
     /// Enables console logging.
     #[must_use = "The builder must be configured before it can be used to initialize the logger."]
     pub const fn console(mut self, enabled: bool) -> Self {
@@ -174,6 +265,21 @@ impl<F: Sealed> LoggerBuilder<WithName, F> {
         self
     }
 
+    /// Ships records to a syslog daemon as RFC 5424 messages.
+    ///
+    /// The connection is opened by [`LoggerBuilder::init`], which fails with
+    /// [`LoggerError::InvalidConfiguration`] if the socket is unavailable. Delivery failures
+    /// after that are ignored. Over TCP, records are queued for a sender thread that connects
+    /// and writes with timeouts and backs off between reconnects, so an unreachable daemon
+    /// never blocks the application; records are dropped while the daemon is down or the
+    /// queue is full.
+    #[cfg(feature = "syslog")]
+    #[must_use = "The builder must be configured before it can be used to initialize the logger."]
+    pub fn syslog(mut self, config: SyslogConfig) -> Self {
+        self.config.syslog = Some(config);
+        self
+    }
+
     /// Sets the path to log files.
     pub fn path(self, path: impl Into<PathBuf>) -> LoggerBuilder<WithName, WithFile> {
         let mut config = self.config;
@@ -195,6 +301,7 @@ impl<F: Sealed> LoggerBuilder<WithName, F> {
         validate_config(&self.config, &self.name.0)?;
 
         let env_filter = build_env_filter(&self.config)?;
+        let (filter_layer, filter) = reload::Layer::new(env_filter);
 
         let mut layers = Vec::new();
 
@@ -213,44 +320,87 @@ impl<F: Sealed> LoggerBuilder<WithName, F> {
             layers.push(tracing_opentelemetry::layer().with_tracer(tracer).boxed());
         }
 
-        let guard = if let Some(path) = self.config.path {
+        let global_fields = GlobalFields::new(self.config.global_fields);
+
+        for writer in self.config.writers {
+            let writer_layer = layer().with_writer(writer).with_ansi(false);
+            layers.push(if self.config.json {
+                writer_layer.json().map_event_format(|f| global_fields.format(f, true)).boxed()
+            } else {
+                writer_layer.map_event_format(|f| global_fields.format(f, false)).boxed()
+            });
+        }
+
+        #[cfg(feature = "syslog")]
+        if let Some(config) = self.config.syslog {
+            let writer = syslog::SyslogWriter::connect(config, &self.name.0)?;
+            let syslog_layer =
+                layer().with_writer(writer).with_ansi(false).without_time().with_level(false);
+            layers.push(syslog_layer.map_event_format(|f| global_fields.format(f, false)).boxed());
+        }
+
+        let (guard, flusher, compressor) = if let Some(path) = self.config.path {
             fs::create_dir_all(&path).map_err(|e| LoggerError::Internal {
                 message: e.to_string().into(),
                 context: Some(format!("Failed to create path: {}", path.display()).into()),
             })?;
 
-            let file_appender = RollingFileAppender::builder()
-                .rotation(self.config.rotation)
-                .filename_prefix(&self.name.0)
-                .filename_suffix(LOG_FILE_SUFFIX)
-                .max_log_files(self.config.max_files)
-                .build(path)?;
-
-            let (non_blocking, g) = tracing_appender::non_blocking(file_appender);
-
+            let (compressor, sender) = if self.config.compress_rotated {
+                let (compressor, sender) =
+                    Compressor::spawn(path.clone(), self.name.0.clone(), self.config.max_files)?;
+                (Some(compressor), Some(sender))
+            } else {
+                (None, None)
+            };
+
+            let barrier = FlushBarrier::default();
+            let (non_blocking, g) = if self.config.max_file_size.is_some() || sender.is_some() {
+                let file_appender = RollingWriter::new(
+                    path,
+                    &self.name.0,
+                    self.config.rotation,
+                    self.config.max_file_size,
+                    self.config.max_files,
+                    sender,
+                )?;
+                tracing_appender::non_blocking(Tracked::new(file_appender, barrier.clone()))
+            } else {
+                let file_appender = RollingFileAppender::builder()
+                    .rotation(self.config.rotation)
+                    .filename_prefix(&self.name.0)
+                    .filename_suffix(LOG_FILE_SUFFIX)
+                    .max_log_files(self.config.max_files)
+                    .build(path)?;
+                tracing_appender::non_blocking(Tracked::new(file_appender, barrier.clone()))
+            };
+
+            let flusher = Flusher::new(barrier, non_blocking.clone());
             let file_layer = layer().with_writer(non_blocking).with_ansi(false);
 
-            let boxed =
-                if self.config.json { file_layer.json().boxed() } else { file_layer.boxed() };
+            let boxed = if self.config.json {
+                file_layer.json().map_event_format(|f| global_fields.format(f, true)).boxed()
+            } else {
+                file_layer.map_event_format(|f| global_fields.format(f, false)).boxed()
+            };
 
             layers.push(boxed);
-            Some(g)
+            (Some(g), Some(flusher), compressor)
         } else {
-            None
+            (None, None, None)
         };
 
         if layers.is_empty() {
             return Err(LoggerError::InvalidConfiguration {
                 message:
-                    "No logging layers enabled. Enable console, file output, or OpenTelemetry."
+                    "No logging layers enabled. Enable console, file output, a custom writer, syslog, or OpenTelemetry."
                         .into(),
                 context: None,
             });
         }
 
-        tracing_subscriber::registry().with(env_filter).with(layers).try_init()?;
+        tracing_subscriber::registry().with(filter_layer).with(layers).try_init()?;
 
-        Ok(Logger { guard })
+        Ok(Logger { guard, flusher, compressor, filter, level: self.config.level })
     }
 }
 
@@ -262,6 +412,10 @@ impl<F: Sealed> LoggerBuilder<WithName, F> {
 #[derive(Debug)]
 pub struct Logger {
     guard: Option<WorkerGuard>,
+    flusher: Option<Flusher>,
+    compressor: Option<Compressor>,
+    filter: ReloadHandle,
+    level: LevelFilter,
 }
 
 impl Logger {
@@ -292,10 +446,51 @@ impl Logger {
 
     /// Manually triggers a flush of all pending logs in the non-blocking worker.
     ///
-    /// While flushing happens automatically when this handle is dropped, this
-    /// method acts as a best-effort synchronization point before shutdown.
+    /// Blocks until every record logged before the call has been written to the log file,
+    /// and any in-flight compression pass has finished. Waiting is capped at one second, so
+    /// a stalled or already stopped worker cannot hang the caller; records dropped by the
+    /// lossy queue under back-pressure are not waited for.
+    ///
+    /// Flushing also happens automatically when this handle is dropped.
     pub fn flush(&self) {
-        tracing::debug!("Logger flushed");
+        if let Some(flusher) = &self.flusher
+            && !flusher.flush(FLUSH_TIMEOUT)
+        {
+            tracing::debug!("Timed out waiting for the log writer to flush");
+        }
+        if let Some(compressor) = &self.compressor {
+            compressor.wait_idle();
+        }
+    }
+
+    /// Replaces the active filter with a single global `level`.
+    ///
+    /// Module directives configured via [`LoggerBuilder::env_filter`] or `RUST_LOG` are
+    /// discarded; use [`Logger::set_filter`] to keep per-module control.
+    ///
+    /// # Errors
+    /// Returns [`LoggerError::Reload`] if the subscriber is no longer available.
+    pub fn set_level(&self, level: LevelFilter) -> Result<(), LoggerError> {
+        let filter = EnvFilter::builder().with_default_directive(level.into()).parse_lossy("");
+        self.filter.reload(filter).context("Failed to apply log level")
+    }
+
+    /// Replaces the active filter with the given directives (e.g., `myapp=debug,hyper=info`).
+    ///
+    /// Targets not matched by `filter` fall back to the level configured at init.
+    ///
+    /// # Errors
+    /// Returns [`LoggerError::InvalidConfiguration`] if `filter` cannot be parsed.
+    /// Returns [`LoggerError::Reload`] if the subscriber is no longer available.
+    pub fn set_filter(&self, filter: &str) -> Result<(), LoggerError> {
+        let filter = parse_env_filter(self.level, filter)?;
+        self.filter.reload(filter).context("Failed to apply log filter")
+    }
+
+    /// Returns the handle controlling the active filter.
+    #[must_use]
+    pub const fn reload_handle(&self) -> &ReloadHandle {
+        &self.filter
     }
 
     /// Returns a reference to the underlying worker guard, if present.
@@ -310,6 +505,13 @@ impl Drop for Logger {
         if self.guard.is_some() {
             tracing::info!("Logging system shutting down, flushing buffers...");
         }
+
+        // The worker owns the writer that feeds the compressor; stop it first so the
+        // compressor drains its queue and exits instead of racing the final writes.
+        drop(self.guard.take());
+        if let Some(compressor) = self.compressor.take() {
+            compressor.join();
+        }
     }
 }
 
@@ -328,22 +530,32 @@ fn validate_config(config: &LoggerConfig, name: &str) -> Result<(), LoggerError>
         });
     }
 
+    if config.max_file_size == Some(0) {
+        return Err(LoggerError::InvalidConfiguration {
+            message: "max_file_size must be greater than zero".into(),
+            context: None,
+        });
+    }
+
     Ok(())
 }
 
 fn build_env_filter(config: &LoggerConfig) -> Result<EnvFilter, LoggerError> {
-    let builder = EnvFilter::builder().with_default_directive(config.level.into());
     config.env_filter.as_ref().map_or_else(
-        || Ok(builder.from_env_lossy()),
-        |filter| {
-            builder.parse(filter).map_err(|e| LoggerError::InvalidConfiguration {
-                message: format!("Invalid env filter '{filter}': {e}").into(),
-                context: None,
-            })
-        },
+        || Ok(EnvFilter::builder().with_default_directive(config.level.into()).from_env_lossy()),
+        |filter| parse_env_filter(config.level, filter),
     )
 }
 
+fn parse_env_filter(level: LevelFilter, filter: &str) -> Result<EnvFilter, LoggerError> {
+    EnvFilter::builder().with_default_directive(level.into()).parse(filter).map_err(|e| {
+        LoggerError::InvalidConfiguration {
+            message: format!("Invalid env filter '{filter}': {e}").into(),
+            context: None,
+        }
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,233 @@
+//! # Rolling File Writer
+//!
+//! A file writer that rolls on the configured [`Rotation`] period and, optionally, whenever the
+//! active file would exceed a byte limit, whichever triggers first. Files are named
+//! `<prefix>.<period>.log` and, after a size-based roll within the same period,
+//! `<prefix>.<period>.<n>.log`. Closed files are either pruned in place or handed to the
+//! background [`Compressor`](crate::compress::Compressor).
+
+use crate::LOG_FILE_SUFFIX;
+use crate::error::LoggerError;
+use chrono::{DateTime, Utc};
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::Sender;
+use tracing_appender::rolling::Rotation;
+
+/// Suffix appended to rotated files once they are gzip-compressed.
+pub(crate) const COMPRESSED_SUFFIX: &str = "gz";
+
+#[derive(Debug)]
+pub(crate) struct RollingWriter {
+    dir: PathBuf,
+    prefix: String,
+    rotation: Rotation,
+    max_size: Option<u64>,
+    max_files: usize,
+    period: String,
+    next_period: Option<DateTime<Utc>>,
+    index: u32,
+    written: u64,
+    file: File,
+    compressor: Option<Sender<PathBuf>>,
+}
+
+impl RollingWriter {
+    pub(crate) fn new(
+        dir: impl Into<PathBuf>,
+        prefix: impl Into<String>,
+        rotation: Rotation,
+        max_size: Option<u64>,
+        max_files: usize,
+        compressor: Option<Sender<PathBuf>>,
+    ) -> Result<Self, LoggerError> {
+        let dir = dir.into();
+        let prefix = prefix.into();
+        let now = Utc::now();
+        let period = period_key(&rotation, now);
+        let next_period = next_period_start(&rotation, now);
+        let index = last_index(&dir, &prefix, &period);
+
+        let path = file_path(&dir, &prefix, &period, index);
+        let file = open(&path).map_err(|e| LoggerError::Internal {
+            message: e.to_string().into(),
+            context: Some(format!("Failed to open log file: {}", path.display()).into()),
+        })?;
+        let written = file.metadata().map_or(0, |m| m.len());
+
+        // Files left uncompressed by a previous run are queued right away.
+        if let Some(compressor) = &compressor {
+            for stale in log_files(&dir, &prefix) {
+                if stale != path && !is_compressed(&stale) {
+                    let _ = compressor.send(stale);
+                }
+            }
+        }
+
+        Ok(Self {
+            dir,
+            prefix,
+            rotation,
+            max_size,
+            max_files,
+            period,
+            next_period,
+            index,
+            written,
+            file,
+            compressor,
+        })
+    }
+
+    fn roll(&mut self, period: String) -> io::Result<()> {
+        let closed = file_path(&self.dir, &self.prefix, &self.period, self.index);
+        if period == self.period {
+            self.index += 1;
+        } else {
+            self.period = period;
+            self.index = 0;
+        }
+
+        self.file.flush()?;
+        self.file = open(&file_path(&self.dir, &self.prefix, &self.period, self.index))?;
+        self.written = 0;
+
+        match &self.compressor {
+            // The compressor prunes once the file is replaced by its `.gz` counterpart.
+            Some(compressor) => {
+                let _ = compressor.send(closed);
+            },
+            None => prune(&self.dir, &self.prefix, self.max_files),
+        }
+        Ok(())
+    }
+
+    fn exceeds_limit(&self, len: usize) -> bool {
+        self.max_size.is_some_and(|max| self.written > 0 && self.written + len as u64 > max)
+    }
+}
+
+impl Write for RollingWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if self.next_period.is_some_and(|next| Utc::now() >= next) {
+            let now = Utc::now();
+            self.next_period = next_period_start(&self.rotation, now);
+            self.roll(period_key(&self.rotation, now))?;
+        } else if self.exceeds_limit(buf.len()) {
+            self.roll(self.period.clone())?;
+        }
+
+        let n = self.file.write(buf)?;
+        self.written += n as u64;
+        Ok(n)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.file.flush()
+    }
+}
+
+/// Deletes the oldest log files (compressed or not) so at most `max_files` remain.
+///
+/// Files are ordered by the period and roll index encoded in their names, so the active file
+/// is always the newest and never removed.
+pub(crate) fn prune(dir: &Path, prefix: &str, max_files: usize) {
+    let mut files: Vec<_> = log_files(dir, prefix)
+        .into_iter()
+        .filter_map(|path| Some((sort_key(&path, prefix)?, path)))
+        .collect();
+
+    if files.len() <= max_files {
+        return;
+    }
+
+    files.sort();
+    for (_, path) in &files[..files.len() - max_files] {
+        let _ = fs::remove_file(path);
+    }
+}
+
+fn open(path: &Path) -> io::Result<File> {
+    OpenOptions::new().create(true).append(true).open(path)
+}
+
+/// Formats the UTC period containing `now` the same way `RollingFileAppender` names its files.
+fn period_key(rotation: &Rotation, now: DateTime<Utc>) -> String {
+    let format = if *rotation == Rotation::MINUTELY {
+        "%Y-%m-%d-%H-%M"
+    } else if *rotation == Rotation::HOURLY {
+        "%Y-%m-%d-%H"
+    } else if *rotation == Rotation::NEVER {
+        return String::new();
+    } else {
+        "%Y-%m-%d"
+    };
+    now.format(format).to_string()
+}
+
+/// Returns the start of the UTC period following the one containing `now`, or `None` if
+/// `rotation` never rolls, so writes compare a timestamp instead of formatting one.
+fn next_period_start(rotation: &Rotation, now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+    let length: i64 = if *rotation == Rotation::MINUTELY {
+        60
+    } else if *rotation == Rotation::HOURLY {
+        3600
+    } else if *rotation == Rotation::NEVER {
+        return None;
+    } else {
+        86_400
+    };
+    let secs = now.timestamp();
+    DateTime::from_timestamp(secs - secs.rem_euclid(length) + length, 0)
+}
+
+fn file_path(dir: &Path, prefix: &str, period: &str, index: u32) -> PathBuf {
+    let mut name = String::from(prefix);
+    if !period.is_empty() {
+        name.push('.');
+        name.push_str(period);
+    }
+    if index > 0 {
+        name.push('.');
+        name.push_str(&index.to_string());
+    }
+    name.push('.');
+    name.push_str(LOG_FILE_SUFFIX);
+    dir.join(name)
+}
+
+/// Returns the highest existing roll index for `period`, so restarts keep appending to it.
+fn last_index(dir: &Path, prefix: &str, period: &str) -> u32 {
+    (1..).take_while(|index| file_path(dir, prefix, period, *index).exists()).last().unwrap_or(0)
+}
+
+fn log_files(dir: &Path, prefix: &str) -> Vec<PathBuf> {
+    let Ok(entries) = fs::read_dir(dir) else { return Vec::new() };
+    entries
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| sort_key(path, prefix).is_some())
+        .collect()
+}
+
+fn is_compressed(path: &Path) -> bool {
+    path.extension().is_some_and(|ext| ext == COMPRESSED_SUFFIX)
+}
+
+/// Parses `<prefix>[.<period>][.<n>].log[.gz]` into `(period, n)`.
+fn sort_key(path: &Path, prefix: &str) -> Option<(String, u32)> {
+    let name = path.file_name()?.to_str()?;
+    let name = name.strip_suffix(&format!(".{COMPRESSED_SUFFIX}")).unwrap_or(name);
+    let stem = name.strip_prefix(prefix)?.strip_suffix(&format!(".{LOG_FILE_SUFFIX}"))?;
+
+    if stem.is_empty() {
+        return Some((String::new(), 0));
+    }
+
+    let stem = stem.strip_prefix('.')?;
+    match stem.rsplit_once('.') {
+        Some((period, index)) => Some((period.to_owned(), index.parse().ok()?)),
+        None => Some(stem.parse().map_or_else(|_| (stem.to_owned(), 0), |n| (String::new(), n))),
+    }
+}
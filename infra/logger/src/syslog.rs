@@ -0,0 +1,343 @@
+//! # Syslog Output
+//!
+//! Ships records to a syslog daemon (e.g., rsyslog) formatted as RFC 5424 messages. The fmt
+//! layer renders the message body; [`SyslogWriter`] adds the header, picks the severity from
+//! the event level, and sends one datagram or octet-counted TCP frame (RFC 6587) per record.
+//!
+//! TCP frames are handed to a sender thread over a bounded queue, so a slow or unreachable
+//! daemon never stalls the thread that logs: the sender connects and writes with timeouts,
+//! backs off between reconnect attempts, and records are dropped while the queue is full or
+//! the daemon is down.
+
+use crate::error::LoggerError;
+use chrono::{SecondsFormat, Utc};
+use parking_lot::Mutex;
+use std::borrow::Cow;
+use std::fmt;
+use std::io::{self, Write};
+use std::net::{Ipv4Addr, Ipv6Addr, SocketAddr, TcpStream, ToSocketAddrs, UdpSocket};
+#[cfg(unix)]
+use std::os::unix::net::UnixDatagram;
+use std::sync::mpsc::{Receiver, SyncSender, sync_channel};
+use std::time::{Duration, Instant};
+use tracing::{Level, Metadata};
+use tracing_subscriber::fmt::MakeWriter;
+
+const NIL: &str = "-";
+const MAX_APP_NAME_LEN: usize = 48;
+const MAX_HOSTNAME_LEN: usize = 255;
+/// Records queued for the TCP sender thread before new ones are dropped.
+const TCP_QUEUE_CAPACITY: usize = 1024;
+/// Upper bound for establishing a TCP connection to the daemon.
+const TCP_CONNECT_TIMEOUT: Duration = Duration::from_secs(1);
+/// Upper bound for writing one frame; a timed-out connection is discarded.
+const TCP_WRITE_TIMEOUT: Duration = Duration::from_secs(1);
+/// Delay after the first failed reconnect, doubled on each further failure.
+const TCP_MIN_BACKOFF: Duration = Duration::from_millis(500);
+/// Longest delay between reconnect attempts.
+const TCP_MAX_BACKOFF: Duration = Duration::from_secs(30);
+
+/// Syslog facility codes as defined by RFC 5424.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[repr(u8)]
+pub enum Facility {
+    Kern = 0,
+    #[default]
+    User = 1,
+    Mail = 2,
+    Daemon = 3,
+    Auth = 4,
+    Syslog = 5,
+    Lpr = 6,
+    News = 7,
+    Uucp = 8,
+    Cron = 9,
+    AuthPriv = 10,
+    Ftp = 11,
+    Local0 = 16,
+    Local1 = 17,
+    Local2 = 18,
+    Local3 = 19,
+    Local4 = 20,
+    Local5 = 21,
+    Local6 = 22,
+    Local7 = 23,
+}
+
+/// Transport used to reach the syslog daemon.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SyslogTransport {
+    /// One datagram per record.
+    #[default]
+    Udp,
+    /// Octet-counted frames over a persistent connection, written by a sender thread that
+    /// reconnects with backoff.
+    Tcp,
+    /// Datagrams to a local socket such as `/dev/log`.
+    #[cfg(unix)]
+    Unix,
+}
+
+impl fmt::Display for SyslogTransport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Udp => f.write_str("UDP"),
+            Self::Tcp => f.write_str("TCP"),
+            #[cfg(unix)]
+            Self::Unix => f.write_str("unix socket"),
+        }
+    }
+}
+
+/// Configuration for [`LoggerBuilder::syslog`](crate::LoggerBuilder::syslog).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SyslogConfig {
+    /// Facility encoded into each record's priority.
+    pub facility: Facility,
+    /// Transport used to reach the daemon.
+    pub transport: SyslogTransport,
+    /// `host:port` for UDP/TCP, or a socket path for [`SyslogTransport::Unix`].
+    pub address: String,
+}
+
+impl Default for SyslogConfig {
+    fn default() -> Self {
+        Self {
+            facility: Facility::default(),
+            transport: SyslogTransport::default(),
+            address: "127.0.0.1:514".to_owned(),
+        }
+    }
+}
+
+#[derive(Debug)]
+enum Connection {
+    Udp(UdpSocket),
+    /// Queue of the [`TcpSender`] thread, which exits once this end is dropped.
+    Tcp(SyncSender<String>),
+    #[cfg(unix)]
+    Unix(UnixDatagram),
+}
+
+/// [`MakeWriter`] that turns each formatted record into an RFC 5424 message.
+#[derive(Debug)]
+pub(crate) struct SyslogWriter {
+    facility: Facility,
+    hostname: String,
+    app_name: String,
+    connection: Mutex<Connection>,
+}
+
+impl SyslogWriter {
+    /// Connects to the configured daemon.
+    ///
+    /// # Errors
+    /// Returns [`LoggerError::InvalidConfiguration`] if the socket cannot be opened.
+    pub(crate) fn connect(config: SyslogConfig, app_name: &str) -> Result<Self, LoggerError> {
+        let connection = match config.transport {
+            SyslogTransport::Udp => connect_udp(&config.address).map(Connection::Udp),
+            SyslogTransport::Tcp => TcpSender::spawn(&config.address).map(Connection::Tcp),
+            #[cfg(unix)]
+            SyslogTransport::Unix => UnixDatagram::unbound()
+                .and_then(|socket| socket.connect(&config.address).map(|()| socket))
+                .map(Connection::Unix),
+        }
+        .map_err(|e| LoggerError::InvalidConfiguration {
+            message: e.to_string().into(),
+            context: Some(
+                format!(
+                    "Failed to connect to syslog over {} at {}",
+                    config.transport, config.address
+                )
+                .into(),
+            ),
+        })?;
+
+        Ok(Self {
+            facility: config.facility,
+            hostname: header_value(&hostname(), MAX_HOSTNAME_LEN).into_owned(),
+            app_name: header_value(app_name, MAX_APP_NAME_LEN).into_owned(),
+            connection: Mutex::new(connection),
+        })
+    }
+
+    fn send(&self, severity: u8, body: &[u8]) {
+        let body = String::from_utf8_lossy(body);
+        let message = format!(
+            "<{}>1 {} {} {} {} {NIL} {NIL} {}",
+            (self.facility as u8) * 8 + severity,
+            Utc::now().to_rfc3339_opts(SecondsFormat::Micros, true),
+            self.hostname,
+            self.app_name,
+            std::process::id(),
+            body.trim_end(),
+        );
+
+        // A logger has nowhere to report its own delivery failures; records are dropped.
+        let mut connection = self.connection.lock();
+        let _ = match &mut *connection {
+            Connection::Udp(socket) => socket.send(message.as_bytes()).map(|_| ()),
+            Connection::Tcp(queue) => {
+                let _ = queue.try_send(message);
+                Ok(())
+            },
+            #[cfg(unix)]
+            Connection::Unix(socket) => socket.send(message.as_bytes()).map(|_| ()),
+        };
+    }
+}
+
+/// Owns the TCP connection on a dedicated thread and delivers queued records over it.
+#[derive(Debug)]
+struct TcpSender {
+    target: SocketAddr,
+    stream: Option<TcpStream>,
+    backoff: Duration,
+    retry_at: Option<Instant>,
+}
+
+impl TcpSender {
+    /// Connects to `address` once, so an unavailable daemon fails initialization, then hands
+    /// the connection to a sender thread and returns its queue.
+    fn spawn(address: &str) -> io::Result<SyncSender<String>> {
+        let target = resolve(address)?;
+        let stream = open_tcp(target)?;
+        let (queue, records) = sync_channel(TCP_QUEUE_CAPACITY);
+        let sender =
+            Self { target, stream: Some(stream), backoff: TCP_MIN_BACKOFF, retry_at: None };
+
+        std::thread::Builder::new()
+            .name("mhub-syslog-tcp".to_owned())
+            .spawn(move || sender.run(&records))?;
+        Ok(queue)
+    }
+
+    fn run(mut self, records: &Receiver<String>) {
+        for message in records {
+            self.deliver(&message);
+        }
+    }
+
+    /// Writes one frame, reconnecting if the connection broke. Records arriving while a
+    /// reconnect is backing off are dropped.
+    fn deliver(&mut self, message: &str) {
+        let frame = format!("{} {message}", message.len());
+        if let Some(stream) = &mut self.stream {
+            if stream.write_all(frame.as_bytes()).is_ok() {
+                return;
+            }
+            self.stream = None;
+        }
+
+        if self.retry_at.is_some_and(|retry_at| Instant::now() < retry_at) {
+            return;
+        }
+        match open_tcp(self.target)
+            .and_then(|mut stream| stream.write_all(frame.as_bytes()).map(|()| stream))
+        {
+            Ok(stream) => {
+                self.stream = Some(stream);
+                self.backoff = TCP_MIN_BACKOFF;
+                self.retry_at = None;
+            },
+            Err(_) => {
+                self.retry_at = Some(Instant::now() + self.backoff);
+                self.backoff = self.backoff.saturating_mul(2).min(TCP_MAX_BACKOFF);
+            },
+        }
+    }
+}
+
+fn open_tcp(target: SocketAddr) -> io::Result<TcpStream> {
+    let stream = TcpStream::connect_timeout(&target, TCP_CONNECT_TIMEOUT)?;
+    stream.set_write_timeout(Some(TCP_WRITE_TIMEOUT))?;
+    Ok(stream)
+}
+
+impl<'a> MakeWriter<'a> for SyslogWriter {
+    type Writer = SyslogRecord<'a>;
+
+    fn make_writer(&'a self) -> Self::Writer {
+        SyslogRecord { writer: self, severity: severity(Level::INFO), buf: Vec::new() }
+    }
+
+    fn make_writer_for(&'a self, meta: &Metadata<'_>) -> Self::Writer {
+        SyslogRecord { writer: self, severity: severity(*meta.level()), buf: Vec::new() }
+    }
+}
+
+/// Buffers one formatted record and sends it when dropped.
+#[derive(Debug)]
+pub(crate) struct SyslogRecord<'a> {
+    writer: &'a SyslogWriter,
+    severity: u8,
+    buf: Vec<u8>,
+}
+
+impl Write for SyslogRecord<'_> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.buf.extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+impl Drop for SyslogRecord<'_> {
+    fn drop(&mut self) {
+        if !self.buf.is_empty() {
+            self.writer.send(self.severity, &self.buf);
+        }
+    }
+}
+
+/// Maps a tracing level onto an RFC 5424 severity.
+fn severity(level: Level) -> u8 {
+    match level {
+        Level::ERROR => 3,
+        Level::WARN => 4,
+        Level::INFO => 6,
+        _ => 7,
+    }
+}
+
+/// Resolves `address` once, up front, so reconnects never wait on DNS.
+fn resolve(address: &str) -> io::Result<SocketAddr> {
+    address.to_socket_addrs()?.next().ok_or_else(|| {
+        io::Error::new(io::ErrorKind::AddrNotAvailable, "address resolved to nothing")
+    })
+}
+
+fn connect_udp(address: &str) -> io::Result<UdpSocket> {
+    let target = resolve(address)?;
+    let socket = if target.is_ipv6() {
+        UdpSocket::bind((Ipv6Addr::UNSPECIFIED, 0))?
+    } else {
+        UdpSocket::bind((Ipv4Addr::UNSPECIFIED, 0))?
+    };
+    socket.connect(target)?;
+    Ok(socket)
+}
+
+fn hostname() -> String {
+    std::env::var("HOSTNAME")
+        .ok()
+        .or_else(|| std::fs::read_to_string("/etc/hostname").ok())
+        .map(|name| name.trim().to_owned())
+        .filter(|name| !name.is_empty())
+        .unwrap_or_else(|| NIL.to_owned())
+}
+
+/// Restricts a header field to printable, non-space ASCII as required by RFC 5424.
+fn header_value(value: &str, max_len: usize) -> Cow<'_, str> {
+    let valid = |c: char| c.is_ascii_graphic();
+    if value.is_empty() {
+        return Cow::Borrowed(NIL);
+    }
+    if value.len() <= max_len && value.chars().all(valid) {
+        return Cow::Borrowed(value);
+    }
+    Cow::Owned(value.chars().map(|c| if valid(c) { c } else { '_' }).take(max_len).collect())
+}
@@ -0,0 +1,45 @@
+use flate2::read::GzDecoder;
+use mhub_logger::{LevelFilter, Logger, Rotation};
+use std::fs::{self, File};
+use std::io::Read;
+use tempfile::tempdir;
+
+#[test]
+fn compress_rotated_gzips_closed_files() -> Result<(), Box<dyn std::error::Error>> {
+    let tmp_dir = tempdir()?;
+    let log_dir = tmp_dir.path().join("logs");
+
+    let logger = Logger::builder()
+        .name("integration-compress-rotated")
+        .console(false)
+        .path(&log_dir)
+        .rotation(Rotation::NEVER)
+        .max_file_size(1024)
+        .max_files(4)
+        .compress_rotated(true)
+        .level(LevelFilter::INFO)
+        .init()?;
+
+    let line = "x".repeat(100);
+    for i in 0..50 {
+        tracing::info!(i, "{line}");
+    }
+
+    logger.flush();
+    drop(logger);
+
+    let files: Vec<_> = fs::read_dir(&log_dir)?.flatten().map(|entry| entry.path()).collect();
+    let plain = files.iter().filter(|path| path.extension().is_some_and(|ext| ext == "log"));
+    let compressed: Vec<_> =
+        files.iter().filter(|path| path.extension().is_some_and(|ext| ext == "gz")).collect();
+
+    assert_eq!(plain.count(), 1, "only the active file should stay uncompressed");
+    assert!(!compressed.is_empty(), "rotated files should be compressed");
+    assert!(files.len() <= 4, "compressed files should count towards max_files");
+
+    let mut contents = String::new();
+    GzDecoder::new(File::open(compressed[0])?).read_to_string(&mut contents)?;
+    assert!(contents.contains(&line));
+
+    Ok(())
+}
@@ -0,0 +1,41 @@
+use mhub_logger::{LevelFilter, Logger};
+use parking_lot::Mutex;
+use std::io::{self, Write};
+use std::sync::Arc;
+
+#[derive(Clone, Default)]
+struct SharedBuffer(Arc<Mutex<Vec<u8>>>);
+
+impl Write for SharedBuffer {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.lock().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[test]
+fn custom_writer_receives_json_records() {
+    let buffer = SharedBuffer::default();
+    let sink = buffer.clone();
+
+    let _logger = Logger::builder()
+        .name("integration-custom-writer")
+        .console(false)
+        .level(LevelFilter::INFO)
+        .json()
+        .writer(move || sink.clone())
+        .init()
+        .expect("logger should initialize");
+
+    tracing::info!(user = "ada", "captured in memory");
+
+    let output = String::from_utf8(buffer.0.lock().clone()).expect("output should be UTF-8");
+    let line = output.lines().find(|line| line.contains("captured in memory"));
+    let line = line.expect("logged line should reach the custom writer");
+    assert!(line.starts_with('{'), "JSON mode should apply to custom writers: {line}");
+    assert!(line.contains("\"user\":\"ada\""), "fields should be captured: {line}");
+}
@@ -0,0 +1,30 @@
+use mhub_logger::{LevelFilter, Logger};
+use std::fs;
+use tempfile::tempdir;
+
+#[test]
+fn flush_drains_pending_records() -> Result<(), Box<dyn std::error::Error>> {
+    let tmp_dir = tempdir()?;
+    let log_dir = tmp_dir.path().join("logs");
+
+    let logger = Logger::builder()
+        .name("integration-flush")
+        .console(false)
+        .path(&log_dir)
+        .level(LevelFilter::INFO)
+        .init()?;
+
+    tracing::info!("record written before flush");
+    logger.flush();
+
+    let log_file = fs::read_dir(&log_dir)?
+        .flatten()
+        .map(|entry| entry.path())
+        .find(|path| path.extension().and_then(|ext| ext.to_str()) == Some("log"))
+        .expect("log file should be created");
+
+    let contents = fs::read_to_string(log_file)?;
+    assert!(contents.contains("record written before flush"));
+
+    Ok(())
+}
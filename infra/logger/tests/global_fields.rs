@@ -0,0 +1,56 @@
+use mhub_logger::{LevelFilter, Logger};
+use parking_lot::Mutex;
+use std::io::{self, Write};
+use std::sync::Arc;
+
+#[derive(Clone, Default)]
+struct SharedBuffer(Arc<Mutex<Vec<u8>>>);
+
+impl Write for SharedBuffer {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.lock().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[test]
+fn global_fields_are_added_to_json_records() {
+    let buffer = SharedBuffer::default();
+    let sink = buffer.clone();
+
+    let _logger = Logger::builder()
+        .name("integration-global-fields")
+        .console(false)
+        .level(LevelFilter::INFO)
+        .json()
+        .with_global_fields([
+            ("service".to_owned(), "billing".to_owned()),
+            ("version".to_owned(), "1.2.3".to_owned()),
+            ("instance_id".to_owned(), "node-1".to_owned()),
+        ])
+        .writer(move || sink.clone())
+        .init()
+        .expect("logger should initialize");
+
+    tracing::info!("bare event");
+    tracing::info!(service = "override", "event with own service");
+
+    let output = String::from_utf8(buffer.0.lock().clone()).expect("output should be UTF-8");
+    let record = |needle: &str| -> serde_json::Value {
+        let line = output.lines().find(|line| line.contains(needle)).expect("line should exist");
+        serde_json::from_str(line).expect("line should be valid JSON")
+    };
+
+    let bare = record("bare event");
+    assert_eq!(bare["fields"]["service"], "billing");
+    assert_eq!(bare["fields"]["version"], "1.2.3");
+    assert_eq!(bare["fields"]["instance_id"], "node-1");
+
+    let own = record("event with own service");
+    assert_eq!(own["fields"]["service"], "override", "per-event values should win");
+    assert_eq!(own["fields"]["version"], "1.2.3");
+}
@@ -0,0 +1,23 @@
+use mhub_logger::{LevelFilter, Logger, LoggerError};
+use tracing::Level;
+
+#[test]
+fn set_level_and_filter_change_verbosity_at_runtime() {
+    let logger = Logger::builder()
+        .name("integration-reload-level")
+        .level(LevelFilter::INFO)
+        .init()
+        .expect("logger should initialize");
+
+    assert!(!tracing::enabled!(Level::DEBUG), "DEBUG should be filtered at INFO");
+
+    logger.set_level(LevelFilter::DEBUG).expect("level reload should succeed");
+    assert!(tracing::enabled!(Level::DEBUG), "DEBUG should pass after raising the level");
+
+    logger.set_filter("warn").expect("filter reload should succeed");
+    assert!(!tracing::enabled!(Level::INFO), "INFO should be filtered at WARN");
+
+    let err = logger.set_filter("mhub=loud").expect_err("invalid filter should be rejected");
+    assert!(matches!(err, LoggerError::InvalidConfiguration { .. }));
+    assert!(!tracing::enabled!(Level::INFO), "a rejected filter must not replace the active one");
+}
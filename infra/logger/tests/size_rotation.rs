@@ -0,0 +1,41 @@
+use mhub_logger::{LevelFilter, Logger, Rotation};
+use std::fs;
+use tempfile::tempdir;
+
+#[test]
+fn size_rotation_rolls_to_new_file() -> Result<(), Box<dyn std::error::Error>> {
+    let tmp_dir = tempdir()?;
+    let log_dir = tmp_dir.path().join("logs");
+
+    let logger = Logger::builder()
+        .name("integration-size-rotation")
+        .console(false)
+        .path(&log_dir)
+        .rotation(Rotation::NEVER)
+        .max_file_size(1024)
+        .max_files(3)
+        .level(LevelFilter::INFO)
+        .init()?;
+
+    let line = "x".repeat(100);
+    for i in 0..50 {
+        tracing::info!(i, "{line}");
+    }
+
+    logger.flush();
+    drop(logger);
+
+    let files: Vec<_> = fs::read_dir(&log_dir)?
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().and_then(|ext| ext.to_str()) == Some("log"))
+        .collect();
+
+    assert!(files.len() >= 2, "size limit should force at least one roll");
+    assert!(files.len() <= 3, "rolled files should respect max_files");
+    for file in &files {
+        assert!(fs::metadata(file)?.len() <= 1024, "{} exceeds the size limit", file.display());
+    }
+
+    Ok(())
+}
@@ -0,0 +1,53 @@
+#![cfg(feature = "syslog")]
+
+use mhub_logger::{Facility, LevelFilter, Logger, LoggerError, SyslogConfig, SyslogTransport};
+use std::net::{TcpListener, UdpSocket};
+use std::time::Duration;
+
+#[test]
+fn syslog_sends_rfc5424_records() -> Result<(), Box<dyn std::error::Error>> {
+    let daemon = UdpSocket::bind("127.0.0.1:0")?;
+    daemon.set_read_timeout(Some(Duration::from_secs(2)))?;
+
+    let _logger = Logger::builder()
+        .name("integration-syslog")
+        .console(false)
+        .level(LevelFilter::INFO)
+        .syslog(SyslogConfig {
+            facility: Facility::Local0,
+            transport: SyslogTransport::Udp,
+            address: daemon.local_addr()?.to_string(),
+        })
+        .init()?;
+
+    tracing::warn!("hello syslog");
+
+    let mut buf = [0u8; 2048];
+    let len = daemon.recv(&mut buf)?;
+    let record = std::str::from_utf8(&buf[..len])?;
+
+    // Local0 (16) * 8 + Warning (4).
+    assert!(record.starts_with("<132>1 "), "unexpected header: {record}");
+    assert!(record.contains(" integration-syslog "), "app name missing: {record}");
+    assert!(record.ends_with("hello syslog"), "unexpected body: {record}");
+
+    Ok(())
+}
+
+#[test]
+fn syslog_unavailable_socket_is_rejected() -> Result<(), Box<dyn std::error::Error>> {
+    let address = TcpListener::bind("127.0.0.1:0")?.local_addr()?.to_string();
+
+    let result = Logger::builder()
+        .name("integration-syslog-unavailable")
+        .console(false)
+        .syslog(SyslogConfig {
+            transport: SyslogTransport::Tcp,
+            address,
+            ..SyslogConfig::default()
+        })
+        .init();
+
+    assert!(matches!(result, Err(LoggerError::InvalidConfiguration { context: Some(_), .. })));
+    Ok(())
+}
@@ -0,0 +1,44 @@
+#![cfg(feature = "syslog")]
+
+use mhub_logger::{LevelFilter, Logger, SyslogConfig, SyslogTransport};
+use std::io::Read;
+use std::net::TcpListener;
+use std::time::{Duration, Instant};
+
+#[test]
+fn syslog_tcp_never_blocks_on_a_dead_daemon() -> Result<(), Box<dyn std::error::Error>> {
+    let daemon = TcpListener::bind("127.0.0.1:0")?;
+
+    let _logger = Logger::builder()
+        .name("integration-syslog-tcp")
+        .console(false)
+        .level(LevelFilter::INFO)
+        .syslog(SyslogConfig {
+            transport: SyslogTransport::Tcp,
+            address: daemon.local_addr()?.to_string(),
+            ..SyslogConfig::default()
+        })
+        .init()?;
+
+    let (mut connection, _) = daemon.accept()?;
+    connection.set_read_timeout(Some(Duration::from_secs(2)))?;
+    tracing::info!("hello tcp");
+
+    let mut buf = [0u8; 2048];
+    let len = connection.read(&mut buf)?;
+    let frame = std::str::from_utf8(&buf[..len])?;
+    let (length, record) = frame.split_once(' ').expect("octet-counted frame");
+    assert_eq!(length.parse::<usize>()?, record.len(), "unexpected frame: {frame}");
+    assert!(record.ends_with("hello tcp"), "unexpected body: {record}");
+
+    drop(connection);
+    drop(daemon);
+
+    let started = Instant::now();
+    for i in 0..5_000 {
+        tracing::info!(i, "daemon is gone");
+    }
+    assert!(started.elapsed() < Duration::from_secs(1), "logging blocked on the dead daemon");
+
+    Ok(())
+}
@@ -0,0 +1,14 @@
+//! Runs `main` on a runtime built from an explicit [`RuntimeConfig`] instead of a preset.
+
+use mhub_runtime::RuntimeConfig;
+
+fn runtime_config() -> RuntimeConfig {
+    RuntimeConfig::default().with_worker_threads(2).with_thread_name("custom-worker")
+}
+
+#[mhub_runtime::main(config = runtime_config())]
+async fn main() -> mhub_runtime::Result<()> {
+    let answer = tokio::spawn(async { 6 * 7 }).await?;
+    assert_eq!(answer, 42);
+    Ok(())
+}
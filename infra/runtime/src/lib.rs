@@ -8,6 +8,7 @@
 //! ## Profiles
 //! * **High Performance**: Optimized for server-side processing with larger stacks and longer keep-alive.
 //! * **Memory Efficient**: Optimized for client-side or resource-constrained environments.
+//! * **Current Thread**: Single-threaded scheduler for CLI tools and tests with light async I/O.
 //! * **Global**: A shared, lazy-initialized singleton runtime for the entire process.
 //!
 //! ## Example
@@ -24,8 +25,13 @@ pub use anyhow::Result;
 pub use mhub_derive::main;
 
 use anyhow::anyhow;
-use std::{sync::OnceLock, thread::available_parallelism, time::Duration};
-use tokio::runtime::{Builder, Runtime};
+use std::{
+    fmt,
+    sync::{Arc, OnceLock},
+    thread::available_parallelism,
+    time::Duration,
+};
+use tokio::runtime::{Builder, Handle, Runtime};
 use tracing::{debug, info};
 
 /// The default number of worker threads if detection fails.
@@ -36,6 +42,10 @@ const DEFAULT_STACK_SIZE: usize = 3 * 1024 * 1024;
 const MIN_STACK_SIZE: usize = 1024 * 1024;
 /// Maximum allowed stack size (16 `MiB`).
 const MAX_STACK_SIZE: usize = 16 * 1024 * 1024;
+/// The default upper bound for the blocking thread pool (Tokio's own default).
+const DEFAULT_MAX_BLOCKING_THREADS: usize = 512;
+/// Maximum allowed size of the blocking thread pool.
+const MAX_BLOCKING_THREADS: usize = 4096;
 /// How long an idle thread stays alive.
 const THREAD_KEEP_ALIVE: Duration = Duration::from_secs(60);
 
@@ -69,28 +79,69 @@ fn normalize_config(config: &RuntimeConfig) -> RuntimeConfig {
 
     RuntimeConfig {
         worker_threads: config.worker_threads.clamp(1, 1024),
+        max_blocking_threads: config.max_blocking_threads.clamp(1, MAX_BLOCKING_THREADS),
         stack_size: validate_stack_size(config.stack_size),
         thread_name,
         thread_keep_alive: config.thread_keep_alive,
+        scheduler: config.scheduler,
+        on_thread_start: config.on_thread_start.clone(),
+        on_thread_stop: config.on_thread_stop.clone(),
     }
 }
 
+/// A callback invoked on runtime threads, see [`RuntimeConfig::on_thread_start`].
+pub type ThreadCallback = Arc<dyn Fn() + Send + Sync>;
+
+/// The Tokio scheduler flavor used by a runtime.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Scheduler {
+    /// Work-stealing scheduler spread across `worker_threads` threads.
+    #[default]
+    MultiThread,
+    /// All tasks run on the thread that drives the runtime; task ordering is deterministic
+    /// and no worker threads are spawned. `worker_threads` is ignored.
+    CurrentThread,
+}
+
 /// Configuration for the Tokio runtime.
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 pub struct RuntimeConfig {
+    pub scheduler: Scheduler,
     pub worker_threads: usize,
+    pub max_blocking_threads: usize,
     pub stack_size: usize,
     pub thread_name: String,
     pub thread_keep_alive: Duration,
+    pub on_thread_start: Option<ThreadCallback>,
+    pub on_thread_stop: Option<ThreadCallback>,
+}
+
+impl fmt::Debug for RuntimeConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RuntimeConfig")
+            .field("scheduler", &self.scheduler)
+            .field("worker_threads", &self.worker_threads)
+            .field("max_blocking_threads", &self.max_blocking_threads)
+            .field("stack_size", &self.stack_size)
+            .field("thread_name", &self.thread_name)
+            .field("thread_keep_alive", &self.thread_keep_alive)
+            .field("on_thread_start", &self.on_thread_start.is_some())
+            .field("on_thread_stop", &self.on_thread_stop.is_some())
+            .finish()
+    }
 }
 
 impl Default for RuntimeConfig {
     fn default() -> Self {
         Self {
             worker_threads: get_worker_threads(),
+            max_blocking_threads: DEFAULT_MAX_BLOCKING_THREADS,
             stack_size: DEFAULT_STACK_SIZE,
             thread_name: "thread-worker".to_owned(),
             thread_keep_alive: THREAD_KEEP_ALIVE,
+            scheduler: Scheduler::MultiThread,
+            on_thread_start: None,
+            on_thread_stop: None,
         }
     }
 }
@@ -101,9 +152,13 @@ impl RuntimeConfig {
     pub fn high_performance() -> Self {
         Self {
             worker_threads: get_worker_threads(),
+            max_blocking_threads: 1024,
             stack_size: 4 * 1024 * 1024,
             thread_name: "thread-hp".to_owned(),
             thread_keep_alive: Duration::from_secs(300),
+            scheduler: Scheduler::MultiThread,
+            on_thread_start: None,
+            on_thread_stop: None,
         }
     }
 
@@ -112,9 +167,28 @@ impl RuntimeConfig {
     pub fn memory_efficient() -> Self {
         Self {
             worker_threads: (get_worker_threads() / 2).max(1),
+            max_blocking_threads: 128,
             stack_size: 2 * 1024 * 1024,
             thread_name: "thread-mem".to_owned(),
             thread_keep_alive: Duration::from_secs(30),
+            scheduler: Scheduler::MultiThread,
+            on_thread_start: None,
+            on_thread_stop: None,
+        }
+    }
+
+    /// Preset for CLI tools and tests that run light async I/O on the calling thread.
+    #[must_use = "Use this configuration for single-threaded tools and tests"]
+    pub fn current_thread() -> Self {
+        Self {
+            worker_threads: 1,
+            max_blocking_threads: 64,
+            stack_size: DEFAULT_STACK_SIZE,
+            thread_name: "thread-ct".to_owned(),
+            thread_keep_alive: THREAD_KEEP_ALIVE,
+            scheduler: Scheduler::CurrentThread,
+            on_thread_start: None,
+            on_thread_stop: None,
         }
     }
 
@@ -124,6 +198,13 @@ impl RuntimeConfig {
         self
     }
 
+    /// Caps the blocking thread pool used by `spawn_blocking` and blocking file I/O.
+    #[must_use = "Customize the size of the blocking thread pool"]
+    pub fn with_max_blocking_threads(mut self, threads: usize) -> Self {
+        self.max_blocking_threads = threads.clamp(1, MAX_BLOCKING_THREADS);
+        self
+    }
+
     #[must_use = "Customize the stack size for worker threads"]
     pub fn with_stack_size(mut self, size: usize) -> Self {
         self.stack_size = validate_stack_size(size);
@@ -142,6 +223,26 @@ impl RuntimeConfig {
         self.thread_keep_alive = keep_alive;
         self
     }
+
+    /// Registers a callback that runs on every runtime thread right after it starts.
+    ///
+    /// This covers each worker thread and every thread of the blocking pool, so it suits
+    /// CPU affinity or thread-local allocator setup. With [`Scheduler::CurrentThread`] the
+    /// thread driving the runtime is not spawned by Tokio and does not run the callback.
+    #[must_use = "Customize per-thread initialization"]
+    pub fn on_thread_start(mut self, callback: ThreadCallback) -> Self {
+        self.on_thread_start = Some(callback);
+        self
+    }
+
+    /// Registers a callback that runs on every runtime thread right before it exits.
+    ///
+    /// Like [`RuntimeConfig::on_thread_start`], it applies to worker and blocking threads.
+    #[must_use = "Customize per-thread teardown"]
+    pub fn on_thread_stop(mut self, callback: ThreadCallback) -> Self {
+        self.on_thread_stop = Some(callback);
+        self
+    }
 }
 
 /// Creates a new Tokio runtime with a custom stack size.
@@ -194,11 +295,11 @@ pub fn build_runtime(stack_size: usize) -> Result<Runtime> {
 /// Creates a new Tokio runtime with a custom configuration.
 ///
 /// This function provides full control over the runtime configuration by accepting
-/// a [`RuntimeConfig`] instance. It creates a multithreaded Tokio runtime with all
-/// features enabled (I/O, timers, etc.) and applies the specified configuration settings.
+/// a [`RuntimeConfig`] instance. It creates a Tokio runtime with all features enabled
+/// (I/O, timers, etc.) and applies the specified configuration settings.
 ///
 /// The runtime is built with:
-/// * Multi-threaded scheduler for optimal task distribution
+/// * The scheduler selected by [`RuntimeConfig::scheduler`] (multi-threaded by default)
 /// * All Tokio features enabled (I/O, time, net, signal, etc.)
 /// * Configurable worker threads, stack size, thread naming, and keep-alive duration
 /// * Debug logging of the configuration for troubleshooting
@@ -251,13 +352,27 @@ pub fn build_runtime_with_config(config: &RuntimeConfig) -> Result<Runtime> {
     let config = normalize_config(config);
     debug!(config = ?config, "Building tokio runtime");
 
-    let mut builder = Builder::new_multi_thread();
+    let mut builder = match config.scheduler {
+        Scheduler::MultiThread => {
+            let mut builder = Builder::new_multi_thread();
+            builder.worker_threads(config.worker_threads);
+            builder
+        },
+        Scheduler::CurrentThread => Builder::new_current_thread(),
+    };
     builder
-        .worker_threads(config.worker_threads)
+        .max_blocking_threads(config.max_blocking_threads)
         .thread_name(&config.thread_name)
         .thread_stack_size(config.stack_size)
         .thread_keep_alive(config.thread_keep_alive);
 
+    if let Some(callback) = config.on_thread_start {
+        builder.on_thread_start(move || callback());
+    }
+    if let Some(callback) = config.on_thread_stop {
+        builder.on_thread_stop(move || callback());
+    }
+
     builder.enable_all();
 
     builder.build().map_err(|e| anyhow!("Failed to initialize runtime: {e}"))
@@ -272,6 +387,7 @@ pub fn build_runtime_with_config(config: &RuntimeConfig) -> Result<Runtime> {
 ///
 /// The default configuration includes:
 /// * Worker threads: Auto-detected based on available parallelism
+/// * Blocking threads: up to 512
 /// * Stack size: 3 `MiB` per thread
 /// * Thread name: "thread-worker"
 /// * Keep-alive: 60 seconds for idle threads
@@ -312,6 +428,103 @@ pub fn build_service_runtime() -> Result<Runtime> {
     build_runtime_with_config(&config)
 }
 
+/// Creates a runtime from `config` together with a [`RuntimeHandle`] for observing it.
+///
+/// # Errors
+///
+/// Returns an [`anyhow::Error`] under the same conditions as [`build_runtime_with_config()`].
+pub fn build_runtime_with_handle(config: &RuntimeConfig) -> Result<(Runtime, RuntimeHandle)> {
+    let runtime = build_runtime_with_config(config)?;
+    let handle = RuntimeHandle::from(&runtime);
+    Ok((runtime, handle))
+}
+
+/// A cheap, cloneable handle for observing a running Tokio runtime.
+#[derive(Debug, Clone)]
+pub struct RuntimeHandle {
+    inner: Handle,
+}
+
+impl RuntimeHandle {
+    /// Returns a handle to the runtime driving the current task.
+    ///
+    /// # Panics
+    ///
+    /// Panics if called outside the context of a Tokio runtime.
+    #[must_use]
+    pub fn current() -> Self {
+        Self { inner: Handle::current() }
+    }
+
+    /// Returns the underlying Tokio handle, e.g., to spawn tasks.
+    #[must_use]
+    pub const fn inner(&self) -> &Handle {
+        &self.inner
+    }
+
+    /// Takes a point-in-time snapshot of the runtime's scheduler metrics.
+    #[must_use]
+    pub fn metrics(&self) -> RuntimeMetrics {
+        let metrics = self.inner.metrics();
+        RuntimeMetrics {
+            workers: metrics.num_workers(),
+            alive_tasks: metrics.num_alive_tasks(),
+            global_queue_depth: metrics.global_queue_depth(),
+            ..unstable_metrics(&metrics)
+        }
+    }
+}
+
+#[cfg(tokio_unstable)]
+fn unstable_metrics(metrics: &tokio::runtime::RuntimeMetrics) -> RuntimeMetrics {
+    RuntimeMetrics {
+        blocking_threads: metrics.num_blocking_threads(),
+        idle_blocking_threads: metrics.num_idle_blocking_threads(),
+        blocking_queue_depth: metrics.blocking_queue_depth(),
+        spawned_tasks: metrics.spawned_tasks_count(),
+        ..RuntimeMetrics::default()
+    }
+}
+
+#[cfg(not(tokio_unstable))]
+fn unstable_metrics(_metrics: &tokio::runtime::RuntimeMetrics) -> RuntimeMetrics {
+    RuntimeMetrics::default()
+}
+
+impl From<&Runtime> for RuntimeHandle {
+    fn from(runtime: &Runtime) -> Self {
+        Self { inner: runtime.handle().clone() }
+    }
+}
+
+impl From<Handle> for RuntimeHandle {
+    fn from(inner: Handle) -> Self {
+        Self { inner }
+    }
+}
+
+/// A snapshot of runtime scheduler metrics, e.g., for a `/metrics` endpoint.
+///
+/// Fields marked *unstable* are only populated when built with `--cfg tokio_unstable`
+/// and read as zero otherwise.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RuntimeMetrics {
+    /// Number of worker threads driving the scheduler.
+    pub workers: usize,
+    /// Number of tasks that are spawned and not yet completed.
+    pub alive_tasks: usize,
+    /// Number of tasks waiting in the scheduler's global queue.
+    pub global_queue_depth: usize,
+    /// *Unstable:* threads currently in the blocking pool.
+    pub blocking_threads: usize,
+    /// *Unstable:* idle threads in the blocking pool.
+    pub idle_blocking_threads: usize,
+    /// *Unstable:* tasks waiting for a blocking pool thread.
+    pub blocking_queue_depth: usize,
+    /// *Unstable:* total tasks spawned since the runtime was created.
+    pub spawned_tasks: u64,
+}
+
 static GLOBAL_RUNTIME: OnceLock<Runtime> = OnceLock::new();
 
 /// Access the lazily initialized global process runtime.
@@ -343,6 +556,18 @@ mod tests {
         assert_eq!(config.worker_threads, 1024);
     }
 
+    #[test]
+    fn test_max_blocking_threads_normalization() {
+        let config = RuntimeConfig::default().with_max_blocking_threads(64);
+        assert_eq!(normalize_config(&config).max_blocking_threads, 64);
+
+        let config = RuntimeConfig { max_blocking_threads: 0, ..RuntimeConfig::default() };
+        assert_eq!(normalize_config(&config).max_blocking_threads, 1);
+
+        let config = RuntimeConfig { max_blocking_threads: 100_000, ..RuntimeConfig::default() };
+        assert_eq!(normalize_config(&config).max_blocking_threads, MAX_BLOCKING_THREADS);
+    }
+
     #[test]
     fn test_stack_size_validation() {
         let config = RuntimeConfig::default().with_stack_size(100);
@@ -352,6 +577,60 @@ mod tests {
         assert_eq!(config.stack_size, MAX_STACK_SIZE);
     }
 
+    #[test]
+    fn test_current_thread_runtime_runs_future() {
+        let config = RuntimeConfig::current_thread();
+        assert_eq!(config.scheduler, Scheduler::CurrentThread);
+
+        let runtime = build_runtime_with_config(&config).expect("runtime should build");
+        let caller = std::thread::current().id();
+        let (value, thread) = runtime.block_on(async {
+            let handle = tokio::spawn(async { (21 * 2, std::thread::current().id()) });
+            handle.await.expect("task should complete")
+        });
+
+        assert_eq!(value, 42);
+        assert_eq!(thread, caller, "tasks should run on the calling thread");
+    }
+
+    #[test]
+    fn test_thread_callbacks_run_on_workers() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let started = Arc::new(AtomicUsize::new(0));
+        let stopped = Arc::new(AtomicUsize::new(0));
+        let (on_start, on_stop) = (Arc::clone(&started), Arc::clone(&stopped));
+
+        let config = RuntimeConfig::default()
+            .with_worker_threads(2)
+            .on_thread_start(Arc::new(move || {
+                on_start.fetch_add(1, Ordering::SeqCst);
+            }))
+            .on_thread_stop(Arc::new(move || {
+                on_stop.fetch_add(1, Ordering::SeqCst);
+            }));
+
+        let runtime = build_runtime_with_config(&config).expect("runtime should build");
+        runtime.block_on(async {});
+
+        // Dropping the runtime joins its threads, so both counters are final afterwards.
+        drop(runtime);
+        assert!(started.load(Ordering::SeqCst) >= 2, "every worker should run the callback");
+        assert_eq!(stopped.load(Ordering::SeqCst), started.load(Ordering::SeqCst));
+    }
+
+    #[test]
+    fn test_runtime_handle_metrics() {
+        let config = RuntimeConfig::default().with_worker_threads(2);
+        let (runtime, handle) = build_runtime_with_handle(&config).expect("runtime should build");
+
+        let _task = runtime.spawn(std::future::pending::<()>());
+        let metrics = handle.metrics();
+
+        assert_eq!(metrics.workers, 2);
+        assert!(metrics.alive_tasks >= 1, "the pending task should be alive");
+    }
+
     #[test]
     fn test_global_runtime_singleton() {
         let first = get_global_runtime() as *const Runtime;
@@ -1,21 +1,45 @@
-use crate::engine::{Compression, Storage, StorageInner};
+use crate::encryption::Sealer;
+use crate::engine::{Compression, ShardingConfig, Storage, StorageInner};
 use crate::error::{StorageError, StorageErrorExt};
+use crate::maintenance::DEFAULT_TMP_MAX_AGE;
+use mhub_vault::Vault;
+use mhub_vault::algorithms::VaultCipher;
 use private::Sealed;
 use std::path::PathBuf;
 use std::sync::Arc;
 use std::sync::atomic::AtomicU64;
+use std::time::Duration;
 use tokio::fs;
-use tracing::info;
+use tokio::time::MissedTickBehavior;
+use tracing::{info, warn};
+
+/// Shortest interval accepted by [`StorageBuilder::expiry_sweep_interval`].
+const MIN_SWEEP_INTERVAL: Duration = Duration::from_secs(1);
 
 #[derive(Debug, Clone)]
 struct StorageConfig {
     compression: Compression,
+    sharding: ShardingConfig,
     create: bool,
+    shallow_last_modified: bool,
+    checksums: bool,
+    lock_writes: bool,
+    expiry_sweep_interval: Option<Duration>,
+    encryption: Option<Arc<dyn Sealer>>,
 }
 
 impl Default for StorageConfig {
     fn default() -> Self {
-        Self { compression: Compression::None, create: true }
+        Self {
+            compression: Compression::None,
+            sharding: ShardingConfig::default(),
+            create: true,
+            shallow_last_modified: false,
+            checksums: false,
+            lock_writes: false,
+            expiry_sweep_interval: None,
+            encryption: None,
+        }
     }
 }
 
@@ -45,12 +69,86 @@ impl<S: Sealed> StorageBuilder<S> {
         self
     }
 
+    /// Sets the directory sharding layout; defaults to two levels of two characters.
+    ///
+    /// Workloads with few, large files can use [`ShardingConfig::Disabled`] to avoid creating
+    /// mostly empty shard directories.
+    #[must_use = "Sets the directory sharding layout"]
+    pub const fn sharding(mut self, sharding: ShardingConfig) -> Self {
+        self.config.sharding = sharding;
+        self
+    }
+
     #[must_use = "Sets whether the storage engine should be created if it does not exist"]
     pub const fn create(mut self, enable: bool) -> Self {
         self.config.create = enable;
         self
     }
 
+    /// Reports `last_modified` from the directory mtime instead of scanning every file.
+    ///
+    /// This is a cheaper approximation: a directory mtime only changes when its direct
+    /// children are added, removed, or renamed, so updates inside nested shards are missed.
+    #[must_use = "Sets whether last_modified uses the directory mtime approximation"]
+    pub const fn shallow_last_modified(mut self, enable: bool) -> Self {
+        self.config.shallow_last_modified = enable;
+        self
+    }
+
+    /// Appends a CRC32C of the plaintext to every written file and verifies it on read.
+    ///
+    /// Reads detect the checksum trailer by a magic marker, so files written while this was
+    /// disabled remain readable, and checksummed files stay verifiable if it is turned off.
+    ///
+    /// Ignored for [encrypted](StorageBuilder::encryption) files, which the vault already
+    /// authenticates; a plaintext checksum stored next to the ciphertext would leak data.
+    #[must_use = "Sets whether writes carry an integrity checksum"]
+    pub const fn checksums(mut self, enable: bool) -> Self {
+        self.config.checksums = enable;
+        self
+    }
+
+    /// Makes every write take the file's advisory lock for its duration.
+    ///
+    /// Writes then wait for holders of [`Storage::lock`] on the same path. Inside a locked
+    /// read-modify-write cycle, write through [`FileLock::write`](crate::FileLock::write)
+    /// instead of [`Storage::write`], which would wait for the caller's own lock.
+    #[must_use = "Sets whether writes take the advisory file lock"]
+    pub const fn lock_writes(mut self, enable: bool) -> Self {
+        self.config.lock_writes = enable;
+        self
+    }
+
+    /// Spawns a background task deleting expired files every `interval`.
+    ///
+    /// The task runs on the Tokio runtime that calls [`connect`](StorageBuilder::connect)
+    /// and stops once every [`Storage`] handle is dropped. Without it, expired files are only
+    /// hidden from reads until [`Storage::sweep_expired`] is called. Intervals shorter than
+    /// one second are raised to one second.
+    #[must_use = "Sets the interval of the background expiry sweeper"]
+    pub const fn expiry_sweep_interval(mut self, interval: Duration) -> Self {
+        let interval = if interval.as_secs() == 0 { MIN_SWEEP_INTERVAL } else { interval };
+        self.config.expiry_sweep_interval = Some(interval);
+        self
+    }
+
+    /// Encrypts file contents at rest with `vault`.
+    ///
+    /// Each file is sealed in the local domain with its logical path (namespace included) as
+    /// associated data, so ciphertext swapped between files fails to unseal. Compression is
+    /// left to the vault: storage-level LZ4 is turned off to avoid compressing twice.
+    ///
+    /// `copy` and `rename` re-seal the contents under the new path, and the streaming API is
+    /// unavailable while encryption is enabled.
+    #[must_use = "Sets the vault used to encrypt files at rest"]
+    pub fn encryption<C>(mut self, vault: Vault<C>) -> Self
+    where
+        C: VaultCipher + Send + Sync,
+    {
+        self.config.encryption = Some(Arc::new(vault));
+        self
+    }
+
     fn transition<N: Sealed>(self, state: N) -> StorageBuilder<N> {
         StorageBuilder { state, config: self.config }
     }
@@ -75,8 +173,9 @@ impl StorageBuilder<WithRoot> {
     /// 1. **Bootstrapping**: Creates the root directory if `create(true)` was set.
     /// 2. **Canonicalization**: Resolves the root path to an absolute, physical path
     ///    on disk to prevent symlink-based escape attacks.
-    /// 3. **Self-Healing**: Scans the root for orphaned `.tmp` files left behind by
-    ///    previous system crashes and removes them to reclaim space.
+    /// 3. **Self-Healing**: Scans the root for orphaned `.mhubtmp.*` files left behind by
+    ///    previous system crashes and removes those older than one hour to reclaim space,
+    ///    leaving in-progress writes of other processes untouched.
     /// 4. **Registration**: Returns a thread-safe [`Storage`] handle.
     ///
     /// # Reliability
@@ -105,16 +204,50 @@ impl StorageBuilder<WithRoot> {
             .await
             .context(format!("Failed to resolve storage root: {}", root.display()))?;
 
+        let mut compression = self.config.compression;
+        if self.config.encryption.is_some() && compression != Compression::None {
+            info!("Storage compression disabled; the vault compresses encrypted files");
+            compression = Compression::None;
+        }
+
         let storage = Storage {
             inner: Arc::new(StorageInner {
                 root: canonical,
-                compression: self.config.compression,
+                compression,
+                sharding: self.config.sharding,
+                shallow_last_modified: self.config.shallow_last_modified,
+                checksums: self.config.checksums,
+                lock_writes: self.config.lock_writes,
+                encryption: self.config.encryption,
                 tmp_counter: AtomicU64::new(1),
             }),
         };
 
-        storage.purge_tmp().await;
+        storage.purge_tmp_older_than(DEFAULT_TMP_MAX_AGE).await;
+
+        if let Some(interval) = self.config.expiry_sweep_interval {
+            spawn_sweeper(&storage, interval);
+            info!(?interval, "Started background expiry sweeper");
+        }
 
         Ok(storage)
     }
 }
+
+fn spawn_sweeper(storage: &Storage, interval: Duration) {
+    let inner = Arc::downgrade(&storage.inner);
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(interval);
+        ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+        ticker.tick().await;
+        loop {
+            ticker.tick().await;
+            let Some(inner) = inner.upgrade() else {
+                break;
+            };
+            if let Err(err) = (Storage { inner }).sweep_expired().await {
+                warn!(error = %err, "Background expiry sweep failed");
+            }
+        }
+    });
+}
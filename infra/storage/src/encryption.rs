@@ -0,0 +1,48 @@
+//! At-rest encryption through [`mhub_vault::Vault`].
+//!
+//! Files are sealed in the [`Local`] domain with their logical path as associated data, so a
+//! ciphertext copied over another file fails to unseal instead of silently decrypting.
+
+use mhub_vault::algorithms::VaultCipher;
+use mhub_vault::domains::Local;
+use mhub_vault::{ProtectedPayload, Vault, VaultError};
+use std::fmt;
+use std::path::{Component, Path};
+
+/// Object-safe view of a [`Vault`], erasing its cipher parameter.
+pub(crate) trait Sealer: fmt::Debug + Send + Sync {
+    fn seal(&self, data: &[u8], aad: &[u8]) -> Result<Vec<u8>, VaultError>;
+    fn unseal(&self, data: &[u8], aad: &[u8]) -> Result<Vec<u8>, VaultError>;
+}
+
+impl<C> Sealer for Vault<C>
+where
+    C: VaultCipher + Send + Sync,
+{
+    fn seal(&self, data: &[u8], aad: &[u8]) -> Result<Vec<u8>, VaultError> {
+        self.seal_bytes::<Local>(data, aad).map(ProtectedPayload::into_inner)
+    }
+
+    fn unseal(&self, data: &[u8], aad: &[u8]) -> Result<Vec<u8>, VaultError> {
+        self.unseal_bytes::<Local>(data, aad)
+    }
+}
+
+/// Builds the associated data for a file: its namespace and logical path, `/`-separated.
+///
+/// Sharding directories are not part of it, and `storage.write("tenant/a.txt")` binds to
+/// the same value as `storage.namespace("tenant")?.write("a.txt")`. Segments contribute their
+/// raw OS bytes, so paths that are not valid UTF-8 still bind to distinct values.
+pub(crate) fn aad(namespace: Option<&str>, path: &Path) -> Vec<u8> {
+    let mut segments: Vec<&[u8]> = namespace.map(str::as_bytes).into_iter().collect();
+    for component in path.components() {
+        match component {
+            Component::Normal(segment) => segments.push(segment.as_encoded_bytes()),
+            Component::ParentDir => {
+                segments.pop();
+            },
+            _ => {},
+        }
+    }
+    segments.join(&b'/')
+}
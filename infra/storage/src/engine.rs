@@ -6,17 +6,26 @@
 //! and namespaced access.
 
 use crate::builder::StorageBuilder;
+use crate::encryption::{self, Sealer};
 use crate::error::{StorageError, StorageErrorExt};
+use crate::expiry;
+use crate::integrity;
+use crate::lock::{self, FileLock};
 use crate::maintenance;
 use crate::namespace::{NamespaceName, NamespacedStorage};
 use crate::security;
+use crate::stream;
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use std::io::SeekFrom;
 use std::ops::Deref;
-use std::path::{Path, PathBuf};
+use std::path::{Component, Path, PathBuf};
 use std::sync::Arc;
 use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, SystemTime};
 use tokio::fs;
-use tokio::io::AsyncWriteExt;
-use tracing::debug;
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncSeekExt, AsyncWriteExt};
+use tracing::{debug, warn};
 
 #[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
 pub enum Compression {
@@ -44,6 +53,45 @@ impl Compression {
     }
 }
 
+/// Directory sharding applied to file names, configured via [`StorageBuilder::sharding`].
+///
+/// With `Depth { levels, width }`, a file name of at least `levels * width` characters is
+/// placed under `levels` nested directories named after its consecutive `width`-character
+/// prefixes, e.g. `avatar.png` becomes `av/at/avatar.png` with the default 2×2 layout. Shorter
+/// names are stored unsharded.
+///
+/// Changing the layout of an existing root makes previously written files unreachable,
+/// since paths are resolved with the current configuration.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum ShardingConfig {
+    /// Files are stored directly under their logical directory.
+    Disabled,
+    /// Files are spread across `levels` directories of `width` characters each.
+    Depth { levels: u8, width: u8 },
+}
+
+impl Default for ShardingConfig {
+    fn default() -> Self {
+        Self::Depth { levels: 2, width: 2 }
+    }
+}
+
+impl ShardingConfig {
+    /// Returns the shard directory names for `filename`, outermost first.
+    pub(crate) fn shards(self, filename: &str) -> Vec<String> {
+        let Self::Depth { levels, width } = self else {
+            return Vec::new();
+        };
+        let (levels, width) = (usize::from(levels), usize::from(width));
+
+        let chars: Vec<char> = filename.chars().collect();
+        if width == 0 || chars.len() < levels * width {
+            return Vec::new();
+        }
+        chars.chunks(width).take(levels).map(|chunk| chunk.iter().collect()).collect()
+    }
+}
+
 /// The internal shared state of a [`Storage`] instance.
 #[derive(Debug)]
 pub struct StorageInner {
@@ -51,6 +99,16 @@ pub struct StorageInner {
     pub(crate) root: PathBuf,
     /// Whether transparent LZ4 compression is globally enabled for this instance.
     pub(crate) compression: Compression,
+    /// The directory sharding layout applied to file names.
+    pub(crate) sharding: ShardingConfig,
+    /// Whether `last_modified` uses the directory mtime instead of a full scan.
+    pub(crate) shallow_last_modified: bool,
+    /// Whether writes append a CRC32C trailer that reads verify.
+    pub(crate) checksums: bool,
+    /// Whether writes take the advisory file lock.
+    pub(crate) lock_writes: bool,
+    /// The vault sealing file contents at rest, if encryption is enabled.
+    pub(crate) encryption: Option<Arc<dyn Sealer>>,
     /// A unique counter used to generate temporary file names.
     pub(crate) tmp_counter: AtomicU64,
 }
@@ -140,6 +198,82 @@ impl Storage {
         Ok(NamespacedStorage::new(self.clone(), ns.0))
     }
 
+    /// Recursively deletes a namespace and everything stored in it.
+    ///
+    /// Intended for offboarding a tenant. The name is validated the same way as in
+    /// [`namespace`](Self::namespace), and the canonical directory is re-checked against the
+    /// sandbox before anything is removed. The storage root itself and symlinked namespace
+    /// directories are never deleted.
+    ///
+    /// Returns the number of files removed. Lock and expiry sidecars are removed with the
+    /// namespace but not counted.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`StorageError::PathTraversalAttempt`] if the name is invalid or resolves to the
+    /// root, a symlink, or a location outside the sandbox.
+    /// Returns [`StorageError::DirectoryNotFound`] if the namespace does not exist.
+    /// Returns [`StorageError::Io`] if the removal fails.
+    pub async fn delete_namespace<N>(&self, name: N) -> Result<u64, StorageError>
+    where
+        N: TryInto<NamespaceName, Error = StorageError>,
+    {
+        let ns = name.try_into()?;
+        let dir = self.root.join(ns.as_ref());
+
+        match fs::symlink_metadata(&dir).await {
+            Ok(meta) if meta.file_type().is_symlink() => {
+                return Err(StorageError::PathTraversalAttempt {
+                    message: dir.display().to_string().into(),
+                    context: Some("Refusing to delete a symlinked namespace".into()),
+                });
+            },
+            Ok(meta) if meta.is_dir() => {},
+            Ok(_) => {
+                return Err(StorageError::DirectoryNotFound {
+                    message: dir.display().to_string().into(),
+                    context: Some("Namespace is not a directory".into()),
+                });
+            },
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+                return Err(StorageError::DirectoryNotFound {
+                    message: dir.display().to_string().into(),
+                    context: None,
+                });
+            },
+            Err(err) => {
+                return Err(StorageError::Io {
+                    source: err,
+                    context: Some(format!("Failed to inspect namespace: {}", dir.display()).into()),
+                });
+            },
+        }
+
+        let canonical = security::resolve_path(&self.root, ns.as_ref())?;
+        if canonical == self.root {
+            return Err(StorageError::PathTraversalAttempt {
+                message: canonical.display().to_string().into(),
+                context: Some("Refusing to delete the storage root".into()),
+            });
+        }
+
+        let target = canonical.clone();
+        let removed = tokio::task::spawn_blocking(move || {
+            let files = maintenance::count_files(&target);
+            std::fs::remove_dir_all(&target).map(|()| files)
+        })
+        .await
+        .map_err(|err| StorageError::Io {
+            source: std::io::Error::other(err),
+            context: Some("Namespace deletion task panicked".into()),
+        })?
+        .context(format!("Failed to delete namespace: {}", canonical.display()))?;
+
+        Self::sync_dir(&self.root).await;
+        debug!(namespace = %ns, files = removed, "Namespace deleted");
+        Ok(removed)
+    }
+
     /// Resolves a relative path to a physical path on the disk within the storage root.
     ///
     /// This method performs strict security validation to prevent path traversal attacks:
@@ -161,7 +295,7 @@ impl Storage {
         namespace: Option<&str>,
         path: impl AsRef<Path>,
     ) -> Result<PathBuf, StorageError> {
-        security::resolve_sharding(&self.root, namespace, path)
+        security::resolve_sharding(&self.root, self.sharding, namespace, path)
     }
 
     /// Reads the entire contents of a file from storage into a byte vector.
@@ -176,8 +310,11 @@ impl Storage {
     ///
     /// # Errors
     ///
-    /// Returns [`StorageError::FileNotFound`] if the path does not exist.
+    /// Returns [`StorageError::FileNotFound`] if the path does not exist or its TTL has passed.
     /// Returns [`StorageError::Decompress`] if the data is corrupted or compression is misconfigured.
+    /// Returns [`StorageError::ChecksumMismatch`] if the file carries a checksum that no longer
+    /// matches its contents.
+    /// Returns [`StorageError::Vault`] if encryption is enabled and the file cannot be unsealed.
     pub async fn read(&self, path: impl AsRef<Path>) -> Result<Vec<u8>, StorageError> {
         self.read_internal(None, path).await
     }
@@ -187,7 +324,9 @@ impl Storage {
         namespace: Option<&str>,
         path: impl AsRef<Path>,
     ) -> Result<Vec<u8>, StorageError> {
+        let path = path.as_ref();
         let resolved = self.resolve_internal(namespace, path)?;
+        Self::ensure_live(&resolved).await?;
 
         let data = match fs::read(&resolved).await {
             Ok(data) => data,
@@ -205,7 +344,300 @@ impl Storage {
             },
         };
 
-        self.inner.compression.decompress(&data)
+        let (payload, checksum) = integrity::split(&data);
+        let plaintext = match &self.encryption {
+            Some(vault) => vault
+                .unseal(payload, &encryption::aad(namespace, path))
+                .context(format!("Unsealing failed: {}", resolved.display()))?,
+            None => self.inner.compression.decompress(payload)?,
+        };
+        if let Some(expected) = checksum {
+            integrity::verify(&resolved, &plaintext, expected)?;
+        }
+        Ok(plaintext)
+    }
+
+    /// Reads up to `len` bytes starting at `offset`, without loading the whole file.
+    ///
+    /// Intended for serving HTTP range requests from stored assets. The range is clamped to
+    /// the end of the file, so an `offset` past the end yields an empty vector. A checksum
+    /// trailer is excluded from the readable range but not verified, since only part of the
+    /// plaintext is read.
+    ///
+    /// Only files stored as plain bytes can be sliced: range reads are rejected when
+    /// compression or at-rest encryption is configured, and for streamed files, which are
+    /// recognized by their LZ4 frame header. Block-compressed files carry no header, so their
+    /// detection relies on the configured [`Compression`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`StorageError::Unsupported`] if the file is compressed or encrypted.
+    /// Returns [`StorageError::FileNotFound`] if the path does not exist or its TTL has passed.
+    /// Returns [`StorageError::Io`] if the file cannot be read.
+    pub async fn read_range(
+        &self,
+        path: impl AsRef<Path>,
+        offset: u64,
+        len: u64,
+    ) -> Result<Vec<u8>, StorageError> {
+        self.read_range_internal(None, path, offset, len).await
+    }
+
+    pub(crate) async fn read_range_internal(
+        &self,
+        namespace: Option<&str>,
+        path: impl AsRef<Path>,
+        offset: u64,
+        len: u64,
+    ) -> Result<Vec<u8>, StorageError> {
+        let resolved = self.resolve_internal(namespace, path)?;
+        let unsupported = |reason: &'static str| StorageError::Unsupported {
+            message: format!("Range read of {}", resolved.display()).into(),
+            context: Some(reason.into()),
+        };
+
+        if self.compression != Compression::None {
+            return Err(unsupported("Files are block-compressed"));
+        }
+        if self.encryption.is_some() {
+            return Err(unsupported("Files are encrypted at rest"));
+        }
+        Self::ensure_live(&resolved).await?;
+
+        let mut file = match fs::File::open(&resolved).await {
+            Ok(file) => file,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+                return Err(StorageError::FileNotFound {
+                    message: resolved.display().to_string().into(),
+                    context: None,
+                });
+            },
+            Err(err) => {
+                return Err(StorageError::Io {
+                    source: err,
+                    context: Some(format!("Read failed: {}", resolved.display()).into()),
+                });
+            },
+        };
+
+        let size = file.metadata().await.context("Failed to get metadata")?.len();
+        let mut header = [0u8; 4];
+        if size >= header.len() as u64 {
+            file.read_exact(&mut header).await.context("Failed to read header")?;
+            if header == stream::FRAME_MAGIC {
+                return Err(unsupported("File was written as an LZ4 frame stream"));
+            }
+        }
+
+        let mut end = size;
+        let trailer_len = integrity::TRAILER_LEN as u64;
+        if size >= trailer_len {
+            let mut trailer = [0u8; integrity::TRAILER_LEN];
+            file.seek(SeekFrom::Start(size - trailer_len)).await.context("Seek failed")?;
+            file.read_exact(&mut trailer).await.context("Failed to read trailer")?;
+            if integrity::split(&trailer).1.is_some() {
+                end -= trailer_len;
+            }
+        }
+
+        let start = offset.min(end);
+        let take = len.min(end - start);
+        let mut data = vec![0; usize::try_from(take).unwrap_or(usize::MAX)];
+        file.seek(SeekFrom::Start(start)).await.context("Seek failed")?;
+        file.read_exact(&mut data).await.context(format!("Read failed: {}", resolved.display()))?;
+        Ok(data)
+    }
+
+    /// Acquires an exclusive advisory lock on a file, waiting until it is available.
+    ///
+    /// The lock is held on a `.mhublock` sidecar next to the sharded file (which need not
+    /// exist yet) and released when the returned [`FileLock`] is dropped. Use it to guard
+    /// read-modify-write cycles against other processes sharing the root.
+    ///
+    /// Advisory locks only coordinate cooperating writers: processes or code paths that
+    /// write without taking the lock are not prevented from doing so. Enable
+    /// [`StorageBuilder::lock_writes`] to make every `write` take it.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use mhub_storage::{Storage, StorageError};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), StorageError> {
+    /// # let tmp = tempfile::tempdir().unwrap();
+    /// # let storage = Storage::builder().root(tmp.path()).connect().await?;
+    /// let lock = storage.lock("counters/visits").await?;
+    /// let visits = lock.read().await.map_or(0, |data| data.len());
+    /// lock.write(&vec![0; visits + 1]).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`StorageError::PathTraversalAttempt`] if the path escapes the sandbox.
+    /// Returns [`StorageError::Io`] if the sidecar cannot be created or locked.
+    pub async fn lock(&self, path: impl AsRef<Path>) -> Result<FileLock, StorageError> {
+        self.lock_internal(None, path, true).await
+    }
+
+    /// Acquires an exclusive advisory lock on a file without waiting.
+    ///
+    /// See [`lock`](Self::lock) for the semantics and caveats.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`StorageError::Locked`] if another handle holds the lock.
+    /// Returns [`StorageError::PathTraversalAttempt`] if the path escapes the sandbox.
+    /// Returns [`StorageError::Io`] if the sidecar cannot be created or locked.
+    pub async fn try_lock(&self, path: impl AsRef<Path>) -> Result<FileLock, StorageError> {
+        self.lock_internal(None, path, false).await
+    }
+
+    pub(crate) async fn lock_internal(
+        &self,
+        namespace: Option<&str>,
+        path: impl AsRef<Path>,
+        wait: bool,
+    ) -> Result<FileLock, StorageError> {
+        let path = path.as_ref();
+        let resolved = self.resolve_internal(namespace, path)?;
+
+        if let Some(parent) = resolved.parent() {
+            fs::create_dir_all(parent)
+                .await
+                .context(format!("Failed to create shards for {}", resolved.display()))?;
+        }
+
+        let sidecar = lock::sidecar(&resolved);
+        let target = sidecar.clone();
+        let file = tokio::task::spawn_blocking(move || lock::acquire(&target, wait))
+            .await
+            .map_err(|err| StorageError::Io {
+                source: std::io::Error::other(err),
+                context: Some("Lock task panicked".into()),
+            })?
+            .context(format!("Failed to lock: {}", sidecar.display()))?
+            .ok_or_else(|| StorageError::Locked {
+                message: resolved.display().to_string().into(),
+                context: Some("Held by another handle".into()),
+            })?;
+
+        debug!(path = %resolved.display(), "File lock acquired");
+        Ok(FileLock::new(file, self.clone(), namespace, path))
+    }
+
+    /// Reads a file and decodes it as UTF-8.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use mhub_storage::{Storage, StorageError};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), StorageError> {
+    /// # let tmp = tempfile::tempdir().unwrap();
+    /// # let storage = Storage::builder().root(tmp.path()).connect().await?;
+    /// storage.write("notes/readme.txt", "hello".as_bytes()).await?;
+    /// assert_eq!(storage.read_to_string("notes/readme.txt").await?, "hello");
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`read`](Self::read), and [`StorageError::Encoding`] if the
+    /// contents are not valid UTF-8.
+    pub async fn read_to_string(&self, path: impl AsRef<Path>) -> Result<String, StorageError> {
+        self.read_to_string_internal(None, path).await
+    }
+
+    pub(crate) async fn read_to_string_internal(
+        &self,
+        namespace: Option<&str>,
+        path: impl AsRef<Path>,
+    ) -> Result<String, StorageError> {
+        let path = path.as_ref();
+        let data = self.read_internal(namespace, path).await?;
+        String::from_utf8(data).context(format!("Failed to decode: {}", path.display()))
+    }
+
+    /// Reads a file and deserializes it from JSON.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use mhub_storage::{Storage, StorageError};
+    /// # use serde::{Deserialize, Serialize};
+    /// #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    /// struct Settings {
+    ///     theme: String,
+    /// }
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), StorageError> {
+    /// # let tmp = tempfile::tempdir().unwrap();
+    /// # let storage = Storage::builder().root(tmp.path()).connect().await?;
+    /// let settings = Settings { theme: "dark".to_owned() };
+    /// storage.write_json("users/settings.json", &settings).await?;
+    ///
+    /// let restored: Settings = storage.read_json("users/settings.json").await?;
+    /// assert_eq!(restored, settings);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`read`](Self::read), and [`StorageError::Serialize`] if the
+    /// contents are not valid JSON for `T`.
+    pub async fn read_json<T>(&self, path: impl AsRef<Path>) -> Result<T, StorageError>
+    where
+        T: DeserializeOwned,
+    {
+        self.read_json_internal(None, path).await
+    }
+
+    pub(crate) async fn read_json_internal<T>(
+        &self,
+        namespace: Option<&str>,
+        path: impl AsRef<Path>,
+    ) -> Result<T, StorageError>
+    where
+        T: DeserializeOwned,
+    {
+        let path = path.as_ref();
+        let data = self.read_internal(namespace, path).await?;
+        serde_json::from_slice(&data).context(format!("Failed to parse: {}", path.display()))
+    }
+
+    /// Serializes `value` to JSON and writes it atomically, like [`write`](Self::write).
+    ///
+    /// See [`read_json`](Self::read_json) for an example.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`write`](Self::write), and [`StorageError::Serialize`] if
+    /// `value` cannot be serialized.
+    pub async fn write_json<T>(&self, path: impl AsRef<Path>, value: &T) -> Result<(), StorageError>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.write_json_internal(None, path, value).await
+    }
+
+    pub(crate) async fn write_json_internal<T>(
+        &self,
+        namespace: Option<&str>,
+        path: impl AsRef<Path>,
+        value: &T,
+    ) -> Result<(), StorageError>
+    where
+        T: Serialize + ?Sized,
+    {
+        let path = path.as_ref();
+        let data = serde_json::to_vec(value)
+            .context(format!("Failed to serialize: {}", path.display()))?;
+        self.write_internal(namespace, path, &data).await
     }
 
     /// Writes data to a file in storage atomically.
@@ -241,6 +673,36 @@ impl Storage {
         path: impl AsRef<Path>,
         data: &[u8],
     ) -> Result<(), StorageError> {
+        self.write_expiring(namespace, path, data, None).await
+    }
+
+    async fn write_expiring(
+        &self,
+        namespace: Option<&str>,
+        path: impl AsRef<Path>,
+        data: &[u8],
+        ttl: Option<Duration>,
+    ) -> Result<(), StorageError> {
+        let path = path.as_ref();
+        let _lock = if self.lock_writes {
+            Some(self.lock_internal(namespace, path, true).await?)
+        } else {
+            None
+        };
+        self.write_unlocked(namespace, path, data, ttl).await
+    }
+
+    /// Writes without taking the advisory lock. With a `ttl`, the expiry sidecar is replaced
+    /// before the data is swapped in, so the new contents never appear without their expiry;
+    /// without one, the sidecar is removed afterwards.
+    pub(crate) async fn write_unlocked(
+        &self,
+        namespace: Option<&str>,
+        path: impl AsRef<Path>,
+        data: &[u8],
+        ttl: Option<Duration>,
+    ) -> Result<(), StorageError> {
+        let path = path.as_ref();
         let resolved = self.resolve_internal(namespace, path)?;
 
         if let Some(parent) = resolved.parent() {
@@ -251,7 +713,15 @@ impl Storage {
 
         let temp = unique_tmp_path(&resolved, &self.tmp_counter);
 
-        let final_data = self.inner.compression.compress(data);
+        let mut final_data = match &self.encryption {
+            Some(vault) => vault
+                .seal(data, &encryption::aad(namespace, path))
+                .context(format!("Sealing failed: {}", resolved.display()))?,
+            None => self.inner.compression.compress(data),
+        };
+        if self.checksums && self.encryption.is_none() {
+            integrity::append(&mut final_data, data);
+        }
 
         {
             let mut file = fs::OpenOptions::new()
@@ -264,12 +734,234 @@ impl Storage {
             file.sync_all().await.context("Hardware sync failed")?;
         }
 
-        if let Err(err) = fs::rename(&temp, &resolved).await {
+        if let Some(ttl) = ttl {
+            let sidecar_temp = unique_tmp_path(&expiry::sidecar(&resolved), &self.tmp_counter);
+            if let Err(err) = expiry::set(&resolved, &sidecar_temp, ttl).await {
+                let _ = fs::remove_file(&temp).await;
+                return Err(StorageError::Io {
+                    source: err,
+                    context: Some(
+                        format!("Failed to record expiry: {}", resolved.display()).into(),
+                    ),
+                });
+            }
+        }
+
+        Self::swap_into_place(&temp, &resolved).await?;
+        if ttl.is_none() {
+            // A plain write makes the file permanent again.
+            expiry::clear(&resolved).await;
+        }
+        Ok(())
+    }
+
+    /// Writes data atomically, like [`write`](Self::write), and schedules the file to expire
+    /// after `ttl`.
+    ///
+    /// The expiry is recorded in a `.mhubexp` sidecar. Once it has passed, [`read`](Self::read)
+    /// reports the file as missing, and [`sweep_expired`](Self::sweep_expired) (or the
+    /// background sweeper configured with [`StorageBuilder::expiry_sweep_interval`]) deletes
+    /// it. Writing the path again without a TTL makes the file permanent. The sidecar is
+    /// replaced atomically before the data, so a crash never leaves the new contents
+    /// without their expiry.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`write`](Self::write).
+    pub async fn write_with_ttl(
+        &self,
+        path: impl AsRef<Path>,
+        data: &[u8],
+        ttl: Duration,
+    ) -> Result<(), StorageError> {
+        self.write_with_ttl_internal(None, path, data, ttl).await
+    }
+
+    pub(crate) async fn write_with_ttl_internal(
+        &self,
+        namespace: Option<&str>,
+        path: impl AsRef<Path>,
+        data: &[u8],
+        ttl: Duration,
+    ) -> Result<(), StorageError> {
+        self.write_expiring(namespace, path, data, Some(ttl)).await
+    }
+
+    /// Deletes every file whose TTL has passed, returning how many were removed.
+    ///
+    /// Files that cannot be deleted are logged and retried on the next sweep.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`StorageError::Io`] if the sweep task panics.
+    pub async fn sweep_expired(&self) -> Result<u64, StorageError> {
+        let root = self.root.clone();
+        let now = SystemTime::now();
+        let removed = tokio::task::spawn_blocking(move || expiry::sweep(&root, now))
+            .await
+            .map_err(|err| StorageError::Io {
+                source: std::io::Error::other(err),
+                context: Some("Expiry sweep task panicked".into()),
+            })?;
+        if removed > 0 {
+            debug!(removed, "Expired files swept");
+        }
+        Ok(removed)
+    }
+
+    /// Streams `reader` into a file atomically, compressing it as a single LZ4 frame.
+    ///
+    /// Memory use stays bounded regardless of the blob size, which makes this suitable for
+    /// large uploads. The same atomic swap as [`write`](Self::write) applies: data goes to a
+    /// unique temporary file that is synced and then renamed over the target. If `reader`
+    /// fails midway, the temporary file is removed and the target is left untouched.
+    ///
+    /// # Format
+    ///
+    /// Streamed files always use the LZ4 **frame** format, regardless of the configured
+    /// [`Compression`]. This is not the size-prepended block format used by `write`, so
+    /// streamed files must be read back with [`read_stream`](Self::read_stream), not
+    /// [`read`](Self::read), and vice versa. Streaming is unavailable when at-rest encryption
+    /// is enabled.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`StorageError::PathTraversalAttempt`] if the path escapes the sandbox.
+    /// Returns [`StorageError::Unsupported`] if at-rest encryption is enabled.
+    /// Returns [`StorageError::Io`] if `reader` fails, the disk is full, or a hardware failure occurs.
+    pub async fn write_stream(
+        &self,
+        path: impl AsRef<Path>,
+        reader: impl AsyncRead + Unpin,
+    ) -> Result<(), StorageError> {
+        self.write_stream_internal(None, path, reader).await
+    }
+
+    pub(crate) async fn write_stream_internal(
+        &self,
+        namespace: Option<&str>,
+        path: impl AsRef<Path>,
+        reader: impl AsyncRead + Unpin,
+    ) -> Result<(), StorageError> {
+        self.ensure_streamable()?;
+        let path = path.as_ref();
+        let resolved = self.resolve_internal(namespace, path)?;
+        let _lock = if self.lock_writes {
+            Some(self.lock_internal(namespace, path, true).await?)
+        } else {
+            None
+        };
+
+        if let Some(parent) = resolved.parent() {
+            fs::create_dir_all(parent)
+                .await
+                .context(format!("Failed to create shards for {}", resolved.display()))?;
+        }
+
+        let temp = unique_tmp_path(&resolved, &self.tmp_counter);
+
+        let written = async {
+            let mut file = fs::OpenOptions::new()
+                .create_new(true)
+                .write(true)
+                .open(&temp)
+                .await
+                .context(format!("Temp creation failed: {}", temp.display()))?;
+            stream::encode(reader, &mut file).await.context("Stream write failed")?;
+            file.sync_all().await.context("Hardware sync failed")
+        }
+        .await;
+
+        if let Err(err) = written {
+            // Best effort; leftovers are collected by `purge_tmp` anyway.
+            let _ = fs::remove_file(&temp).await;
+            return Err(err);
+        }
+
+        Self::swap_into_place(&temp, &resolved).await?;
+        // Like a plain write, a streamed write makes the file permanent again.
+        expiry::clear(&resolved).await;
+        Ok(())
+    }
+
+    /// Opens a file written by [`write_stream`](Self::write_stream) for streaming reads.
+    ///
+    /// The LZ4 frame is decoded incrementally on a blocking thread, so the whole blob is
+    /// never held in memory. Decoding errors surface as [`std::io::Error`]s from the reader.
+    ///
+    /// Files written with [`write`](Self::write) are not readable here; see the format
+    /// notes on `write_stream`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`StorageError::FileNotFound`] if the path does not exist or its TTL has passed.
+    /// Returns [`StorageError::Unsupported`] if at-rest encryption is enabled.
+    /// Returns [`StorageError::Io`] if the file cannot be opened.
+    pub async fn read_stream(
+        &self,
+        path: impl AsRef<Path>,
+    ) -> Result<impl AsyncRead + Send + Unpin + use<>, StorageError> {
+        self.read_stream_internal(None, path).await
+    }
+
+    pub(crate) async fn read_stream_internal(
+        &self,
+        namespace: Option<&str>,
+        path: impl AsRef<Path>,
+    ) -> Result<impl AsyncRead + Send + Unpin + use<>, StorageError> {
+        self.ensure_streamable()?;
+        let resolved = self.resolve_internal(namespace, path)?;
+        Self::ensure_live(&resolved).await?;
+
+        let file = match fs::File::open(&resolved).await {
+            Ok(file) => file,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+                return Err(StorageError::FileNotFound {
+                    message: resolved.display().to_string().into(),
+                    context: None,
+                });
+            },
+            Err(err) => {
+                return Err(StorageError::Io {
+                    source: err,
+                    context: Some(format!("Read failed: {}", resolved.display()).into()),
+                });
+            },
+        };
+
+        Ok(stream::decode(file.into_std().await))
+    }
+
+    /// Fails with [`StorageError::FileNotFound`] if the file's TTL has passed, so expired files
+    /// are never served before the sweeper deletes them.
+    async fn ensure_live(resolved: &Path) -> Result<(), StorageError> {
+        if !expiry::is_expired(resolved).await {
+            return Ok(());
+        }
+        Err(StorageError::FileNotFound {
+            message: resolved.display().to_string().into(),
+            context: Some("File has expired".into()),
+        })
+    }
+
+    fn ensure_streamable(&self) -> Result<(), StorageError> {
+        if self.encryption.is_none() {
+            return Ok(());
+        }
+        Err(StorageError::Unsupported {
+            message: "Streaming".into(),
+            context: Some("Unavailable while at-rest encryption is enabled".into()),
+        })
+    }
+
+    /// Renames a synced temporary file over `resolved` and syncs the parent directory.
+    async fn swap_into_place(temp: &Path, resolved: &Path) -> Result<(), StorageError> {
+        if let Err(err) = fs::rename(temp, resolved).await {
             if err.kind() == std::io::ErrorKind::AlreadyExists {
-                fs::remove_file(&resolved)
+                fs::remove_file(resolved)
                     .await
                     .context(format!("Failed to replace existing file: {}", resolved.display()))?;
-                fs::rename(&temp, &resolved).await.context(format!(
+                fs::rename(temp, resolved).await.context(format!(
                     "Atomic swap failed: {} -> {}",
                     temp.display(),
                     resolved.display()
@@ -307,6 +999,9 @@ impl Storage {
     ///
     /// Returns [`StorageError::Io`] if the file does not exist or if there are
     /// not enough permissions to perform the deletion.
+    ///
+    /// The file's expiry sidecar is removed, and so is its lock sidecar unless another handle
+    /// currently holds the lock.
     pub async fn delete(&self, path: impl AsRef<Path>) -> Result<(), StorageError> {
         self.delete_internal(None, path).await
     }
@@ -332,10 +1027,189 @@ impl Storage {
                 });
             },
         }
+        expiry::clear(&resolved).await;
+
+        let sidecar = lock::sidecar(&resolved);
+        let removed = tokio::task::spawn_blocking(move || lock::remove(&sidecar)).await;
+        if let Err(err) = removed.map_err(std::io::Error::other).and_then(|result| result) {
+            warn!(path = %resolved.display(), error = %err, "Failed to remove lock sidecar");
+        }
+
         debug!(path = %resolved.display(), "File deleted");
         Ok(())
     }
 
+    /// Copies a file within the sandbox atomically.
+    ///
+    /// Bytes are copied as stored on disk, so compressed files are not decompressed and
+    /// recompressed. The copy is written to a unique temporary file, synced, and renamed over
+    /// `to`, giving the same guarantees as [`write`](Self::write). Both paths are sharded
+    /// and validated like any other path; a leading namespace segment (`tenant/doc.txt`)
+    /// addresses that namespace's files.
+    ///
+    /// With at-rest encryption, the contents are unsealed and re-sealed for the new path.
+    ///
+    /// The copy inherits the TTL of `from`, if it has one (see
+    /// [`write_with_ttl`](Self::write_with_ttl)); otherwise it is permanent.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`StorageError::PathTraversalAttempt`] if either path escapes the sandbox.
+    /// Returns [`StorageError::FileNotFound`] if `from` does not exist.
+    /// Returns [`StorageError::Io`] if the copy fails.
+    pub async fn copy(
+        &self,
+        from: impl AsRef<Path>,
+        to: impl AsRef<Path>,
+    ) -> Result<(), StorageError> {
+        self.copy_internal(None, from, to).await
+    }
+
+    pub(crate) async fn copy_internal(
+        &self,
+        namespace: Option<&str>,
+        from: impl AsRef<Path>,
+        to: impl AsRef<Path>,
+    ) -> Result<(), StorageError> {
+        let (from, to) = (from.as_ref(), to.as_ref());
+        let source = self.resolve_internal(namespace, from)?;
+        let target = self.resolve_internal(namespace, to)?;
+
+        if self.encryption.is_some() {
+            // Ciphertext is bound to its path, so it has to be re-sealed for the new one.
+            let data = self.read_internal(namespace, from).await?;
+            self.write_internal(namespace, to, &data).await?;
+        } else {
+            self.copy_resolved(&source, &target).await?;
+        }
+
+        if source == target {
+            return Ok(());
+        }
+        let temp = unique_tmp_path(&expiry::sidecar(&target), &self.tmp_counter);
+        expiry::copy(&source, &target, &temp)
+            .await
+            .context(format!("Failed to copy expiry: {}", target.display()))
+    }
+
+    /// Moves a file within the sandbox.
+    ///
+    /// Uses a single `rename` when possible, which is atomic and cheap. If the source and
+    /// target shards live on different filesystems, it falls back to an atomic
+    /// [`copy`](Self::copy) followed by deleting the source. Both paths are sharded and
+    /// validated like any other path; a leading namespace segment moves files across
+    /// namespaces.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`StorageError::PathTraversalAttempt`] if either path escapes the sandbox.
+    /// Returns [`StorageError::FileNotFound`] if `from` does not exist.
+    /// Returns [`StorageError::Io`] if the move fails.
+    ///
+    /// With at-rest encryption, the move is a re-sealing copy followed by a delete.
+    ///
+    /// The TTL of `from`, if it has one, moves with the file.
+    pub async fn rename(
+        &self,
+        from: impl AsRef<Path>,
+        to: impl AsRef<Path>,
+    ) -> Result<(), StorageError> {
+        self.rename_internal(None, from, to).await
+    }
+
+    pub(crate) async fn rename_internal(
+        &self,
+        namespace: Option<&str>,
+        from: impl AsRef<Path>,
+        to: impl AsRef<Path>,
+    ) -> Result<(), StorageError> {
+        if self.encryption.is_some() {
+            let (from, to) = (from.as_ref(), to.as_ref());
+            if self.resolve_internal(namespace, from)? == self.resolve_internal(namespace, to)? {
+                return Ok(());
+            }
+            self.copy_internal(namespace, from, to).await?;
+            return self.delete_internal(namespace, from).await;
+        }
+
+        let source = self.resolve_internal(namespace, from)?;
+        let target = self.resolve_internal(namespace, to)?;
+
+        if !fs::try_exists(&source).await.unwrap_or(false) {
+            return Err(StorageError::FileNotFound {
+                message: source.display().to_string().into(),
+                context: None,
+            });
+        }
+        if source == target {
+            return Ok(());
+        }
+
+        if let Some(parent) = target.parent() {
+            fs::create_dir_all(parent)
+                .await
+                .context(format!("Failed to create shards for {}", target.display()))?;
+        }
+
+        match Self::swap_into_place(&source, &target).await {
+            Err(StorageError::Io { source: err, .. })
+                if err.kind() == std::io::ErrorKind::CrossesDevices =>
+            {
+                self.copy_resolved(&source, &target).await?;
+                fs::remove_file(&source)
+                    .await
+                    .context(format!("Failed to remove moved file: {}", source.display()))?;
+            },
+            result => result?,
+        }
+
+        let temp = unique_tmp_path(&expiry::sidecar(&target), &self.tmp_counter);
+        expiry::rename(&source, &target, &temp)
+            .await
+            .context(format!("Failed to move expiry: {}", target.display()))?;
+
+        if let Some(parent) = source.parent() {
+            Self::sync_dir(parent).await;
+        }
+
+        debug!(from = %source.display(), to = %target.display(), "File moved");
+        Ok(())
+    }
+
+    async fn copy_resolved(&self, source: &Path, target: &Path) -> Result<(), StorageError> {
+        if let Some(parent) = target.parent() {
+            fs::create_dir_all(parent)
+                .await
+                .context(format!("Failed to create shards for {}", target.display()))?;
+        }
+
+        let temp = unique_tmp_path(target, &self.tmp_counter);
+
+        let copied = async {
+            fs::copy(source, &temp).await?;
+            fs::OpenOptions::new().write(true).open(&temp).await?.sync_all().await
+        }
+        .await;
+
+        if let Err(err) = copied {
+            let _ = fs::remove_file(&temp).await;
+            if err.kind() == std::io::ErrorKind::NotFound {
+                return Err(StorageError::FileNotFound {
+                    message: source.display().to_string().into(),
+                    context: None,
+                });
+            }
+            return Err(StorageError::Io {
+                source: err,
+                context: Some(
+                    format!("Copy failed: {} -> {}", source.display(), temp.display()).into(),
+                ),
+            });
+        }
+
+        Self::swap_into_place(&temp, target).await
+    }
+
     /// Checks if a file exists within the storage sandbox.
     ///
     /// This performs a metadata check on the resolved physical path.
@@ -346,12 +1220,20 @@ impl Storage {
     ///
     /// # Errors
     ///
-    /// Returns `Ok(false)` if the file is not found. Returns an `Err` only if
-    /// path resolution fails (e.g., due to a security violation) or if a
+    /// Returns `Ok(false)` if the file is not found or its TTL has passed. Returns an `Err`
+    /// only if path resolution fails (e.g., due to a security violation) or if a
     /// critical I/O error occurs.
     pub fn exists(&self, path: impl AsRef<Path>) -> Result<bool, StorageError> {
-        let resolved = self.resolve_internal(None, path)?;
-        Ok(resolved.exists())
+        self.exists_internal(None, path)
+    }
+
+    pub(crate) fn exists_internal(
+        &self,
+        namespace: Option<&str>,
+        path: impl AsRef<Path>,
+    ) -> Result<bool, StorageError> {
+        let resolved = self.resolve_internal(namespace, path)?;
+        Ok(resolved.exists() && !expiry::is_expired_blocking(&resolved))
     }
 
     /// Retrieves filesystem metadata for a file within the sandbox.
@@ -371,13 +1253,22 @@ impl Storage {
     ///
     /// # Errors
     ///
-    /// Returns [`StorageError::FileNotFound`] if the target does not exist.
+    /// Returns [`StorageError::FileNotFound`] if the target does not exist or its TTL has passed.
     /// Returns [`StorageError::Io`] if a hardware or permission error occurs.
     pub async fn metadata(
         &self,
         path: impl AsRef<Path>,
     ) -> Result<std::fs::Metadata, StorageError> {
-        let resolved = self.resolve_internal(None, path)?;
+        self.metadata_internal(None, path).await
+    }
+
+    pub(crate) async fn metadata_internal(
+        &self,
+        namespace: Option<&str>,
+        path: impl AsRef<Path>,
+    ) -> Result<std::fs::Metadata, StorageError> {
+        let resolved = self.resolve_internal(namespace, path)?;
+        Self::ensure_live(&resolved).await?;
         match fs::metadata(&resolved).await {
             Ok(meta) => Ok(meta),
             Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
@@ -393,8 +1284,98 @@ impl Storage {
         }
     }
 
+    /// Returns the most recent modification time across the whole storage root.
+    ///
+    /// Only stored files are scanned, so writes advance the reported time while deletions and
+    /// the storage's own temporary files and sidecars do not; an empty root reports
+    /// [`UNIX_EPOCH`](std::time::UNIX_EPOCH). When [`StorageBuilder::shallow_last_modified`] is
+    /// enabled, only the root directory mtime is consulted.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`StorageError::Io`] if the root cannot be inspected.
+    pub async fn last_modified(&self) -> Result<SystemTime, StorageError> {
+        self.last_modified_internal(None).await
+    }
+
+    pub(crate) async fn last_modified_internal(
+        &self,
+        namespace: Option<&str>,
+    ) -> Result<SystemTime, StorageError> {
+        let dir = namespace.map_or_else(|| self.root.clone(), |ns| self.root.join(ns));
+
+        if !dir.is_dir() {
+            return Err(StorageError::DirectoryNotFound {
+                message: dir.display().to_string().into(),
+                context: None,
+            });
+        }
+
+        if self.shallow_last_modified {
+            return fs::metadata(&dir)
+                .await
+                .and_then(|meta| meta.modified())
+                .context(format!("Failed to get modification time: {}", dir.display()));
+        }
+
+        let scan = dir.clone();
+        tokio::task::spawn_blocking(move || maintenance::latest_mtime(&scan))
+            .await
+            .map_err(|err| StorageError::Io {
+                source: std::io::Error::other(err),
+                context: Some("Modification time scan task panicked".into()),
+            })?
+            .context(format!("Failed to scan modification times: {}", dir.display()))
+    }
+
+    pub(crate) async fn list_internal(
+        &self,
+        namespace: &str,
+        prefix: &Path,
+    ) -> Result<Vec<String>, StorageError> {
+        let dir = self.root.join(namespace);
+        if !dir.is_dir() {
+            return Ok(Vec::new());
+        }
+
+        // Prefixes are confined to the namespace; `..` or absolute prefixes are rejected.
+        security::resolve_path(&dir, prefix)?;
+        let prefix = prefix
+            .components()
+            .filter(|c| matches!(c, Component::Normal(_)))
+            .map(|c| c.as_os_str().to_string_lossy())
+            .collect::<Vec<_>>()
+            .join("/");
+
+        let scan = dir.clone();
+        let sharding = self.sharding;
+        let task = tokio::task::spawn_blocking(move || maintenance::logical_keys(&scan, sharding));
+        let mut keys = task.await.map_err(|err| StorageError::Io {
+            source: std::io::Error::other(err),
+            context: Some(format!("Listing task panicked: {}", dir.display()).into()),
+        })?;
+        // Matched per path component, so `avatars` does not list `avatars2/...`.
+        keys.retain(|key| Path::new(key).starts_with(&prefix));
+        keys.sort_unstable();
+        Ok(keys)
+    }
+
+    /// Removes orphaned `.mhubtmp.*` files older than five minutes, see
+    /// [`Storage::purge_tmp_older_than`].
+    ///
+    /// [`StorageBuilder::connect`] uses a more conservative one-hour threshold.
     pub async fn purge_tmp(&self) {
-        maintenance::purge_tmp(&self.root).await;
+        self.purge_tmp_older_than(maintenance::PURGE_TMP_MAX_AGE).await;
+    }
+
+    /// Removes `.mhubtmp.*` files beneath the root whose mtime is more than `age` ago, along
+    /// with directories left empty.
+    ///
+    /// Temporary files younger than `age` are kept, since another process sharing the root
+    /// may still be writing them; `age` must therefore exceed the longest expected write.
+    /// Failures are logged, never returned.
+    pub async fn purge_tmp_older_than(&self, age: Duration) {
+        maintenance::purge_tmp(&self.root, age).await;
     }
 
     async fn sync_dir(path: &Path) {
@@ -12,12 +12,30 @@ pub enum StorageError {
     #[error("Path traversal security violation{}: {message}", format_context(.context))]
     PathTraversalAttempt { message: Cow<'static, str>, context: Option<Cow<'static, str>> },
 
+    #[error("File is locked{}: {message}", format_context(.context))]
+    Locked { message: Cow<'static, str>, context: Option<Cow<'static, str>> },
+
+    #[error("Unsupported operation{}: {message}", format_context(.context))]
+    Unsupported { message: Cow<'static, str>, context: Option<Cow<'static, str>> },
+
     #[error("Hardware I/O failure{}: {source}", format_context(.context))]
     Io { source: std::io::Error, context: Option<Cow<'static, str>> },
 
+    #[error("Checksum mismatch{}: {message}", format_context(.context))]
+    ChecksumMismatch { message: Cow<'static, str>, context: Option<Cow<'static, str>> },
+
+    #[error("Invalid UTF-8{}: {source}", format_context(.context))]
+    Encoding { source: std::string::FromUtf8Error, context: Option<Cow<'static, str>> },
+
+    #[error("JSON serialization failure{}: {source}", format_context(.context))]
+    Serialize { source: serde_json::Error, context: Option<Cow<'static, str>> },
+
     #[error("Compression failure{}: {source}", format_context(.context))]
     Compress { source: lz4_flex::block::CompressError, context: Option<Cow<'static, str>> },
 
     #[error("Decompression failure{}: {source}", format_context(.context))]
     Decompress { source: lz4_flex::block::DecompressError, context: Option<Cow<'static, str>> },
+
+    #[error("Encryption failure{}: {source}", format_context(.context))]
+    Vault { source: mhub_vault::VaultError, context: Option<Cow<'static, str>> },
 }
@@ -0,0 +1,186 @@
+//! Time-to-live bookkeeping for expiring files.
+//!
+//! The expiry of a file written with a TTL is kept in a `.mhubexp` sidecar next to it, as
+//! milliseconds since the Unix epoch (little-endian `u64`). Keeping it out of the file itself
+//! leaves the payload format untouched and lets the sweeper find candidates without reading
+//! any payloads.
+//!
+//! Sidecars are replaced atomically (temporary file plus rename), so a crash never leaves a
+//! file without the expiry it was written with.
+
+use crate::lock;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tokio::fs;
+use tokio::io::AsyncWriteExt;
+use tracing::warn;
+use walkdir::WalkDir;
+
+/// File name suffix of expiry sidecars.
+pub(crate) const EXPIRY_SUFFIX: &str = ".mhubexp";
+
+/// Returns the expiry sidecar path for a resolved file path.
+pub(crate) fn sidecar(resolved: &Path) -> PathBuf {
+    let mut name = resolved.file_name().unwrap_or_default().to_os_string();
+    name.push(EXPIRY_SUFFIX);
+    resolved.with_file_name(name)
+}
+
+/// Encodes the instant `ttl` after `now`, saturating at the largest representable expiry so
+/// huge TTLs never wrap around into the past.
+fn encode(now: SystemTime, ttl: Duration) -> [u8; 8] {
+    let millis = now.duration_since(UNIX_EPOCH).unwrap_or_default().saturating_add(ttl).as_millis();
+    u64::try_from(millis).unwrap_or(u64::MAX).to_le_bytes()
+}
+
+/// Returns whether a sidecar's recorded expiry has passed at `now`. Malformed sidecars count
+/// as expired.
+fn is_past(data: &[u8], now: SystemTime) -> bool {
+    let Ok(bytes) = <[u8; 8]>::try_from(data) else {
+        return true;
+    };
+    let millis = Duration::from_millis(u64::from_le_bytes(bytes));
+    UNIX_EPOCH.checked_add(millis).is_some_and(|expires_at| expires_at <= now)
+}
+
+/// Records that the file at `resolved` expires `ttl` from now, replacing any previous expiry
+/// atomically through the unique temporary file `temp`.
+pub(crate) async fn set(resolved: &Path, temp: &Path, ttl: Duration) -> std::io::Result<()> {
+    replace(resolved, temp, &encode(SystemTime::now(), ttl)).await
+}
+
+/// Gives the file at `target` the expiry of the file at `source`, or makes it permanent if
+/// `source` has none. `temp` is used to replace the sidecar atomically.
+pub(crate) async fn copy(source: &Path, target: &Path, temp: &Path) -> std::io::Result<()> {
+    match fs::read(sidecar(source)).await {
+        Ok(data) => replace(target, temp, &data).await,
+        Err(err) if err.kind() == ErrorKind::NotFound => {
+            clear(target).await;
+            Ok(())
+        },
+        Err(err) => Err(err),
+    }
+}
+
+/// Moves the expiry of the file at `source` to the file at `target`, or makes `target`
+/// permanent if `source` has none. Falls back to [`copy`] when the rename fails, e.g.
+/// across filesystems.
+pub(crate) async fn rename(source: &Path, target: &Path, temp: &Path) -> std::io::Result<()> {
+    match fs::rename(sidecar(source), sidecar(target)).await {
+        Ok(()) => Ok(()),
+        Err(err) if err.kind() == ErrorKind::NotFound => {
+            clear(target).await;
+            Ok(())
+        },
+        Err(_) => {
+            copy(source, target, temp).await?;
+            clear(source).await;
+            Ok(())
+        },
+    }
+}
+
+/// Writes `data` to `temp`, syncs it, and renames it over the sidecar of `resolved`.
+async fn replace(resolved: &Path, temp: &Path, data: &[u8]) -> std::io::Result<()> {
+    let written = async {
+        let mut file = fs::OpenOptions::new().create_new(true).write(true).open(temp).await?;
+        file.write_all(data).await?;
+        file.sync_all().await?;
+        fs::rename(temp, sidecar(resolved)).await
+    }
+    .await;
+    if written.is_err() {
+        let _ = fs::remove_file(temp).await;
+    }
+    written
+}
+
+/// Removes any expiry recorded for the file at `resolved`.
+pub(crate) async fn clear(resolved: &Path) {
+    match fs::remove_file(sidecar(resolved)).await {
+        Ok(()) => {},
+        Err(err) if err.kind() == ErrorKind::NotFound => {},
+        Err(err) => warn!(path = %resolved.display(), error = %err, "Failed to clear expiry"),
+    }
+}
+
+/// Returns whether the file at `resolved` has a sidecar whose expiry has passed.
+pub(crate) async fn is_expired(resolved: &Path) -> bool {
+    match fs::read(sidecar(resolved)).await {
+        Ok(data) => is_past(&data, SystemTime::now()),
+        Err(_) => false,
+    }
+}
+
+/// Blocking variant of [`is_expired`], for synchronous callers.
+pub(crate) fn is_expired_blocking(resolved: &Path) -> bool {
+    std::fs::read(sidecar(resolved)).is_ok_and(|data| is_past(&data, SystemTime::now()))
+}
+
+/// Deletes every file beneath `root` that has expired at `now`, together with its sidecar.
+///
+/// Returns the number of files deleted. Sidecars whose file is already gone are removed
+/// without being counted. Each file is deleted under its advisory lock, and files whose lock
+/// is held are left for the next sweep.
+pub(crate) fn sweep(root: &Path, now: SystemTime) -> u64 {
+    let mut removed = 0;
+    for entry in WalkDir::new(root).into_iter().flatten() {
+        if !entry.file_type().is_file() {
+            continue;
+        }
+        let Some(name) = entry.file_name().to_str() else {
+            continue;
+        };
+        let Some(target) = name.strip_suffix(EXPIRY_SUFFIX) else {
+            continue;
+        };
+        let target = entry.path().with_file_name(target);
+        if std::fs::read(entry.path()).is_ok_and(|data| is_past(&data, now)) {
+            removed += u64::from(sweep_file(&target, now));
+        }
+    }
+    removed
+}
+
+/// Deletes the expired file at `target` and its sidecar, returning whether the file existed.
+fn sweep_file(target: &Path, now: SystemTime) -> bool {
+    let lock_path = lock::sidecar(target);
+    let guard = match lock::acquire(&lock_path, false) {
+        Ok(Some(guard)) => guard,
+        Ok(None) => return false,
+        Err(err) => {
+            warn!(path = %target.display(), error = %err, "Failed to lock expired file");
+            return false;
+        },
+    };
+
+    // A write that finished before the lock was taken may have refreshed or cleared the TTL.
+    let expiry = sidecar(target);
+    let deleted = if std::fs::read(&expiry).is_ok_and(|data| is_past(&data, now)) {
+        delete_expired(target, &expiry)
+    } else {
+        false
+    };
+
+    drop(guard);
+    if let Err(err) = lock::remove(&lock_path) {
+        warn!(path = %lock_path.display(), error = %err, "Failed to remove lock sidecar");
+    }
+    deleted
+}
+
+fn delete_expired(target: &Path, expiry: &Path) -> bool {
+    let deleted = match std::fs::remove_file(target) {
+        Ok(()) => true,
+        Err(err) if err.kind() == ErrorKind::NotFound => false,
+        Err(err) => {
+            warn!(path = %target.display(), error = %err, "Failed to delete expired file");
+            return false;
+        },
+    };
+    if let Err(err) = std::fs::remove_file(expiry) {
+        warn!(path = %expiry.display(), error = %err, "Failed to delete expiry sidecar");
+    }
+    deleted
+}
@@ -0,0 +1,50 @@
+//! Optional per-file integrity trailer.
+//!
+//! When checksums are enabled, the stored bytes are followed by a 12-byte trailer: the
+//! CRC32C of the uncompressed plaintext (little-endian) and a fixed magic marker. The marker
+//! lets files written without checksums keep reading as before.
+//!
+//! Encrypted files get no trailer: a plaintext checksum stored in clear would leak
+//! information about the contents, and the vault's authentication tag already detects
+//! corruption.
+
+use crate::error::StorageError;
+use std::path::Path;
+
+const MAGIC: &[u8; 8] = b"MHUBCRC1";
+pub(crate) const TRAILER_LEN: usize = 4 + MAGIC.len();
+
+/// Appends the trailer for `plaintext` to the already encoded `stored` bytes.
+pub(crate) fn append(stored: &mut Vec<u8>, plaintext: &[u8]) {
+    stored.extend_from_slice(&crc32c::crc32c(plaintext).to_le_bytes());
+    stored.extend_from_slice(MAGIC);
+}
+
+/// Splits stored bytes into the payload and the recorded checksum, if a trailer is present.
+pub(crate) fn split(data: &[u8]) -> (&[u8], Option<u32>) {
+    let Some(start) = data.len().checked_sub(TRAILER_LEN) else {
+        return (data, None);
+    };
+    let (payload, trailer) = data.split_at(start);
+    let (crc, magic) = trailer.split_at(4);
+    if magic != MAGIC {
+        return (data, None);
+    }
+    let crc = u32::from_le_bytes(crc.try_into().unwrap_or_default());
+    (payload, Some(crc))
+}
+
+/// Verifies decoded `plaintext` against the checksum recorded for `path`.
+///
+/// # Errors
+/// Returns [`StorageError::ChecksumMismatch`] if the checksums differ.
+pub(crate) fn verify(path: &Path, plaintext: &[u8], expected: u32) -> Result<(), StorageError> {
+    let actual = crc32c::crc32c(plaintext);
+    if actual == expected {
+        return Ok(());
+    }
+    Err(StorageError::ChecksumMismatch {
+        message: path.display().to_string().into(),
+        context: Some(format!("expected {expected:08x}, found {actual:08x}").into()),
+    })
+}
@@ -8,6 +8,8 @@
 //! - **Sandbox Security**: Strict path traversal protection using physical path canonicalization.
 //! - **Atomic Writes**: Uses an "atomic swap" pattern (unique temp write + `fsync` + `rename`) to prevent data corruption during crashes.
 //! - **Transparent Compression**: Integrated LZ4 block compression that is invisible to the consumer.
+//! - **At-rest Encryption**: Optional sealing through `mhub_vault`, bound to each file's logical path.
+//! - **Streaming**: Bounded-memory reads and writes of large blobs using the LZ4 frame format.
 //! - **Namespacing & Sharding**: Logical data partitioning with automatic directory sharding to maintain filesystem performance.
 //! - **Self-Healing**: Automatically identifies and cleans up orphaned temporary files during initialization.
 //!
@@ -67,13 +69,19 @@
 //! ```
 
 mod builder;
+mod encryption;
 mod engine;
 mod error;
+mod expiry;
+mod integrity;
+mod lock;
 mod maintenance;
 mod namespace;
 mod security;
+mod stream;
 
 pub use builder::StorageBuilder;
-pub use engine::{Compression, Storage};
+pub use engine::{Compression, ShardingConfig, Storage};
 pub use error::{StorageError, StorageErrorExt};
+pub use lock::FileLock;
 pub use namespace::NamespacedStorage;
@@ -0,0 +1,143 @@
+//! Advisory file locks for coordinating writers that share a storage root.
+//!
+//! Locks are taken with the OS advisory primitives (`flock` on Unix, `LockFileEx` on
+//! Windows) on a `.mhublock` sidecar next to the sharded file. They only coordinate
+//! cooperating processes: anything that writes without taking the lock is not stopped.
+
+use crate::engine::Storage;
+use crate::error::StorageError;
+use std::fs::File;
+use std::path::{Path, PathBuf};
+
+/// File name suffix of lock sidecars.
+pub(crate) const LOCK_SUFFIX: &str = ".mhublock";
+
+/// An exclusive advisory lock on a stored file, released when dropped.
+///
+/// Returned by [`Storage::lock`] and [`Storage::try_lock`]. While the guard is alive, other
+/// lockers of the same path wait (or fail, for `try_lock`). Use [`read`](Self::read) and
+/// [`write`](Self::write) for read-modify-write cycles: they go through the guard instead of
+/// trying to lock again, which matters when `StorageBuilder::lock_writes` is enabled.
+#[must_use = "the lock is released when the guard is dropped"]
+#[derive(Debug)]
+pub struct FileLock {
+    file: File,
+    storage: Storage,
+    namespace: Option<String>,
+    path: PathBuf,
+}
+
+impl FileLock {
+    pub(crate) fn new(file: File, storage: Storage, namespace: Option<&str>, path: &Path) -> Self {
+        Self { file, storage, namespace: namespace.map(ToOwned::to_owned), path: path.to_owned() }
+    }
+
+    /// Returns the logical path the lock guards.
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Reads the locked file, like [`Storage::read`].
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`Storage::read`].
+    pub async fn read(&self) -> Result<Vec<u8>, StorageError> {
+        self.storage.read_internal(self.namespace.as_deref(), &self.path).await
+    }
+
+    /// Atomically writes the locked file, like [`Storage::write`], while holding the lock.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`Storage::write`].
+    pub async fn write(&self, data: &[u8]) -> Result<(), StorageError> {
+        self.storage.write_unlocked(self.namespace.as_deref(), &self.path, data, None).await
+    }
+}
+
+impl Drop for FileLock {
+    fn drop(&mut self) {
+        // Closing the handle releases the lock as well; unlocking first makes it explicit.
+        let _ = self.file.unlock();
+    }
+}
+
+/// Returns the sidecar lock path for a resolved file path.
+pub(crate) fn sidecar(resolved: &Path) -> PathBuf {
+    let mut name = resolved.file_name().unwrap_or_default().to_os_string();
+    name.push(LOCK_SUFFIX);
+    resolved.with_file_name(name)
+}
+
+/// Removes the sidecar of a deleted file if no other handle holds its lock.
+///
+/// A held sidecar is left in place: deleting it would let a new locker create a fresh file
+/// and hold the lock concurrently with the current holder.
+pub(crate) fn remove(sidecar: &Path) -> std::io::Result<()> {
+    let file = match std::fs::OpenOptions::new().write(true).open(sidecar) {
+        Ok(file) => file,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(()),
+        Err(err) => return Err(err),
+    };
+    match file.try_lock() {
+        Ok(()) => {},
+        Err(std::fs::TryLockError::WouldBlock) => return Ok(()),
+        Err(std::fs::TryLockError::Error(err)) => return Err(err),
+    }
+    // Windows refuses to delete open files, so the handle is closed first there.
+    #[cfg(windows)]
+    drop(file);
+    match std::fs::remove_file(sidecar) {
+        Err(err) if err.kind() != std::io::ErrorKind::NotFound => Err(err),
+        _ => Ok(()),
+    }
+}
+
+/// Opens the sidecar and acquires the lock, waiting for it when `wait` is set.
+///
+/// Returns `Ok(None)` if `wait` is unset and another handle holds the lock. If [`remove`]
+/// unlinked the sidecar while this handle waited, the lock guards an orphaned inode, so it is
+/// released and taken again on the file now at `sidecar`.
+pub(crate) fn acquire(sidecar: &Path, wait: bool) -> std::io::Result<Option<File>> {
+    loop {
+        let file =
+            std::fs::OpenOptions::new().create(true).truncate(false).write(true).open(sidecar)?;
+        if wait {
+            file.lock()?;
+        } else {
+            match file.try_lock() {
+                Ok(()) => {},
+                Err(std::fs::TryLockError::WouldBlock) => return Ok(None),
+                Err(std::fs::TryLockError::Error(err)) => return Err(err),
+            }
+        }
+        if is_linked(&file, sidecar)? {
+            return Ok(Some(file));
+        }
+    }
+}
+
+/// Returns whether `file` is still the file linked at `path`.
+#[cfg(unix)]
+fn is_linked(file: &File, path: &Path) -> std::io::Result<bool> {
+    use std::os::unix::fs::MetadataExt;
+
+    let held = file.metadata()?;
+    match std::fs::metadata(path) {
+        Ok(linked) => Ok(held.dev() == linked.dev() && held.ino() == linked.ino()),
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(false),
+        Err(err) => Err(err),
+    }
+}
+
+/// Returns whether `file` is still the file linked at `path`.
+///
+/// Windows refuses to delete a file that has open handles, so a held sidecar is never
+/// unlinked from under its holder.
+#[cfg(not(unix))]
+#[allow(clippy::unnecessary_wraps)]
+fn is_linked(_file: &File, _path: &Path) -> std::io::Result<bool> {
+    Ok(true)
+}
@@ -1,12 +1,23 @@
+use crate::engine::ShardingConfig;
+use crate::expiry;
+use crate::lock;
+use crate::security;
 use std::path::Path;
-use std::time::{Duration, SystemTime};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 use tracing::{error, info};
 use walkdir::{DirEntry, WalkDir};
 
-pub(crate) async fn purge_tmp(root: &Path) {
+/// Age above which [`StorageBuilder::connect`](crate::StorageBuilder::connect) treats a
+/// temporary file as orphaned. Far longer than any write, so files of live writers in other
+/// processes sharing the root are left alone.
+pub(crate) const DEFAULT_TMP_MAX_AGE: Duration = Duration::from_secs(3600);
+
+/// Age above which [`Storage::purge_tmp`](crate::Storage::purge_tmp) removes a temporary file.
+pub(crate) const PURGE_TMP_MAX_AGE: Duration = Duration::from_secs(300);
+
+pub(crate) async fn purge_tmp(root: &Path, threshold: Duration) {
     let root = root.to_path_buf();
     let now = SystemTime::now();
-    let threshold = Duration::from_secs(300);
 
     match tokio::task::spawn_blocking(move || remove_stale(&root, now, threshold)).await {
         Ok((removed, failed)) if removed > 0 || failed > 0 => {
@@ -49,6 +60,49 @@ fn remove_stale(root: &Path, now: SystemTime, threshold: Duration) -> (usize, us
     (removed, failed)
 }
 
+/// Returns the latest mtime of the stored files beneath `dir`, or [`UNIX_EPOCH`] if there are
+/// none.
+///
+/// Only regular files are considered: directory mtimes also move when the storage creates and
+/// renames its own temporary files, and temporary files and sidecars are internal as well.
+pub(crate) fn latest_mtime(dir: &Path) -> std::io::Result<SystemTime> {
+    std::fs::read_dir(dir)?;
+
+    Ok(WalkDir::new(dir)
+        .min_depth(1)
+        .into_iter()
+        .flatten()
+        .filter(|entry| entry.file_type().is_file() && !is_tmp(entry) && !is_sidecar(entry))
+        .filter_map(|entry| entry.metadata().ok().and_then(|m| m.modified().ok()))
+        .fold(UNIX_EPOCH, SystemTime::max))
+}
+
+/// Collects the logical keys of every stored file beneath the namespace directory `dir`.
+///
+/// Symlinks are not followed, and temporary files, lock and expiry sidecars, or paths that do not follow
+/// the sharding layout are skipped.
+pub(crate) fn logical_keys(dir: &Path, sharding: ShardingConfig) -> Vec<String> {
+    WalkDir::new(dir)
+        .min_depth(1)
+        .into_iter()
+        .flatten()
+        .filter(|entry| entry.file_type().is_file() && !is_tmp(entry) && !is_sidecar(entry))
+        .filter_map(|entry| {
+            entry.path().strip_prefix(dir).ok().and_then(|rel| security::unshard(sharding, rel))
+        })
+        .collect()
+}
+
+/// Counts the regular files beneath `dir`, including temporary files but not lock or expiry
+/// sidecars, without following symlinks.
+pub(crate) fn count_files(dir: &Path) -> u64 {
+    WalkDir::new(dir)
+        .into_iter()
+        .flatten()
+        .filter(|entry| entry.file_type().is_file() && !is_sidecar(entry))
+        .count() as u64
+}
+
 fn is_tmp(entry: &DirEntry) -> bool {
     if !entry.file_type().is_file() {
         return false;
@@ -60,10 +114,19 @@ fn is_tmp(entry: &DirEntry) -> bool {
         .map_or(false, |name| name.contains(".mhubtmp."))
 }
 
+/// Returns `true` if `entry` was last modified more than `threshold` ago.
+///
+/// Files with an unreadable or future mtime are kept: they may belong to a live writer.
 fn is_stale(entry: &DirEntry, now: SystemTime, threshold: Duration) -> bool {
     std::fs::metadata(entry.path())
         .ok()
         .and_then(|m| m.modified().ok())
         .and_then(|modified| now.duration_since(modified).ok())
-        .map_or(true, |age| age > threshold)
+        .is_some_and(|age| age > threshold)
+}
+
+fn is_sidecar(entry: &DirEntry) -> bool {
+    entry.file_name().to_str().is_some_and(|name| {
+        name.ends_with(lock::LOCK_SUFFIX) || name.ends_with(expiry::EXPIRY_SUFFIX)
+    })
 }
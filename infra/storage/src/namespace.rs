@@ -1,10 +1,14 @@
 use crate::engine::Storage;
-use crate::error::{StorageError, StorageErrorExt};
+use crate::error::StorageError;
+use crate::lock::FileLock;
+use serde::Serialize;
+use serde::de::DeserializeOwned;
 use std::borrow::Cow;
 use std::fmt;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
-use tokio::fs;
+use std::time::{Duration, SystemTime};
+use tokio::io::AsyncRead;
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct NamespaceName(pub String);
@@ -104,12 +108,124 @@ impl NamespacedStorage {
     ///
     /// # Errors
     ///
-    /// Returns [`StorageError::FileNotFound`] if the path does not exist.
+    /// Returns [`StorageError::FileNotFound`] if the path does not exist or its TTL has passed.
     /// Returns [`StorageError::Decompress`] if the data is corrupted or compression is misconfigured.
+    /// Returns [`StorageError::ChecksumMismatch`] if the file carries a checksum that no longer
+    /// matches its contents.
+    /// Returns [`StorageError::Vault`] if encryption is enabled and the file cannot be unsealed.
     pub async fn read(&self, path: impl AsRef<Path>) -> Result<Vec<u8>, StorageError> {
         self.storage.read_internal(Some(&self.namespace), path).await
     }
 
+    /// Reads up to `len` bytes of a file starting at `offset`.
+    ///
+    /// See [`Storage::read_range`] for clamping and format restrictions.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`StorageError::Unsupported`] if the file is compressed or encrypted.
+    /// Returns [`StorageError::FileNotFound`] if the path does not exist.
+    /// Returns [`StorageError::Io`] if the file cannot be read.
+    pub async fn read_range(
+        &self,
+        path: impl AsRef<Path>,
+        offset: u64,
+        len: u64,
+    ) -> Result<Vec<u8>, StorageError> {
+        self.storage.read_range_internal(Some(&self.namespace), path, offset, len).await
+    }
+
+    /// Acquires an exclusive advisory lock on a file of this namespace, waiting for it.
+    ///
+    /// See [`Storage::lock`] for the semantics and caveats.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`StorageError::PathTraversalAttempt`] if the path escapes the sandbox.
+    /// Returns [`StorageError::Io`] if the sidecar cannot be created or locked.
+    pub async fn lock(&self, path: impl AsRef<Path>) -> Result<FileLock, StorageError> {
+        self.storage.lock_internal(Some(&self.namespace), path, true).await
+    }
+
+    /// Acquires an exclusive advisory lock on a file of this namespace without waiting.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`StorageError::Locked`] if another handle holds the lock.
+    /// Returns [`StorageError::PathTraversalAttempt`] if the path escapes the sandbox.
+    /// Returns [`StorageError::Io`] if the sidecar cannot be created or locked.
+    pub async fn try_lock(&self, path: impl AsRef<Path>) -> Result<FileLock, StorageError> {
+        self.storage.lock_internal(Some(&self.namespace), path, false).await
+    }
+
+    /// Reads a file of this namespace and decodes it as UTF-8.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use mhub_storage::{Storage, StorageError};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), StorageError> {
+    /// # let tmp = tempfile::tempdir().unwrap();
+    /// # let storage = Storage::builder().root(tmp.path()).connect().await?;
+    /// let docs = storage.namespace("docs")?;
+    /// docs.write("intro.md", "# Intro".as_bytes()).await?;
+    /// assert_eq!(docs.read_to_string("intro.md").await?, "# Intro");
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`read`](Self::read), and [`StorageError::Encoding`] if the
+    /// contents are not valid UTF-8.
+    pub async fn read_to_string(&self, path: impl AsRef<Path>) -> Result<String, StorageError> {
+        self.storage.read_to_string_internal(Some(&self.namespace), path).await
+    }
+
+    /// Reads a file of this namespace and deserializes it from JSON.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use mhub_storage::{Storage, StorageError};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), StorageError> {
+    /// # let tmp = tempfile::tempdir().unwrap();
+    /// # let storage = Storage::builder().root(tmp.path()).connect().await?;
+    /// let user = storage.namespace("user_42")?;
+    /// user.write_json("tags.json", &["admin", "ops"]).await?;
+    ///
+    /// let tags: Vec<String> = user.read_json("tags.json").await?;
+    /// assert_eq!(tags, ["admin", "ops"]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`read`](Self::read), and [`StorageError::Serialize`] if the
+    /// contents are not valid JSON for `T`.
+    pub async fn read_json<T>(&self, path: impl AsRef<Path>) -> Result<T, StorageError>
+    where
+        T: DeserializeOwned,
+    {
+        self.storage.read_json_internal(Some(&self.namespace), path).await
+    }
+
+    /// Serializes `value` to JSON and writes it to this namespace atomically.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`write`](Self::write), and [`StorageError::Serialize`] if
+    /// `value` cannot be serialized.
+    pub async fn write_json<T>(&self, path: impl AsRef<Path>, value: &T) -> Result<(), StorageError>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.storage.write_json_internal(Some(&self.namespace), path, value).await
+    }
+
     /// Writes data to a file in storage atomically.
     ///
     /// This method ensures data integrity by using an "atomic swap" pattern:
@@ -134,6 +250,52 @@ impl NamespacedStorage {
         self.storage.write_internal(Some(&self.namespace), path, data).await
     }
 
+    /// Streams `reader` into a file of this namespace atomically as a single LZ4 frame.
+    ///
+    /// See [`Storage::write_stream`] for the format caveats: streamed files are only
+    /// readable through [`read_stream`](Self::read_stream).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`StorageError::PathTraversalAttempt`] if the path escapes the sandbox.
+    /// Returns [`StorageError::Io`] if `reader` fails, the disk is full, or a hardware failure occurs.
+    pub async fn write_stream(
+        &self,
+        path: impl AsRef<Path>,
+        reader: impl AsyncRead + Unpin,
+    ) -> Result<(), StorageError> {
+        self.storage.write_stream_internal(Some(&self.namespace), path, reader).await
+    }
+
+    /// Opens a file written by [`write_stream`](Self::write_stream) for streaming reads.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`StorageError::FileNotFound`] if the path does not exist.
+    /// Returns [`StorageError::Io`] if the file cannot be opened.
+    pub async fn read_stream(
+        &self,
+        path: impl AsRef<Path>,
+    ) -> Result<impl AsyncRead + Send + Unpin + use<>, StorageError> {
+        self.storage.read_stream_internal(Some(&self.namespace), path).await
+    }
+
+    /// Writes a file of this namespace atomically and schedules it to expire after `ttl`.
+    ///
+    /// See [`Storage::write_with_ttl`] for details.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`write`](Self::write).
+    pub async fn write_with_ttl(
+        &self,
+        path: impl AsRef<Path>,
+        data: &[u8],
+        ttl: Duration,
+    ) -> Result<(), StorageError> {
+        self.storage.write_with_ttl_internal(Some(&self.namespace), path, data, ttl).await
+    }
+
     /// Deletes a file from the storage sandbox.
     ///
     /// This method resolves the path (including sharding if applicable) and removes
@@ -152,6 +314,40 @@ impl NamespacedStorage {
         self.storage.delete_internal(Some(&self.namespace), path).await
     }
 
+    /// Copies a file within this namespace atomically, without recompressing it.
+    ///
+    /// See [`Storage::copy`] for details.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`StorageError::PathTraversalAttempt`] if either path escapes the sandbox.
+    /// Returns [`StorageError::FileNotFound`] if `from` does not exist.
+    /// Returns [`StorageError::Io`] if the copy fails.
+    pub async fn copy(
+        &self,
+        from: impl AsRef<Path>,
+        to: impl AsRef<Path>,
+    ) -> Result<(), StorageError> {
+        self.storage.copy_internal(Some(&self.namespace), from, to).await
+    }
+
+    /// Moves a file within this namespace.
+    ///
+    /// See [`Storage::rename`] for details; use it directly to move files across namespaces.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`StorageError::PathTraversalAttempt`] if either path escapes the sandbox.
+    /// Returns [`StorageError::FileNotFound`] if `from` does not exist.
+    /// Returns [`StorageError::Io`] if the move fails.
+    pub async fn rename(
+        &self,
+        from: impl AsRef<Path>,
+        to: impl AsRef<Path>,
+    ) -> Result<(), StorageError> {
+        self.storage.rename_internal(Some(&self.namespace), from, to).await
+    }
+
     /// Checks if a file exists within the storage sandbox.
     ///
     /// This performs a metadata check on the resolved physical path.
@@ -162,12 +358,11 @@ impl NamespacedStorage {
     ///
     /// # Errors
     ///
-    /// Returns `Ok(false)` if the file is not found. Returns an `Err` only if
-    /// path resolution fails (e.g., due to a security violation) or if a
+    /// Returns `Ok(false)` if the file is not found or its TTL has passed. Returns an `Err`
+    /// only if path resolution fails (e.g., due to a security violation) or if a
     /// critical I/O error occurs.
     pub fn exists(&self, path: impl AsRef<Path>) -> Result<bool, StorageError> {
-        let resolved = self.storage.resolve_internal(Some(&self.namespace), path)?;
-        Ok(resolved.exists())
+        self.storage.exists_internal(Some(&self.namespace), path)
     }
 
     /// Retrieves filesystem metadata for a file within the sandbox.
@@ -187,15 +382,41 @@ impl NamespacedStorage {
     ///
     /// # Errors
     ///
-    /// Returns [`StorageError::FileNotFound`] if the target does not exist.
+    /// Returns [`StorageError::FileNotFound`] if the target does not exist or its TTL has passed.
     /// Returns [`StorageError::Io`] if a hardware or permission error occurs.
     pub async fn metadata(
         &self,
         path: impl AsRef<Path>,
     ) -> Result<std::fs::Metadata, StorageError> {
-        let resolved = self.storage.resolve_internal(Some(&self.namespace), path)?;
-        fs::metadata(&resolved)
-            .await
-            .context(format!("Failed to get metadata: {}", resolved.display()))
+        self.storage.metadata_internal(Some(&self.namespace), path).await
+    }
+
+    /// Lists the logical keys of the files stored in this namespace, sorted.
+    ///
+    /// Keys are returned as written (e.g. `avatars/avatar.png`), not as their sharded
+    /// physical paths, and are filtered by the logical `prefix`, matched on whole path
+    /// components (`avatars` matches `avatars/a.png` but not `avatars2/a.png`); pass `""` to
+    /// list everything. An empty or missing namespace yields an empty list.
+    /// Temporary files of in-flight writes are skipped and symlinks are not followed.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`StorageError::PathTraversalAttempt`] if the prefix escapes the sandbox.
+    /// Returns [`StorageError::Io`] if the directory scan fails.
+    pub async fn list(&self, prefix: impl AsRef<Path>) -> Result<Vec<String>, StorageError> {
+        self.storage.list_internal(&self.namespace, prefix.as_ref()).await
+    }
+
+    /// Returns the most recent modification time across the namespace's files.
+    ///
+    /// Only stored files count: temporary files, sidecars, and directory mtimes are ignored, so
+    /// deletions do not advance the reported time.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`StorageError::DirectoryNotFound`] if nothing was written to the namespace yet.
+    /// Returns [`StorageError::Io`] if the namespace directory cannot be inspected.
+    pub async fn last_modified(&self) -> Result<SystemTime, StorageError> {
+        self.storage.last_modified_internal(Some(&self.namespace)).await
     }
 }
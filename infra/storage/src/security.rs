@@ -1,3 +1,4 @@
+use crate::engine::ShardingConfig;
 use crate::error::StorageError;
 use std::path::{Component, Path, PathBuf};
 
@@ -58,6 +59,7 @@ pub(crate) fn resolve_path(root: &Path, path: impl AsRef<Path>) -> Result<PathBu
 /// Subdirectories are preserved, and sharding is applied to the final filename.
 pub(crate) fn resolve_sharding(
     root: &Path,
+    sharding: ShardingConfig,
     ns: Option<&str>,
     path: impl AsRef<Path>,
 ) -> Result<PathBuf, StorageError> {
@@ -76,19 +78,31 @@ pub(crate) fn resolve_sharding(
     if let Some(p) = parent {
         shard.push(p);
     }
-
-    let chars: Vec<char> = filename.chars().collect();
-    if chars.len() >= 4 {
-        let shard1: String = chars[0..2].iter().collect();
-        let shard2: String = chars[2..4].iter().collect();
-        shard.push(shard1);
-        shard.push(shard2);
-    }
+    shard.extend(sharding.shards(filename));
     shard.push(filename);
 
     resolve_path(root, shard)
 }
 
+/// Reverses [`resolve_sharding`] for a path relative to the namespace directory.
+///
+/// Returns the logical key with `/` separators, or `None` if the path is not valid UTF-8
+/// or lacks the shard directories its file name requires.
+pub(crate) fn unshard(sharding: ShardingConfig, relative: &Path) -> Option<String> {
+    let mut segments =
+        relative.components().map(|c| c.as_os_str().to_str()).collect::<Option<Vec<_>>>()?;
+    let filename = segments.pop()?;
+
+    for expected in sharding.shards(filename).iter().rev() {
+        if segments.pop()? != expected {
+            return None;
+        }
+    }
+
+    segments.push(filename);
+    Some(segments.join("/"))
+}
+
 fn validate_canonical(root: &Path, canonical: PathBuf) -> Result<PathBuf, StorageError> {
     if canonical.starts_with(root) {
         Ok(canonical)
@@ -0,0 +1,111 @@
+//! Streaming LZ4 frame encoding and decoding for large blobs.
+//!
+//! Unlike the block format used by [`Compression::Lz4`](crate::Compression::Lz4), the frame
+//! format is self-delimiting and can be produced and consumed in chunks, so memory use stays
+//! bounded regardless of the blob size.
+
+use lz4_flex::frame::{FrameDecoder, FrameEncoder};
+use std::io::{self, Read, Write};
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use tokio::fs;
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWriteExt, ReadBuf};
+use tokio::sync::mpsc;
+
+/// Leading bytes of every LZ4 frame, i.e. of every streamed file.
+pub(crate) const FRAME_MAGIC: [u8; 4] = 0x184D_2204_u32.to_le_bytes();
+
+const CHUNK_SIZE: usize = 64 * 1024;
+const READ_AHEAD: usize = 4;
+
+/// Compresses everything from `reader` into `file` as a single LZ4 frame.
+///
+/// Returns the number of uncompressed bytes consumed.
+pub(crate) async fn encode<R>(mut reader: R, file: &mut fs::File) -> io::Result<u64>
+where
+    R: AsyncRead + Unpin,
+{
+    let mut encoder = FrameEncoder::new(Vec::with_capacity(CHUNK_SIZE));
+    let mut chunk = vec![0; CHUNK_SIZE];
+    let mut total = 0;
+
+    loop {
+        let read = reader.read(&mut chunk).await?;
+        if read == 0 {
+            break;
+        }
+        total += read as u64;
+        encoder.write_all(&chunk[..read])?;
+
+        let pending = encoder.get_mut();
+        if !pending.is_empty() {
+            file.write_all(pending).await?;
+            pending.clear();
+        }
+    }
+
+    file.write_all(&encoder.finish()?).await?;
+    Ok(total)
+}
+
+/// Decodes an LZ4 frame from `file` on a blocking thread, exposing the plain bytes as an
+/// [`AsyncRead`].
+pub(crate) fn decode(file: std::fs::File) -> FrameReader {
+    let (tx, rx) = mpsc::channel(READ_AHEAD);
+    tokio::task::spawn_blocking(move || {
+        let mut decoder = FrameDecoder::new(io::BufReader::new(file));
+        loop {
+            let mut chunk = vec![0; CHUNK_SIZE];
+            let message = match decoder.read(&mut chunk) {
+                Ok(0) => return,
+                Ok(read) => {
+                    chunk.truncate(read);
+                    Ok(chunk)
+                },
+                Err(err) if err.kind() == io::ErrorKind::Interrupted => continue,
+                Err(err) => Err(err),
+            };
+            let failed = message.is_err();
+            // A closed channel means the reader was dropped; stop decoding.
+            if tx.blocking_send(message).is_err() || failed {
+                return;
+            }
+        }
+    });
+
+    FrameReader { rx, chunk: Vec::new(), offset: 0 }
+}
+
+/// Async reader over the chunks produced by the blocking decoder task.
+#[derive(Debug)]
+pub(crate) struct FrameReader {
+    rx: mpsc::Receiver<io::Result<Vec<u8>>>,
+    chunk: Vec<u8>,
+    offset: usize,
+}
+
+impl AsyncRead for FrameReader {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        while self.offset == self.chunk.len() {
+            match self.rx.poll_recv(cx) {
+                Poll::Ready(Some(Ok(chunk))) => {
+                    self.chunk = chunk;
+                    self.offset = 0;
+                },
+                Poll::Ready(Some(Err(err))) => return Poll::Ready(Err(err)),
+                Poll::Ready(None) => return Poll::Ready(Ok(())),
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+
+        let available = &self.chunk[self.offset..];
+        let len = available.len().min(buf.remaining());
+        buf.put_slice(&available[..len]);
+        self.offset += len;
+        Poll::Ready(Ok(()))
+    }
+}
@@ -92,3 +92,618 @@ async fn test_read_missing_returns_file_not_found() {
         other => panic!("unexpected error: {other:?}"),
     }
 }
+
+#[tokio::test]
+async fn test_last_modified_tracks_stored_files_only() {
+    let temp = TempDir::new().unwrap();
+    let storage = Storage::builder().root(temp.path()).connect().await.unwrap();
+    let ns = storage.namespace("sync").unwrap();
+
+    ns.write("first.bin", b"1").await.unwrap();
+    backdate(temp.path(), std::time::Duration::from_secs(60));
+    let initial = ns.last_modified().await.unwrap();
+
+    ns.write("second.bin", b"2").await.unwrap();
+    let after_write = ns.last_modified().await.unwrap();
+    assert!(after_write > initial, "writing a new file must advance last_modified");
+
+    backdate(temp.path(), std::time::Duration::from_secs(30));
+    let settled = ns.last_modified().await.unwrap();
+    ns.delete("first.bin").await.unwrap();
+    let _guard = ns.lock("second.bin").await.unwrap();
+    assert_eq!(
+        ns.last_modified().await.unwrap(),
+        settled,
+        "deletions and sidecars must not advance last_modified"
+    );
+
+    assert_eq!(storage.last_modified().await.unwrap(), settled);
+}
+
+/// Sets the mtime of every regular file beneath `root` to `age` ago.
+fn backdate(root: &std::path::Path, age: std::time::Duration) {
+    let mtime = std::time::SystemTime::now() - age;
+    for entry in walkdir::WalkDir::new(root).into_iter().flatten() {
+        if entry.file_type().is_file() {
+            let file = std::fs::File::options().append(true).open(entry.path()).unwrap();
+            file.set_modified(mtime).unwrap();
+        }
+    }
+}
+
+#[tokio::test]
+async fn test_last_modified_missing_namespace() {
+    let temp = TempDir::new().unwrap();
+    let storage = Storage::builder().root(temp.path()).connect().await.unwrap();
+
+    let err =
+        storage.namespace("empty").unwrap().last_modified().await.expect_err("expected error");
+    assert!(matches!(err, StorageError::DirectoryNotFound { .. }));
+}
+
+#[tokio::test]
+async fn test_namespace_list_returns_logical_keys() {
+    let temp = TempDir::new().unwrap();
+    let storage = Storage::builder().root(temp.path()).connect().await.unwrap();
+    let ns = storage.namespace("listing").unwrap();
+
+    ns.write("avatars/avatar.png", b"a").await.unwrap();
+    ns.write("avatars/big/photo.jpg", b"b").await.unwrap();
+    ns.write("cv.md", b"c").await.unwrap();
+    ns.write("avatars2/other.png", b"d").await.unwrap();
+    let tmp = ns.resolve("avatars/avatar.png").unwrap().with_file_name("avatar.png.mhubtmp.7");
+    std::fs::write(tmp, b"partial").unwrap();
+
+    let keys = ns.list("").await.unwrap();
+    assert_eq!(
+        keys,
+        vec!["avatars/avatar.png", "avatars/big/photo.jpg", "avatars2/other.png", "cv.md"]
+    );
+
+    let keys = ns.list("avatars").await.unwrap();
+    assert_eq!(keys, vec!["avatars/avatar.png", "avatars/big/photo.jpg"]);
+
+    assert!(ns.list("../other").await.is_err());
+    assert!(storage.namespace("empty").unwrap().list("").await.unwrap().is_empty());
+    assert!(storage.namespace("empty").unwrap().list("avatars").await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_purge_tmp_older_than_keeps_fresh_files() {
+    use std::time::{Duration, SystemTime};
+
+    let temp = TempDir::new().unwrap();
+    let storage = Storage::builder().root(temp.path()).connect().await.unwrap();
+    storage.write("docs/report.md", b"final").await.unwrap();
+
+    let target = storage.resolve("docs/report.md").unwrap();
+    let dir = target.parent().unwrap();
+    let fresh = dir.join("report.md.mhubtmp.1");
+    let old = dir.join("report.md.mhubtmp.2");
+    std::fs::write(&fresh, b"in progress").unwrap();
+    std::fs::write(&old, b"orphaned").unwrap();
+    std::fs::File::options()
+        .write(true)
+        .open(&old)
+        .unwrap()
+        .set_modified(SystemTime::now() - Duration::from_secs(7200))
+        .unwrap();
+
+    storage.purge_tmp_older_than(Duration::from_secs(3600)).await;
+    assert!(fresh.exists(), "a temp file younger than the threshold must survive");
+    assert!(!old.exists(), "a temp file older than the threshold must be removed");
+    assert_eq!(storage.read("docs/report.md").await.unwrap(), b"final");
+
+    Storage::builder().root(temp.path()).connect().await.unwrap();
+    assert!(fresh.exists(), "connect must not purge recent temp files");
+}
+
+#[tokio::test]
+async fn test_stream_round_trip_is_atomic() {
+    use tokio::io::AsyncReadExt;
+
+    let temp = TempDir::new().unwrap();
+    let storage = Storage::builder().root(temp.path()).connect().await.unwrap();
+    let ns = storage.namespace("blobs").unwrap();
+    let blob: Vec<u8> = (0..300_000u32).map(|i| (i % 251) as u8).collect();
+
+    ns.write_stream("large.bin", blob.as_slice()).await.unwrap();
+
+    let mut reader = ns.read_stream("large.bin").await.unwrap();
+    let mut restored = Vec::new();
+    reader.read_to_end(&mut restored).await.unwrap();
+    assert_eq!(restored, blob);
+    assert!(ns.metadata("large.bin").await.unwrap().len() < blob.len() as u64);
+
+    let failing = blob[..1024].chain(FailingReader);
+    assert!(ns.write_stream("large.bin", failing).await.is_err());
+
+    let mut reader = ns.read_stream("large.bin").await.unwrap();
+    let mut restored = Vec::new();
+    reader.read_to_end(&mut restored).await.unwrap();
+    assert_eq!(restored, blob);
+
+    let shard = ns.resolve("large.bin").unwrap();
+    assert_eq!(std::fs::read_dir(shard.parent().unwrap()).unwrap().count(), 1);
+}
+
+#[tokio::test]
+async fn test_delete_namespace_removes_nested_files() {
+    let temp = TempDir::new().unwrap();
+    let storage = Storage::builder().root(temp.path()).connect().await.unwrap();
+    let tenant = storage.namespace("tenant_42").unwrap();
+    let other = storage.namespace("tenant_43").unwrap();
+
+    tenant.write("profile.json", b"{}").await.unwrap();
+    tenant.write("avatars/avatar.png", b"a").await.unwrap();
+    tenant.write("docs/2024/report.pdf", b"r").await.unwrap();
+    other.write("profile.json", b"{}").await.unwrap();
+    tenant.write_with_ttl("cache.bin", b"c", std::time::Duration::from_secs(3600)).await.unwrap();
+    drop(tenant.lock("profile.json").await.unwrap());
+
+    // Lock and expiry sidecars go with the namespace without being counted.
+    assert_eq!(storage.delete_namespace("tenant_42").await.unwrap(), 4);
+    assert!(!temp.path().join("tenant_42").exists());
+    assert!(other.exists("profile.json").unwrap());
+
+    assert!(matches!(
+        storage.delete_namespace("tenant_42").await,
+        Err(StorageError::DirectoryNotFound { .. })
+    ));
+    assert!(matches!(
+        storage.delete_namespace("..").await,
+        Err(StorageError::PathTraversalAttempt { .. })
+    ));
+}
+
+#[tokio::test]
+async fn test_copy_and_rename_within_namespace() {
+    let temp = TempDir::new().unwrap();
+    let storage =
+        Storage::builder().root(temp.path()).compression(Compression::Lz4).connect().await.unwrap();
+    let ns = storage.namespace("docs").unwrap();
+    ns.write("draft.txt", b"hello hello hello").await.unwrap();
+
+    ns.copy("draft.txt", "archive/draft.txt").await.unwrap();
+    assert_eq!(ns.read("archive/draft.txt").await.unwrap(), b"hello hello hello");
+    assert_eq!(
+        std::fs::read(ns.resolve("draft.txt").unwrap()).unwrap(),
+        std::fs::read(ns.resolve("archive/draft.txt").unwrap()).unwrap()
+    );
+
+    ns.rename("draft.txt", "final.txt").await.unwrap();
+    assert!(!ns.exists("draft.txt").unwrap());
+    assert_eq!(ns.read("final.txt").await.unwrap(), b"hello hello hello");
+
+    assert!(matches!(
+        ns.rename("draft.txt", "other.txt").await,
+        Err(StorageError::FileNotFound { .. })
+    ));
+    assert!(ns.copy("final.txt", "../../escape.txt").await.is_err());
+}
+
+#[tokio::test]
+async fn test_rename_across_namespaces() {
+    let temp = TempDir::new().unwrap();
+    let storage = Storage::builder().root(temp.path()).connect().await.unwrap();
+    let from = storage.namespace("tenant_a").unwrap();
+    let to = storage.namespace("tenant_b").unwrap();
+    from.write("invoices/march.pdf", b"pdf").await.unwrap();
+
+    storage.rename("tenant_a/invoices/march.pdf", "tenant_b/march.pdf").await.unwrap();
+
+    assert!(!from.exists("invoices/march.pdf").unwrap());
+    assert_eq!(to.read("march.pdf").await.unwrap(), b"pdf");
+    assert_eq!(to.list("").await.unwrap(), vec!["march.pdf"]);
+}
+
+#[tokio::test]
+async fn test_checksum_detects_corruption() {
+    let temp = TempDir::new().unwrap();
+    let storage = Storage::builder().root(temp.path()).checksums(true).connect().await.unwrap();
+
+    let ns = storage.namespace("accounts").unwrap();
+
+    ns.write("ledger.dat", b"balance=100").await.unwrap();
+    assert_eq!(ns.read("ledger.dat").await.unwrap(), b"balance=100");
+
+    let path = ns.resolve("ledger.dat").unwrap();
+    let mut raw = std::fs::read(&path).unwrap();
+    raw[8] ^= 0x01;
+    std::fs::write(&path, raw).unwrap();
+
+    assert!(matches!(ns.read("ledger.dat").await, Err(StorageError::ChecksumMismatch { .. })));
+}
+
+#[tokio::test]
+async fn test_checksums_keep_legacy_files_readable() {
+    let temp = TempDir::new().unwrap();
+    let legacy =
+        Storage::builder().root(temp.path()).compression(Compression::Lz4).connect().await.unwrap();
+    legacy.write("old.bin", b"written before checksums").await.unwrap();
+
+    let storage = Storage::builder()
+        .root(temp.path())
+        .compression(Compression::Lz4)
+        .checksums(true)
+        .connect()
+        .await
+        .unwrap();
+    assert_eq!(storage.read("old.bin").await.unwrap(), b"written before checksums");
+
+    storage.write("new.bin", b"written with checksums").await.unwrap();
+    assert_eq!(legacy.read("new.bin").await.unwrap(), b"written with checksums");
+}
+
+#[tokio::test]
+async fn test_text_and_json_helpers_map_errors() {
+    let temp = TempDir::new().unwrap();
+    let storage = Storage::builder().root(temp.path()).connect().await.unwrap();
+    let ns = storage.namespace("config").unwrap();
+
+    ns.write("binary.bin", &[0xff, 0xfe]).await.unwrap();
+    assert!(matches!(ns.read_to_string("binary.bin").await, Err(StorageError::Encoding { .. })));
+    assert!(matches!(
+        ns.read_json::<Vec<u32>>("binary.bin").await,
+        Err(StorageError::Serialize { .. })
+    ));
+
+    ns.write_json("ports.json", &[80u16, 443]).await.unwrap();
+    assert_eq!(ns.read_to_string("ports.json").await.unwrap(), "[80,443]");
+    assert_eq!(storage.read_json::<Vec<u16>>("config/ports.json").await.unwrap(), [80, 443]);
+}
+
+#[tokio::test]
+async fn test_read_range_slices_plain_files() {
+    let temp = TempDir::new().unwrap();
+    let storage = Storage::builder().root(temp.path()).checksums(true).connect().await.unwrap();
+    let assets = storage.namespace("assets").unwrap();
+    assets.write("video.bin", b"0123456789").await.unwrap();
+
+    assert_eq!(assets.read_range("video.bin", 2, 3).await.unwrap(), b"234");
+    assert_eq!(assets.read_range("video.bin", 7, 100).await.unwrap(), b"789");
+    assert!(assets.read_range("video.bin", 10, 5).await.unwrap().is_empty());
+    assert!(assets.read_range("video.bin", u64::MAX, 5).await.unwrap().is_empty());
+    assert!(matches!(
+        assets.read_range("missing.bin", 0, 1).await,
+        Err(StorageError::FileNotFound { .. })
+    ));
+
+    assets.write_stream("stream.bin", &b"0123456789"[..]).await.unwrap();
+    assert!(matches!(
+        assets.read_range("stream.bin", 0, 1).await,
+        Err(StorageError::Unsupported { .. })
+    ));
+}
+
+#[tokio::test]
+async fn test_read_range_rejects_compressed_storage() {
+    let temp = TempDir::new().unwrap();
+    let storage =
+        Storage::builder().root(temp.path()).compression(Compression::Lz4).connect().await.unwrap();
+    storage.write("assets/video.bin", b"0123456789").await.unwrap();
+
+    assert!(matches!(
+        storage.read_range("assets/video.bin", 0, 4).await,
+        Err(StorageError::Unsupported { .. })
+    ));
+}
+
+#[tokio::test]
+async fn test_lock_excludes_second_holder() {
+    let temp = TempDir::new().unwrap();
+    let storage = Storage::builder().root(temp.path()).lock_writes(true).connect().await.unwrap();
+    let ns = storage.namespace("counters").unwrap();
+
+    let lock = ns.lock("visits.txt").await.unwrap();
+    assert!(matches!(ns.try_lock("visits.txt").await, Err(StorageError::Locked { .. })));
+
+    let writer = ns.clone();
+    let (started_tx, started_rx) = tokio::sync::oneshot::channel();
+    let blocked = tokio::spawn(async move {
+        started_tx.send(()).unwrap();
+        writer.write("visits.txt", b"2").await
+    });
+    started_rx.await.unwrap();
+
+    // The writer waits for the holder, so its value lands last whatever the scheduling.
+    lock.write(b"1").await.unwrap();
+    assert_eq!(lock.read().await.unwrap(), b"1");
+    drop(lock);
+
+    blocked.await.unwrap().unwrap();
+    let relocked = ns.try_lock("visits.txt").await.unwrap();
+    assert_eq!(relocked.read().await.unwrap(), b"2");
+    assert_eq!(ns.list("").await.unwrap(), vec!["visits.txt"]);
+
+    // A held lock sidecar survives deletion; a free one is removed with the file.
+    ns.delete("visits.txt").await.unwrap();
+    assert_eq!(lock_sidecars(temp.path()), 1);
+    drop(relocked);
+    ns.write("visits.txt", b"3").await.unwrap();
+    ns.delete("visits.txt").await.unwrap();
+    assert_eq!(lock_sidecars(temp.path()), 0);
+}
+
+fn lock_sidecars(root: &std::path::Path) -> usize {
+    walkdir::WalkDir::new(root)
+        .into_iter()
+        .flatten()
+        .filter(|entry| entry.file_name().to_string_lossy().ends_with(".mhublock"))
+        .count()
+}
+
+#[tokio::test]
+async fn test_ttl_expiry_and_sweep() {
+    let temp = TempDir::new().unwrap();
+    let storage = Storage::builder().root(temp.path()).connect().await.unwrap();
+    let cache = storage.namespace("cache").unwrap();
+
+    cache.write_with_ttl("stale.json", b"old", std::time::Duration::ZERO).await.unwrap();
+    cache.write_with_ttl("fresh.json", b"new", std::time::Duration::from_secs(3600)).await.unwrap();
+    cache.write("pinned.json", b"keep").await.unwrap();
+
+    assert!(matches!(cache.read("stale.json").await, Err(StorageError::FileNotFound { .. })));
+    assert!(matches!(
+        cache.read_range("stale.json", 0, 1).await,
+        Err(StorageError::FileNotFound { .. })
+    ));
+    assert!(matches!(cache.metadata("stale.json").await, Err(StorageError::FileNotFound { .. })));
+    assert!(!cache.exists("stale.json").unwrap());
+    assert_eq!(cache.read("fresh.json").await.unwrap(), b"new");
+
+    assert_eq!(storage.sweep_expired().await.unwrap(), 1);
+    assert!(!cache.exists("stale.json").unwrap());
+    assert_eq!(cache.list("").await.unwrap(), vec!["fresh.json", "pinned.json"]);
+    assert_eq!(storage.sweep_expired().await.unwrap(), 0);
+
+    // Rewriting without a TTL makes the file permanent again.
+    cache.write_with_ttl("pinned.json", b"temp", std::time::Duration::ZERO).await.unwrap();
+    cache.write("pinned.json", b"keep").await.unwrap();
+    assert_eq!(cache.read("pinned.json").await.unwrap(), b"keep");
+}
+
+#[tokio::test]
+async fn test_stream_reads_honour_expiry() {
+    use tokio::io::AsyncReadExt;
+
+    let temp = TempDir::new().unwrap();
+    let storage = Storage::builder().root(temp.path()).lock_writes(true).connect().await.unwrap();
+
+    storage.write_with_ttl("blob.bin", b"old", std::time::Duration::ZERO).await.unwrap();
+    assert!(matches!(
+        storage.read_stream("blob.bin").await,
+        Err(StorageError::FileNotFound { .. })
+    ));
+
+    // A streamed write replaces the expiring file with a permanent one.
+    storage.write_stream("blob.bin", b"new".as_slice()).await.unwrap();
+    let mut restored = Vec::new();
+    storage.read_stream("blob.bin").await.unwrap().read_to_end(&mut restored).await.unwrap();
+    assert_eq!(restored, b"new");
+    assert_eq!(storage.sweep_expired().await.unwrap(), 0);
+}
+
+#[tokio::test]
+async fn test_huge_ttl_never_expires() {
+    let temp = TempDir::new().unwrap();
+    let storage = Storage::builder().root(temp.path()).connect().await.unwrap();
+
+    storage.write_with_ttl("forever.bin", b"kept", std::time::Duration::MAX).await.unwrap();
+
+    assert_eq!(storage.read("forever.bin").await.unwrap(), b"kept");
+    assert_eq!(storage.sweep_expired().await.unwrap(), 0);
+}
+
+#[tokio::test]
+async fn test_copy_and_rename_carry_expiry() {
+    let temp = TempDir::new().unwrap();
+    let storage = Storage::builder().root(temp.path()).connect().await.unwrap();
+
+    storage.write_with_ttl("stale.bin", b"old", std::time::Duration::ZERO).await.unwrap();
+    storage.write("pinned.bin", b"keep").await.unwrap();
+    storage.write_with_ttl("copy.bin", b"tmp", std::time::Duration::ZERO).await.unwrap();
+
+    storage.copy("stale.bin", "copied.bin").await.unwrap();
+    storage.rename("stale.bin", "moved.bin").await.unwrap();
+    // Copying a permanent file over an expiring one makes the target permanent.
+    storage.copy("pinned.bin", "copy.bin").await.unwrap();
+
+    for path in ["copied.bin", "moved.bin"] {
+        assert!(matches!(storage.read(path).await, Err(StorageError::FileNotFound { .. })));
+    }
+    assert_eq!(storage.read("copy.bin").await.unwrap(), b"keep");
+    assert_eq!(storage.sweep_expired().await.unwrap(), 2);
+    assert_eq!(storage.list("").await.unwrap(), vec!["copy.bin", "pinned.bin"]);
+}
+
+#[tokio::test]
+async fn test_zero_sweep_interval_is_accepted() {
+    let temp = TempDir::new().unwrap();
+    let storage = Storage::builder()
+        .root(temp.path())
+        .expiry_sweep_interval(std::time::Duration::ZERO)
+        .connect()
+        .await;
+    assert!(storage.is_ok());
+}
+
+#[tokio::test]
+async fn test_sweep_skips_locked_files() {
+    let temp = TempDir::new().unwrap();
+    let storage = Storage::builder().root(temp.path()).connect().await.unwrap();
+    storage.write_with_ttl("held.bin", b"old", std::time::Duration::ZERO).await.unwrap();
+
+    let lock = storage.lock("held.bin").await.unwrap();
+    assert_eq!(storage.sweep_expired().await.unwrap(), 0);
+    lock.write(b"renewed").await.unwrap();
+    drop(lock);
+
+    assert_eq!(storage.sweep_expired().await.unwrap(), 0);
+    assert_eq!(storage.read("held.bin").await.unwrap(), b"renewed");
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_background_sweeper_removes_expired_files() {
+    let interval = std::time::Duration::from_secs(60);
+    let temp = TempDir::new().unwrap();
+    let storage = Storage::builder()
+        .root(temp.path())
+        .expiry_sweep_interval(interval)
+        .connect()
+        .await
+        .unwrap();
+    let cache = storage.namespace("cache").unwrap();
+    cache.write_with_ttl("token.bin", b"t", std::time::Duration::ZERO).await.unwrap();
+    // `exists` already hides the expired file, so look for it on disk.
+    let on_disk = || {
+        walkdir::WalkDir::new(temp.path())
+            .into_iter()
+            .flatten()
+            .any(|entry| entry.file_name() == "token.bin")
+    };
+    assert!(on_disk());
+
+    // The clock is paused, so the sweeper only ticks when the test advances it.
+    let swept = tokio::time::timeout(interval * 10, async {
+        while on_disk() {
+            tokio::time::advance(interval).await;
+            tokio::task::yield_now().await;
+        }
+    })
+    .await;
+    assert!(swept.is_ok(), "the sweeper never removed the expired file");
+}
+
+fn vault(secret: &str) -> mhub_vault::Vault {
+    mhub_vault::Vault::builder()
+        .derived_keys(secret, "storage-salt", "node-1")
+        .unwrap()
+        .build()
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_encryption_round_trip() {
+    let temp = TempDir::new().unwrap();
+    let storage = Storage::builder()
+        .root(temp.path())
+        .compression(Compression::Lz4)
+        .encryption(vault("master-key"))
+        .connect()
+        .await
+        .unwrap();
+    let ns = storage.namespace("secrets").unwrap();
+
+    ns.write("api/token.txt", b"plain secret value").await.unwrap();
+    assert_eq!(ns.read("api/token.txt").await.unwrap(), b"plain secret value");
+
+    let raw = std::fs::read(ns.resolve("api/token.txt").unwrap()).unwrap();
+    assert!(!raw.windows(6).any(|w| w == b"secret"));
+
+    ns.rename("api/token.txt", "api/moved.txt").await.unwrap();
+    assert_eq!(ns.read("api/moved.txt").await.unwrap(), b"plain secret value");
+
+    // Ciphertext is bound to its path, so swapping files is detected.
+    ns.write("other.txt", b"other").await.unwrap();
+    std::fs::copy(ns.resolve("other.txt").unwrap(), ns.resolve("api/moved.txt").unwrap()).unwrap();
+    assert!(matches!(ns.read("api/moved.txt").await, Err(StorageError::Vault { .. })));
+}
+
+#[tokio::test]
+async fn test_encryption_skips_plaintext_checksum() {
+    let temp = TempDir::new().unwrap();
+    let storage = Storage::builder()
+        .root(temp.path())
+        .encryption(vault("master-key"))
+        .checksums(true)
+        .connect()
+        .await
+        .unwrap();
+
+    storage.write("ledger.dat", b"balance=100").await.unwrap();
+    assert_eq!(storage.read("ledger.dat").await.unwrap(), b"balance=100");
+
+    let raw = std::fs::read(storage.resolve("ledger.dat").unwrap()).unwrap();
+    assert!(!raw.ends_with(b"MHUBCRC1"), "encrypted files must not carry a plaintext CRC");
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn test_encryption_binds_non_utf8_paths() {
+    use std::ffi::OsStr;
+    use std::os::unix::ffi::OsStrExt;
+    use std::path::Path;
+
+    let temp = TempDir::new().unwrap();
+    let storage = Storage::builder()
+        .root(temp.path())
+        .encryption(vault("master-key"))
+        .connect()
+        .await
+        .unwrap();
+    let first = Path::new(OsStr::from_bytes(b"dir-\xff")).join("secret.txt");
+    let second = Path::new(OsStr::from_bytes(b"dir-\xfe")).join("secret.txt");
+
+    storage.write(&first, b"first").await.unwrap();
+    storage.write(&second, b"second").await.unwrap();
+    std::fs::copy(storage.resolve(&first).unwrap(), storage.resolve(&second).unwrap()).unwrap();
+
+    assert!(matches!(storage.read(&second).await, Err(StorageError::Vault { .. })));
+}
+
+#[tokio::test]
+async fn test_encryption_rejects_wrong_key() {
+    let temp = TempDir::new().unwrap();
+    let writer =
+        Storage::builder().root(temp.path()).encryption(vault("key-one")).connect().await.unwrap();
+    writer.write("vault/data.bin", b"classified").await.unwrap();
+
+    let reader =
+        Storage::builder().root(temp.path()).encryption(vault("key-two")).connect().await.unwrap();
+    assert!(matches!(reader.read("vault/data.bin").await, Err(StorageError::Vault { .. })));
+}
+
+async fn sharded_path(sharding: ShardingConfig) -> (TempDir, std::path::PathBuf, Vec<String>) {
+    let temp = TempDir::new().unwrap();
+    let storage = Storage::builder().root(temp.path()).sharding(sharding).connect().await.unwrap();
+    let ns = storage.namespace("media").unwrap();
+    ns.write("clips/abcdefgh.mp4", b"clip").await.unwrap();
+    ns.write("a.txt", b"short").await.unwrap();
+
+    assert_eq!(ns.read("clips/abcdefgh.mp4").await.unwrap(), b"clip");
+    let relative = ns.resolve("clips/abcdefgh.mp4").unwrap();
+    let relative = relative.strip_prefix(storage.resolve("media").unwrap()).unwrap().to_owned();
+    let keys = ns.list("").await.unwrap();
+    (temp, relative, keys)
+}
+
+#[tokio::test]
+async fn test_sharding_depths() {
+    let expected_keys = vec!["a.txt".to_owned(), "clips/abcdefgh.mp4".to_owned()];
+    let cases = [
+        (ShardingConfig::Disabled, "clips/abcdefgh.mp4"),
+        (ShardingConfig::Depth { levels: 0, width: 2 }, "clips/abcdefgh.mp4"),
+        (ShardingConfig::Depth { levels: 1, width: 3 }, "clips/abc/abcdefgh.mp4"),
+        (ShardingConfig::Depth { levels: 3, width: 2 }, "clips/ab/cd/ef/abcdefgh.mp4"),
+        (ShardingConfig::default(), "clips/ab/cd/abcdefgh.mp4"),
+    ];
+
+    for (sharding, expected) in cases {
+        let (_temp, relative, keys) = sharded_path(sharding).await;
+        assert_eq!(relative, std::path::Path::new(expected), "{sharding:?}");
+        assert_eq!(keys, expected_keys, "{sharding:?}");
+    }
+}
+
+struct FailingReader;
+
+impl tokio::io::AsyncRead for FailingReader {
+    fn poll_read(
+        self: std::pin::Pin<&mut Self>,
+        _: &mut std::task::Context<'_>,
+        _: &mut tokio::io::ReadBuf<'_>,
+    ) -> std::task::Poll<std::io::Result<()>> {
+        std::task::Poll::Ready(Err(std::io::Error::other("connection reset")))
+    }
+}
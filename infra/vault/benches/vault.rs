@@ -11,7 +11,7 @@ fn bench_seal_unseal(c: &mut Criterion) {
     let mut group = c.benchmark_group("seal_unseal");
 
     let vault = Vault::<Aes>::builder()
-        .derived_keys("bench-ikm", "bench-salt", "bench-id")
+        .derived_keys_unchecked("bench-ikm", "bench-salt", "bench-id")
         .unwrap()
         .compression(true)
         .build()
@@ -1,20 +1,35 @@
 use crate::engine::{Vault, VaultInner};
 use crate::error::VaultError;
-use crate::types::{Aes, VaultCipher};
+use crate::types::{
+    Aes, CompressionKind, DEFAULT_COMPRESSION_MIN_SIZE, MAX_KEY_EPOCH, NonceStrategy, VaultCipher,
+};
 use aead::Key;
 use hkdf::Hkdf;
 use private::Sealed;
 use sha2::Sha256;
 use std::marker::PhantomData;
 use std::sync::Arc;
+use std::sync::atomic::AtomicU64;
+use tracing::warn;
 use zeroize::{Zeroize, ZeroizeOnDrop};
 
+/// Salts shorter than this are accepted by [`VaultBuilder::derived_keys`] with a warning.
+const MIN_SALT_LEN: usize = 16;
+
 #[derive(Debug, Default, ZeroizeOnDrop)]
 pub struct NoKeys;
-#[derive(Debug, Zeroize, ZeroizeOnDrop)]
+#[derive(Zeroize, ZeroizeOnDrop)]
 pub struct WithKeys {
     local: [u8; 32],
     fleet: [u8; 32],
+    local_siv: [u8; 32],
+    fleet_siv: [u8; 32],
+}
+
+impl std::fmt::Debug for WithKeys {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("WithKeys { <redacted> }")
+    }
 }
 
 mod private {
@@ -32,13 +47,27 @@ impl Sealed for WithKeys {}
 pub struct VaultBuilder<C: VaultCipher = Aes, K: Sealed + ZeroizeOnDrop = NoKeys> {
     #[zeroize(skip)]
     _cipher: PhantomData<C>,
-    compression: bool,
+    #[zeroize(skip)]
+    compression: CompressionKind,
+    #[zeroize(skip)]
+    compression_min_size: usize,
+    #[zeroize(skip)]
+    nonce_strategy: NonceStrategy,
+    #[zeroize(skip)]
+    key_epoch: u8,
     keys: K,
 }
 
 impl<C: VaultCipher> Default for VaultBuilder<C> {
     fn default() -> Self {
-        Self { _cipher: PhantomData, compression: false, keys: NoKeys }
+        Self {
+            _cipher: PhantomData,
+            compression: CompressionKind::None,
+            compression_min_size: DEFAULT_COMPRESSION_MIN_SIZE,
+            nonce_strategy: NonceStrategy::Random,
+            key_epoch: 0,
+            keys: NoKeys,
+        }
     }
 }
 
@@ -62,20 +91,67 @@ impl<C: VaultCipher> VaultBuilder<C> {
     /// * `salt`: Uniquifies keys across different environments.
     /// * `id`: Binds the [`Local`] key to a specific machine/identity.
     ///
+    /// An empty `ikm` or `salt` is rejected, and a salt shorter than 16 bytes is logged as a
+    /// warning. Use [`VaultBuilder::derived_keys_unchecked`] to skip these checks.
+    ///
     /// # Results
     /// Returns a [`VaultBuilder`] configured with derived local and fleet keys.
     ///
     /// # Errors
-    /// Returns [`VaultError::InvalidConfiguration`] if key derivation fails.
+    /// Returns [`VaultError::InvalidConfiguration`] if `ikm` or `salt` is empty.
+    /// Returns [`VaultError::Encryption`] if key derivation fails.
     pub fn derived_keys(
         self,
         ikm: impl AsRef<[u8]>,
         salt: impl AsRef<[u8]>,
         id: impl AsRef<[u8]>,
+    ) -> Result<VaultBuilder<C, WithKeys>, VaultError> {
+        let (ikm, salt) = (ikm.as_ref(), salt.as_ref());
+        if ikm.is_empty() {
+            return Err(VaultError::InvalidConfiguration {
+                message: "Input keying material must not be empty".into(),
+                context: Some("ikm".into()),
+            });
+        }
+        if salt.is_empty() {
+            return Err(VaultError::InvalidConfiguration {
+                message: "Salt must not be empty".into(),
+                context: Some("salt".into()),
+            });
+        }
+        if salt.len() < MIN_SALT_LEN {
+            warn!(
+                len = salt.len(),
+                recommended = MIN_SALT_LEN,
+                "Vault salt is shorter than recommended"
+            );
+        }
+
+        self.derived_keys_unchecked(ikm, salt, id)
+    }
+
+    /// Derives cryptographic keys like [`VaultBuilder::derived_keys`], without validating the
+    /// strength of `ikm` and `salt`.
+    ///
+    /// Meant for tests and fixtures that intentionally use short or empty values; never feed
+    /// it user-provided secrets.
+    ///
+    /// # Results
+    /// Returns a [`VaultBuilder`] configured with derived local and fleet keys.
+    ///
+    /// # Errors
+    /// Returns [`VaultError::Encryption`] if key derivation fails.
+    pub fn derived_keys_unchecked(
+        self,
+        ikm: impl AsRef<[u8]>,
+        salt: impl AsRef<[u8]>,
+        id: impl AsRef<[u8]>,
     ) -> Result<VaultBuilder<C, WithKeys>, VaultError> {
         let (_, hk) = Hkdf::<Sha256>::extract(Some(salt.as_ref()), ikm.as_ref());
         let mut fleet = [0u8; 32];
         let mut local = [0u8; 32];
+        let mut fleet_siv = [0u8; 32];
+        let mut local_siv = [0u8; 32];
 
         hk.expand(b"v1_fleet:", &mut fleet).map_err(|_| VaultError::Encryption {
             message: "HKDF expansion failed for fleet key".into(),
@@ -90,12 +166,29 @@ impl<C: VaultCipher> VaultBuilder<C> {
             context: None,
         })?;
 
+        hk.expand(b"v1_fleet_siv:", &mut fleet_siv).map_err(|_| VaultError::Encryption {
+            message: "HKDF expansion failed for fleet SIV key".into(),
+            context: None,
+        })?;
+
+        info.clear();
+        info.extend_from_slice(b"v1_local_siv:");
+        info.extend_from_slice(id.as_ref());
+
+        hk.expand(&info, &mut local_siv).map_err(|_| VaultError::Encryption {
+            message: "HKDF expansion failed for local SIV key".into(),
+            context: None,
+        })?;
+
         info.zeroize();
 
         Ok(VaultBuilder {
             _cipher: PhantomData,
             compression: self.compression,
-            keys: WithKeys { local, fleet },
+            compression_min_size: self.compression_min_size,
+            nonce_strategy: self.nonce_strategy,
+            key_epoch: self.key_epoch,
+            keys: WithKeys { local, fleet, local_siv, fleet_siv },
         })
     }
 }
@@ -122,7 +215,78 @@ impl<C: VaultCipher, K: Sealed + ZeroizeOnDrop> VaultBuilder<C, K> {
     /// None.
     #[must_use]
     pub const fn compression(mut self, enabled: bool) -> Self {
-        self.compression = enabled;
+        self.compression = if enabled { CompressionKind::Lz4 } else { CompressionKind::None };
+        self
+    }
+
+    /// Selects the compression algorithm applied before encryption.
+    ///
+    /// `compression(true)` is shorthand for [`CompressionKind::Lz4`], and `compression(false)`
+    /// for [`CompressionKind::None`]; whichever is called last wins. The same threat model as
+    /// [`VaultBuilder::compression`] applies to every algorithm.
+    ///
+    /// # Results
+    /// Returns the builder with the compression algorithm set to the provided value.
+    ///
+    /// # Errors
+    /// None.
+    #[must_use]
+    pub const fn compression_algorithm(mut self, kind: CompressionKind) -> Self {
+        self.compression = kind;
+        self
+    }
+
+    /// Sets the minimum plaintext size, in bytes, for compression to be applied.
+    ///
+    /// Smaller inputs are sealed uncompressed, since compressing tiny records wastes CPU and
+    /// often grows the output. Defaults to 256 bytes; use `0` to compress everything.
+    ///
+    /// # Results
+    /// Returns the builder with the compression threshold set to the provided value.
+    ///
+    /// # Errors
+    /// None.
+    #[must_use]
+    pub const fn compression_min_size(mut self, bytes: usize) -> Self {
+        self.compression_min_size = bytes;
+        self
+    }
+
+    /// Selects how nonces are generated for each sealing operation.
+    ///
+    /// [`NonceStrategy::Random`] is the default. [`NonceStrategy::Counter`] avoids
+    /// birthday-bound collisions for high-volume keys, but requires a prefix that is unique
+    /// among all vault instances sharing the same keys. The counter starts at a random value
+    /// in every instance, so a restart with the same keys does not replay earlier nonces.
+    ///
+    /// # Results
+    /// Returns the builder with the nonce strategy set to the provided value.
+    ///
+    /// # Errors
+    /// None.
+    #[must_use]
+    pub const fn nonce_strategy(mut self, strategy: NonceStrategy) -> Self {
+        self.nonce_strategy = strategy;
+        self
+    }
+
+    /// Sets the key epoch recorded in the header of every sealed payload.
+    ///
+    /// Bump the epoch whenever keys are rotated, so [`ProtectedPayload::inspect`] can tell
+    /// which generation of keys sealed a blob without decrypting it. Epochs range from `0`
+    /// (the default) to [`MAX_KEY_EPOCH`] and wrap around in practice; they only need to
+    /// distinguish the keys that are live at the same time.
+    ///
+    /// # Results
+    /// Returns the builder with the key epoch set to the provided value.
+    ///
+    /// # Errors
+    /// None. [`VaultBuilder::build`] rejects epochs above [`MAX_KEY_EPOCH`].
+    ///
+    /// [`ProtectedPayload::inspect`]: crate::ProtectedPayload::inspect
+    #[must_use]
+    pub const fn key_epoch(mut self, epoch: u8) -> Self {
+        self.key_epoch = epoch;
         self
     }
 }
@@ -134,12 +298,28 @@ impl<C: VaultCipher> VaultBuilder<C, WithKeys> {
     /// Returns a fully initialized [`Vault`].
     ///
     /// # Errors
-    /// Returns [`VaultError::InvalidConfiguration`] if keys were not provided or derived.
+    /// Returns [`VaultError::InvalidConfiguration`] if keys were not provided or derived, or
+    /// if the key epoch exceeds [`MAX_KEY_EPOCH`].
     pub fn build(mut self) -> Result<Vault<C>, VaultError> {
+        if self.key_epoch > MAX_KEY_EPOCH {
+            return Err(VaultError::InvalidConfiguration {
+                message: format!("Key epoch {} exceeds {MAX_KEY_EPOCH}", self.key_epoch).into(),
+                context: Some("key_epoch".into()),
+            });
+        }
+
         let vault = VaultInner {
             local_cipher: Self::init_cipher(&self.keys.local, "Local")?,
             fleet_cipher: Self::init_cipher(&self.keys.fleet, "Fleet")?,
+            local_siv_key: self.keys.local_siv,
+            fleet_siv_key: self.keys.fleet_siv,
+            local_fingerprint: Self::fingerprint(&self.keys.local)?,
+            fleet_fingerprint: Self::fingerprint(&self.keys.fleet)?,
+            key_epoch: self.key_epoch,
             compression: self.compression,
+            compression_min_size: self.compression_min_size,
+            nonce_strategy: self.nonce_strategy,
+            nonce_counter: AtomicU64::new(Self::counter_start()),
         };
 
         self.zeroize();
@@ -147,6 +327,29 @@ impl<C: VaultCipher> VaultBuilder<C, WithKeys> {
         Ok(Vault { inner: Arc::new(vault) })
     }
 
+    /// Picks a random starting point for the nonce counter.
+    ///
+    /// Starting every vault instance at zero would repeat `(key, nonce)` pairs after a restart
+    /// with the same keys. The top bit is cleared so at least 2^63 nonces remain before the
+    /// counter is exhausted.
+    fn counter_start() -> u64 {
+        let mut seed = [0u8; 8];
+        getrandom::fill(&mut seed).expect("System RNG unavailable for nonce counter seeding");
+        u64::from_be_bytes(seed) >> 1
+    }
+
+    /// Derives the public fingerprint of a domain key, see [`Vault::key_fingerprint`].
+    fn fingerprint(key: &[u8; 32]) -> Result<[u8; 8], VaultError> {
+        let mut fingerprint = [0u8; 8];
+        Hkdf::<Sha256>::new(None, key).expand(b"v1_fingerprint:", &mut fingerprint).map_err(
+            |_| VaultError::Encryption {
+                message: "HKDF expansion failed for key fingerprint".into(),
+                context: None,
+            },
+        )?;
+        Ok(fingerprint)
+    }
+
     fn init_cipher(key: &[u8; 32], context: &'static str) -> Result<C, VaultError> {
         let key = Key::<C>::try_from(&key[..]).map_err(|_| VaultError::InvalidConfiguration {
             message: format!("Invalid key length {}, must be 32 bytes", key.len()).into(),
@@ -1,14 +1,21 @@
 use aead::Nonce;
 use aead::inout::InOutBuf;
 use getrandom::fill;
+use hmac::digest::KeyInit;
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
 use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use zeroize::{Zeroize, Zeroizing};
 
 use crate::builder::VaultBuilder;
 use crate::domains::{Fleet, Local};
 use crate::error::{VaultError, VaultErrorExt};
 use crate::types::{
-    Aes, FLAG_COMPRESSED, HEADER_LEN, NONCE_LEN, PAYLOAD_VERSION_V1, PayloadKind, ProtectedPayload,
-    TAG_LEN, VaultCipher, VaultSerde,
+    Aes, Codec, CompressionKind, EXTENDED_NONCE_LEN, FLAG_COMPRESSED, FLAG_DETERMINISTIC,
+    FLAG_EPOCH_SHIFT, FLAG_EXTENDED_NONCE, HEADER_LEN, NONCE_LEN, NonceStrategy,
+    PAYLOAD_VERSION_V1, PAYLOAD_VERSION_V2, PURPOSE_AAD_SEPARATOR, PayloadKind, ProtectedPayload,
+    TAG_LEN, VaultCipher, VaultSerde, nonce_len,
 };
 
 /// High-performance cryptographic vault.
@@ -16,14 +23,45 @@ use crate::types::{
 /// The vault manages two independent ciphers for different security domains and
 /// maintains the state for high-performance nonce generation.
 #[allow(unreachable_pub)]
-#[derive(Debug)]
 pub struct VaultInner<C = Aes>
 where
     C: VaultCipher,
 {
     pub local_cipher: C,
     pub fleet_cipher: C,
-    pub compression: bool,
+    pub local_siv_key: [u8; 32],
+    pub fleet_siv_key: [u8; 32],
+    pub local_fingerprint: [u8; 8],
+    pub fleet_fingerprint: [u8; 8],
+    pub key_epoch: u8,
+    pub compression: CompressionKind,
+    pub compression_min_size: usize,
+    pub nonce_strategy: NonceStrategy,
+    pub nonce_counter: AtomicU64,
+}
+
+/// Wipes the derived keys once the last [`Vault`] handle is gone.
+impl<C: VaultCipher> Drop for VaultInner<C> {
+    fn drop(&mut self) {
+        self.local_cipher.zeroize_key();
+        self.fleet_cipher.zeroize_key();
+        self.local_siv_key.zeroize();
+        self.fleet_siv_key.zeroize();
+    }
+}
+
+/// Redacted: only non-sensitive metadata is printed, never cipher or key material.
+impl<C: VaultCipher> std::fmt::Debug for VaultInner<C> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("VaultInner")
+            .field("algorithm", &std::any::type_name::<C>())
+            .field("compression", &self.compression)
+            .field("compression_min_size", &self.compression_min_size)
+            .field("nonce_strategy", &self.nonce_strategy)
+            .field("key_epoch", &self.key_epoch)
+            .field("domains", &2usize)
+            .finish_non_exhaustive()
+    }
 }
 
 /// A thread-safe, high-performance container for cryptographic operations.
@@ -69,7 +107,6 @@ where
 /// # Ok(())
 /// # }
 /// ```
-#[derive(Debug)]
 pub struct Vault<C = Aes>
 where
     C: VaultCipher,
@@ -77,6 +114,12 @@ where
     pub(crate) inner: Arc<VaultInner<C>>,
 }
 
+impl<C: VaultCipher> std::fmt::Debug for Vault<C> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Vault").field("inner", &self.inner).finish()
+    }
+}
+
 impl<C: VaultCipher> Clone for Vault<C> {
     fn clone(&self) -> Self {
         Self { inner: Arc::clone(&self.inner) }
@@ -99,12 +142,60 @@ where
         VaultBuilder::<C>::new()
     }
 
-    /// Generates unique, high-performance nonce.
+    /// Returns a short, non-reversible fingerprint of the `K` domain key.
+    ///
+    /// The fingerprint is the first 8 bytes of an HKDF-SHA256 expansion of the domain key
+    /// under a dedicated label, so it reveals nothing usable about the key itself. It is
+    /// **not secret**: it exists to correlate vaults, payloads and key rotation jobs in logs
+    /// and tooling (e.g. asserting that the old and new vault of a rotation really differ).
+    ///
+    /// Two vaults share a fingerprint for a domain exactly when they share its key, which for
+    /// [`Local`] also depends on the machine `id` passed to [`VaultBuilder::derived_keys`].
+    #[must_use]
+    pub fn key_fingerprint<K: PayloadKind<C>>(&self) -> [u8; 8] {
+        *K::select_fingerprint(self)
+    }
+
+    /// Returns the key epoch recorded in the header of every payload this vault seals.
+    ///
+    /// See [`VaultBuilder::key_epoch`].
+    #[must_use]
+    pub fn key_epoch(&self) -> u8 {
+        self.inner.key_epoch
+    }
+
+    /// Returns the header flags shared by every payload this vault seals.
     #[inline]
-    fn next_nonce() -> Nonce<C> {
+    pub(crate) fn base_flags(&self) -> u8 {
+        self.inner.key_epoch << FLAG_EPOCH_SHIFT
+    }
+
+    /// Generates the next nonce according to the configured [`NonceStrategy`].
+    ///
+    /// In counter mode the nonce is `prefix || counter` with the 64-bit counter in
+    /// big-endian order occupying the trailing bytes.
+    #[inline]
+    pub(crate) fn next_nonce(&self) -> Result<Nonce<C>, VaultError> {
         let mut nonce = Nonce::<C>::default();
-        fill(&mut nonce).expect("System RNG unavailable for nonce generation");
-        nonce
+        match self.inner.nonce_strategy {
+            NonceStrategy::Random => {
+                fill(&mut nonce).expect("System RNG unavailable for nonce generation");
+            },
+            NonceStrategy::Counter { prefix } => {
+                let counter = self
+                    .inner
+                    .nonce_counter
+                    .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |c| c.checked_add(1))
+                    .map_err(|_| VaultError::NonceExhausted {
+                        message: "Nonce counter exhausted".into(),
+                        context: Some("Rotate the vault keys before sealing again".into()),
+                    })?;
+                let len = nonce.len();
+                nonce[..prefix.len()].copy_from_slice(&prefix);
+                nonce[len - 8..].copy_from_slice(&counter.to_be_bytes());
+            },
+        }
+        Ok(nonce)
     }
 
     /// Seals a value using `postcard` (compact binary format).
@@ -126,6 +217,89 @@ where
         self.seal_bytes::<K>(bytes.as_slice(), T::TAG.as_bytes())
     }
 
+    /// Seals a value bound to an explicit purpose.
+    ///
+    /// The AAD is `TAG || "#purpose:" || purpose`, so a payload sealed for `"session"` fails
+    /// authentication where a `"reset-token"` is expected, even though both share the same
+    /// type. Purpose-bound payloads are also rejected by [`Vault::unseal`].
+    ///
+    /// # Results
+    /// Returns an encrypted [`ProtectedPayload`] bound to the type tag and purpose.
+    ///
+    /// # Errors
+    /// * [`VaultError::PostcardSerialization`] If the value cannot be serialized.
+    /// * [`VaultError::Encryption`] If the AEAD encryption fails.
+    pub fn seal_for<K, T>(
+        &self,
+        data: &T,
+        purpose: &'static str,
+    ) -> Result<ProtectedPayload<K, C>, VaultError>
+    where
+        K: PayloadKind<C>,
+        T: VaultSerde,
+    {
+        let bytes = postcard::to_stdvec(data).context("Postcard encoding failed")?;
+        self.seal_bytes::<K>(bytes.as_slice(), &Self::purpose_context(T::TAG, purpose))
+    }
+
+    /// Seals a value bound to [`Tagged::TAG`] plus caller-supplied associated data.
+    ///
+    /// The AAD is `TAG || len(extra_aad) as u32 BE || extra_aad`; the length prefix keeps the
+    /// boundary between tag and extra bytes unambiguous. Use it to bind a payload to e.g. a
+    /// tenant ID so it cannot be replayed into another tenant under the same key.
+    ///
+    /// # Results
+    /// Returns an encrypted [`ProtectedPayload`] bound to the type tag and `extra_aad`.
+    ///
+    /// # Errors
+    /// * [`VaultError::Serialization`] If the value cannot be serialized.
+    /// * [`VaultError::InvalidPayload`] If `extra_aad` is longer than `u32::MAX` bytes.
+    /// * [`VaultError::Encryption`] If the AEAD encryption fails.
+    pub fn seal_with_aad<K, T>(
+        &self,
+        data: &T,
+        extra_aad: &[u8],
+    ) -> Result<ProtectedPayload<K, C>, VaultError>
+    where
+        K: PayloadKind<C>,
+        T: VaultSerde,
+    {
+        let bytes = postcard::to_stdvec(data).context("Postcard encoding failed")?;
+        self.seal_bytes::<K>(bytes.as_slice(), &Self::extended_context(T::TAG, extra_aad)?)
+    }
+
+    /// Seals a slice of values, preserving their order.
+    ///
+    /// A single serialization buffer is reused across items. Sealing stops at the first
+    /// failure, whose context names the offending index.
+    ///
+    /// # Results
+    /// Returns one [`ProtectedPayload`] per item, in input order.
+    ///
+    /// # Errors
+    /// * [`VaultError::Serialization`] If an item cannot be serialized.
+    /// * [`VaultError::Encryption`] If the AEAD encryption fails.
+    pub fn seal_batch<K, T>(&self, items: &[T]) -> Result<Vec<ProtectedPayload<K, C>>, VaultError>
+    where
+        K: PayloadKind<C>,
+        T: VaultSerde,
+    {
+        let mut sealed = Vec::with_capacity(items.len());
+        let mut scratch = Vec::new();
+
+        for (index, item) in items.iter().enumerate() {
+            scratch.clear();
+            scratch = postcard::to_extend(item, scratch)
+                .context(format!("Postcard encoding failed at batch index {index}"))?;
+            sealed.push(
+                self.seal_bytes::<K>(scratch.as_slice(), T::TAG.as_bytes())
+                    .context(format!("Sealing failed at batch index {index}"))?,
+            );
+        }
+
+        Ok(sealed)
+    }
+
     /// Encrypts raw bytes into a domain-aware [`ProtectedPayload`].
     ///
     /// # Results
@@ -141,7 +315,55 @@ where
         let cipher = K::select_cipher(self);
         let bytes = data.as_ref();
 
-        let blob = Self::encrypt_internal(cipher, bytes, context, self.inner.compression)?;
+        let compression = self.codec_for(bytes);
+        let nonce = self.next_nonce()?;
+        let blob =
+            Self::encrypt_internal(cipher, &nonce, bytes, context, compression, self.base_flags())?;
+        Ok(ProtectedPayload::from(blob))
+    }
+
+    /// Encrypts raw bytes deterministically, for deduplicating content-addressed storage.
+    ///
+    /// The nonce is a synthetic IV: `HMAC-SHA256(siv_key, context || data)` truncated to the
+    /// cipher nonce length, using a dedicated key derived alongside the cipher key. Identical
+    /// `data` and `context` in the same domain therefore always yield identical payloads.
+    /// The payload unseals with the regular [`Vault::unseal_bytes`].
+    ///
+    /// # Security
+    /// This mode **leaks plaintext equality**: anyone who can see two payloads learns whether
+    /// they hold the same data. Never use it for attacker-influenced data or low-entropy
+    /// values an attacker could guess and confirm. Prefer [`Vault::seal_bytes`] everywhere
+    /// deduplication is not required.
+    ///
+    /// # Results
+    /// Returns an encrypted [`ProtectedPayload`] bound to the provided context bytes.
+    ///
+    /// # Errors
+    /// * [`VaultError::Encryption`] If the AEAD encryption fails.
+    pub fn seal_deterministic<K: PayloadKind<C>>(
+        &self,
+        data: impl AsRef<[u8]>,
+        context: &[u8],
+    ) -> Result<ProtectedPayload<K, C>, VaultError> {
+        let cipher = K::select_cipher(self);
+        let bytes = data.as_ref();
+
+        let mut mac =
+            <Hmac<Sha256> as KeyInit>::new_from_slice(K::select_siv_key(self)).map_err(|_| {
+                VaultError::Encryption { message: "Invalid SIV key".into(), context: None }
+            })?;
+        mac.update(&(context.len() as u64).to_be_bytes());
+        mac.update(context);
+        mac.update(bytes);
+        let siv = mac.finalize().into_bytes();
+
+        let mut nonce = Nonce::<C>::default();
+        let nonce_len = nonce.len();
+        nonce.copy_from_slice(&siv[..nonce_len]);
+
+        let compression = self.codec_for(bytes);
+        let flags = self.base_flags() | FLAG_DETERMINISTIC;
+        let blob = Self::encrypt_internal(cipher, &nonce, bytes, context, compression, flags)?;
         Ok(ProtectedPayload::from(blob))
     }
 
@@ -178,6 +400,126 @@ where
         postcard::from_bytes(&bytes).context("Postcard decoding failed")
     }
 
+    /// Unseals a value previously sealed with [`Vault::seal_for`].
+    ///
+    /// # Results
+    /// Returns the decoded value if it was sealed for `purpose`.
+    ///
+    /// # Errors
+    /// * [`VaultError::Decryption`] If the payload was sealed for another purpose, or the
+    ///   key or data is invalid.
+    /// * [`VaultError::PostcardSerialization`] If the decrypted bytes cannot be parsed.
+    /// * [`VaultError::Decompression`] If the LZ4 stream is corrupt.
+    pub fn unseal_for<K, T>(
+        &self,
+        payload: impl AsRef<[u8]>,
+        purpose: &'static str,
+    ) -> Result<T, VaultError>
+    where
+        K: PayloadKind<C>,
+        T: VaultSerde,
+    {
+        let context = Self::purpose_context(T::TAG, purpose);
+        let bytes = self.unseal_bytes_raw::<K>(payload.as_ref(), &context)?;
+        postcard::from_bytes(&bytes).context("Postcard decoding failed")
+    }
+
+    /// Unseals a value previously sealed with [`Vault::seal_with_aad`].
+    ///
+    /// # Results
+    /// Returns the decoded value if `extra_aad` matches the one used for sealing.
+    ///
+    /// # Errors
+    /// * [`VaultError::Decryption`] If the extra AAD, key, or data is invalid.
+    /// * [`VaultError::InvalidPayload`] If the payload is malformed.
+    /// * [`VaultError::Serialization`] If the decrypted bytes cannot be parsed.
+    /// * [`VaultError::Decompression`] If the LZ4 stream is corrupt.
+    pub fn unseal_with_aad<K, T>(
+        &self,
+        payload: impl AsRef<[u8]>,
+        extra_aad: &[u8],
+    ) -> Result<T, VaultError>
+    where
+        K: PayloadKind<C>,
+        T: VaultSerde,
+    {
+        let context = Self::extended_context(T::TAG, extra_aad)?;
+        let bytes = self.unseal_bytes_raw::<K>(payload.as_ref(), &context)?;
+        postcard::from_bytes(&bytes).context("Postcard decoding failed")
+    }
+
+    /// Unseals a value if it authenticates under this vault's key and the type tag.
+    ///
+    /// Useful for probing whether a blob belongs to a given domain or context: a payload
+    /// sealed under another key or tag yields `Ok(None)` instead of an error.
+    ///
+    /// # Results
+    /// Returns `Some(value)` if the payload authenticated, `None` otherwise.
+    ///
+    /// # Errors
+    /// * [`VaultError::InvalidPayload`] If the payload is malformed.
+    /// * [`VaultError::Serialization`] If authentic bytes cannot be parsed as `T`.
+    /// * [`VaultError::Decompression`] If the LZ4 stream is corrupt.
+    pub fn try_unseal<K, T>(&self, payload: impl AsRef<[u8]>) -> Result<Option<T>, VaultError>
+    where
+        K: PayloadKind<C>,
+        T: VaultSerde,
+    {
+        let cipher = K::select_cipher(self);
+        let Some(bytes) = Self::open_internal(cipher, payload.as_ref(), T::TAG.as_bytes())? else {
+            return Ok(None);
+        };
+        postcard::from_bytes(&bytes).context("Postcard decoding failed").map(Some)
+    }
+
+    /// Unseals a local-domain value if it authenticates. See [`Vault::try_unseal`].
+    ///
+    /// # Errors
+    /// Same as [`Vault::try_unseal`].
+    pub fn try_unseal_local<T>(&self, payload: impl AsRef<[u8]>) -> Result<Option<T>, VaultError>
+    where
+        T: VaultSerde,
+    {
+        self.try_unseal::<Local, T>(payload)
+    }
+
+    /// Unseals a fleet-domain value if it authenticates. See [`Vault::try_unseal`].
+    ///
+    /// # Errors
+    /// Same as [`Vault::try_unseal`].
+    pub fn try_unseal_fleet<T>(&self, payload: impl AsRef<[u8]>) -> Result<Option<T>, VaultError>
+    where
+        T: VaultSerde,
+    {
+        self.try_unseal::<Fleet, T>(payload)
+    }
+
+    /// Unseals a slice of payloads, preserving their order.
+    ///
+    /// Unsealing stops at the first failure, whose context names the offending index.
+    ///
+    /// # Results
+    /// Returns one decoded value per payload, in input order.
+    ///
+    /// # Errors
+    /// * [`VaultError::Decryption`] If the context, key, or data is invalid.
+    /// * [`VaultError::Serialization`] If the decrypted bytes cannot be parsed.
+    /// * [`VaultError::Decompression`] If the LZ4 stream is corrupt.
+    pub fn unseal_batch<K, T>(&self, payloads: &[impl AsRef<[u8]>]) -> Result<Vec<T>, VaultError>
+    where
+        K: PayloadKind<C>,
+        T: VaultSerde,
+    {
+        payloads
+            .iter()
+            .enumerate()
+            .map(|(index, payload)| {
+                self.unseal::<K, T>(payload)
+                    .context(format!("Unsealing failed at batch index {index}"))
+            })
+            .collect()
+    }
+
     /// Unseals a value from raw bytes using the local domain.
     ///
     /// # Results
@@ -262,6 +604,71 @@ where
         self.unseal_bytes::<Fleet>(payload, context)
     }
 
+    /// Re-encrypts a payload sealed by `old` under this vault's keys.
+    ///
+    /// The plaintext only exists in a transient buffer that is zeroized before returning, so
+    /// key rotation jobs never hand decrypted data to the caller. The payload is re-sealed with
+    /// this vault's compression and nonce settings.
+    ///
+    /// # Results
+    /// Returns the re-sealed bytes bound to the same `context`.
+    ///
+    /// # Errors
+    /// * [`VaultError::InvalidPayload`] If the payload is malformed.
+    /// * [`VaultError::Decryption`] If `old` cannot authenticate the payload.
+    /// * [`VaultError::Decompression`] If the LZ4 stream is corrupt.
+    /// * [`VaultError::Encryption`] If the AEAD encryption fails.
+    pub fn reseal<K: PayloadKind<C>>(
+        &self,
+        old: &Self,
+        payload: &[u8],
+        context: &[u8],
+    ) -> Result<Vec<u8>, VaultError> {
+        let plaintext =
+            Zeroizing::new(Self::decrypt_internal(K::select_cipher(old), payload, context)?);
+
+        let compression = self.codec_for(&plaintext);
+        let nonce = self.next_nonce()?;
+        Self::encrypt_internal(
+            K::select_cipher(self),
+            &nonce,
+            &plaintext,
+            context,
+            compression,
+            self.base_flags(),
+        )
+    }
+
+    /// Re-encrypts a local-domain payload sealed by `old` under this vault's keys.
+    ///
+    /// See [`Vault::reseal`].
+    ///
+    /// # Errors
+    /// Same as [`Vault::reseal`].
+    pub fn reseal_local(
+        &self,
+        old: &Self,
+        payload: &[u8],
+        context: &[u8],
+    ) -> Result<Vec<u8>, VaultError> {
+        self.reseal::<Local>(old, payload, context)
+    }
+
+    /// Re-encrypts a fleet-domain payload sealed by `old` under this vault's keys.
+    ///
+    /// See [`Vault::reseal`].
+    ///
+    /// # Errors
+    /// Same as [`Vault::reseal`].
+    pub fn reseal_fleet(
+        &self,
+        old: &Self,
+        payload: &[u8],
+        context: &[u8],
+    ) -> Result<Vec<u8>, VaultError> {
+        self.reseal::<Fleet>(old, payload, context)
+    }
+
     fn unseal_bytes_raw<K: PayloadKind<C>>(
         &self,
         payload: &[u8],
@@ -271,42 +678,118 @@ where
         Self::decrypt_internal(cipher, payload, context)
     }
 
-    fn encrypt_internal(
+    /// Resolves the compressor for `data`, skipping inputs below the configured minimum size.
+    ///
+    /// Payloads sealed without compression leave [`FLAG_COMPRESSED`] unset, so unsealing does
+    /// not depend on the threshold.
+    fn codec_for(&self, data: &[u8]) -> Option<Codec> {
+        if data.len() < self.inner.compression_min_size {
+            return None;
+        }
+        self.inner.compression.codec()
+    }
+
+    /// Builds the AAD `tag || len(extra) as u32 BE || extra` for [`Vault::seal_with_aad`].
+    fn extended_context(tag: &str, extra: &[u8]) -> Result<Vec<u8>, VaultError> {
+        let len = u32::try_from(extra.len()).map_err(|_| VaultError::InvalidPayload {
+            message: "Extra AAD too long".into(),
+            context: Some(format!("len={}", extra.len()).into()),
+        })?;
+
+        let mut aad = Vec::with_capacity(tag.len() + 4 + extra.len());
+        aad.extend_from_slice(tag.as_bytes());
+        aad.extend_from_slice(&len.to_be_bytes());
+        aad.extend_from_slice(extra);
+        Ok(aad)
+    }
+
+    /// Builds the AAD `tag || "#purpose:" || purpose` for [`Vault::seal_for`].
+    fn purpose_context(tag: &str, purpose: &str) -> Vec<u8> {
+        let mut aad = Vec::with_capacity(tag.len() + PURPOSE_AAD_SEPARATOR.len() + purpose.len());
+        aad.extend_from_slice(tag.as_bytes());
+        aad.extend_from_slice(PURPOSE_AAD_SEPARATOR);
+        aad.extend_from_slice(purpose.as_bytes());
+        aad
+    }
+
+    pub(crate) fn encrypt_internal(
         cipher: &C,
+        nonce: &Nonce<C>,
         data: &[u8],
         aad: &[u8],
-        compress: bool,
+        compression: Option<Codec>,
+        flags: u8,
     ) -> Result<Vec<u8>, VaultError> {
         // Compression is performed BEFORE encryption. This can leak information via ciphertext length
         // in attacker-controlled scenarios. See crate-level documentation for guidance.
-        let owned = if compress { lz4_flex::compress_prepend_size(data) } else { Vec::new() };
-        let data = if compress { owned.as_slice() } else { data };
-        let flags = if compress { FLAG_COMPRESSED } else { 0 };
+        let owned = match compression {
+            Some(codec) => codec.compress(data)?,
+            None => Zeroizing::default(),
+        };
+        let data = if compression.is_some() { owned.as_slice() } else { data };
+        let mut flags = flags | compression.map_or(0, Codec::flags);
+        if nonce.len() == EXTENDED_NONCE_LEN {
+            flags |= FLAG_EXTENDED_NONCE;
+        }
 
-        let nonce = Self::next_nonce();
+        let header = [PAYLOAD_VERSION_V2, flags];
+        let aad = header_aad(&header, aad);
 
-        let mut buf = Vec::with_capacity(HEADER_LEN + NONCE_LEN + data.len() + TAG_LEN);
-        buf.push(PAYLOAD_VERSION_V1);
-        buf.push(flags);
-        buf.extend_from_slice(&nonce);
+        let mut buf = Vec::with_capacity(HEADER_LEN + nonce.len() + data.len() + TAG_LEN);
+        buf.extend_from_slice(&header);
+        buf.extend_from_slice(nonce);
         buf.extend_from_slice(data);
 
         let (_hdr, rest) = buf.split_at_mut(HEADER_LEN);
         let (_nonce_part, data_part) = rest.split_at_mut(nonce.len());
         let in_out = InOutBuf::from(data_part);
 
-        let tag = cipher.encrypt_inout_detached(&nonce, aad, in_out).map_err(|_| {
-            VaultError::Encryption {
+        let Ok(tag) = cipher.encrypt_inout_detached(nonce, &aad, in_out) else {
+            // The buffer may still hold plaintext.
+            buf.zeroize();
+            return Err(VaultError::Encryption {
                 message: "Encryption failed".into(),
                 context: Some("AEAD encryption failed".into()),
-            }
-        })?;
+            });
+        };
 
         buf.extend_from_slice(tag.as_slice());
         Ok(buf)
     }
 
-    fn decrypt_internal(cipher: &C, blob: &[u8], aad: &[u8]) -> Result<Vec<u8>, VaultError> {
+    /// Authenticates and decrypts `blob`, mapping every tag failure to the same
+    /// [`VaultError::Decryption`].
+    ///
+    /// # Timing
+    /// Tag verification is delegated to the AEAD implementation, which compares tags in
+    /// constant time and verifies them before releasing any plaintext. Around it, this function
+    /// only branches on public data: the payload length and the header flags. Decompression
+    /// runs strictly after authentication, so nothing here depends on plaintext content, and
+    /// the returned error is identical wherever the payload was tampered with.
+    pub(crate) fn decrypt_internal(
+        cipher: &C,
+        blob: &[u8],
+        aad: &[u8],
+    ) -> Result<Vec<u8>, VaultError> {
+        match Self::open_internal(cipher, blob, aad)? {
+            // Moves the plaintext out without copying; only the emptied wrapper is dropped.
+            Some(mut plaintext) => Ok(std::mem::take(&mut *plaintext)),
+            None => Err(VaultError::Decryption {
+                message: "Decryption failed".into(),
+                context: Some("AEAD authentication failed".into()),
+            }),
+        }
+    }
+
+    /// Decrypts `blob`, returning `Ok(None)` when AEAD authentication fails.
+    ///
+    /// The plaintext and any compressed intermediate are zeroized when dropped. Malformed
+    /// payloads and decompression failures are still reported as errors.
+    fn open_internal(
+        cipher: &C,
+        blob: &[u8],
+        aad: &[u8],
+    ) -> Result<Option<Zeroizing<Vec<u8>>>, VaultError> {
         if blob.len() < (HEADER_LEN + NONCE_LEN + TAG_LEN) {
             return Err(VaultError::InvalidPayload {
                 message: format!(
@@ -322,74 +805,363 @@ where
         let version = blob[0];
         let flags = blob[1];
 
-        if version != PAYLOAD_VERSION_V1 {
+        let aad = match version {
+            PAYLOAD_VERSION_V1 => aad.to_vec(),
+            PAYLOAD_VERSION_V2 => header_aad(&blob[..HEADER_LEN], aad),
+            _ => {
+                return Err(VaultError::InvalidPayload {
+                    message: "Unsupported payload version".into(),
+                    context: Some(format!("version={version}").into()),
+                });
+            },
+        };
+
+        let nonce_len = nonce_len(flags);
+        if blob.len() < (HEADER_LEN + nonce_len + TAG_LEN) {
             return Err(VaultError::InvalidPayload {
-                message: "Unsupported payload version".into(),
-                context: Some(format!("version={version}").into()),
+                message: format!(
+                    "Payload too short ({} bytes) for a {nonce_len}-byte nonce",
+                    blob.len()
+                )
+                .into(),
+                context: None,
             });
         }
 
         let rest = &blob[HEADER_LEN..];
-        let (nonce_slice, rest) = rest.split_at(NONCE_LEN);
+        let (nonce_slice, rest) = rest.split_at(nonce_len);
         let (ciphertext, tag_slice) = rest.split_at(rest.len() - TAG_LEN);
 
-        let nonce = nonce_slice.try_into().map_err(|_| VaultError::Decryption {
-            message: "Invalid nonce length".into(),
-            context: None,
-        })?;
+        // A nonce length that does not match the cipher can only come from a tampered flag,
+        // so it is reported exactly like a failed tag.
+        let Ok(nonce) = nonce_slice.try_into() else {
+            return Ok(None);
+        };
 
         let tag = tag_slice.try_into().map_err(|_| VaultError::Decryption {
             message: "Invalid tag length".into(),
             context: None,
         })?;
 
-        let mut buf = ciphertext.to_vec();
+        // Zeroized on drop, including when authentication fails: never let a rejected buffer
+        // outlive the call, whatever the cipher left in it.
+        let mut buf = Zeroizing::new(ciphertext.to_vec());
         let in_out = InOutBuf::from(&mut buf[..]);
 
-        cipher.decrypt_inout_detached(&nonce, aad, in_out, &tag).map_err(|_| {
-            VaultError::Decryption {
-                message: "Decryption failed".into(),
-                context: Some("AEAD authentication failed".into()),
-            }
-        })?;
+        if cipher.decrypt_inout_detached(&nonce, &aad, in_out, &tag).is_err() {
+            return Ok(None);
+        }
 
         let compressed = (flags & FLAG_COMPRESSED) != 0;
         if compressed {
-            buf = lz4_flex::decompress_size_prepended(&buf).map_err(|_| {
-                VaultError::Decompression {
-                    message: "Decompression failed".into(),
-                    context: Some("LZ4 stream invalid".into()),
-                }
-            })?;
+            // Replacing `buf` drops, and so zeroizes, the compressed plaintext.
+            buf = Codec::decompress(flags, &buf)?;
         }
 
-        Ok(buf)
+        Ok(Some(buf))
     }
 }
 
+/// Builds the AEAD associated data of a version 2 payload: `header || aad`.
+fn header_aad(header: &[u8], aad: &[u8]) -> Vec<u8> {
+    let mut bound = Vec::with_capacity(header.len() + aad.len());
+    bound.extend_from_slice(header);
+    bound.extend_from_slice(aad);
+    bound
+}
+
 #[cfg(test)]
 mod tests {
+    use crate::algorithms::VaultCipher;
     use crate::prelude::*;
+    use crate::types::FLAG_COMPRESSED;
+    use crate::{DecompressReason, NonceStrategy};
+    use aead::consts::{U12, U16, U32};
+    use aead::inout::InOutBuf;
+    use aead::{AeadCore, AeadInOut, Key, KeyInit, KeySizeUser, Nonce, Tag, TagPosition};
+    use std::cell::RefCell;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    thread_local! {
+        /// `(nonce, aad, buffer)` lengths seen by [`TracingCipher`] on the current thread.
+        static DECRYPT_TRACE: RefCell<Vec<(usize, usize, usize)>> =
+            const { RefCell::new(Vec::new()) };
+    }
+
+    static STUB_ZEROIZED: AtomicUsize = AtomicUsize::new(0);
+
+    /// Pass-through cipher that only records key zeroization.
+    struct StubCipher;
+
+    impl KeySizeUser for StubCipher {
+        type KeySize = U32;
+    }
+
+    impl KeyInit for StubCipher {
+        fn new(_key: &Key<Self>) -> Self {
+            Self
+        }
+    }
+
+    impl AeadCore for StubCipher {
+        type NonceSize = U12;
+        type TagSize = U16;
+        const TAG_POSITION: TagPosition = TagPosition::Postfix;
+    }
+
+    impl AeadInOut for StubCipher {
+        fn encrypt_inout_detached(
+            &self,
+            _nonce: &Nonce<Self>,
+            _associated_data: &[u8],
+            _buffer: InOutBuf<'_, '_, u8>,
+        ) -> aead::Result<Tag<Self>> {
+            Ok(Tag::<Self>::default())
+        }
+
+        fn decrypt_inout_detached(
+            &self,
+            _nonce: &Nonce<Self>,
+            _associated_data: &[u8],
+            _buffer: InOutBuf<'_, '_, u8>,
+            _tag: &Tag<Self>,
+        ) -> aead::Result<()> {
+            Ok(())
+        }
+    }
+
+    impl VaultCipher for StubCipher {
+        fn zeroize_key(&mut self) {
+            STUB_ZEROIZED.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    /// Pass-through cipher that records the shape of every decrypt call.
+    struct TracingCipher;
+
+    impl KeySizeUser for TracingCipher {
+        type KeySize = U32;
+    }
+
+    impl KeyInit for TracingCipher {
+        fn new(_key: &Key<Self>) -> Self {
+            Self
+        }
+    }
+
+    impl AeadCore for TracingCipher {
+        type NonceSize = U12;
+        type TagSize = U16;
+        const TAG_POSITION: TagPosition = TagPosition::Postfix;
+    }
+
+    impl AeadInOut for TracingCipher {
+        fn encrypt_inout_detached(
+            &self,
+            _nonce: &Nonce<Self>,
+            _associated_data: &[u8],
+            _buffer: InOutBuf<'_, '_, u8>,
+        ) -> aead::Result<Tag<Self>> {
+            Ok(Tag::<Self>::default())
+        }
+
+        fn decrypt_inout_detached(
+            &self,
+            nonce: &Nonce<Self>,
+            associated_data: &[u8],
+            buffer: InOutBuf<'_, '_, u8>,
+            _tag: &Tag<Self>,
+        ) -> aead::Result<()> {
+            DECRYPT_TRACE.with_borrow_mut(|trace| {
+                trace.push((nonce.len(), associated_data.len(), buffer.len()));
+            });
+            Ok(())
+        }
+    }
+
+    impl VaultCipher for TracingCipher {}
+
+    fn decrypt_trace(vault: &Vault<TracingCipher>, payload: &[u8]) -> Vec<(usize, usize, usize)> {
+        DECRYPT_TRACE.with_borrow_mut(Vec::clear);
+        vault.unseal_bytes::<Local>(payload, b"ctx").expect("stub cipher always authenticates");
+        DECRYPT_TRACE.take()
+    }
+
+    #[test]
+    fn test_equal_length_payloads_take_identical_decrypt_paths() {
+        let vault = Vault::<TracingCipher>::builder()
+            .compression(true)
+            .derived_keys_unchecked("master", "salt", "id")
+            .unwrap()
+            .build()
+            .unwrap();
+
+        // Both inputs stay below the compression threshold, so only their content differs.
+        let zeros = vault.seal_bytes::<Local>([0x00; 64], b"ctx").unwrap();
+        let ones = vault.seal_bytes::<Local>([0xFF; 64], b"ctx").unwrap();
+        assert_eq!(zeros.len(), ones.len());
+        assert_eq!(zeros.inspect().unwrap(), ones.inspect().unwrap());
+
+        let trace = decrypt_trace(&vault, &zeros);
+        assert_eq!(trace, vec![(12, 5, 64)], "exactly one AEAD call over the whole ciphertext");
+        assert_eq!(trace, decrypt_trace(&vault, &ones));
+    }
+
+    #[test]
+    fn test_drop_zeroizes_cipher_keys() {
+        let vault = Vault::<StubCipher>::builder()
+            .derived_keys_unchecked("master", "salt", "id")
+            .unwrap()
+            .build()
+            .unwrap();
+        let handle = vault.clone();
+
+        drop(vault);
+        assert_eq!(STUB_ZEROIZED.load(Ordering::SeqCst), 0, "keys must outlive live handles");
+
+        drop(handle);
+        assert_eq!(STUB_ZEROIZED.load(Ordering::SeqCst), 2, "both domain keys must be wiped");
+    }
 
     #[test]
     fn test_vault_builder() {
-        let builder =
-            Vault::<ChaCha>::builder().derived_keys("master", "salt", "id").unwrap().build();
+        let builder = Vault::<ChaCha>::builder()
+            .derived_keys_unchecked("master", "salt", "id")
+            .unwrap()
+            .build();
         assert!(builder.is_ok(), "Vault should build with derived keys");
     }
 
     #[test]
     fn test_nonce_sequence() {
-        let n1 = Vault::<ChaCha>::next_nonce();
-        let n2 = Vault::<ChaCha>::next_nonce();
+        let vault = Vault::<ChaCha>::builder()
+            .derived_keys_unchecked("master", "salt", "id")
+            .unwrap()
+            .build()
+            .unwrap();
+        let n1 = vault.next_nonce().unwrap();
+        let n2 = vault.next_nonce().unwrap();
 
         assert_ne!(n1, n2);
     }
 
+    #[test]
+    fn test_counter_nonce_sequence() {
+        let prefix = [0xAA, 0xBB, 0xCC, 0xDD];
+        let vault = Vault::<ChaCha>::builder()
+            .nonce_strategy(NonceStrategy::Counter { prefix })
+            .derived_keys_unchecked("master", "salt", "id")
+            .unwrap()
+            .build()
+            .unwrap();
+
+        let n1 = vault.next_nonce().unwrap();
+        let n2 = vault.next_nonce().unwrap();
+
+        let c1 = u64::from_be_bytes(n1[4..].try_into().unwrap());
+        let c2 = u64::from_be_bytes(n2[4..].try_into().unwrap());
+
+        assert_eq!(&n1[..4], &prefix);
+        assert!(c1 < 1 << 63);
+        assert_eq!(c2, c1 + 1);
+    }
+
+    #[test]
+    fn test_counter_nonce_starts_randomly() {
+        let build = || {
+            Vault::<ChaCha>::builder()
+                .nonce_strategy(NonceStrategy::Counter { prefix: [0; 4] })
+                .derived_keys_unchecked("master", "salt", "id")
+                .unwrap()
+                .build()
+                .unwrap()
+        };
+
+        assert_ne!(build().next_nonce().unwrap(), build().next_nonce().unwrap());
+    }
+
+    #[test]
+    fn test_counter_nonce_exhaustion() {
+        let vault = Vault::<ChaCha>::builder()
+            .nonce_strategy(NonceStrategy::Counter { prefix: [0; 4] })
+            .derived_keys_unchecked("master", "salt", "id")
+            .unwrap()
+            .build()
+            .unwrap();
+        vault.inner.nonce_counter.store(u64::MAX, Ordering::Relaxed);
+
+        let result = vault.seal_bytes::<Local>(b"data", b"ctx");
+        assert!(matches!(result, Err(VaultError::NonceExhausted { .. })));
+    }
+
+    #[test]
+    fn test_legacy_payload_without_header_aad_still_opens() {
+        let vault = setup_vault(false);
+        let nonce = vault.next_nonce().unwrap();
+        let mut data = b"legacy".to_vec();
+        let tag = vault
+            .inner
+            .local_cipher
+            .encrypt_inout_detached(&nonce, b"ctx", InOutBuf::from(&mut data[..]))
+            .unwrap();
+
+        let mut payload = vec![1, 0];
+        payload.extend_from_slice(&nonce);
+        payload.extend_from_slice(&data);
+        payload.extend_from_slice(&tag);
+
+        assert_eq!(vault.unseal_bytes::<Local>(&payload, b"ctx").unwrap(), b"legacy");
+    }
+
+    /// Seals `plaintext` uncompressed under flags that mark it compressed, so the corrupt
+    /// stream authenticates and reaches the decompressor.
+    fn seal_as_compressed(vault: &Vault<ChaCha>, plaintext: &[u8], algorithm_bits: u8) -> Vec<u8> {
+        let nonce = vault.next_nonce().unwrap();
+        let flags = FLAG_COMPRESSED | algorithm_bits;
+        Vault::encrypt_internal(&vault.inner.local_cipher, &nonce, plaintext, b"ctx", None, flags)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_decompression_reports_size_prefix_overflow() {
+        let vault = setup_vault(false);
+        let bytes = seal_as_compressed(&vault, &[0xFF, 0xFF, 0xFF, 0x7F, 0x10, 0x41], 0);
+
+        let result = vault.unseal_bytes::<Local>(&bytes, b"ctx");
+        assert!(matches!(
+            result,
+            Err(VaultError::Decompression { reason: DecompressReason::SizePrefixOverflow, .. })
+        ));
+    }
+
+    #[test]
+    fn test_decompression_reports_truncated_prefix() {
+        let vault = setup_vault(false);
+        let bytes = seal_as_compressed(&vault, &[0x01, 0x00], 0);
+
+        let result = vault.unseal_bytes::<Local>(&bytes, b"ctx");
+        assert!(matches!(
+            result,
+            Err(VaultError::Decompression { reason: DecompressReason::Truncated, .. })
+        ));
+    }
+
+    #[test]
+    fn test_decompression_reports_invalid_stream() {
+        let vault = setup_vault(false);
+        let bytes = seal_as_compressed(&vault, b"definitely not a zstd frame", 0b0100);
+
+        let result = vault.unseal_bytes::<Local>(&bytes, b"ctx");
+        assert!(matches!(
+            result,
+            Err(VaultError::Decompression { reason: DecompressReason::Invalid, .. })
+        ));
+    }
+
     fn setup_vault(compression: bool) -> Vault<ChaCha> {
         Vault::builder()
             .compression(compression)
-            .derived_keys("ikm", "salt", "id")
+            .derived_keys_unchecked("ikm", "salt", "id")
             .unwrap()
             .build()
             .expect("Vault should build with derived keys")
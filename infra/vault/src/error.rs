@@ -3,6 +3,7 @@
 //! This module defines the [`VaultError`] enum and [`Result`] type used throughout
 //! the vault crate for reporting cryptographic, serialization, and configuration failures.
 
+use crate::types::DecompressReason;
 use std::borrow::Cow;
 
 /// A specialized [`VaultError`] enum for vault-related failures.
@@ -24,13 +25,24 @@ pub enum VaultError {
     Serialization { source: postcard::Error, context: Option<Cow<'static, str>> },
 
     /// Failure during data decompression.
-    #[error("Decompression error{}: {message}", format_context(.context))]
-    Decompression { message: Cow<'static, str>, context: Option<Cow<'static, str>> },
+    ///
+    /// Decompression only runs after the payload authenticated, so this points at data that
+    /// was corrupted before sealing or at a codec bug rather than at ciphertext tampering.
+    #[error("Decompression error{}: {reason}", format_context(.context))]
+    Decompression { reason: DecompressReason, context: Option<Cow<'static, str>> },
+
+    /// Failure while reading or writing a sealed stream.
+    #[error("I/O error{}: {source}", format_context(.context))]
+    Io { source: std::io::Error, context: Option<Cow<'static, str>> },
 
     /// Failure when the vault or builder is incorrectly configured.
     #[error("Invalid configuration{}: {message}", format_context(.context))]
     InvalidConfiguration { message: Cow<'static, str>, context: Option<Cow<'static, str>> },
 
+    /// Failure when the counter-based nonce space is exhausted for the current keys.
+    #[error("Nonce exhausted{}: {message}", format_context(.context))]
+    NonceExhausted { message: Cow<'static, str>, context: Option<Cow<'static, str>> },
+
     /// Failure when the provided payload is malformed or too short.
     #[error("Invalid payload{}: {message}", format_context(.context))]
     InvalidPayload { message: Cow<'static, str>, context: Option<Cow<'static, str>> },
@@ -8,22 +8,36 @@
 //! Encrypted payloads are stored as a versioned binary blob with an explicit header:
 //!
 //! ```text
-//! [V(1)][FLAGS(1)][NONCE(12)][CIPHERTEXT(N)][TAG(16)]
+//! [V(1)][FLAGS(1)][NONCE(12|24)][CIPHERTEXT(N)][TAG(16)]
 //! ```
 //!
+//! The nonce is 12 bytes for `AES-GCM` and `ChaCha20Poly1305`, and 24 bytes for
+//! `XChaCha20Poly1305`; a `FLAGS` bit records which one is present.
+//!
 //! The header enables forward-compatible upgrades and ensures that settings such as compression
 //! are encoded in the payload itself.
 //!
+//! ## Key Rotation
+//!
+//! [`Vault::key_fingerprint`] identifies a domain key by a short, non-secret hash, and
+//! [`VaultBuilder::key_epoch`] stamps a 3-bit epoch into the `FLAGS` of every sealed payload.
+//! Rotation tooling can read the epoch via [`ProtectedPayload::inspect`] to pick the vault
+//! that unseals a blob, then move it to the new keys with [`Vault::reseal`].
+//!
 //! ## Nonce Policy
 //!
-//! This vault uses **random 96-bit nonces** for every encryption operation.
+//! By default, this vault uses **random 96-bit nonces** for every encryption operation.
 //! This is a standard approach for `AES-GCM` and `ChaCha20Poly1305`, but it is probabilistic.
-//! If you expect extremely high-volume encryption per key, consider designing a stricter nonce
-//! strategy (e.g., counter-based nonces) and rotating keys appropriately.
+//! For extremely high-volume encryption per key, select [`NonceStrategy::Counter`] via
+//! [`VaultBuilder::nonce_strategy`] and rotate keys appropriately. The nonce is stored in the
+//! payload header either way, so the wire format does not change.
+//!
+//! Alternatively, use [`XChaCha`](algorithms::XChaCha): its random 192-bit nonces make
+//! collisions negligible without any coordination, at the cost of 12 extra bytes per payload.
 //!
 //! ## Compression Threat Model
 //!
-//! Compression (LZ4) is applied **before encryption** when enabled.
+//! Compression (LZ4 or zstd) is applied **before encryption** when enabled.
 //! While correct, it may leak information via ciphertext length in scenarios where an attacker can:
 //! 1) influence plaintext, and
 //! 2) observe ciphertext sizes.
@@ -31,6 +45,24 @@
 //! Use compression primarily for internal storage where lengths are not attacker-observable.
 //! Disable it for attacker-controlled inputs and public protocols.
 //!
+//! ## Authentication & Timing
+//!
+//! The whole payload is covered by the AEAD tag: the `[V][FLAGS]` header is prepended to the
+//! caller's AAD, and flipping any flag, nonce, ciphertext or tag bit fails with the same
+//! [`VaultError::Decryption`], which does not reveal where the payload was modified. Tags are
+//! compared in constant time by the cipher crates, and the vault itself only branches on
+//! payload length and header flags, never on plaintext; decompression runs after
+//! authentication. Buffers that fail authentication are zeroized.
+//!
+//! An unknown version is rejected up front. Version 1 payloads, written before the header was
+//! authenticated, still open, but their flags are not protected.
+//!
+//! ## Streaming
+//!
+//! [`Vault::seal_stream`] and [`Vault::unseal_stream`] process large inputs in 64 KiB frames
+//! with bounded memory. Each frame is a regular payload whose AAD binds the frame counter, so
+//! truncated or reordered streams are rejected.
+//!
 //! ## Examples
 //!
 //! ### Basic Usage via Prelude
@@ -66,6 +98,7 @@ mod builder;
 mod engine;
 mod error;
 pub mod extensions;
+mod stream;
 mod types;
 
 pub use builder::VaultBuilder;
@@ -73,18 +106,21 @@ pub use engine::Vault;
 pub use error::{VaultError, VaultErrorExt};
 pub use mhub_derive::vault_model;
 pub use serde;
-pub use types::{ProtectedPayload, Tagged, VaultSerde};
+pub use types::{
+    CompressionKind, DecompressReason, MAX_KEY_EPOCH, NonceStrategy, PayloadHeader,
+    ProtectedPayload, Tagged, VaultSerde,
+};
 
 pub mod prelude {
     pub use crate::engine::Vault;
     pub use crate::error::{VaultError, VaultErrorExt};
     pub use crate::extensions::VaultExt;
-    pub use crate::types::{Aes, ChaCha, Fleet, Local, ProtectedPayload, Tagged};
+    pub use crate::types::{Aes, ChaCha, Fleet, Local, ProtectedPayload, Tagged, XChaCha};
     pub use mhub_derive::vault_model;
 }
 
 pub mod algorithms {
-    pub use crate::types::{Aes, ChaCha, VaultCipher};
+    pub use crate::types::{Aes, ChaCha, VaultCipher, XChaCha};
 }
 
 pub mod domains {
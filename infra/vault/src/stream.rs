@@ -0,0 +1,273 @@
+//! # Streaming Encryption
+//!
+//! Chunked AEAD sealing for inputs too large to buffer in memory.
+//!
+//! A sealed stream is laid out as:
+//!
+//! ```text
+//! [STREAM_V(1)][CHUNK_SIZE(4, LE)][STREAM_ID(16)] { [KIND(1)][LEN(4, LE)][FRAME(LEN)] }*
+//! ```
+//!
+//! Every `FRAME` is a regular sealed payload (`[V][FLAGS][NONCE][CIPHERTEXT][TAG]`) with a fresh
+//! nonce. Its AAD is the caller context followed by the stream header, the frame counter
+//! (big-endian `u64`) and the frame `KIND`. `STREAM_ID` is random per sealed stream, so frames
+//! cannot be reordered, replayed across streams, or moved past the final frame. A stream that
+//! ends without a final frame is rejected as truncated.
+
+use crate::engine::Vault;
+use crate::error::{VaultError, VaultErrorExt};
+use crate::types::{EXTENDED_NONCE_LEN, HEADER_LEN, PayloadKind, TAG_LEN, VaultCipher};
+use getrandom::fill;
+use std::io::ErrorKind;
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+use zeroize::Zeroizing;
+
+/// Stream header version.
+const STREAM_VERSION_V1: u8 = 1;
+
+/// Length of the random stream identifier.
+const STREAM_ID_LEN: usize = 16;
+
+/// Stream header layout: `[version: u8][chunk_size: u32 LE][stream_id: [u8; 16]]`
+const STREAM_HEADER_LEN: usize = 5 + STREAM_ID_LEN;
+
+/// Plaintext size of every non-final frame (64 KiB).
+const STREAM_CHUNK_SIZE: u32 = 64 * 1024;
+
+/// Upper bound accepted for the chunk size declared by a stream header (1 MiB).
+const STREAM_MAX_CHUNK_SIZE: usize = 1024 * 1024;
+
+/// Frame kind: more frames follow.
+const FRAME_DATA: u8 = 0;
+
+/// Frame kind: last frame of the stream.
+const FRAME_FINAL: u8 = 1;
+
+impl<C: VaultCipher> Vault<C> {
+    /// Seals everything read from `reader` into `writer` as a framed stream.
+    ///
+    /// Input is split into 64 KiB frames that are sealed independently. Frames are never
+    /// compressed, regardless of the vault compression setting.
+    ///
+    /// Memory use is bounded by roughly three frames (about 192 KiB) per call, independent of
+    /// the input size.
+    ///
+    /// # Results
+    /// Returns the number of plaintext bytes sealed.
+    ///
+    /// # Errors
+    /// * [`VaultError::Io`] If reading the input or writing the output fails.
+    /// * [`VaultError::Encryption`] If the AEAD encryption fails.
+    /// * [`VaultError::NonceExhausted`] If the counter nonce space is exhausted.
+    pub async fn seal_stream<K, R, W>(
+        &self,
+        mut reader: R,
+        mut writer: W,
+        context: &[u8],
+    ) -> Result<u64, VaultError>
+    where
+        K: PayloadKind<C>,
+        R: AsyncRead + Unpin,
+        W: AsyncWrite + Unpin,
+    {
+        let cipher = K::select_cipher(self);
+        let header = stream_header();
+        let chunk_size = STREAM_CHUNK_SIZE as usize;
+        writer.write_all(&header).await.context("Failed to write stream header")?;
+
+        let mut current = Zeroizing::new(vec![0u8; chunk_size]);
+        let mut next = Zeroizing::new(vec![0u8; chunk_size]);
+        let mut len = read_chunk(&mut reader, &mut current).await?;
+        let mut total = 0u64;
+
+        for counter in 0u64.. {
+            // A short chunk can only come from EOF; a full one needs a look-ahead read.
+            let next_len =
+                if len == chunk_size { read_chunk(&mut reader, &mut next).await? } else { 0 };
+            let kind = if next_len == 0 { FRAME_FINAL } else { FRAME_DATA };
+
+            let aad = frame_aad(context, &header, counter, kind);
+            let nonce = self.next_nonce()?;
+            let frame = Self::encrypt_internal(
+                cipher,
+                &nonce,
+                &current[..len],
+                &aad,
+                None,
+                self.base_flags(),
+            )?;
+
+            writer.write_u8(kind).await.context("Failed to write stream frame")?;
+            writer
+                .write_u32_le(frame_len(frame.len())?)
+                .await
+                .context("Failed to write stream frame")?;
+            writer.write_all(&frame).await.context("Failed to write stream frame")?;
+            total += len as u64;
+
+            if kind == FRAME_FINAL {
+                break;
+            }
+            std::mem::swap(&mut current, &mut next);
+            len = next_len;
+        }
+
+        writer.flush().await.context("Failed to flush sealed stream")?;
+        Ok(total)
+    }
+
+    /// Unseals a stream produced by [`Vault::seal_stream`] from `reader` into `writer`.
+    ///
+    /// Frames are authenticated one at a time and written out as soon as they verify, so a
+    /// failure part-way leaves the already verified prefix in `writer`. Callers must discard
+    /// the output unless this method returns `Ok`.
+    ///
+    /// Memory use is bounded by two frames of the declared chunk size (at most 1 MiB each).
+    ///
+    /// # Results
+    /// Returns the number of plaintext bytes recovered.
+    ///
+    /// # Errors
+    /// * [`VaultError::Io`] If reading the input or writing the output fails.
+    /// * [`VaultError::InvalidPayload`] If the stream is truncated, has trailing data, or a
+    ///   header/frame is malformed.
+    /// * [`VaultError::Decryption`] If a frame fails authentication, including reordered or
+    ///   replayed frames.
+    pub async fn unseal_stream<K, R, W>(
+        &self,
+        mut reader: R,
+        mut writer: W,
+        context: &[u8],
+    ) -> Result<u64, VaultError>
+    where
+        K: PayloadKind<C>,
+        R: AsyncRead + Unpin,
+        W: AsyncWrite + Unpin,
+    {
+        let cipher = K::select_cipher(self);
+
+        let mut header = [0u8; STREAM_HEADER_LEN];
+        read_exact_or_truncated(&mut reader, &mut header).await?;
+        if header[0] != STREAM_VERSION_V1 {
+            return Err(VaultError::InvalidPayload {
+                message: "Unsupported stream version".into(),
+                context: Some(format!("version={}", header[0]).into()),
+            });
+        }
+
+        let chunk_size = u32::from_le_bytes([header[1], header[2], header[3], header[4]]) as usize;
+        if chunk_size == 0 || chunk_size > STREAM_MAX_CHUNK_SIZE {
+            return Err(VaultError::InvalidPayload {
+                message: format!("Invalid stream chunk size ({chunk_size} bytes)").into(),
+                context: None,
+            });
+        }
+        let max_frame_len = HEADER_LEN + EXTENDED_NONCE_LEN + chunk_size + TAG_LEN;
+
+        let mut frame = Vec::with_capacity(max_frame_len);
+        let mut total = 0u64;
+
+        for counter in 0u64.. {
+            let mut frame_header = [0u8; 5];
+            read_exact_or_truncated(&mut reader, &mut frame_header).await?;
+            let kind = frame_header[0];
+            let len = u32::from_le_bytes([
+                frame_header[1],
+                frame_header[2],
+                frame_header[3],
+                frame_header[4],
+            ]) as usize;
+
+            if (kind != FRAME_DATA && kind != FRAME_FINAL) || len > max_frame_len {
+                return Err(VaultError::InvalidPayload {
+                    message: "Malformed stream frame".into(),
+                    context: Some(format!("frame={counter}, kind={kind}, len={len}").into()),
+                });
+            }
+
+            frame.resize(len, 0);
+            read_exact_or_truncated(&mut reader, &mut frame).await?;
+
+            let aad = frame_aad(context, &header, counter, kind);
+            let plaintext = Zeroizing::new(Self::decrypt_internal(cipher, &frame, &aad)?);
+
+            if kind == FRAME_DATA && plaintext.len() != chunk_size {
+                return Err(VaultError::InvalidPayload {
+                    message: "Short non-final stream frame".into(),
+                    context: Some(format!("frame={counter}").into()),
+                });
+            }
+
+            writer.write_all(&plaintext).await.context("Failed to write unsealed stream")?;
+            total += plaintext.len() as u64;
+
+            if kind == FRAME_FINAL {
+                break;
+            }
+        }
+
+        if reader.read(&mut [0u8; 1]).await.context("Failed to read sealed stream")? != 0 {
+            return Err(VaultError::InvalidPayload {
+                message: "Trailing data after final stream frame".into(),
+                context: None,
+            });
+        }
+
+        writer.flush().await.context("Failed to flush unsealed stream")?;
+        Ok(total)
+    }
+}
+
+fn stream_header() -> [u8; STREAM_HEADER_LEN] {
+    let mut header = [0u8; STREAM_HEADER_LEN];
+    header[0] = STREAM_VERSION_V1;
+    header[1..5].copy_from_slice(&STREAM_CHUNK_SIZE.to_le_bytes());
+    fill(&mut header[5..]).expect("System RNG unavailable for stream id generation");
+    header
+}
+
+fn frame_aad(context: &[u8], header: &[u8], counter: u64, kind: u8) -> Vec<u8> {
+    let mut aad = Vec::with_capacity(context.len() + header.len() + 9);
+    aad.extend_from_slice(context);
+    aad.extend_from_slice(header);
+    aad.extend_from_slice(&counter.to_be_bytes());
+    aad.push(kind);
+    aad
+}
+
+fn frame_len(len: usize) -> Result<u32, VaultError> {
+    u32::try_from(len).map_err(|_| VaultError::Encryption {
+        message: "Stream frame too large".into(),
+        context: Some(format!("len={len}").into()),
+    })
+}
+
+/// Fills `buf` from `reader` until it is full or EOF is reached.
+async fn read_chunk<R: AsyncRead + Unpin>(
+    reader: &mut R,
+    buf: &mut [u8],
+) -> Result<usize, VaultError> {
+    let mut filled = 0;
+    while filled < buf.len() {
+        let n = reader.read(&mut buf[filled..]).await.context("Failed to read stream input")?;
+        if n == 0 {
+            break;
+        }
+        filled += n;
+    }
+    Ok(filled)
+}
+
+async fn read_exact_or_truncated<R: AsyncRead + Unpin>(
+    reader: &mut R,
+    buf: &mut [u8],
+) -> Result<(), VaultError> {
+    match reader.read_exact(buf).await {
+        Ok(_) => Ok(()),
+        Err(err) if err.kind() == ErrorKind::UnexpectedEof => Err(VaultError::InvalidPayload {
+            message: "Sealed stream is truncated".into(),
+            context: None,
+        }),
+        Err(err) => Err(err).context("Failed to read sealed stream"),
+    }
+}
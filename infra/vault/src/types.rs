@@ -1,37 +1,261 @@
 use crate::engine::Vault;
-use aead::{AeadInOut, KeyInit};
+use crate::error::VaultError;
+use aead::{AeadInOut, Key, KeyInit};
 use aes_gcm::Aes256Gcm;
-use chacha20poly1305::ChaCha20Poly1305;
+use chacha20poly1305::{ChaCha20Poly1305, XChaCha20Poly1305};
 use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 use std::marker::PhantomData;
 use std::ops::Deref;
+use zeroize::Zeroizing;
 
 // --- Aliases ---
 
 pub type Aes = Aes256Gcm;
 pub type ChaCha = ChaCha20Poly1305;
+pub type XChaCha = XChaCha20Poly1305;
 
-pub trait VaultCipher: AeadInOut + KeyInit + 'static {}
-impl<T: AeadInOut + KeyInit + 'static> VaultCipher for T {}
+/// An AEAD cipher usable by [`Vault`].
+pub trait VaultCipher: AeadInOut + KeyInit + 'static {
+    /// Overwrites the key material held by the cipher.
+    ///
+    /// Called when the vault is dropped. The default re-keys the cipher in place with an
+    /// all-zero key, replacing the expanded key schedule stored inside `self`.
+    fn zeroize_key(&mut self) {
+        *self = Self::new(&Key::<Self>::default());
+        std::sync::atomic::compiler_fence(std::sync::atomic::Ordering::SeqCst);
+    }
+}
+
+impl VaultCipher for Aes {}
+impl VaultCipher for ChaCha {}
+impl VaultCipher for XChaCha {}
 
 // --- Payload format constants ---
 
-/// Payload header version for [`ProtectedPayload`].
+/// Legacy payload header version, whose header bytes are not authenticated. Still opened.
 pub(crate) const PAYLOAD_VERSION_V1: u8 = 1;
 
+/// Payload header version written by [`Vault`](crate::Vault): the header is bound into the
+/// AAD, so its flags cannot be altered without failing authentication.
+pub(crate) const PAYLOAD_VERSION_V2: u8 = 2;
+
 /// Header layout: `[version: u8][flags: u8]`
 pub(crate) const HEADER_LEN: usize = 2;
 
 /// AEAD nonce length (96-bit).
 pub(crate) const NONCE_LEN: usize = 12;
 
+/// Extended AEAD nonce length (192-bit), used by [`XChaCha`].
+pub(crate) const EXTENDED_NONCE_LEN: usize = 24;
+
 /// AEAD tag length (128-bit).
 pub(crate) const TAG_LEN: usize = 16;
 
 /// Flag bit: payload ciphertext was compressed before encryption.
 pub(crate) const FLAG_COMPRESSED: u8 = 1 << 0;
 
+/// Flag bit: header carries a 192-bit extended nonce instead of the 96-bit default.
+pub(crate) const FLAG_EXTENDED_NONCE: u8 = 1 << 1;
+
+/// Flag bit: nonce is a synthetic IV derived from the plaintext, not random or counter-based.
+pub(crate) const FLAG_DETERMINISTIC: u8 = 1 << 4;
+
+/// Flag bits carrying the key epoch, see
+/// [`VaultBuilder::key_epoch`](crate::VaultBuilder::key_epoch).
+///
+/// Zero for payloads sealed before epochs existed, which is also the default epoch.
+pub(crate) const FLAG_EPOCH_MASK: u8 = 0b111 << FLAG_EPOCH_SHIFT;
+
+/// Bit offset of the key epoch within the header flags.
+pub(crate) const FLAG_EPOCH_SHIFT: u8 = 5;
+
+/// Largest key epoch that fits in the header flags.
+pub const MAX_KEY_EPOCH: u8 = FLAG_EPOCH_MASK >> FLAG_EPOCH_SHIFT;
+
+/// Flag bits identifying the compression algorithm when [`FLAG_COMPRESSED`] is set.
+///
+/// Zero means LZ4, which keeps payloads written before the algorithm bits existed readable.
+pub(crate) const FLAG_ALGORITHM_MASK: u8 = 0b11 << 2;
+
+/// Algorithm bits: LZ4 (size-prefixed block).
+pub(crate) const FLAG_ALGORITHM_LZ4: u8 = 0;
+
+/// Algorithm bits: zstd frame.
+pub(crate) const FLAG_ALGORITHM_ZSTD: u8 = 1 << 2;
+
+/// Returns the nonce length encoded by the header `flags`.
+pub(crate) const fn nonce_len(flags: u8) -> usize {
+    if flags & FLAG_EXTENDED_NONCE == 0 { NONCE_LEN } else { EXTENDED_NONCE_LEN }
+}
+
+/// AAD separator between the type tag and the purpose of purpose-bound payloads.
+pub(crate) const PURPOSE_AAD_SEPARATOR: &[u8] = b"#purpose:";
+
+/// Default minimum plaintext size, in bytes, for compression to be applied.
+pub(crate) const DEFAULT_COMPRESSION_MIN_SIZE: usize = 256;
+
+/// Upper bound of the LZ4 block compression ratio, used to reject implausible size prefixes.
+const LZ4_MAX_RATIO: usize = 255;
+
+// --- Compression ---
+
+/// Compression algorithm applied before encryption.
+///
+/// - [`CompressionKind::None`]: the default. Payloads are stored uncompressed.
+/// - [`CompressionKind::Lz4`]: fast LZ4 block compression.
+/// - [`CompressionKind::Zstd`]: zstd at the given `level` (1..=22). Noticeably better ratios
+///   for text-heavy records such as JSON, at a higher CPU cost than LZ4.
+///
+/// The algorithm is recorded in the payload header, so any vault can unseal payloads
+/// regardless of the algorithm it seals with.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum CompressionKind {
+    #[default]
+    None,
+    Lz4,
+    Zstd {
+        level: i32,
+    },
+}
+
+impl CompressionKind {
+    pub(crate) const fn codec(self) -> Option<Codec> {
+        match self {
+            Self::None => None,
+            Self::Lz4 => Some(Codec::Lz4),
+            Self::Zstd { level } => Some(Codec::Zstd(level)),
+        }
+    }
+}
+
+/// Resolved compressor used by the seal path.
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum Codec {
+    Lz4,
+    Zstd(i32),
+}
+
+impl Codec {
+    /// Returns the header flags describing payloads compressed by this codec.
+    pub(crate) const fn flags(self) -> u8 {
+        match self {
+            Self::Lz4 => FLAG_COMPRESSED | FLAG_ALGORITHM_LZ4,
+            Self::Zstd(_) => FLAG_COMPRESSED | FLAG_ALGORITHM_ZSTD,
+        }
+    }
+
+    /// Compresses `data`. The output is still plaintext, so it is zeroized on drop.
+    pub(crate) fn compress(self, data: &[u8]) -> Result<Zeroizing<Vec<u8>>, VaultError> {
+        let compressed = match self {
+            Self::Lz4 => Ok(lz4_flex::compress_prepend_size(data)),
+            Self::Zstd(level) => {
+                zstd::bulk::compress(data, level).map_err(|err| VaultError::Encryption {
+                    message: "Compression failed".into(),
+                    context: Some(format!("zstd: {err}").into()),
+                })
+            },
+        };
+        compressed.map(Zeroizing::new)
+    }
+
+    /// Decompresses `data` according to the algorithm bits in `flags`, zeroizing the output
+    /// on drop.
+    pub(crate) fn decompress(flags: u8, data: &[u8]) -> Result<Zeroizing<Vec<u8>>, VaultError> {
+        let decompressed = match flags & FLAG_ALGORITHM_MASK {
+            FLAG_ALGORITHM_LZ4 => Self::decompress_lz4(data),
+            FLAG_ALGORITHM_ZSTD => {
+                zstd::decode_all(data).map_err(|err| VaultError::Decompression {
+                    reason: DecompressReason::Invalid,
+                    context: Some(format!("zstd: {err}").into()),
+                })
+            },
+            bits => Err(VaultError::Decompression {
+                reason: DecompressReason::Invalid,
+                context: Some(format!("unknown algorithm={}", bits >> 2).into()),
+            }),
+        };
+        decompressed.map(Zeroizing::new)
+    }
+
+    /// Decodes a size-prefixed LZ4 block, validating the prefix before allocating for it.
+    fn decompress_lz4(data: &[u8]) -> Result<Vec<u8>, VaultError> {
+        let Some((prefix, block)) = data.split_first_chunk::<4>() else {
+            return Err(VaultError::Decompression {
+                reason: DecompressReason::Truncated,
+                context: Some(format!("LZ4 size prefix needs 4 bytes, got {}", data.len()).into()),
+            });
+        };
+
+        let size = u32::from_le_bytes(*prefix) as usize;
+        let max_size = block.len().saturating_mul(LZ4_MAX_RATIO);
+        if size > max_size {
+            return Err(VaultError::Decompression {
+                reason: DecompressReason::SizePrefixOverflow,
+                context: Some(
+                    format!(
+                        "LZ4 size prefix {size} exceeds {max_size} for a {}-byte block",
+                        block.len()
+                    )
+                    .into(),
+                ),
+            });
+        }
+
+        lz4_flex::decompress(block, size).map_err(|err| {
+            let reason = match err {
+                lz4_flex::block::DecompressError::ExpectedAnotherByte => {
+                    DecompressReason::Truncated
+                },
+                _ => DecompressReason::Invalid,
+            };
+            VaultError::Decompression { reason, context: Some(format!("LZ4: {err}").into()) }
+        })
+    }
+}
+
+/// Why decompressing an authenticated payload failed.
+///
+/// - [`DecompressReason::SizePrefixOverflow`]: the LZ4 size prefix claims more output than the
+///   block could possibly expand to.
+/// - [`DecompressReason::Truncated`]: the compressed stream ends before it is complete.
+/// - [`DecompressReason::Invalid`]: the stream is malformed or uses an unknown algorithm.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DecompressReason {
+    SizePrefixOverflow,
+    Truncated,
+    Invalid,
+}
+
+impl std::fmt::Display for DecompressReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::SizePrefixOverflow => "size prefix overflow",
+            Self::Truncated => "truncated stream",
+            Self::Invalid => "invalid stream",
+        })
+    }
+}
+
+// --- Nonces ---
+
+/// Strategy used to generate AEAD nonces.
+///
+/// - [`NonceStrategy::Random`]: a fresh random nonce per operation (default). Safe without
+///   coordination, but collisions become likely after roughly 2^32 operations per key.
+/// - [`NonceStrategy::Counter`]: a fixed 4-byte `prefix` followed by a 64-bit counter that
+///   starts at a random value below 2^63 in every vault instance. Nonces never repeat within
+///   one instance, and a restart only overlaps earlier nonces if the random ranges collide.
+///   The prefix must still be unique per process sharing the key.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum NonceStrategy {
+    #[default]
+    Random,
+    Counter {
+        prefix: [u8; 4],
+    },
+}
+
 // --- Markers ---
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -47,11 +271,12 @@ pub struct Fleet;
 /// The payload is packed using the following memory layout:
 ///
 /// ```text
-/// [V(1)][FLAGS(1)][NONCE(12)][CIPHERTEXT(N)][TAG(16)]
+/// [V(1)][FLAGS(1)][NONCE(12|24)][CIPHERTEXT(N)][TAG(16)]
 /// ```
 ///
 /// - `V` is the payload format version.
-/// - `FLAGS` currently contains the compression bit.
+/// - `FLAGS` contains the compression, extended-nonce and key-epoch bits.
+/// - `NONCE` is 24 bytes for [`XChaCha`] payloads and 12 bytes otherwise.
 /// - The `Kind` type parameter ensures correct domain usage ([`Local`] or [`Fleet`]).
 #[derive(Clone, Serialize, Deserialize)]
 pub struct ProtectedPayload<Kind, C = Aes> {
@@ -62,9 +287,14 @@ pub struct ProtectedPayload<Kind, C = Aes> {
     _cipher: PhantomData<C>,
 }
 
+/// Prints header metadata and length only; the ciphertext is never included.
 impl<K, C> std::fmt::Debug for ProtectedPayload<K, C> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("ProtectedPayload").field("data", &self.data).finish()
+        f.debug_struct("ProtectedPayload")
+            .field("version", &self.version())
+            .field("compressed", &self.is_compressed())
+            .field("len", &self.data.len())
+            .finish()
     }
 }
 
@@ -83,10 +313,10 @@ impl<K, C> std::hash::Hash for ProtectedPayload<K, C> {
 }
 
 impl<K, C> ProtectedPayload<K, C> {
-    /// Returns the payload format version.
+    /// Returns the payload format version, or `0` if the buffer is empty.
     #[must_use]
-    pub fn version(&self) -> Option<u8> {
-        self.data.first().copied()
+    pub fn version(&self) -> u8 {
+        self.data.first().copied().unwrap_or_default()
     }
 
     /// Returns `true` if the payload indicates compression.
@@ -95,13 +325,26 @@ impl<K, C> ProtectedPayload<K, C> {
         self.data.get(1).copied().is_some_and(|f| (f & FLAG_COMPRESSED) != 0)
     }
 
+    /// Parses and validates the payload header without touching the cipher.
+    ///
+    /// # Results
+    /// Returns the decoded [`PayloadHeader`].
+    ///
+    /// # Errors
+    /// * [`VaultError::InvalidPayload`] If the buffer is too short for its header, nonce and
+    ///   tag, or has an unsupported version.
+    pub fn inspect(&self) -> Result<PayloadHeader, VaultError> {
+        PayloadHeader::parse(&self.data)
+    }
+
     /// Splits the payload into its constituent cryptographic parts.
     ///
     /// Returns a tuple of `(header, nonce, ciphertext, tag)`.
     #[must_use]
     pub fn split(&self) -> (&[u8], &[u8], &[u8], &[u8]) {
+        let nonce_len = self.data.get(1).map_or(NONCE_LEN, |flags| nonce_len(*flags));
         let (header, rest) = self.data.split_at(HEADER_LEN);
-        let (nonce, rest) = rest.split_at(NONCE_LEN);
+        let (nonce, rest) = rest.split_at(nonce_len);
         let (ciphertext, tag) = rest.split_at(rest.len().saturating_sub(TAG_LEN));
         (header, nonce, ciphertext, tag)
     }
@@ -136,18 +379,36 @@ mod private {
 
 pub trait PayloadKind<C: VaultCipher>: private::Sealed + 'static {
     fn select_cipher(vault: &Vault<C>) -> &C;
+    fn select_siv_key(vault: &Vault<C>) -> &[u8; 32];
+    fn select_fingerprint(vault: &Vault<C>) -> &[u8; 8];
 }
 
 impl<C: VaultCipher> PayloadKind<C> for Local {
     fn select_cipher(vault: &Vault<C>) -> &C {
         &vault.inner.local_cipher
     }
+
+    fn select_siv_key(vault: &Vault<C>) -> &[u8; 32] {
+        &vault.inner.local_siv_key
+    }
+
+    fn select_fingerprint(vault: &Vault<C>) -> &[u8; 8] {
+        &vault.inner.local_fingerprint
+    }
 }
 
 impl<C: VaultCipher> PayloadKind<C> for Fleet {
     fn select_cipher(vault: &Vault<C>) -> &C {
         &vault.inner.fleet_cipher
     }
+
+    fn select_siv_key(vault: &Vault<C>) -> &[u8; 32] {
+        &vault.inner.fleet_siv_key
+    }
+
+    fn select_fingerprint(vault: &Vault<C>) -> &[u8; 8] {
+        &vault.inner.fleet_fingerprint
+    }
 }
 
 pub trait Tagged {
@@ -163,4 +424,81 @@ impl<K, C> ProtectedPayload<K, C> {
     pub fn as_slice(&self) -> &[u8] {
         &self.data
     }
+
+    /// Consumes the payload and returns the sealed bytes without copying.
+    #[must_use]
+    pub fn into_inner(self) -> Vec<u8> {
+        self.data
+    }
+
+    /// Wraps sealed bytes after validating the payload header and minimum length.
+    ///
+    /// Unlike the unchecked [`From<Vec<u8>>`] conversion, this rejects buffers that cannot
+    /// possibly be a sealed payload. Authenticity is still only established on unseal.
+    ///
+    /// # Errors
+    /// Returns [`VaultError::InvalidPayload`] if the buffer is too short or has an
+    /// unsupported version.
+    pub fn from_inner(bytes: Vec<u8>) -> Result<Self, VaultError> {
+        PayloadHeader::parse(&bytes)?;
+        Ok(Self::from(bytes))
+    }
+}
+
+/// Plaintext metadata decoded from the `[V][FLAGS][NONCE]` prefix of a [`ProtectedPayload`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PayloadHeader {
+    /// Payload format version.
+    pub version: u8,
+    /// Raw header flags.
+    pub flags: u8,
+    /// Whether the plaintext was compressed before encryption.
+    pub compressed: bool,
+    /// Whether the payload was sealed with [`Vault::seal_deterministic`].
+    pub deterministic: bool,
+    /// Key epoch of the sealing vault, see
+    /// [`VaultBuilder::key_epoch`](crate::VaultBuilder::key_epoch).
+    ///
+    /// The header is not authenticated, so treat the epoch as a routing hint only.
+    pub key_epoch: u8,
+    /// Length of the stored nonce in bytes.
+    pub nonce_len: usize,
+    /// Length of the ciphertext in bytes, excluding nonce and tag.
+    pub ciphertext_len: usize,
+}
+
+impl PayloadHeader {
+    pub(crate) fn parse(bytes: &[u8]) -> Result<Self, VaultError> {
+        let min_len =
+            HEADER_LEN + bytes.get(1).map_or(NONCE_LEN, |flags| nonce_len(*flags)) + TAG_LEN;
+        if bytes.len() < min_len {
+            return Err(VaultError::InvalidPayload {
+                message: format!(
+                    "Payload too short ({} bytes). Expected at least {min_len} bytes",
+                    bytes.len()
+                )
+                .into(),
+                context: None,
+            });
+        }
+
+        let (version, flags) = (bytes[0], bytes[1]);
+        if version != PAYLOAD_VERSION_V1 && version != PAYLOAD_VERSION_V2 {
+            return Err(VaultError::InvalidPayload {
+                message: "Unsupported payload version".into(),
+                context: Some(format!("version={version}").into()),
+            });
+        }
+
+        let nonce_len = nonce_len(flags);
+        Ok(Self {
+            version,
+            flags,
+            compressed: (flags & FLAG_COMPRESSED) != 0,
+            deterministic: (flags & FLAG_DETERMINISTIC) != 0,
+            key_epoch: (flags & FLAG_EPOCH_MASK) >> FLAG_EPOCH_SHIFT,
+            nonce_len,
+            ciphertext_len: bytes.len() - HEADER_LEN - nonce_len - TAG_LEN,
+        })
+    }
 }
@@ -2,6 +2,7 @@ pub mod fixtures;
 
 use fixtures::setup_vault;
 use mhub_vault::prelude::*;
+use mhub_vault::{CompressionKind, MAX_KEY_EPOCH};
 
 #[vault_model(tag = "v1.profile")]
 struct Profile {
@@ -64,3 +65,457 @@ fn unseal_bytes_requires_matching_context() {
     let wrong = vault.unseal_bytes::<Local>(&sealed, b"ctx");
     assert!(wrong.is_err(), "Unsealing should fail with non-empty context");
 }
+
+#[test]
+fn unseal_for_requires_matching_purpose() {
+    let vault = setup_vault();
+    let profile = Profile { username: "ada".to_owned(), enabled: true };
+
+    let sealed = vault.seal_for::<Local, _>(&profile, "session").expect("seal failed");
+
+    let unsealed: Profile =
+        vault.unseal_for::<Local, _>(&sealed, "session").expect("unseal failed");
+    assert_eq!(profile, unsealed);
+
+    let wrong = vault.unseal_for::<Local, Profile>(&sealed, "reset-token");
+    assert!(matches!(wrong, Err(VaultError::Decryption { .. })));
+}
+
+#[test]
+fn purpose_bound_payload_rejects_plain_unseal() {
+    let vault = setup_vault();
+    let profile = Profile { username: "ada".to_owned(), enabled: true };
+
+    let sealed = vault.seal_for::<Local, _>(&profile, "session").expect("seal failed");
+
+    let plain = vault.unseal_local::<Profile>(&sealed);
+    assert!(matches!(plain, Err(VaultError::Decryption { .. })));
+}
+
+#[test]
+fn into_inner_from_inner_roundtrip() {
+    let vault = setup_vault();
+    let profile = Profile { username: "ada".to_owned(), enabled: true };
+
+    let bytes = vault.seal::<Local, _>(&profile).expect("seal failed").into_inner();
+    let restored = ProtectedPayload::<Local>::from_inner(bytes).expect("valid payload");
+
+    let unsealed: Profile = vault.unseal_local(&restored).expect("unseal failed");
+    assert_eq!(profile, unsealed);
+}
+
+#[test]
+fn from_inner_rejects_short_buffer() {
+    let result = ProtectedPayload::<Local>::from_inner(vec![1, 0, 0]);
+    assert!(matches!(result, Err(VaultError::InvalidPayload { .. })));
+}
+
+#[test]
+fn vault_debug_redacts_key_material() {
+    let vault = setup_vault();
+
+    let salt: &[u8] = b"unique-salt";
+    let (_, hk) = hkdf::Hkdf::<sha2::Sha256>::extract(Some(salt), b"master-secret-123");
+    let mut fleet = [0u8; 32];
+    hk.expand(b"v1_fleet:", &mut fleet).unwrap();
+
+    let debug = format!("{vault:?}");
+    assert!(debug.contains("algorithm"), "expected metadata in {debug}");
+    assert!(!debug.contains(&format!("{fleet:?}")), "vault Debug leaked key bytes");
+    assert!(!debug.contains(&format!("{:?}", &fleet[..8])), "vault Debug leaked key bytes");
+}
+
+#[test]
+fn payload_debug_omits_ciphertext() {
+    let vault = setup_vault();
+    let sealed = vault.seal_bytes::<Local>(b"sensitive bytes", b"ctx").expect("seal failed");
+
+    let debug = format!("{sealed:?}");
+    assert!(debug.contains("len"), "expected length in {debug}");
+    assert!(!debug.contains(&format!("{:?}", sealed.as_slice())));
+    assert!(!debug.contains(&format!("{:?}", &sealed.as_slice()[2..10])));
+}
+
+#[test]
+fn xchacha_roundtrip_uses_extended_nonce() {
+    let vault = Vault::<XChaCha>::builder()
+        .derived_keys("master-secret-123", "unique-salt", "machine-01")
+        .unwrap()
+        .build()
+        .expect("Vault setup failed");
+    let profile = Profile { username: "ada".to_owned(), enabled: true };
+
+    let sealed = vault.seal::<Local, _>(&profile).expect("seal failed");
+    let (header, nonce, _, tag) = sealed.split();
+    assert_eq!(header[1] & 0b10, 0b10, "extended nonce flag must be set");
+    assert_eq!(nonce.len(), 24);
+    assert_eq!(tag.len(), 16);
+
+    let unsealed: Profile = vault.unseal_local(&sealed).expect("unseal failed");
+    assert_eq!(profile, unsealed);
+
+    let restored = ProtectedPayload::<Local, XChaCha>::from_inner(sealed.into_inner()).unwrap();
+    assert_eq!(vault.unseal_local::<Profile>(&restored).unwrap(), profile);
+}
+
+#[test]
+fn standard_nonce_payloads_keep_legacy_layout() {
+    let vault = setup_vault();
+    let sealed = vault.seal_bytes::<Local>(b"legacy", b"ctx").expect("seal failed");
+
+    let (header, nonce, _, _) = sealed.split();
+    assert_eq!(header[1] & 0b10, 0, "extended nonce flag must be clear");
+    assert_eq!(nonce.len(), 12);
+    assert_eq!(vault.unseal_bytes::<Local>(&sealed, b"ctx").unwrap(), b"legacy");
+}
+
+#[test]
+fn reseal_local_moves_payload_to_new_keys() {
+    let old = setup_vault();
+    let new = Vault::builder()
+        .derived_keys("rotated-secret-456", "unique-salt", "machine-01")
+        .unwrap()
+        .build()
+        .expect("Vault setup failed");
+    let profile = Profile { username: "ada".to_owned(), enabled: true };
+
+    let sealed = old.seal::<Local, _>(&profile).expect("seal failed");
+    let resealed = new.reseal_local(&old, &sealed, b"v1.profile").expect("reseal failed");
+
+    assert_eq!(new.unseal_local::<Profile>(&resealed).unwrap(), profile);
+    assert!(old.unseal_local::<Profile>(&resealed).is_err());
+}
+
+#[test]
+fn reseal_fleet_rejects_wrong_old_vault() {
+    let old = setup_vault();
+    let new = Vault::builder()
+        .derived_keys("rotated-secret-456", "unique-salt", "machine-01")
+        .unwrap()
+        .build()
+        .expect("Vault setup failed");
+
+    let sealed = old.seal_bytes::<Fleet>(b"fleet data", b"ctx").expect("seal failed");
+    let result = new.reseal_fleet(&new, &sealed, b"ctx");
+
+    assert!(matches!(result, Err(VaultError::Decryption { .. })));
+}
+
+#[test]
+fn inspect_reports_header_without_decrypting() {
+    let vault = setup_vault();
+    let sealed = vault.seal_bytes::<Local>(vec![7u8; 512], b"ctx").expect("seal failed");
+
+    let header = sealed.inspect().expect("valid header");
+    assert_eq!(header.version, sealed.version());
+    assert_eq!(header.version, 2);
+    assert!(header.compressed);
+    assert_eq!(header.compressed, sealed.is_compressed());
+    assert_eq!(header.nonce_len, 12);
+    assert_eq!(header.ciphertext_len, sealed.len() - 2 - 12 - 16);
+}
+
+#[test]
+fn derived_keys_rejects_empty_inputs() {
+    let empty_ikm = Vault::<Aes>::builder().derived_keys("", "unique-salt", "machine-01");
+    assert!(matches!(empty_ikm, Err(VaultError::InvalidConfiguration { .. })));
+
+    let empty_salt = Vault::<Aes>::builder().derived_keys("master-secret-123", "", "machine-01");
+    assert!(matches!(empty_salt, Err(VaultError::InvalidConfiguration { .. })));
+}
+
+#[test]
+fn derived_keys_unchecked_accepts_weak_inputs() {
+    let vault = Vault::<Aes>::builder()
+        .derived_keys_unchecked("", "", "machine-01")
+        .expect("unchecked derivation skips validation")
+        .build()
+        .unwrap();
+
+    let sealed = vault.seal_bytes::<Local>(b"data", b"ctx").unwrap();
+    assert_eq!(vault.unseal_bytes::<Local>(&sealed, b"ctx").unwrap(), b"data");
+}
+
+#[test]
+fn derived_keys_matches_unchecked_for_valid_inputs() {
+    let checked = setup_vault();
+    let unchecked = Vault::<Aes>::builder()
+        .derived_keys_unchecked("master-secret-123", "unique-salt", "machine-01")
+        .unwrap()
+        .build()
+        .unwrap();
+
+    assert_eq!(checked.key_fingerprint::<Local>(), unchecked.key_fingerprint::<Local>());
+    assert_eq!(checked.key_fingerprint::<Fleet>(), unchecked.key_fingerprint::<Fleet>());
+}
+
+#[test]
+fn key_fingerprint_identifies_domain_keys() {
+    let vault = setup_vault();
+    let same = setup_vault();
+    let other_machine = Vault::<Aes>::builder()
+        .derived_keys("master-secret-123", "unique-salt", "machine-02")
+        .unwrap()
+        .build()
+        .unwrap();
+
+    assert_eq!(vault.key_fingerprint::<Local>(), same.key_fingerprint::<Local>());
+    assert_ne!(vault.key_fingerprint::<Local>(), vault.key_fingerprint::<Fleet>());
+    assert_ne!(vault.key_fingerprint::<Local>(), other_machine.key_fingerprint::<Local>());
+    assert_eq!(vault.key_fingerprint::<Fleet>(), other_machine.key_fingerprint::<Fleet>());
+}
+
+#[test]
+fn inspect_reports_key_epoch() {
+    let vault = Vault::<Aes>::builder()
+        .key_epoch(5)
+        .derived_keys("master-secret-123", "unique-salt", "machine-01")
+        .unwrap()
+        .build()
+        .unwrap();
+    assert_eq!(vault.key_epoch(), 5);
+
+    let sealed = vault.seal_bytes::<Local>(b"data", b"ctx").expect("seal failed");
+    let header = sealed.inspect().expect("valid header");
+    assert_eq!(header.key_epoch, 5);
+    assert!(!header.compressed);
+    assert_eq!(header.nonce_len, 12);
+    assert_eq!(vault.unseal_bytes::<Local>(&sealed, b"ctx").unwrap(), b"data");
+
+    let deterministic = vault.seal_deterministic::<Fleet>(b"data", b"ctx").unwrap();
+    let header = deterministic.inspect().unwrap();
+    assert_eq!(header.key_epoch, 5);
+    assert!(header.deterministic);
+
+    let legacy = setup_vault().seal_bytes::<Local>(b"data", b"ctx").unwrap();
+    assert_eq!(legacy.inspect().unwrap().key_epoch, 0);
+}
+
+#[test]
+fn build_rejects_out_of_range_key_epoch() {
+    let result = Vault::<Aes>::builder()
+        .key_epoch(MAX_KEY_EPOCH + 1)
+        .derived_keys("master-secret-123", "unique-salt", "machine-01")
+        .unwrap()
+        .build();
+    assert!(matches!(result, Err(VaultError::InvalidConfiguration { .. })));
+}
+
+#[test]
+fn inspect_rejects_malformed_payloads() {
+    let short = ProtectedPayload::<Local>::from(vec![1, 0, 0]);
+    assert!(matches!(short.inspect(), Err(VaultError::InvalidPayload { .. })));
+
+    let mut bytes = vec![0u8; 2 + 12 + 16];
+    bytes[0] = 9;
+    let unknown = ProtectedPayload::<Local>::from(bytes);
+    assert!(matches!(unknown.inspect(), Err(VaultError::InvalidPayload { .. })));
+
+    assert_eq!(ProtectedPayload::<Local>::from(Vec::new()).version(), 0);
+}
+
+#[test]
+fn zstd_compression_roundtrip() {
+    let vault = Vault::builder()
+        .derived_keys("master-secret-123", "unique-salt", "machine-01")
+        .unwrap()
+        .compression_algorithm(CompressionKind::Zstd { level: 3 })
+        .build()
+        .expect("Vault setup failed");
+    let payload = br#"{"user":"ada","roles":["admin","editor"]}"#.repeat(64);
+
+    let sealed = vault.seal_bytes::<Local>(&payload, b"json").expect("seal failed");
+    let (header, ..) = sealed.split();
+    assert!(sealed.is_compressed());
+    assert_eq!(header[1] & 0b1100, 0b0100, "expected zstd algorithm bits");
+    assert!(sealed.len() < payload.len());
+
+    assert_eq!(vault.unseal_bytes::<Local>(&sealed, b"json").unwrap(), payload);
+}
+
+#[test]
+fn lz4_payloads_remain_readable_by_zstd_vault() {
+    let lz4 = setup_vault();
+    let zstd = Vault::builder()
+        .derived_keys("master-secret-123", "unique-salt", "machine-01")
+        .unwrap()
+        .compression_algorithm(CompressionKind::Zstd { level: 3 })
+        .build()
+        .expect("Vault setup failed");
+    let payload = b"legacy lz4 payload ".repeat(32);
+
+    let sealed = lz4.seal_bytes::<Local>(&payload, b"ctx").expect("seal failed");
+    let (header, ..) = sealed.split();
+    assert_eq!(header[1] & 0b1100, 0, "LZ4 payloads keep the algorithm bits clear");
+
+    assert_eq!(zstd.unseal_bytes::<Local>(&sealed, b"ctx").unwrap(), payload);
+}
+
+#[test]
+fn small_payloads_skip_compression() {
+    let vault = setup_vault();
+
+    let sealed = vault.seal_bytes::<Local>(b"ten bytes!", b"ctx").expect("seal failed");
+    let (header, ..) = sealed.split();
+    assert_eq!(header[1] & 0b1, 0, "payload below the threshold must not be compressed");
+    assert_eq!(vault.unseal_bytes::<Local>(&sealed, b"ctx").unwrap(), b"ten bytes!");
+
+    let eager = Vault::builder()
+        .derived_keys("master-secret-123", "unique-salt", "machine-01")
+        .unwrap()
+        .compression(true)
+        .compression_min_size(0)
+        .build()
+        .expect("Vault setup failed");
+    assert!(eager.seal_bytes::<Local>(b"ten bytes!", b"ctx").unwrap().is_compressed());
+}
+
+#[test]
+fn seal_batch_preserves_order() {
+    let vault = setup_vault();
+    let profiles: Vec<Profile> =
+        (0..1000).map(|i| Profile { username: format!("user-{i}"), enabled: i % 2 == 0 }).collect();
+
+    let sealed = vault.seal_batch::<Local, _>(&profiles).expect("seal failed");
+    assert_eq!(sealed.len(), profiles.len());
+
+    let unsealed: Vec<Profile> = vault.unseal_batch::<Local, _>(&sealed).expect("unseal failed");
+    assert_eq!(unsealed, profiles);
+}
+
+#[test]
+fn unseal_batch_reports_failing_index() {
+    let vault = setup_vault();
+    let profiles: Vec<Profile> =
+        (0..3).map(|i| Profile { username: format!("user-{i}"), enabled: true }).collect();
+    let mut sealed: Vec<Vec<u8>> = vault
+        .seal_batch::<Local, _>(&profiles)
+        .unwrap()
+        .into_iter()
+        .map(ProtectedPayload::into_inner)
+        .collect();
+    let last = sealed[1].len() - 1;
+    sealed[1][last] ^= 0xFF;
+
+    let err = vault.unseal_batch::<Local, Profile>(&sealed).unwrap_err();
+    assert!(matches!(err, VaultError::Decryption { .. }));
+    assert!(err.to_string().contains("batch index 1"), "unexpected error: {err}");
+}
+
+#[test]
+fn seal_deterministic_is_stable_per_input() {
+    let vault = setup_vault();
+
+    let first = vault.seal_deterministic::<Local>(b"blob contents", b"cas").expect("seal failed");
+    let second = vault.seal_deterministic::<Local>(b"blob contents", b"cas").expect("seal failed");
+    let other = vault.seal_deterministic::<Local>(b"other contents", b"cas").expect("seal failed");
+    let other_ctx =
+        vault.seal_deterministic::<Local>(b"blob contents", b"alt").expect("seal failed");
+
+    assert_eq!(first, second);
+    assert_ne!(first, other);
+    assert_ne!(first.split().1, other_ctx.split().1);
+    assert!(first.inspect().unwrap().deterministic);
+
+    assert_eq!(vault.unseal_bytes::<Local>(&first, b"cas").unwrap(), b"blob contents");
+}
+
+#[test]
+fn seal_deterministic_separates_domains() {
+    let vault = setup_vault();
+
+    let local = vault.seal_deterministic::<Local>(b"same", b"cas").expect("seal failed");
+    let fleet = vault.seal_deterministic::<Fleet>(b"same", b"cas").expect("seal failed");
+
+    assert_ne!(local.as_slice(), fleet.as_slice());
+    assert!(!vault.seal_bytes::<Local>(b"same", b"cas").unwrap().inspect().unwrap().deterministic);
+}
+
+#[vault_model(tag = "v1.other")]
+struct Other {
+    value: u32,
+}
+
+#[vault_model(tag = "v1.cached")]
+struct Cached {
+    value: u32,
+    #[vault_skip]
+    cache: Vec<u8>,
+    #[serde(skip)]
+    scratch: Option<String>,
+}
+
+#[test]
+fn skipped_fields_are_not_sealed() {
+    let vault = setup_vault();
+    let cached = Cached { value: 7, cache: vec![1, 2, 3], scratch: Some("tmp".to_owned()) };
+
+    let sealed = vault.seal::<Local, _>(&cached).expect("seal failed");
+    let unsealed: Cached = vault.unseal_local(&sealed).expect("unseal failed");
+
+    assert_eq!(unsealed.value, 7);
+    assert!(unsealed.cache.is_empty());
+    assert_eq!(unsealed.scratch, None);
+    // Skipped fields take no part in equality or debug output.
+    assert_eq!(unsealed, cached);
+    assert!(!format!("{cached:?}").contains("cache:"));
+}
+
+#[vault_model(tag = "v1.command")]
+enum Command {
+    Start { id: u32, label: String },
+    Stop,
+}
+
+#[test]
+fn enums_round_trip() {
+    let vault = setup_vault();
+
+    for command in [Command::Start { id: 3, label: "north".to_owned() }, Command::Stop] {
+        let sealed = vault.seal::<Local, _>(&command).expect("seal failed");
+        let unsealed: Command = vault.unseal_local(&sealed).expect("unseal failed");
+        assert_eq!(unsealed, command);
+    }
+    assert_ne!(Command::Stop, Command::Start { id: 3, label: "north".to_owned() });
+}
+
+#[test]
+fn try_unseal_local_distinguishes_foreign_payloads() {
+    let vault = setup_vault();
+    let profile = Profile { username: "ada".to_owned(), enabled: true };
+    let sealed = vault.seal::<Local, _>(&profile).expect("seal failed");
+
+    assert_eq!(vault.try_unseal_local::<Profile>(&sealed).unwrap(), Some(profile));
+    assert_eq!(vault.try_unseal_local::<Other>(&sealed).unwrap(), None);
+    assert!(vault.try_unseal_fleet::<Profile>(&sealed).unwrap().is_none());
+
+    let other = vault.seal::<Local, _>(&Other { value: 7 }).expect("seal failed");
+    assert_eq!(vault.try_unseal_local::<Other>(&other).unwrap(), Some(Other { value: 7 }));
+}
+
+#[test]
+fn try_unseal_local_still_reports_malformed_payloads() {
+    let vault = setup_vault();
+
+    let result = vault.try_unseal_local::<Profile>([1u8, 0, 0]);
+    assert!(matches!(result, Err(VaultError::InvalidPayload { .. })));
+
+    let garbage = vault.seal_bytes::<Local>([0xFFu8; 8], b"v1.profile").unwrap();
+    let result = vault.try_unseal_local::<Profile>(&garbage);
+    assert!(matches!(result, Err(VaultError::Serialization { .. })));
+}
+
+#[test]
+fn unseal_with_aad_requires_matching_extra_aad() {
+    let vault = setup_vault();
+    let profile = Profile { username: "ada".to_owned(), enabled: true };
+
+    let sealed = vault.seal_with_aad::<Local, _>(&profile, b"tenant-a").expect("seal failed");
+
+    let unsealed: Profile = vault.unseal_with_aad::<Local, _>(&sealed, b"tenant-a").unwrap();
+    assert_eq!(unsealed, profile);
+
+    let replayed = vault.unseal_with_aad::<Local, Profile>(&sealed, b"tenant-b");
+    assert!(matches!(replayed, Err(VaultError::Decryption { .. })));
+    assert!(vault.unseal_local::<Profile>(&sealed).is_err());
+}
@@ -37,8 +37,11 @@ fn test_context_binding_security() {
 
 #[test]
 fn test_algorithm_agility_cha_cha() {
-    let vault =
-        Vault::<ChaCha>::builder().derived_keys("key", "salt", "id").unwrap().build().unwrap();
+    let vault = Vault::<ChaCha>::builder()
+        .derived_keys_unchecked("key", "salt", "id")
+        .unwrap()
+        .build()
+        .unwrap();
 
     let data = vec![1, 2, 3, 4, 5];
     let sealed = vault.seal_bytes::<Local>(data.clone(), b"test").unwrap();
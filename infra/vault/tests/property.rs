@@ -1,14 +1,77 @@
 use mhub_vault::prelude::*;
 use proptest::prelude::*;
 
+/// `[V][FLAGS]` prefix; bound into the AAD, so it is covered by the AEAD tag as well.
+const HEADER_LEN: usize = 2;
+
+fn chacha_vault() -> Vault<ChaCha> {
+    Vault::<ChaCha>::builder()
+        .derived_keys_unchecked("ikm", "salt", "machine-id")
+        .unwrap()
+        .build()
+        .unwrap()
+}
+
+fn assert_rejected(vault: &Vault<ChaCha>, payload: &[u8]) -> String {
+    match vault.unseal_bytes::<Local>(payload, b"ctx") {
+        Err(err @ VaultError::Decryption { .. }) => err.to_string(),
+        other => panic!("tampered payload must fail with VaultError::Decryption, got {other:?}"),
+    }
+}
+
+#[test]
+fn every_single_bit_flip_after_the_version_is_rejected() {
+    let vault = chacha_vault();
+    let sealed = vault.seal_bytes::<Local>(b"attack at dawn", b"ctx").unwrap();
+    let mut last = sealed.to_vec();
+    *last.last_mut().unwrap() ^= 1;
+    let expected = assert_rejected(&vault, &last);
+
+    // The version byte is covered by `unknown_version_byte_is_rejected_before_decryption`.
+    for index in 1..sealed.len() {
+        for bit in 0..8 {
+            let mut tampered = sealed.to_vec();
+            tampered[index] ^= 1 << bit;
+            // The error must not reveal where the payload was modified.
+            assert_eq!(assert_rejected(&vault, &tampered), expected, "byte {index}, bit {bit}");
+        }
+    }
+}
+
+#[test]
+fn unknown_version_byte_is_rejected_before_decryption() {
+    let vault = chacha_vault();
+    let mut sealed = vault.seal_bytes::<Local>(b"attack at dawn", b"ctx").unwrap().into_inner();
+    sealed[0] ^= 0x80;
+
+    let result = vault.unseal_bytes::<Local>(&sealed, b"ctx");
+    assert!(matches!(result, Err(VaultError::InvalidPayload { .. })));
+}
+
 proptest! {
+    #[test]
+    fn mutating_any_authenticated_byte_fails_decryption(
+        data in proptest::collection::vec(any::<u8>(), 0..512),
+        position in any::<prop::sample::Index>(),
+        mask in 1..=u8::MAX,
+    ) {
+        let vault = chacha_vault();
+        let mut sealed = vault.seal_bytes::<Local>(&data, b"ctx").unwrap().into_inner();
+        let index = position.index(sealed.len());
+        sealed[index] ^= mask;
+
+        // Header bytes may also be rejected as malformed before any decryption is attempted.
+        let result = vault.unseal_bytes::<Local>(&sealed, b"ctx");
+        prop_assert!(
+            matches!(result, Err(VaultError::Decryption { .. }))
+                || (index < HEADER_LEN && matches!(result, Err(VaultError::InvalidPayload { .. }))),
+            "byte {} ^ {:#04x} was accepted: {:?}", index, mask, result
+        );
+    }
+
     #[test]
     fn roundtrip_arbitrary_bytes_across_domains(data in proptest::collection::vec(any::<u8>(), 0..2048)) {
-        let vault = Vault::<ChaCha>::builder()
-            .derived_keys("ikm", "salt", "machine-id")
-            .unwrap()
-            .build()
-            .unwrap();
+        let vault = chacha_vault();
 
         let sealed_local = vault.seal_bytes::<Local>(&data, b"ctx").unwrap();
         let unsealed_local = vault.unseal_bytes::<Local>(&sealed_local, b"ctx").unwrap();
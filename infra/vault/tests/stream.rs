@@ -0,0 +1,118 @@
+pub mod fixtures;
+
+use fixtures::setup_vault;
+use mhub_vault::prelude::*;
+
+const CHUNK: usize = 64 * 1024;
+const STREAM_HEADER_LEN: usize = 21;
+
+fn sample(len: usize) -> Vec<u8> {
+    (0..len).map(|i| u8::try_from(i % 251).unwrap()).collect()
+}
+
+/// Returns the byte ranges of each `[KIND][LEN][FRAME]` record in a sealed stream.
+fn frame_ranges(sealed: &[u8]) -> Vec<std::ops::Range<usize>> {
+    let mut ranges = Vec::new();
+    let mut pos = STREAM_HEADER_LEN;
+    while pos < sealed.len() {
+        let len = u32::from_le_bytes(sealed[pos + 1..pos + 5].try_into().unwrap()) as usize;
+        ranges.push(pos..pos + 5 + len);
+        pos += 5 + len;
+    }
+    ranges
+}
+
+async fn seal(vault: &Vault, input: &[u8]) -> Vec<u8> {
+    let mut sealed = Vec::new();
+    let written = vault.seal_stream::<Local, _, _>(input, &mut sealed, b"backup").await.unwrap();
+    assert_eq!(written, input.len() as u64);
+    sealed
+}
+
+#[tokio::test]
+async fn stream_roundtrip_spans_multiple_frames() {
+    let vault = setup_vault();
+    let input = sample(CHUNK * 3 + 123);
+
+    let sealed = seal(&vault, &input).await;
+    assert_eq!(frame_ranges(&sealed).len(), 4);
+
+    let mut output = Vec::new();
+    let read = vault
+        .unseal_stream::<Local, _, _>(sealed.as_slice(), &mut output, b"backup")
+        .await
+        .expect("unseal failed");
+
+    assert_eq!(read, input.len() as u64);
+    assert_eq!(output, input);
+}
+
+#[tokio::test]
+async fn stream_roundtrip_handles_empty_and_exact_chunk_inputs() {
+    let vault = setup_vault();
+
+    for input in [Vec::new(), sample(CHUNK)] {
+        let sealed = seal(&vault, &input).await;
+        assert_eq!(frame_ranges(&sealed).len(), 1);
+
+        let mut output = Vec::new();
+        vault
+            .unseal_stream::<Local, _, _>(sealed.as_slice(), &mut output, b"backup")
+            .await
+            .expect("unseal failed");
+        assert_eq!(output, input);
+    }
+}
+
+#[tokio::test]
+async fn stream_rejects_truncation() {
+    let vault = setup_vault();
+    let sealed = seal(&vault, &sample(CHUNK * 2 + 1)).await;
+    let last = frame_ranges(&sealed).pop().unwrap();
+
+    let result =
+        vault.unseal_stream::<Local, _, _>(&sealed[..last.start], &mut Vec::new(), b"backup").await;
+    assert!(matches!(result, Err(VaultError::InvalidPayload { .. })));
+}
+
+#[tokio::test]
+async fn stream_rejects_reordered_frames() {
+    let vault = setup_vault();
+    let sealed = seal(&vault, &sample(CHUNK * 2 + 1)).await;
+    let ranges = frame_ranges(&sealed);
+
+    let mut reordered = sealed[..STREAM_HEADER_LEN].to_vec();
+    reordered.extend_from_slice(&sealed[ranges[1].clone()]);
+    reordered.extend_from_slice(&sealed[ranges[0].clone()]);
+    reordered.extend_from_slice(&sealed[ranges[2].clone()]);
+
+    let result =
+        vault.unseal_stream::<Local, _, _>(reordered.as_slice(), &mut Vec::new(), b"backup").await;
+    assert!(matches!(result, Err(VaultError::Decryption { .. })));
+}
+
+#[tokio::test]
+async fn stream_rejects_frames_spliced_from_another_stream() {
+    let vault = setup_vault();
+    let input = sample(CHUNK * 2 + 1);
+    let first = seal(&vault, &input).await;
+    let second = seal(&vault, &input).await;
+    let ranges = frame_ranges(&second);
+
+    let mut spliced = first[..STREAM_HEADER_LEN].to_vec();
+    spliced.extend_from_slice(&second[ranges[0].start..]);
+
+    let result =
+        vault.unseal_stream::<Local, _, _>(spliced.as_slice(), &mut Vec::new(), b"backup").await;
+    assert!(matches!(result, Err(VaultError::Decryption { .. })));
+}
+
+#[tokio::test]
+async fn stream_rejects_wrong_context() {
+    let vault = setup_vault();
+    let sealed = seal(&vault, b"small").await;
+
+    let result =
+        vault.unseal_stream::<Local, _, _>(sealed.as_slice(), &mut Vec::new(), b"other").await;
+    assert!(matches!(result, Err(VaultError::Decryption { .. })));
+}
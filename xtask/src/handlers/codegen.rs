@@ -13,6 +13,8 @@ use std::path::{Path, PathBuf};
 const MANIFEST_PATH: &str = "infra/database/src/generated/migrations_manifest.rs";
 const INFRA_DIR: &str = "infra";
 const FEATURE_DIR: &str = "crates/features";
+const DOWN_SUFFIX: &str = ".down.surql";
+const BOOTSTRAP_MIGRATION: &str = "0000-bootstrap";
 
 // --- Public API ---
 
@@ -22,6 +24,9 @@ const FEATURE_DIR: &str = "crates/features";
 /// migration scripts across the Vertical Slice Architecture, resolves their dependencies,
 /// and produces a static Rust source file containing the execution plan.
 ///
+/// A `0000-name.down.surql` file next to `0000-name.surql` is embedded as that migration's
+/// down script, which the runner uses to roll the migration back.
+///
 /// # Returns
 /// * `Ok(())` - If the manifest was successfully generated and written to disk.
 /// * `Err` - If any step of the discovery, resolution, or I/O fails.
@@ -33,6 +38,7 @@ const FEATURE_DIR: &str = "crates/features";
 /// - A crate depends on a non-existent crate.
 /// - File I/O fails (permissions, missing paths).
 /// - Migration filenames do not adhere to the `0000-name` format.
+/// - A down script has no matching migration.
 pub fn codegen_migrations() -> Result<()> {
     let project_root = get_project_root()?;
     let manifest_path = project_root.join(MANIFEST_PATH);
@@ -61,6 +67,51 @@ pub fn codegen_migrations() -> Result<()> {
     Ok(())
 }
 
+/// Checks every slice's migrations without regenerating the manifest.
+///
+/// Runs the same filename, down-script, and transaction checks as [`codegen_migrations`],
+/// plus rules that only matter before a migration ships:
+/// - two migrations in one slice share a version number;
+/// - the bootstrap crate has no `0000-bootstrap` migration (warning);
+/// - a script defines a namespace, which the migration runner manages.
+///
+/// # Errors
+/// Returns an error if any rule reports an error-level finding, or if a crate's
+/// migrations cannot be read.
+pub fn lint_migrations() -> Result<()> {
+    let mut findings = Vec::new();
+    let mut crates = 0;
+
+    for dir in [INFRA_DIR, FEATURE_DIR] {
+        for crate_info in get_workspace_crates(dir)? {
+            let migrations_dir = crate_info.path.join("migrations");
+            if !migrations_dir.exists() {
+                continue;
+            }
+            let config = load_migration_config(&crate_info.path)?;
+            findings.extend(lint_migration_dir(&migrations_dir, config.bootstrap)?);
+            crates += 1;
+        }
+    }
+
+    for finding in &findings {
+        let icon = match finding.severity {
+            Severity::Error => "❌",
+            Severity::Warning => "⚠️",
+        };
+        println!("{icon} {}: {}", finding.path.display(), finding.message);
+    }
+
+    let errors = findings.iter().filter(|f| f.severity == Severity::Error).count();
+    let warnings = findings.len() - errors;
+    if errors > 0 {
+        anyhow::bail!("Migration lint failed: {errors} error(s), {warnings} warning(s)");
+    }
+
+    println!("✅ Linted migrations in {crates} crates: {warnings} warning(s).");
+    Ok(())
+}
+
 // --- Domain Models ---
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -170,6 +221,114 @@ fn resolve_execution_order(nodes: Vec<MigrationNode>) -> Result<Vec<MigrationNod
     Ok(sorted)
 }
 
+// --- Logic: Linting ---
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Severity {
+    Error,
+    Warning,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct LintFinding {
+    severity: Severity,
+    path: PathBuf,
+    message: String,
+}
+
+impl LintFinding {
+    fn error(path: &Path, message: impl Into<String>) -> Self {
+        Self { severity: Severity::Error, path: path.to_path_buf(), message: message.into() }
+    }
+
+    fn warning(path: &Path, message: impl Into<String>) -> Self {
+        Self { severity: Severity::Warning, path: path.to_path_buf(), message: message.into() }
+    }
+}
+
+/// Applies every lint rule to one slice's `migrations` directory.
+fn lint_migration_dir(dir: &Path, is_bootstrap: bool) -> Result<Vec<LintFinding>> {
+    let mut paths: Vec<PathBuf> = fs::read_dir(dir)?
+        .map(|entry| entry.map(|e| e.path()))
+        .collect::<Result<_, _>>()
+        .with_context(|| format!("Failed to read {}", dir.display()))?;
+    paths.retain(|path| path.extension().map_or(false, |e| e == "surql"));
+    paths.sort();
+
+    let (downs, files): (Vec<PathBuf>, Vec<PathBuf>) =
+        paths.into_iter().partition(|path| is_down_script(path));
+    let mut findings = Vec::new();
+
+    let mut versions: BTreeMap<String, &Path> = BTreeMap::new();
+    for file in &files {
+        let Ok(stem) = extract_version(file) else {
+            findings
+                .push(LintFinding::error(file, "Invalid filename; rename it to '0000-name.surql'"));
+            continue;
+        };
+        let number = stem.split('-').next().unwrap_or_default().to_owned();
+        if let Some(first) = versions.insert(number.clone(), file) {
+            findings.push(LintFinding::error(
+                file,
+                format!(
+                    "Version {number} is already used by '{}'; give each migration a unique number",
+                    first.display()
+                ),
+            ));
+        }
+    }
+
+    for path in files.iter().chain(&downs) {
+        let content = fs::read_to_string(path)?;
+        if uses_manual_transactions(&content) {
+            findings.push(LintFinding::error(
+                path,
+                "Manual transaction control; remove BEGIN/COMMIT, the runner wraps each migration",
+            ));
+        }
+        if defines_namespace(&content) {
+            findings.push(LintFinding::error(
+                path,
+                "DEFINE NAMESPACE is managed by the migration runner; remove it",
+            ));
+        }
+    }
+
+    for down in &downs {
+        if !files.iter().any(|file| down_script_path(file) == *down) {
+            findings.push(LintFinding::error(
+                down,
+                "Down script has no matching migration; rename it after its '0000-name.surql'",
+            ));
+        }
+    }
+
+    if is_bootstrap
+        && !files
+            .iter()
+            .any(|file| file.file_stem().and_then(|s| s.to_str()) == Some(BOOTSTRAP_MIGRATION))
+    {
+        findings.push(LintFinding::warning(
+            dir,
+            format!("Bootstrap crate has no '{BOOTSTRAP_MIGRATION}.surql' migration"),
+        ));
+    }
+
+    Ok(findings)
+}
+
+fn uses_manual_transactions(content: &str) -> bool {
+    let s = content.to_lowercase();
+    s.contains("begin transaction") || s.contains("commit transaction")
+}
+
+fn defines_namespace(content: &str) -> bool {
+    let s = content.to_lowercase();
+    let words: Vec<&str> =
+        s.split(|c: char| c.is_whitespace() || c == ';').filter(|w| !w.is_empty()).collect();
+    words.windows(2).any(|pair| pair[0] == "define" && matches!(pair[1], "namespace" | "ns"))
+}
+
 // --- Logic: Utils ---
 
 #[derive(Debug, Eq, PartialEq)]
@@ -285,6 +444,12 @@ fn render_entry(w: &mut String, node: &MigrationNode, file: &Path, root: &Path)
     let version = extract_version(file)?;
     let rel_path = resolve_relative_path(file, root)?;
     let checksum = calculate_checksum(file)?;
+    let down = down_script_path(file);
+    let down = if down.exists() {
+        format!("Some(include_str!(\"{}\"))", resolve_relative_path(&down, root)?)
+    } else {
+        "None".to_owned()
+    };
     let desc = node
         .description
         .as_deref()
@@ -296,6 +461,7 @@ fn render_entry(w: &mut String, node: &MigrationNode, file: &Path, root: &Path)
     writeln!(w, "            {desc},")?;
     writeln!(w, "            \"{}\",", escape_str(&version))?;
     writeln!(w, "            include_str!(\"{rel_path}\"),")?;
+    writeln!(w, "            {down},")?;
     writeln!(w, "            \"{checksum}\",")?;
     writeln!(w, "            {},", node.is_bootstrap)?;
     writeln!(w, "        ),")?;
@@ -362,22 +528,43 @@ fn ensure_parent_dir(path: &Path) -> Result<()> {
     Ok(())
 }
 
+/// Collects the forward migrations in `dir`; down scripts are validated but not returned.
 fn read_surql_files(dir: &Path) -> Result<Vec<PathBuf>> {
     let mut files = Vec::new();
+    let mut downs = Vec::new();
     for entry in fs::read_dir(dir)? {
         let path = entry?.path();
         if path.extension().map_or(false, |e| e == "surql") {
             validate_sql_content(&path)?;
-            files.push(path);
+            if is_down_script(&path) { downs.push(path) } else { files.push(path) }
+        }
+    }
+
+    for down in downs {
+        if !files.iter().any(|file| down_script_path(file) == down) {
+            return Err(anyhow::anyhow!(
+                "Down script '{}' has no matching migration",
+                down.display()
+            ));
         }
     }
+
     files.sort();
     Ok(files)
 }
 
+fn is_down_script(path: &Path) -> bool {
+    path.file_name().and_then(|n| n.to_str()).map_or(false, |n| n.ends_with(DOWN_SUFFIX))
+}
+
+/// Returns the path of the down script paired with the migration at `path`.
+fn down_script_path(path: &Path) -> PathBuf {
+    let stem = path.file_stem().and_then(|s| s.to_str()).unwrap_or_default();
+    path.with_file_name(format!("{stem}{DOWN_SUFFIX}"))
+}
+
 fn validate_sql_content(path: &Path) -> Result<()> {
-    let s = fs::read_to_string(path)?.to_lowercase();
-    if s.contains("begin transaction") || s.contains("commit transaction") {
+    if uses_manual_transactions(&fs::read_to_string(path)?) {
         return Err(anyhow::anyhow!(
             "Manual transaction control prohibited in '{}'. The migration runner handles transactions.",
             path.display()
@@ -500,4 +687,99 @@ mod tests {
 
         assert_eq!(keys, vec!["sys.boot", "sys.core", "feat.a"]);
     }
+
+    #[test]
+    fn test_down_scripts_are_paired_with_migrations() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(dir.path().join("0000-init.surql"), "DEFINE TABLE a;").unwrap();
+        fs::write(dir.path().join("0000-init.down.surql"), "REMOVE TABLE a;").unwrap();
+        fs::write(dir.path().join("0001-extra.surql"), "DEFINE TABLE b;").unwrap();
+
+        let files = read_surql_files(dir.path()).unwrap();
+        assert_eq!(files.len(), 2);
+        assert!(down_script_path(&files[0]).exists());
+        assert!(!down_script_path(&files[1]).exists());
+
+        fs::write(dir.path().join("0002-orphan.down.surql"), "REMOVE TABLE c;").unwrap();
+        let err = read_surql_files(dir.path()).unwrap_err();
+        assert!(err.to_string().contains("no matching migration"));
+    }
+
+    fn lint(files: &[(&str, &str)], is_bootstrap: bool) -> Vec<LintFinding> {
+        let dir = tempfile::tempdir().unwrap();
+        for (name, content) in files {
+            fs::write(dir.path().join(name), content).unwrap();
+        }
+        lint_migration_dir(dir.path(), is_bootstrap).unwrap()
+    }
+
+    fn messages(findings: &[LintFinding], severity: Severity) -> Vec<&str> {
+        findings.iter().filter(|f| f.severity == severity).map(|f| f.message.as_str()).collect()
+    }
+
+    #[test]
+    fn test_lint_accepts_clean_migrations() {
+        let findings = lint(
+            &[
+                ("0000-bootstrap.surql", "DEFINE TABLE a;"),
+                ("0001-users.surql", "DEFINE TABLE b;"),
+                ("0001-users.down.surql", "REMOVE TABLE b;"),
+            ],
+            true,
+        );
+        assert!(findings.is_empty(), "{findings:?}");
+    }
+
+    #[test]
+    fn test_lint_rejects_invalid_filenames() {
+        let findings = lint(&[("init.surql", "DEFINE TABLE a;")], false);
+        assert!(messages(&findings, Severity::Error)[0].contains("Invalid filename"));
+    }
+
+    #[test]
+    fn test_lint_rejects_manual_transactions() {
+        let findings =
+            lint(&[("0000-init.surql", "BEGIN TRANSACTION; COMMIT TRANSACTION;")], false);
+        assert!(messages(&findings, Severity::Error)[0].contains("Manual transaction"));
+    }
+
+    #[test]
+    fn test_lint_rejects_orphan_down_scripts() {
+        let findings = lint(
+            &[("0000-init.surql", "DEFINE TABLE a;"), ("0001-gone.down.surql", "REMOVE TABLE b;")],
+            false,
+        );
+        assert!(messages(&findings, Severity::Error)[0].contains("no matching migration"));
+    }
+
+    #[test]
+    fn test_lint_rejects_duplicate_versions() {
+        let findings = lint(
+            &[("0001-users.surql", "DEFINE TABLE a;"), ("0001-roles.surql", "DEFINE TABLE b;")],
+            false,
+        );
+        let errors = messages(&findings, Severity::Error);
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].contains("Version 0001 is already used"));
+    }
+
+    #[test]
+    fn test_lint_warns_on_missing_bootstrap_migration() {
+        let files = [("0000-init.surql", "DEFINE TABLE a;")];
+
+        let findings = lint(&files, true);
+        assert!(messages(&findings, Severity::Error).is_empty());
+        assert!(messages(&findings, Severity::Warning)[0].contains(BOOTSTRAP_MIGRATION));
+
+        assert!(lint(&files, false).is_empty());
+    }
+
+    #[test]
+    fn test_lint_rejects_namespace_definitions() {
+        for sql in ["DEFINE NAMESPACE mhub;", "define\n  ns mhub;"] {
+            let findings = lint(&[("0000-init.surql", sql)], false);
+            assert!(messages(&findings, Severity::Error)[0].contains("DEFINE NAMESPACE"), "{sql}");
+        }
+        assert!(!defines_namespace("DEFINE TABLE namespace_audit;"));
+    }
 }
@@ -1,9 +1,14 @@
-use crate::models::keyset::Keyset;
+use anyhow::Context;
+use mhub_licensing::constraints::{
+    HwidProfile, current_machine_components, matching_components, parse_machine_id_compound,
+};
 use mhub_licensing::generator::{
-    UniversalLicenseConfig, generate_secret, generate_universal_license,
+    UniversalLicenseConfig, generate_secret, load_signing_key, sign_with_key,
 };
 use mhub_licensing::{MachineConstraint, SignedLicense};
 use std::fs;
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
 
 /// Generates a license file.
 ///
@@ -19,6 +24,7 @@ pub fn generate_license(
     min_matches: u16,
     features: &str,
     days: u64,
+    seats: Option<u32>,
 ) -> anyhow::Result<()> {
     fs::create_dir_all("private/licenses").ok();
 
@@ -50,15 +56,13 @@ pub fn generate_license(
         constraint,
         days,
         features: feature_list,
+        feature_days: Vec::new(),
+        max_seats: seats,
         salt,
     };
 
-    let keyset =
-        fs::read("private/keyset").map_err(|e| anyhow::anyhow!("Failed to read keyset: {e}"))?;
-    let keyset: Keyset = postcard::from_bytes(&keyset)
-        .map_err(|e| anyhow::anyhow!("Failed to deserialize keyset: {e}"))?;
-
-    let signed = generate_universal_license(&keyset.master_key, config)?;
+    let master_key = load_signing_key("private/keyset")?;
+    let signed = sign_with_key(master_key, config)?;
 
     let bytes = signed.encode_bin()?;
     fs::write(format!("private/licenses/{alias}.lic"), bytes)?;
@@ -67,3 +71,110 @@ pub fn generate_license(
 
     Ok(())
 }
+
+/// Verifies a license file against a public key and prints what it grants.
+///
+/// The license may be in the binary format written by `lic` or in JSON. The public key may
+/// be a raw 32-byte key, the 64-byte `private/keyset` (master key followed by public key),
+/// or the key as a hex string.
+///
+/// # Result
+/// Returns `Ok(())` if the license is authentic, unexpired, and matches this machine.
+///
+/// # Errors
+/// Returns an error if a file cannot be read or parsed, or if the license fails validation.
+pub fn verify_license(file: &Path, pubkey: &Path) -> anyhow::Result<()> {
+    let license = read_license(file)?;
+    let public_key = read_public_key(pubkey)?;
+    let data = &license.data;
+
+    let features: Vec<String> =
+        license.active_features().iter_names().map(|(name, _)| name.to_lowercase()).collect();
+
+    println!("📄 License {}", license.license_id());
+    println!("   Customer: {}", data.customer);
+    println!("   Alias:    {}", data.alias);
+    println!(
+        "   Features: {}",
+        if features.is_empty() { "none".to_owned() } else { features.join(", ") }
+    );
+    println!("   Expires:  {} ({})", data.expires, remaining(data.expires));
+    for (feature, expires) in &data.feature_expiry {
+        println!("     {feature} expires: {expires} ({})", remaining(*expires));
+    }
+    println!(
+        "   Seats:    {}",
+        data.max_seats.map_or_else(|| "unlimited".to_owned(), |seats| seats.to_string())
+    );
+    println!("   Hardware: {}", hardware_status(&data.constraint));
+
+    match license.validate(&public_key) {
+        Ok(()) => {
+            println!("✅ License is valid");
+            Ok(())
+        },
+        Err(e) => anyhow::bail!("❌ License is invalid: {e}"),
+    }
+}
+
+fn read_license(path: &Path) -> anyhow::Result<SignedLicense> {
+    let bytes = fs::read(path)
+        .with_context(|| format!("Failed to read license file {}", path.display()))?;
+
+    let json = std::str::from_utf8(&bytes).ok().filter(|text| text.trim_start().starts_with('{'));
+    match json {
+        Some(json) => SignedLicense::from_json(json),
+        None => SignedLicense::decode_bin(&bytes),
+    }
+    .map_err(|e| anyhow::anyhow!("Failed to parse license file {}: {e}", path.display()))
+}
+
+fn read_public_key(path: &Path) -> anyhow::Result<[u8; 32]> {
+    let bytes =
+        fs::read(path).with_context(|| format!("Failed to read public key {}", path.display()))?;
+
+    // Hex is recognized before the binary lengths: a hex key is exactly 64 ASCII bytes.
+    let hex = std::str::from_utf8(&bytes)
+        .map(str::trim)
+        .ok()
+        .filter(|text| !text.is_empty() && text.bytes().all(|b| b.is_ascii_hexdigit()));
+    let key = match (hex, bytes.len()) {
+        (Some(hex), _) => hex::decode(hex)
+            .with_context(|| format!("Public key {} is not valid hex", path.display()))?,
+        (None, 32) => bytes,
+        (None, 64) => bytes[32..].to_vec(),
+        (None, _) => {
+            anyhow::bail!("Public key {} is not 32 bytes, a 64-byte keyset, or hex", path.display())
+        },
+    };
+    key.try_into().map_err(|_| anyhow::anyhow!("Public key {} is not 32 bytes", path.display()))
+}
+
+/// Describes the time left until a Unix timestamp.
+fn remaining(expires: i64) -> String {
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs().cast_signed());
+    let days = (expires - now) / 86_400;
+    if expires < now { format!("expired {} days ago", -days) } else { format!("in {days} days") }
+}
+
+/// Reports whether this machine satisfies the license's hardware constraint.
+fn hardware_status(constraint: &MachineConstraint) -> String {
+    let MachineConstraint::Threshold { ids, min_matches } = constraint else {
+        return "site license (any machine)".to_owned();
+    };
+
+    let current = match current_machine_components(&HwidProfile::extended()) {
+        Ok(current) => current,
+        Err(e) => return format!("unknown, cannot fingerprint this machine: {e}"),
+    };
+    let best = ids
+        .iter()
+        .filter_map(|id| parse_machine_id_compound(id).ok())
+        .map(|allowed| matching_components(&allowed, &current))
+        .max()
+        .unwrap_or(0);
+    let verdict = if best >= usize::from(*min_matches) { "matches" } else { "MISMATCH" };
+    format!("{verdict} ({best} of {min_matches} required components, {} machines)", ids.len())
+}
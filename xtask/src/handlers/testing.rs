@@ -1,24 +1,37 @@
-use crate::services::utils::normalize_project_name;
+use crate::services::utils::{get_workspace_members, normalize_project_name};
+use std::process::{Command, Output};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc;
+use std::thread;
+use std::time::{Duration, Instant};
 
 /// Runs tests in the workspace or a specific crate.
 ///
+/// With `parallel`, workspace runs test each member crate in its own `cargo` invocation, up
+/// to `parallel` at a time, and print a summary table. `filter` is forwarded to the test
+/// runner as the test-name pattern.
+///
 /// # Result
 /// Returns an `anyhow::Result<()>` indicating success or failure of the test run.
 ///
 /// # Errors
 /// Returns an error if the test execution fails or if the test runner is not found.
-pub fn run_tests(project: Option<&str>) -> anyhow::Result<()> {
+pub fn run_tests(
+    project: Option<&str>,
+    parallel: Option<usize>,
+    filter: Option<&str>,
+) -> anyhow::Result<()> {
     let target_is_workspace = project.map_or(true, |value| value == "all");
     let target_label = if target_is_workspace { "workspace" } else { "crate" };
 
     println!("🧪 Running {target_label} tests...");
-    let has_nextest = std::process::Command::new("cargo-nextest").arg("--version").output().is_ok();
+    let has_nextest = Command::new("cargo-nextest").arg("--version").output().is_ok();
 
-    let mut args: Vec<String> = if has_nextest {
-        vec!["nextest", "run"].into_iter().map(String::from).collect()
-    } else {
-        vec!["test"].into_iter().map(String::from).collect()
-    };
+    if target_is_workspace && let Some(jobs) = parallel {
+        return run_tests_parallel(jobs, has_nextest, filter);
+    }
+
+    let mut args = runner_args(has_nextest);
 
     if target_is_workspace {
         args.push("--workspace".into());
@@ -29,8 +42,127 @@ pub fn run_tests(project: Option<&str>) -> anyhow::Result<()> {
     }
 
     args.push("--all-features".into());
+    if !has_nextest {
+        args.extend(["--tests", "--lib", "bins"].into_iter().map(String::from));
+    }
+    args.extend(trailing_args(has_nextest, filter));
+
+    println!("🧪 Running tests via '{}'...", if has_nextest { "nextest" } else { "cargo test" });
+    let status = Command::new("cargo").args(args).status()?;
+
+    if !status.success() {
+        anyhow::bail!("Tests failed!");
+    }
+    Ok(())
+}
+
+#[derive(Debug)]
+struct CrateTestResult {
+    name: String,
+    passed: bool,
+    elapsed: Duration,
+}
+
+/// Tests every workspace member separately with at most `jobs` concurrent `cargo` processes.
+fn run_tests_parallel(jobs: usize, has_nextest: bool, filter: Option<&str>) -> anyhow::Result<()> {
+    let crates = get_workspace_members()?;
+    let jobs = jobs.clamp(1, crates.len().max(1));
+    println!("🧪 Testing {} crates with {jobs} parallel jobs...", crates.len());
+
+    let next = AtomicUsize::new(0);
+    let (tx, rx) = mpsc::channel();
+    thread::scope(|scope| {
+        for _ in 0..jobs {
+            let tx = tx.clone();
+            let (crates, next) = (&crates, &next);
+            scope.spawn(move || {
+                while let Some(name) = crates.get(next.fetch_add(1, Ordering::Relaxed)) {
+                    let result = run_crate_tests(name, has_nextest, filter);
+                    if tx.send(result).is_err() {
+                        return;
+                    }
+                }
+            });
+        }
+    });
+    drop(tx);
+
+    let mut results: Vec<CrateTestResult> = rx.into_iter().collect();
+    results.sort_by(|a, b| a.name.cmp(&b.name));
+    render_summary(&results);
+
+    let failed: Vec<&str> =
+        results.iter().filter(|result| !result.passed).map(|result| result.name.as_str()).collect();
+    if !failed.is_empty() {
+        anyhow::bail!("Tests failed in {} crate(s): {}", failed.len(), failed.join(", "));
+    }
+    Ok(())
+}
 
+/// Runs one crate's tests with captured output, printing it only if the crate fails.
+fn run_crate_tests(name: &str, has_nextest: bool, filter: Option<&str>) -> CrateTestResult {
+    let mut args = runner_args(has_nextest);
+    args.extend(["-p", name, "--all-features"].into_iter().map(String::from));
     if has_nextest {
+        // Crates without tests (macros, binaries) must not fail the whole run.
+        args.push("--no-tests=pass".into());
+    } else {
+        // Per-crate runs cannot use `--lib`, which fails for binary-only crates.
+        args.push("--tests".into());
+    }
+    args.extend(trailing_args(has_nextest, filter));
+
+    let started = Instant::now();
+    let output = Command::new("cargo").args(args).output();
+    let elapsed = started.elapsed();
+
+    let passed = match output {
+        Ok(Output { status, stdout, stderr }) => {
+            if !status.success() {
+                println!("❌ {name} failed:\n{}", String::from_utf8_lossy(&stdout));
+                eprintln!("{}", String::from_utf8_lossy(&stderr));
+            }
+            status.success()
+        },
+        Err(e) => {
+            eprintln!("❌ {name}: failed to start cargo: {e}");
+            false
+        },
+    };
+
+    CrateTestResult { name: name.to_owned(), passed, elapsed }
+}
+
+fn render_summary(results: &[CrateTestResult]) {
+    println!("\nTest summary:\n");
+    println!("{:<30} {:<8} {:>10}", "Crate", "Result", "Time");
+    println!("{:-<50}", "");
+
+    for result in results {
+        let status = if result.passed { "ok" } else { "FAILED" };
+        println!("{:<30} {:<8} {:>9.1}s", result.name, status, result.elapsed.as_secs_f64());
+    }
+    println!();
+}
+
+/// Returns the `cargo` subcommand for the selected runner.
+fn runner_args(has_nextest: bool) -> Vec<String> {
+    if has_nextest {
+        vec!["nextest", "run"].into_iter().map(String::from).collect()
+    } else {
+        vec!["test"].into_iter().map(String::from).collect()
+    }
+}
+
+/// Returns the runner's output options together with the test-name filter.
+///
+/// `cargo test` gets the filter after `--`, where it does not collide with a positional
+/// argument already on the command line.
+fn trailing_args(has_nextest: bool, filter: Option<&str>) -> Vec<String> {
+    let mut args = Vec::new();
+
+    if has_nextest {
+        args.extend(filter.map(String::from));
         args.extend(
             [
                 "--failure-output",
@@ -44,16 +176,10 @@ pub fn run_tests(project: Option<&str>) -> anyhow::Result<()> {
             .map(String::from),
         );
     } else {
-        args.extend(["--tests", "--lib", "bins", "--", "-q"].into_iter().map(String::from));
+        args.extend(["--", "-q"].into_iter().map(String::from));
+        args.extend(filter.map(String::from));
     }
-
-    println!("🧪 Running tests via '{}'...", if has_nextest { "nextest" } else { "cargo test" });
-    let status = std::process::Command::new("cargo").args(args).status()?;
-
-    if !status.success() {
-        anyhow::bail!("Tests failed!");
-    }
-    Ok(())
+    args
 }
 
 /// Runs doc tests in the workspace or a specific crate.
@@ -96,3 +222,18 @@ pub fn run_doctests(project: Option<&str>) -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_filter_is_forwarded_before_runner_options() {
+        assert_eq!(trailing_args(false, Some("login")), ["login", "--", "-q"]);
+        assert_eq!(trailing_args(false, None), ["--", "-q"]);
+
+        let nextest = trailing_args(true, Some("login"));
+        assert_eq!(nextest[0], "login");
+        assert!(!nextest.contains(&"--".to_owned()));
+    }
+}
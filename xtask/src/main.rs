@@ -12,10 +12,10 @@ pub mod services;
 
 use crate::handlers::{apps, bench, codegen, dev, features, libs, profiling, run, setup, testing};
 use crate::models::args::{
-    AppAction, AppCommands, Cli, CodegenAction, FeatureAction, LibraryAction,
+    AppAction, AppCommands, Cli, CodegenAction, FeatureAction, LibraryAction, LicenseAction,
 };
 
-use anyhow::Result;
+use anyhow::{Context, Result};
 use clap::Parser;
 
 fn main() -> Result<()> {
@@ -38,15 +38,32 @@ fn main() -> Result<()> {
         AppCommands::Dev { action } => dev::handle_dev_command(action)?,
         AppCommands::Codegen { action } => match action {
             CodegenAction::Migrations {} => codegen::codegen_migrations()?,
+            CodegenAction::LintMigrations {} => codegen::lint_migrations()?,
+        },
+        AppCommands::Test { project, parallel, filter } => {
+            testing::run_tests(project.as_deref(), parallel.map(usize::from), filter.as_deref())?;
         },
-        AppCommands::Test { project } => testing::run_tests(project.as_deref())?,
         AppCommands::Doctest { project } => testing::run_doctests(project.as_deref())?,
         AppCommands::Run { project } => run::run_project(&project)?,
         AppCommands::Bench { project } => bench::run_bench(&project)?,
         AppCommands::Profiling { project } => profiling::run_profiling(&project)?,
-        AppCommands::Lic { customer, alias, machines, matches, features, days } => {
+        AppCommands::Lic { action: Some(LicenseAction::Verify { file, pubkey }), .. } => {
+            handlers::license::verify_license(&file, &pubkey)?;
+        },
+        AppCommands::Lic {
+            action: None,
+            customer,
+            alias,
+            machines,
+            matches,
+            features,
+            days,
+            seats,
+        } => {
+            let customer = customer.context("--customer is required")?;
+            let alias = alias.context("--alias is required")?;
             handlers::license::generate_license(
-                &customer, &alias, &machines, matches, &features, days,
+                &customer, &alias, &machines, matches, &features, days, seats,
             )?;
         },
     }
@@ -4,6 +4,7 @@
 //! It specifies the available subcommands, arguments, and flags for the application.
 
 use clap::{Parser, Subcommand};
+use std::path::PathBuf;
 
 /// The main CLI structure parsing command-line arguments.
 #[derive(Debug, Parser)]
@@ -53,6 +54,14 @@ pub enum AppCommands {
     Test {
         /// Run tests for a specific crate (auto-prefixes with 'mhub-' if missing)
         project: Option<String>,
+
+        /// Test workspace crates separately, running up to N of them at once
+        #[arg(long, value_name = "N", value_parser = clap::value_parser!(u16).range(1..))]
+        parallel: Option<u16>,
+
+        /// Only run tests whose name contains this pattern
+        #[arg(long, value_name = "PATTERN")]
+        filter: Option<String>,
     },
     /// Run doc tests (workspace by default)
     Doctest {
@@ -75,14 +84,18 @@ pub enum AppCommands {
         project: String,
     },
     /// Universal License Generator
+    #[command(args_conflicts_with_subcommands = true, subcommand_negates_reqs = true)]
     Lic {
+        #[command(subcommand)]
+        action: Option<LicenseAction>,
+
         /// The name of the customer (e.g., '`MusterHub` Inc.')
-        #[arg(short, long)]
-        customer: String,
+        #[arg(short, long, required = true)]
+        customer: Option<String>,
 
         /// Short alias for namespaces and resource naming.
-        #[arg(short, long)]
-        alias: String,
+        #[arg(short, long, required = true)]
+        alias: Option<String>,
 
         /// Comma-separated list of IDs, or use 'ANY' for site license
         #[arg(short, long, default_value = "ANY")]
@@ -98,6 +111,10 @@ pub enum AppCommands {
 
         #[arg(short, long, default_value_t = 365)]
         days: u64,
+
+        /// Maximum number of concurrently activated machines (unlimited if omitted)
+        #[arg(long)]
+        seats: Option<u32>,
     },
 }
 
@@ -152,9 +169,26 @@ pub enum DevAction {
     },
 }
 
+/// Enumeration of license commands; without one, `lic` generates a license.
+#[derive(Debug, Subcommand)]
+pub enum LicenseAction {
+    /// Verify a license file and print its contents
+    Verify {
+        /// License file, binary (`.lic`) or JSON
+        #[arg(short, long)]
+        file: PathBuf,
+
+        /// Public key: raw 32 bytes, the `private/keyset` file, or a hex string
+        #[arg(short, long)]
+        pubkey: PathBuf,
+    },
+}
+
 /// Enumeration of codegen commands.
 #[derive(Debug, Subcommand)]
 pub enum CodegenAction {
     /// Generate a hardcoded migration manifest
     Migrations {},
+    /// Check migrations across all slices without regenerating the manifest
+    LintMigrations {},
 }
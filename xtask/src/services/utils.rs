@@ -71,6 +71,44 @@ pub fn get_workspace_crates(sub_dir: &str) -> Result<Vec<CrateInfo>> {
     Ok(crates)
 }
 
+#[derive(Debug, Deserialize)]
+struct WorkspaceManifest {
+    workspace: WorkspaceSection,
+}
+
+#[derive(Debug, Deserialize)]
+struct WorkspaceSection {
+    members: Vec<String>,
+}
+
+/// Lists the package names of all workspace members, expanding `dir/*` globs.
+///
+/// # Result
+/// Returns the package names in manifest order (globbed directories sorted by folder).
+///
+/// # Errors
+/// Returns an error if the workspace manifest or a member's `Cargo.toml` cannot be read
+/// or parsed.
+pub fn get_workspace_members() -> Result<Vec<String>> {
+    let project_root = get_project_root()?;
+    let content = fs::read_to_string(project_root.join("Cargo.toml"))?;
+    let manifest: WorkspaceManifest = toml::from_str(&content)?;
+
+    let mut names = Vec::new();
+    for member in manifest.workspace.members {
+        if let Some(sub_dir) = member.strip_suffix("/*") {
+            names.extend(get_workspace_crates(sub_dir)?.into_iter().map(|info| info.package.name));
+        } else {
+            let content = fs::read_to_string(project_root.join(&member).join("Cargo.toml"))
+                .with_context(|| format!("Failed to read manifest of member '{member}'"))?;
+            let info: CrateInfo = toml::from_str(&content)?;
+            names.push(info.package.name);
+        }
+    }
+
+    Ok(names)
+}
+
 /// Prints a formatted table of crates with their folder, name, and description.
 ///
 /// # Result
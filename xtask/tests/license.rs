@@ -0,0 +1,52 @@
+use assert_cmd::Command;
+use mhub_licensing::generator::generate_keypair;
+use predicates::str::contains;
+use std::fs;
+
+#[test]
+fn generated_license_verifies_end_to_end() {
+    let dir = tempfile::tempdir().unwrap();
+    let (signing, verifying) = generate_keypair().unwrap();
+    fs::create_dir_all(dir.path().join("private")).unwrap();
+    let keyset = [signing.to_bytes(), verifying.to_bytes()].concat();
+    fs::write(dir.path().join("private/keyset"), keyset).unwrap();
+
+    Command::cargo_bin("xtask")
+        .unwrap()
+        .current_dir(dir.path())
+        .args(["lic", "--customer", "Acme Corp", "--alias", "acme", "--features", "quiz"])
+        .assert()
+        .success();
+
+    Command::cargo_bin("xtask")
+        .unwrap()
+        .current_dir(dir.path())
+        .args(["lic", "verify", "--file", "private/licenses/acme.lic", "--pubkey"])
+        .arg("private/keyset")
+        .assert()
+        .success()
+        .stdout(contains("Acme Corp"))
+        .stdout(contains("quiz"))
+        .stdout(contains("site license"))
+        .stdout(contains("License is valid"));
+
+    fs::write(dir.path().join("master.pub"), format!("{}\n", hex::encode(verifying.to_bytes())))
+        .unwrap();
+    Command::cargo_bin("xtask")
+        .unwrap()
+        .current_dir(dir.path())
+        .args(["lic", "verify", "--file", "private/licenses/acme.lic", "--pubkey", "master.pub"])
+        .assert()
+        .success()
+        .stdout(contains("License is valid"));
+
+    let (_, other) = generate_keypair().unwrap();
+    fs::write(dir.path().join("other.pub"), hex::encode(other.to_bytes())).unwrap();
+    Command::cargo_bin("xtask")
+        .unwrap()
+        .current_dir(dir.path())
+        .args(["lic", "verify", "--file", "private/licenses/acme.lic", "--pubkey", "other.pub"])
+        .assert()
+        .failure()
+        .stderr(contains("License is invalid"));
+}